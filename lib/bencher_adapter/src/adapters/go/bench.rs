@@ -1,11 +1,10 @@
 use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonMetric};
 use nom::{
-    branch::alt,
     bytes::complete::{tag, take_till1},
-    character::complete::{anychar, space1},
+    character::complete::space1,
     combinator::{eof, map, map_res},
-    multi::many_till,
-    sequence::tuple,
+    multi::many0,
+    sequence::{preceded, tuple},
     IResult,
 };
 
@@ -13,7 +12,7 @@ use crate::{
     adapters::util::{
         latency_as_nanos, parse_benchmark_name, parse_f64, parse_u64, parse_units, NomError,
     },
-    results::adapter_results::AdapterResults,
+    results::adapter_results::{AdapterMeasure, AdapterResults},
     Adaptable, Settings,
 };
 
@@ -36,11 +35,11 @@ impl Adaptable for AdapterGoBench {
             }
         }
 
-        AdapterResults::new_latency(benchmark_metrics)
+        AdapterResults::new_multi(benchmark_metrics)
     }
 }
 
-fn parse_go(input: &str) -> IResult<&str, (BenchmarkName, JsonMetric)> {
+fn parse_go(input: &str) -> IResult<&str, (BenchmarkName, Vec<AdapterMeasure>)> {
     map_res(
         tuple((
             take_till1(|c| c == ' ' || c == '\t'),
@@ -48,17 +47,18 @@ fn parse_go(input: &str) -> IResult<&str, (BenchmarkName, JsonMetric)> {
             parse_u64,
             space1,
             parse_go_bench,
-            alt((
-                map(eof, |_| ()),
-                map(
-                    tuple((space1, parse_f64, space1, many_till(anychar, eof))),
-                    |_| (),
-                ),
-            )),
+            many0(preceded(space1, parse_go_metric)),
+            eof,
         )),
-        |(name, _, _iter, _, json_metric, ())| -> Result<(BenchmarkName, JsonMetric), NomError> {
+        |(name, _, _iter, _, json_metric, measures, _)| -> Result<
+            (BenchmarkName, Vec<AdapterMeasure>),
+            NomError,
+        > {
             let benchmark_name = parse_benchmark_name(name)?;
-            Ok((benchmark_name, json_metric))
+            let measures = std::iter::once(AdapterMeasure::Latency(json_metric))
+                .chain(measures.into_iter().flatten())
+                .collect();
+            Ok((benchmark_name, measures))
         },
     )(input)
 }
@@ -77,14 +77,43 @@ fn parse_go_bench(input: &str) -> IResult<&str, JsonMetric> {
     )(input)
 }
 
+// Additional metrics from `-benchmem` or `b.ReportMetric`
+// Only the memory allocation metrics are kept.
+fn parse_go_metric(input: &str) -> IResult<&str, Option<AdapterMeasure>> {
+    map(
+        tuple((parse_f64, space1, take_till1(char::is_whitespace))),
+        |(value, _, unit)| {
+            let json_metric = JsonMetric {
+                value: value.into(),
+                lower_value: None,
+                upper_value: None,
+            };
+            match unit {
+                "B/op" => Some(AdapterMeasure::BytesAllocated(json_metric)),
+                "allocs/op" => Some(AdapterMeasure::Allocations(json_metric)),
+                _ => None,
+            }
+        },
+    )(input)
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 pub(crate) mod test_go_bench {
-    use bencher_json::{project::report::JsonAverage, JsonMetric};
+    use bencher_json::{
+        project::{
+            measure::{ALLOCATIONS_SLUG_STR, BYTES_ALLOCATED_SLUG_STR, LATENCY_SLUG_STR},
+            report::JsonAverage,
+        },
+        JsonMetric,
+    };
     use pretty_assertions::assert_eq;
 
     use crate::{
-        adapters::test_util::{convert_file_path, opt_convert_file_path, validate_latency},
+        adapters::test_util::{
+            convert_file_path, opt_convert_file_path, validate_latency, validate_measure,
+        },
+        results::adapter_results::AdapterMeasure,
         AdapterResults, Settings,
     };
 
@@ -96,6 +125,7 @@ pub(crate) mod test_go_bench {
     }
 
     #[test]
+    #[allow(clippy::too_many_lines)]
     fn test_parse_go() {
         for (index, (expected, input)) in [
             (
@@ -103,11 +133,11 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib10-8".parse().unwrap(),
-                        JsonMetric {
+                        vec![AdapterMeasure::Latency(JsonMetric {
                             value: 325.0.into(),
                             lower_value: None,
                             upper_value: None,
-                        },
+                        })],
                     ),
                 )),
                 "BenchmarkFib10-8   		 					5000000		325 ns/op",
@@ -117,11 +147,11 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib20".parse().unwrap(),
-                        JsonMetric {
+                        vec![AdapterMeasure::Latency(JsonMetric {
                             value: 40_537.123.into(),
                             lower_value: None,
                             upper_value: None,
-                        },
+                        })],
                     ),
                 )),
                 "BenchmarkFib20  	 	   					30000		40537.123 ns/op",
@@ -131,11 +161,11 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib/my_tabled_benchmark_-_10-8".parse().unwrap(),
-                        JsonMetric {
+                        vec![AdapterMeasure::Latency(JsonMetric {
                             value: 325.0.into(),
                             lower_value: None,
                             upper_value: None,
-                        },
+                        })],
                     ),
                 )),
                 "BenchmarkFib/my_tabled_benchmark_-_10-8    	5000000		325 ns/op",
@@ -145,11 +175,11 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib/my_tabled_benchmark_-_20".parse().unwrap(),
-                        JsonMetric {
+                        vec![AdapterMeasure::Latency(JsonMetric {
                             value: 40_537.123.into(),
                             lower_value: None,
                             upper_value: None,
-                        },
+                        })],
                     ),
                 )),
                 "BenchmarkFib/my_tabled_benchmark_-_20		30000		40537.123 ns/op",
@@ -159,11 +189,11 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib/my/tabled/benchmark_-_20".parse().unwrap(),
-                        JsonMetric {
+                        vec![AdapterMeasure::Latency(JsonMetric {
                             value: 40_537.456.into(),
                             lower_value: None,
                             upper_value: None,
-                        },
+                        })],
                     ),
                 )),
                 "BenchmarkFib/my/tabled/benchmark_-_20		30001		40537.456 ns/op",
@@ -173,21 +203,54 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib20WithAuxMetric-8".parse().unwrap(),
-                        JsonMetric {
+                        vec![AdapterMeasure::Latency(JsonMetric {
                             value: 25_829.0.into(),
                             lower_value: None,
                             upper_value: None,
-                        },
+                        })],
                     ),
                 )),
                 "BenchmarkFib20WithAuxMetric-8              46714             25829 ns/op                 4.000 auxMetricUnits",
             ),
+            (
+                Ok((
+                    "",
+                    (
+                        "BenchmarkAppend-12".parse().unwrap(),
+                        vec![
+                            AdapterMeasure::Latency(JsonMetric {
+                                value: 7_908.0.into(),
+                                lower_value: None,
+                                upper_value: None,
+                            }),
+                            AdapterMeasure::BytesAllocated(JsonMetric {
+                                value: 25_208.0.into(),
+                                lower_value: None,
+                                upper_value: None,
+                            }),
+                            AdapterMeasure::Allocations(JsonMetric {
+                                value: 12.0.into(),
+                                lower_value: None,
+                                upper_value: None,
+                            }),
+                        ],
+                    ),
+                )),
+                "BenchmarkAppend-12       	  151622	      7908 ns/op	   25208 B/op	      12 allocs/op",
+            ),
         ]
         .into_iter()
         .enumerate()
         {
             assert_eq!(expected, parse_go(input), "#{index}: {input}");
         }
+
+        for input in [
+            "BenchmarkFib10-8   		 					5000000		325 ns/op trailing",
+            "BenchmarkAppend-12       	  151622	      7908 ns/op	   25208 B/op	      12",
+        ] {
+            assert!(parse_go(input).is_err(), "{input}");
+        }
     }
 
     #[test]
@@ -258,4 +321,32 @@ pub(crate) mod test_go_bench {
         let metrics = results.get("BenchmarkFib20WithAuxMetric-8").unwrap();
         validate_latency(metrics, 25829.0, None, None);
     }
+
+    #[test]
+    fn test_adapter_go_bench_mem() {
+        let results = convert_go_bench("mem");
+        validate_adapter_go_bench_mem(&results);
+    }
+
+    pub fn validate_adapter_go_bench_mem(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("BenchmarkFib10-12").unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_measure(metrics, LATENCY_SLUG_STR, 274.5, None, None);
+        validate_measure(metrics, BYTES_ALLOCATED_SLUG_STR, 0.0, None, None);
+        validate_measure(metrics, ALLOCATIONS_SLUG_STR, 0.0, None, None);
+
+        let metrics = results.get("BenchmarkAppend-12").unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_measure(metrics, LATENCY_SLUG_STR, 7_908.0, None, None);
+        validate_measure(metrics, BYTES_ALLOCATED_SLUG_STR, 25_208.0, None, None);
+        validate_measure(metrics, ALLOCATIONS_SLUG_STR, 12.0, None, None);
+
+        let metrics = results.get("BenchmarkSprintf-12").unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_measure(metrics, LATENCY_SLUG_STR, 93.25, None, None);
+        validate_measure(metrics, BYTES_ALLOCATED_SLUG_STR, 16.0, None, None);
+        validate_measure(metrics, ALLOCATIONS_SLUG_STR, 1.0, None, None);
+    }
 }
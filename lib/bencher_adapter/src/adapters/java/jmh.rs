@@ -1,6 +1,8 @@
-use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonAny, JsonMetric};
+use std::collections::HashMap;
 
-use rust_decimal::Decimal;
+use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonMetric};
+
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use serde::Deserialize;
 
 use crate::{
@@ -30,7 +32,8 @@ pub struct Jmh(pub Vec<Benchmark>);
 pub struct Benchmark {
     pub benchmark: BenchmarkName,
     pub primary_metric: PrimaryMetric,
-    pub secondary_metrics: JsonAny,
+    #[serde(default)]
+    pub secondary_metrics: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -42,6 +45,15 @@ pub struct PrimaryMetric {
     pub score_unit: String,
 }
 
+// Secondary metrics have the same shape as the primary metric,
+// but they may contain values that are not numbers (ie `NaN`).
+pub type SecondaryMetric = PrimaryMetric;
+
+const GC_ALLOC_RATE: &str = "gc.alloc.rate";
+const GC_ALLOC_RATE_UNIT: &str = "MB/sec";
+const GC_ALLOC_RATE_NORM: &str = "gc.alloc.rate.norm";
+const GC_ALLOC_RATE_NORM_UNIT: &str = "B/op";
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ScoreConfidence(
@@ -58,7 +70,7 @@ impl TryFrom<Jmh> for Option<AdapterResults> {
             let Benchmark {
                 benchmark: benchmark_name,
                 primary_metric,
-                ..
+                secondary_metrics,
             } = benchmark;
             let PrimaryMetric {
                 score,
@@ -100,22 +112,57 @@ impl TryFrom<Jmh> for Option<AdapterResults> {
                 return Err(AdapterError::BenchmarkUnits(score_unit));
             };
 
-            benchmark_metrics.push((benchmark_name, measure));
+            let mut measures = vec![measure];
+            measures.extend(secondary_measures(secondary_metrics));
+            benchmark_metrics.push((benchmark_name, measures));
         }
 
-        Ok(AdapterResults::new(benchmark_metrics))
+        Ok(AdapterResults::new_multi(benchmark_metrics))
     }
 }
 
+// Only the garbage collection allocation profiler (`-prof gc`) secondary metrics are supported.
+// Any other secondary metrics are ignored.
+fn secondary_measures(
+    secondary_metrics: HashMap<String, serde_json::Value>,
+) -> impl Iterator<Item = AdapterMeasure> {
+    secondary_metrics.into_iter().filter_map(|(name, value)| {
+        let SecondaryMetric {
+            score,
+            score_confidence,
+            score_unit,
+        } = serde_json::from_value(value).ok()?;
+        let json_metric = JsonMetric {
+            value: score.to_f64()?.into(),
+            lower_value: score_confidence.0.to_f64().map(Into::into),
+            upper_value: score_confidence.1.to_f64().map(Into::into),
+        };
+        // The GC profiler secondary metrics are prefixed with a middle dot (ie `·gc.alloc.rate`)
+        match (name.trim_start_matches('·'), score_unit.as_str()) {
+            (GC_ALLOC_RATE, GC_ALLOC_RATE_UNIT) => {
+                Some(AdapterMeasure::AllocationRate(json_metric))
+            },
+            (GC_ALLOC_RATE_NORM, GC_ALLOC_RATE_NORM_UNIT) => {
+                Some(AdapterMeasure::BytesAllocated(json_metric))
+            },
+            _ => None,
+        }
+    })
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 pub(crate) mod test_java_jmh {
-    use bencher_json::project::report::JsonAverage;
+    use bencher_json::project::{
+        measure::{ALLOCATION_RATE_SLUG_STR, BYTES_ALLOCATED_SLUG_STR, LATENCY_SLUG_STR},
+        report::JsonAverage,
+    };
     use pretty_assertions::assert_eq;
 
     use crate::{
         adapters::test_util::{
-            convert_file_path, opt_convert_file_path, validate_latency, validate_throughput,
+            convert_file_path, opt_convert_file_path, validate_latency, validate_measure,
+            validate_throughput,
         },
         AdapterResults, Settings,
     };
@@ -159,6 +206,42 @@ pub(crate) mod test_java_jmh {
         );
     }
 
+    #[test]
+    fn test_adapter_java_jmh_alloc() {
+        let results = convert_java_jmh("alloc");
+        validate_adapter_java_jmh_alloc(&results);
+    }
+
+    pub fn validate_adapter_java_jmh_alloc(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results
+            .get("org.openjdk.jmh.samples.JMHSample_35_Profilers.Maps.test")
+            .unwrap();
+        assert_eq!(metrics.inner.len(), 3);
+        validate_measure(
+            metrics,
+            LATENCY_SLUG_STR,
+            25_500.0,
+            Some(25_000.0),
+            Some(26_000.0),
+        );
+        validate_measure(
+            metrics,
+            ALLOCATION_RATE_SLUG_STR,
+            1_024.5,
+            Some(1_012.0),
+            Some(1_037.0),
+        );
+        validate_measure(
+            metrics,
+            BYTES_ALLOCATED_SLUG_STR,
+            28_000.0,
+            Some(28_000.0),
+            Some(28_000.0),
+        );
+    }
+
     #[test]
     fn test_adapter_java_jmh_average() {
         let file_path = "./tool_output/java/jmh/six.json";
@@ -34,7 +34,8 @@ mod test_magic {
         python::{asv::test_python_asv, pytest::test_python_pytest},
         ruby::benchmark::test_ruby_benchmark,
         rust::{
-            bench::test_rust_bench, criterion::test_rust_criterion, iai::test_rust_iai,
+            bench::test_rust_bench, criterion::test_rust_criterion,
+            criterion_json::test_rust_criterion_json, divan::test_rust_divan, iai::test_rust_iai,
             iai_callgrind::test_rust_iai_callgrind,
         },
         shell::hyperfine::test_shell_hyperfine,
@@ -71,12 +72,24 @@ mod test_magic {
         test_go_bench::validate_adapter_go_bench(&results);
     }
 
+    #[test]
+    fn test_adapter_magic_go_bench_mem() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/go/bench/mem.txt");
+        test_go_bench::validate_adapter_go_bench_mem(&results);
+    }
+
     #[test]
     fn test_adapter_magic_java_jmh() {
         let results = convert_file_path::<AdapterMagic>("./tool_output/java/jmh/six.json");
         test_java_jmh::validate_adapter_java_jmh(&results);
     }
 
+    #[test]
+    fn test_adapter_magic_java_jmh_alloc() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/java/jmh/alloc.json");
+        test_java_jmh::validate_adapter_java_jmh_alloc(&results);
+    }
+
     #[test]
     fn test_adapter_magic_js_benchmark() {
         let results = convert_file_path::<AdapterMagic>("./tool_output/js/benchmark/three.txt");
@@ -131,6 +144,19 @@ mod test_magic {
         test_rust_iai_callgrind::validate_adapter_rust_iai_callgrind(&results);
     }
 
+    #[test]
+    fn test_adapter_magic_rust_divan() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/rust/divan/two.txt");
+        test_rust_divan::validate_adapter_rust_divan(&results);
+    }
+
+    #[test]
+    fn test_adapter_magic_rust_criterion_json() {
+        let results =
+            convert_file_path::<AdapterMagic>("./tool_output/rust/criterion_json/two.json");
+        test_rust_criterion_json::validate_adapter_rust_criterion_json(&results);
+    }
+
     #[test]
     fn test_adapter_magic_shell_hyperfine() {
         let results = convert_file_path::<AdapterMagic>("./tool_output/shell/hyperfine/two.json");
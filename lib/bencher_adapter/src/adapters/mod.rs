@@ -95,7 +95,16 @@ pub(crate) mod test_util {
         lower_value: Option<f64>,
         upper_value: Option<f64>,
     ) {
-        assert_eq!(metrics.inner.len(), 1);
+        validate_measure(metrics, key, value, lower_value, upper_value);
+    }
+
+    pub fn validate_measure(
+        metrics: &AdapterMetrics,
+        key: &str,
+        value: f64,
+        lower_value: Option<f64>,
+        upper_value: Option<f64>,
+    ) {
         let metric = metrics.get(key).unwrap();
         assert_eq!(metric.value, OrderedFloat::from(value));
         assert_eq!(metric.lower_value, lower_value.map(OrderedFloat::from));
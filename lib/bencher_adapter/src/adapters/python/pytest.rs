@@ -5,7 +5,7 @@ use serde::Deserialize;
 
 use crate::{
     adapters::util::{latency_as_nanos, Units},
-    results::adapter_results::AdapterResults,
+    results::adapter_results::{AdapterMeasure, AdapterResults},
     Adaptable, AdapterError, Settings,
 };
 
@@ -49,10 +49,12 @@ pub struct Stats {
     pub median: Decimal,
     #[serde(with = "rust_decimal::serde::float")]
     pub iqr: Decimal,
+    pub ops: Option<f64>,
+    pub rounds: Option<u64>,
 }
 
 impl Pytest {
-    #[allow(clippy::unnecessary_wraps)]
+    #[allow(clippy::cast_precision_loss, clippy::unnecessary_wraps)]
     fn convert(self, settings: Settings) -> Result<Option<AdapterResults>, AdapterError> {
         let benchmarks = self.benchmarks.0;
         let mut benchmark_metrics = Vec::with_capacity(benchmarks.len());
@@ -66,6 +68,8 @@ impl Pytest {
                 stddev,
                 median,
                 iqr,
+                ops,
+                rounds,
             } = stats;
 
             // JSON output is always in seconds
@@ -82,22 +86,43 @@ impl Pytest {
                 upper_value: Some(value + spread),
             };
 
-            benchmark_metrics.push((benchmark_name, json_metric));
+            let mut measures = vec![AdapterMeasure::Latency(json_metric)];
+            // Operations per second
+            if let Some(ops) = ops {
+                measures.push(AdapterMeasure::Throughput(JsonMetric {
+                    value: ops.into(),
+                    lower_value: None,
+                    upper_value: None,
+                }));
+            }
+            if let Some(rounds) = rounds {
+                measures.push(AdapterMeasure::Rounds(JsonMetric {
+                    value: (rounds as f64).into(),
+                    lower_value: None,
+                    upper_value: None,
+                }));
+            }
+
+            benchmark_metrics.push((benchmark_name, measures));
         }
 
-        Ok(AdapterResults::new_latency(benchmark_metrics))
+        Ok(AdapterResults::new_multi(benchmark_metrics))
     }
 }
 
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 pub(crate) mod test_python_pytest {
-    use bencher_json::project::report::JsonAverage;
+    use bencher_json::project::{
+        measure::{ROUNDS_SLUG_STR, THROUGHPUT_SLUG_STR},
+        report::JsonAverage,
+    };
     use pretty_assertions::assert_eq;
 
     use crate::{
         adapters::test_util::{
             convert_file_path, convert_file_path_median, opt_convert_file_path, validate_latency,
+            validate_measure,
         },
         AdapterResults, Settings,
     };
@@ -130,6 +155,15 @@ pub(crate) mod test_python_pytest {
             Some(17_913.591_143_368_08),
             Some(30_263.771_523_090_734),
         );
+        assert_eq!(metrics.inner.len(), 3);
+        validate_measure(
+            metrics,
+            THROUGHPUT_SLUG_STR,
+            41_513.272_817_492_856,
+            None,
+            None,
+        );
+        validate_measure(metrics, ROUNDS_SLUG_STR, 38_523.0, None, None);
 
         let metrics = results.get("bench.py::test_fib_20").unwrap();
         validate_latency(
@@ -138,6 +172,15 @@ pub(crate) mod test_python_pytest {
             Some(2_810_500.507_247_766),
             Some(3_159_560.838_075_959_6),
         );
+        assert_eq!(metrics.inner.len(), 3);
+        validate_measure(
+            metrics,
+            THROUGHPUT_SLUG_STR,
+            335.004_932_833_156_7,
+            None,
+            None,
+        );
+        validate_measure(metrics, ROUNDS_SLUG_STR, 278.0, None, None);
     }
 
     #[test]
@@ -193,6 +236,15 @@ pub(crate) mod test_python_pytest {
             Some(120.604_370_534_148_98),
             Some(179.307_834_438_427_73),
         );
+        assert_eq!(metrics.inner.len(), 3);
+        validate_measure(
+            metrics,
+            THROUGHPUT_SLUG_STR,
+            6_668_618.238_403_659,
+            None,
+            None,
+        );
+        validate_measure(metrics, ROUNDS_SLUG_STR, 68_536.0, None, None);
 
         let metrics = results.get("bench.py::test_sleep_2").unwrap();
         validate_latency(
@@ -201,6 +253,15 @@ pub(crate) mod test_python_pytest {
             Some(2_001_965_388.274_841),
             Some(2_005_720_705.725_158_5),
         );
+        assert_eq!(metrics.inner.len(), 3);
+        validate_measure(
+            metrics,
+            THROUGHPUT_SLUG_STR,
+            0.499_041_080_835_708_86,
+            None,
+            None,
+        );
+        validate_measure(metrics, ROUNDS_SLUG_STR, 5.0, None, None);
 
         let metrics = results.get("bench.py::test_fib_10").unwrap();
         validate_latency(
@@ -0,0 +1,221 @@
+use std::collections::HashMap;
+
+use bencher_json::{project::report::JsonAverage, BenchmarkName, JsonMetric};
+use literally::hmap;
+use ordered_float::OrderedFloat;
+use serde::Deserialize;
+
+use crate::{
+    results::{
+        adapter_metrics::AdapterMetrics,
+        adapter_results::{AdapterResults, LATENCY_NAME_ID, THROUGHPUT_NAME_ID},
+    },
+    Adaptable, Settings,
+};
+
+pub struct AdapterRustCriterionJson;
+
+const NANOS_PER_SECOND: f64 = 1_000_000_000.0;
+
+impl Adaptable for AdapterRustCriterionJson {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        serde_json::from_str::<CriterionJson>(input)
+            .ok()?
+            .into_results(settings)
+    }
+}
+
+/// The `benchmark.json` and `estimates.json` files from each `target/criterion/**/new` directory
+#[derive(Debug, Clone, Deserialize)]
+pub struct CriterionJson(pub Vec<CriterionBenchmark>);
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CriterionBenchmark {
+    pub benchmark: Benchmark,
+    pub estimates: Estimates,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Benchmark {
+    pub full_id: BenchmarkName,
+    pub throughput: Option<Throughput>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum Throughput {
+    Bytes(u64),
+    BytesDecimal(u64),
+    Elements(u64),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Estimates {
+    pub mean: Estimate,
+    pub median: Estimate,
+    pub slope: Option<Estimate>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Estimate {
+    pub confidence_interval: ConfidenceInterval,
+    pub point_estimate: f64,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct ConfidenceInterval {
+    pub lower_bound: f64,
+    pub upper_bound: f64,
+}
+
+impl CriterionJson {
+    fn into_results(self, settings: Settings) -> Option<AdapterResults> {
+        let mut results_map = HashMap::new();
+        for CriterionBenchmark {
+            benchmark,
+            estimates,
+        } in self.0
+        {
+            let estimate = match settings.average {
+                Some(JsonAverage::Mean) => estimates.mean,
+                Some(JsonAverage::Median) => estimates.median,
+                // Just like the terminal output, use the slope if it is available
+                None => estimates.slope.unwrap_or(estimates.mean),
+            };
+
+            let mut metrics = hmap! {
+                LATENCY_NAME_ID.clone() => estimate.latency()
+            };
+            if let Some(json_metric) = benchmark
+                .throughput
+                .and_then(|throughput| throughput.per_second(estimate))
+            {
+                metrics.insert(THROUGHPUT_NAME_ID.clone(), json_metric);
+            }
+            results_map.insert(benchmark.full_id, AdapterMetrics::from(metrics));
+        }
+
+        (!results_map.is_empty()).then(|| results_map.into())
+    }
+}
+
+impl Estimate {
+    // Criterion estimates are always in nanoseconds
+    fn latency(self) -> JsonMetric {
+        JsonMetric {
+            value: self.point_estimate.into(),
+            lower_value: Some(self.confidence_interval.lower_bound.into()),
+            upper_value: Some(self.confidence_interval.upper_bound.into()),
+        }
+    }
+}
+
+impl Throughput {
+    // The throughput is the number of bytes or elements per second.
+    // The upper bound of the latency is the lower bound of the throughput and vice versa.
+    #[allow(clippy::cast_precision_loss)]
+    fn per_second(self, estimate: Estimate) -> Option<JsonMetric> {
+        let count = match self {
+            Self::Bytes(count) | Self::BytesDecimal(count) | Self::Elements(count) => count,
+        } as f64;
+        let per_second = |nanos: f64| {
+            (nanos > 0.0).then(|| OrderedFloat::from(count * NANOS_PER_SECOND / nanos))
+        };
+        Some(JsonMetric {
+            value: per_second(estimate.point_estimate)?,
+            lower_value: per_second(estimate.confidence_interval.upper_bound),
+            upper_value: per_second(estimate.confidence_interval.lower_bound),
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+pub(crate) mod test_rust_criterion_json {
+    use bencher_json::project::{
+        measure::{LATENCY_SLUG_STR, THROUGHPUT_SLUG_STR},
+        report::JsonAverage,
+    };
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{
+            convert_file_path, convert_file_path_median, opt_convert_file_path, validate_latency,
+            validate_measure,
+        },
+        Adaptable, AdapterResults, Settings,
+    };
+
+    use super::AdapterRustCriterionJson;
+
+    const TWO_FILE_PATH: &str = "./tool_output/rust/criterion_json/two.json";
+
+    #[test]
+    fn test_adapter_rust_criterion_json_two() {
+        let results = convert_file_path::<AdapterRustCriterionJson>(TWO_FILE_PATH);
+        validate_adapter_rust_criterion_json(&results);
+    }
+
+    pub fn validate_adapter_rust_criterion_json(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("fibonacci").unwrap();
+        assert_eq!(metrics.inner.len(), 1);
+        validate_latency(metrics, 24_100.0, Some(24_000.0), Some(24_250.0));
+
+        let metrics = results.get("copy/slice/1024").unwrap();
+        assert_eq!(metrics.inner.len(), 2);
+        validate_measure(metrics, LATENCY_SLUG_STR, 500.0, Some(256.0), Some(512.0));
+        validate_measure(
+            metrics,
+            THROUGHPUT_SLUG_STR,
+            2_048_000_000.0,
+            Some(2_000_000_000.0),
+            Some(4_000_000_000.0),
+        );
+    }
+
+    #[test]
+    fn test_adapter_rust_criterion_json_mean() {
+        let results = opt_convert_file_path::<AdapterRustCriterionJson>(
+            TWO_FILE_PATH,
+            Settings {
+                average: Some(JsonAverage::Mean),
+            },
+        )
+        .unwrap();
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("fibonacci").unwrap();
+        validate_latency(metrics, 24_000.0, Some(23_500.0), Some(24_500.0));
+
+        let metrics = results.get("copy/slice/1024").unwrap();
+        validate_measure(metrics, LATENCY_SLUG_STR, 500.0, Some(256.0), Some(512.0));
+    }
+
+    #[test]
+    fn test_adapter_rust_criterion_json_median() {
+        let results = convert_file_path_median::<AdapterRustCriterionJson>(TWO_FILE_PATH);
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("fibonacci").unwrap();
+        validate_latency(metrics, 23_900.0, Some(23_800.0), Some(24_000.0));
+
+        let metrics = results.get("copy/slice/1024").unwrap();
+        validate_measure(metrics, LATENCY_SLUG_STR, 512.0, Some(256.0), Some(1_024.0));
+        validate_measure(
+            metrics,
+            THROUGHPUT_SLUG_STR,
+            2_000_000_000.0,
+            Some(1_000_000_000.0),
+            Some(4_000_000_000.0),
+        );
+    }
+
+    #[test]
+    fn test_adapter_rust_criterion_json_empty() {
+        assert_eq!(
+            AdapterRustCriterionJson::parse("[]", Settings::default()),
+            None
+        );
+    }
+}
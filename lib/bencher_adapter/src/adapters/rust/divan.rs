@@ -0,0 +1,294 @@
+use bencher_json::{project::report::JsonAverage, JsonMetric};
+use nom::{
+    character::complete::{anychar, space0, space1},
+    combinator::{eof, map, map_res},
+    multi::many_till,
+    sequence::{preceded, tuple},
+    IResult,
+};
+use ordered_float::OrderedFloat;
+
+use crate::{
+    adapters::util::{
+        latency_as_nanos, nom_error, parse_benchmark_name, parse_f64, parse_units, NomError,
+    },
+    results::adapter_results::AdapterResults,
+    Adaptable, Settings,
+};
+
+pub struct AdapterRustDivan;
+
+const COLUMN_SEPARATOR: char = '│';
+const HEADER_COLUMNS: [&str; 5] = ["slowest", "median", "mean", "samples", "iters"];
+const FASTEST_COLUMN: &str = "fastest";
+const BRANCH_PREFIXES: [&str; 2] = ["├─ ", "╰─ "];
+const INDENT_PREFIXES: [&str; 2] = ["│  ", "   "];
+const MODULE_SEPARATOR: &str = "::";
+
+impl Adaptable for AdapterRustDivan {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        let mut benchmark_metrics = Vec::new();
+
+        // The name of the benchmark target from the table header
+        let mut root = None;
+        // The path of parent tree nodes for the current row
+        let mut path: Vec<String> = Vec::new();
+        for line in input.lines() {
+            if let Some(header) = parse_divan_header(line) {
+                root = Some(header);
+                path.clear();
+                continue;
+            }
+            let Some(root) = root else {
+                continue;
+            };
+            let Some((depth, row)) = parse_divan_tree(line) else {
+                continue;
+            };
+
+            path.truncate(depth);
+            match parse_divan_row(row, settings) {
+                DivanRow::Group(name) => path.push(name.to_owned()),
+                DivanRow::Benchmark(name, json_metric) => {
+                    let benchmark_name = std::iter::once(root)
+                        .filter(|root| !root.is_empty())
+                        .chain(path.iter().map(String::as_str))
+                        .chain(std::iter::once(name.as_str()))
+                        .collect::<Vec<_>>()
+                        .join(MODULE_SEPARATOR);
+                    path.push(name);
+                    if let Ok(benchmark_name) = parse_benchmark_name(&benchmark_name) {
+                        benchmark_metrics.push((benchmark_name, json_metric));
+                    }
+                },
+                DivanRow::None => {},
+            }
+        }
+
+        AdapterResults::new_latency(benchmark_metrics)
+    }
+}
+
+enum DivanRow<'i> {
+    Group(&'i str),
+    Benchmark(String, JsonMetric),
+    None,
+}
+
+// `example  fastest  │ slowest  │ median  │ mean  │ samples │ iters`
+fn parse_divan_header(line: &str) -> Option<&str> {
+    let mut columns = line.split(COLUMN_SEPARATOR);
+    let root = columns
+        .next()?
+        .trim_end()
+        .strip_suffix(FASTEST_COLUMN)?
+        .trim();
+    for header in HEADER_COLUMNS {
+        if columns.next()?.trim() != header {
+            return None;
+        }
+    }
+    columns.next().is_none().then_some(root)
+}
+
+// Each level of the tree is indented by three characters, followed by a branch.
+// Lines without a branch, such as throughput counters, are skipped.
+fn parse_divan_tree(line: &str) -> Option<(usize, &str)> {
+    let mut depth = 0;
+    let mut remainder = line;
+    loop {
+        if let Some(row) = BRANCH_PREFIXES
+            .iter()
+            .find_map(|prefix| remainder.strip_prefix(prefix))
+        {
+            return Some((depth, row));
+        }
+        remainder = INDENT_PREFIXES
+            .iter()
+            .find_map(|prefix| remainder.strip_prefix(prefix))?;
+        depth += 1;
+    }
+}
+
+fn parse_divan_row(row: &str, settings: Settings) -> DivanRow<'_> {
+    let columns = row.split(COLUMN_SEPARATOR).collect::<Vec<_>>();
+    let [name_fastest, slowest, median, mean, _samples, _iters] = columns.as_slice() else {
+        return DivanRow::None;
+    };
+
+    // Rows for modules, functions with arguments, and generic types only have a name
+    if slowest.trim().is_empty() {
+        let name = name_fastest.trim();
+        return if name.is_empty() {
+            DivanRow::None
+        } else {
+            DivanRow::Group(name)
+        };
+    }
+
+    let Ok((_, (name, fastest))) = parse_divan_name_fastest(name_fastest) else {
+        return DivanRow::None;
+    };
+    let (Ok((_, slowest)), Ok((_, median)), Ok((_, mean))) = (
+        parse_divan_duration(slowest),
+        parse_divan_duration(median),
+        parse_divan_duration(mean),
+    ) else {
+        return DivanRow::None;
+    };
+
+    let value = match settings.average {
+        Some(JsonAverage::Mean) | None => mean,
+        Some(JsonAverage::Median) => median,
+    };
+    DivanRow::Benchmark(
+        name,
+        JsonMetric {
+            value,
+            lower_value: Some(fastest),
+            upper_value: Some(slowest),
+        },
+    )
+}
+
+fn parse_divan_name_fastest(input: &str) -> IResult<&str, (String, OrderedFloat<f64>)> {
+    map_res(
+        many_till(anychar, preceded(space1, parse_divan_duration)),
+        |(name_chars, fastest)| -> Result<(String, OrderedFloat<f64>), NomError> {
+            let name: String = name_chars.into_iter().collect();
+            if name.trim().is_empty() {
+                Err(nom_error(name))
+            } else {
+                Ok((name.trim().to_owned(), fastest))
+            }
+        },
+    )(input)
+}
+
+fn parse_divan_duration(input: &str) -> IResult<&str, OrderedFloat<f64>> {
+    map(
+        tuple((space0, parse_f64, space1, parse_units, space0, eof)),
+        |(_, duration, _, units, _, _)| latency_as_nanos(duration, units),
+    )(input)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+pub(crate) mod test_rust_divan {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        adapters::test_util::{convert_file_path, convert_file_path_median, validate_latency},
+        AdapterResults,
+    };
+
+    use super::{parse_divan_header, parse_divan_tree, AdapterRustDivan};
+
+    fn convert_rust_divan(suffix: &str) -> AdapterResults {
+        let file_path = format!("./tool_output/rust/divan/{suffix}.txt");
+        convert_file_path::<AdapterRustDivan>(&file_path)
+    }
+
+    #[test]
+    fn test_parse_divan_header() {
+        assert_eq!(
+            parse_divan_header(
+                "example       fastest       │ slowest       │ median        │ mean          │ samples │ iters"
+            ),
+            Some("example")
+        );
+        assert_eq!(
+            parse_divan_header(
+                "├─ add        0.166 ns      │ 0.186 ns      │ 0.167 ns      │ 0.168 ns      │ 100     │ 409600"
+            ),
+            None
+        );
+        assert_eq!(parse_divan_header("Timer precision: 41 ns"), None);
+    }
+
+    #[test]
+    fn test_parse_divan_tree() {
+        assert_eq!(parse_divan_tree("├─ add  1 ns"), Some((0, "add  1 ns")));
+        assert_eq!(parse_divan_tree("│  ╰─ add"), Some((1, "add")));
+        assert_eq!(parse_divan_tree("   │  ├─ add"), Some((2, "add")));
+        assert_eq!(parse_divan_tree("│      10.96 GB/s    │"), None);
+        assert_eq!(parse_divan_tree("Timer precision: 41 ns"), None);
+    }
+
+    #[test]
+    fn test_adapter_rust_divan_two() {
+        let results = convert_rust_divan("two");
+        validate_adapter_rust_divan(&results);
+    }
+
+    pub fn validate_adapter_rust_divan(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("example::add").unwrap();
+        validate_latency(metrics, 0.168, Some(0.166), Some(0.186));
+
+        let metrics = results.get("example::fibonacci").unwrap();
+        validate_latency(metrics, 198.1, Some(196.1), Some(217.0));
+    }
+
+    #[test]
+    fn test_adapter_rust_divan_median() {
+        let results =
+            convert_file_path_median::<AdapterRustDivan>("./tool_output/rust/divan/two.txt");
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("example::add").unwrap();
+        validate_latency(metrics, 0.167, Some(0.166), Some(0.186));
+
+        let metrics = results.get("example::fibonacci").unwrap();
+        validate_latency(metrics, 197.5, Some(196.1), Some(217.0));
+    }
+
+    #[test]
+    fn test_adapter_rust_divan_many() {
+        let results = convert_rust_divan("many");
+        assert_eq!(results.inner.len(), 9);
+
+        let metrics = results
+            .get("example::collections::vec_push::i32::10")
+            .unwrap();
+        validate_latency(metrics, 44.66, Some(42.45), Some(61.2));
+
+        let metrics = results
+            .get("example::collections::vec_push::i32::1000")
+            .unwrap();
+        validate_latency(metrics, 1_401.0, Some(1_312.0), Some(2_103.0));
+
+        let metrics = results
+            .get("example::collections::vec_push::String::10")
+            .unwrap();
+        validate_latency(metrics, 118.2, Some(113.4), Some(145.7));
+
+        let metrics = results
+            .get("example::collections::vec_push::String::1000")
+            .unwrap();
+        validate_latency(metrics, 13_400.0, Some(12_950.0), Some(18_330.0));
+
+        let metrics = results
+            .get("example::collections::copy_from_slice")
+            .unwrap();
+        validate_latency(metrics, 23.73, Some(23.34), Some(26.29));
+
+        let metrics = results.get("example::fibonacci").unwrap();
+        validate_latency(metrics, 1_263_000.0, Some(1_245_000.0), Some(1_500_000.0));
+
+        let metrics = results.get("example::threads::t=1").unwrap();
+        validate_latency(metrics, 5_567.0, Some(4_791.0), Some(18_580.0));
+
+        let metrics = results.get("example::threads::t=4").unwrap();
+        validate_latency(metrics, 6_988.0, Some(5_500.0), Some(22_580.0));
+
+        let metrics = results.get("other::sleep").unwrap();
+        validate_latency(
+            metrics,
+            1_250_000_000.0,
+            Some(1_000_000_000.0),
+            Some(1_500_000_000.0),
+        );
+    }
+}
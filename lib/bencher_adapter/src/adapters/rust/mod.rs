@@ -1,10 +1,13 @@
 pub mod bench;
 pub mod criterion;
+pub mod criterion_json;
+pub mod divan;
 pub mod iai;
 pub mod iai_callgrind;
 
 use self::{
-    criterion::AdapterRustCriterion, iai::AdapterRustIai, iai_callgrind::AdapterRustIaiCallgrind,
+    criterion::AdapterRustCriterion, criterion_json::AdapterRustCriterionJson,
+    divan::AdapterRustDivan, iai::AdapterRustIai, iai_callgrind::AdapterRustIaiCallgrind,
 };
 use crate::{Adaptable, AdapterResults, Settings};
 use bench::AdapterRustBench;
@@ -17,6 +20,8 @@ impl Adaptable for AdapterRust {
             .or_else(|| AdapterRustCriterion::parse(input, settings))
             .or_else(|| AdapterRustIai::parse(input, settings))
             .or_else(|| AdapterRustIaiCallgrind::parse(input, settings))
+            .or_else(|| AdapterRustDivan::parse(input, settings))
+            .or_else(|| AdapterRustCriterionJson::parse(input, settings))
     }
 }
 
@@ -25,7 +30,8 @@ mod test_rust {
     use super::AdapterRust;
     use crate::adapters::{
         rust::{
-            bench::test_rust_bench, criterion::test_rust_criterion, iai::test_rust_iai,
+            bench::test_rust_bench, criterion::test_rust_criterion,
+            criterion_json::test_rust_criterion_json, divan::test_rust_divan, iai::test_rust_iai,
             iai_callgrind::test_rust_iai_callgrind,
         },
         test_util::convert_file_path,
@@ -54,4 +60,17 @@ mod test_rust {
         let results = convert_file_path::<AdapterRust>("./tool_output/rust/iai_callgrind/two.txt");
         test_rust_iai_callgrind::validate_adapter_rust_iai_callgrind(&results);
     }
+
+    #[test]
+    fn test_adapter_rust_divan() {
+        let results = convert_file_path::<AdapterRust>("./tool_output/rust/divan/two.txt");
+        test_rust_divan::validate_adapter_rust_divan(&results);
+    }
+
+    #[test]
+    fn test_adapter_rust_criterion_json() {
+        let results =
+            convert_file_path::<AdapterRust>("./tool_output/rust/criterion_json/two.json");
+        test_rust_criterion_json::validate_adapter_rust_criterion_json(&results);
+    }
 }
@@ -13,7 +13,8 @@ use adapters::{
     python::{asv::AdapterPythonAsv, pytest::AdapterPythonPytest, AdapterPython},
     ruby::{benchmark::AdapterRubyBenchmark, AdapterRuby},
     rust::{
-        bench::AdapterRustBench, criterion::AdapterRustCriterion, iai::AdapterRustIai,
+        bench::AdapterRustBench, criterion::AdapterRustCriterion,
+        criterion_json::AdapterRustCriterionJson, divan::AdapterRustDivan, iai::AdapterRustIai,
         iai_callgrind::AdapterRustIaiCallgrind, AdapterRust,
     },
     shell::{hyperfine::AdapterShellHyperfine, AdapterShell},
@@ -58,6 +59,8 @@ impl Adaptable for Adapter {
             Adapter::RustCriterion => AdapterRustCriterion::parse(input, settings),
             Adapter::RustIai => AdapterRustIai::parse(input, settings),
             Adapter::RustIaiCallgrind => AdapterRustIaiCallgrind::parse(input, settings),
+            Adapter::RustDivan => AdapterRustDivan::parse(input, settings),
+            Adapter::RustCriterionJson => AdapterRustCriterionJson::parse(input, settings),
             Adapter::Shell => AdapterShell::parse(input, settings),
             Adapter::ShellHyperfine => AdapterShellHyperfine::parse(input, settings),
         }
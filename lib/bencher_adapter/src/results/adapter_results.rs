@@ -3,13 +3,16 @@ use std::{collections::HashMap, str::FromStr};
 use bencher_json::{
     project::{
         measure::{
-            ESTIMATED_CYCLES_SLUG_STR, INSTRUCTIONS_SLUG_STR, L1_ACCESSES_SLUG_STR,
-            L2_ACCESSES_SLUG_STR, LATENCY_SLUG_STR, RAM_ACCESSES_SLUG_STR, THROUGHPUT_SLUG_STR,
-            TOTAL_ACCESSES_SLUG_STR,
+            ALLOCATIONS_SLUG_STR, ALLOCATIONS_UNIT, ALLOCATION_RATE_SLUG_STR, ALLOCATION_RATE_UNIT,
+            BYTES_ALLOCATED_SLUG_STR, BYTES_ALLOCATED_UNIT, ESTIMATED_CYCLES_SLUG_STR,
+            ESTIMATED_CYCLES_UNIT, INSTRUCTIONS_SLUG_STR, INSTRUCTIONS_UNIT, L1_ACCESSES_SLUG_STR,
+            L1_ACCESSES_UNIT, L2_ACCESSES_SLUG_STR, L2_ACCESSES_UNIT, LATENCY_SLUG_STR,
+            LATENCY_UNIT, RAM_ACCESSES_SLUG_STR, RAM_ACCESSES_UNIT, ROUNDS_SLUG_STR, ROUNDS_UNIT,
+            THROUGHPUT_SLUG_STR, THROUGHPUT_UNIT, TOTAL_ACCESSES_SLUG_STR, TOTAL_ACCESSES_UNIT,
         },
         metric::Mean,
     },
-    BenchmarkName, JsonMetric, NameId,
+    BenchmarkName, JsonMetric, MeasureNameId, MeasureUnit, NameId,
 };
 use literally::hmap;
 use once_cell::sync::Lazy;
@@ -51,6 +54,22 @@ pub static TOTAL_ACCESSES_NAME_ID: Lazy<NameId> =
 pub static ESTIMATED_CYCLES_NAME_ID: Lazy<NameId> =
     Lazy::new(|| ESTIMATED_CYCLES_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
 
+#[allow(clippy::expect_used)]
+pub static ALLOCATIONS_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| ALLOCATIONS_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
+#[allow(clippy::expect_used)]
+pub static BYTES_ALLOCATED_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| BYTES_ALLOCATED_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
+#[allow(clippy::expect_used)]
+pub static ALLOCATION_RATE_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| ALLOCATION_RATE_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
+#[allow(clippy::expect_used)]
+pub static ROUNDS_NAME_ID: Lazy<NameId> =
+    Lazy::new(|| ROUNDS_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR));
+
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AdapterResults {
     #[serde(flatten)]
@@ -65,10 +84,27 @@ impl From<ResultsMap> for AdapterResults {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AdapterMeasure {
     Latency(JsonMetric),
     Throughput(JsonMetric),
+    Allocations(JsonMetric),
+    BytesAllocated(JsonMetric),
+    AllocationRate(JsonMetric),
+    Rounds(JsonMetric),
+}
+
+impl AdapterMeasure {
+    fn into_name_id_metric(self) -> (NameId, JsonMetric) {
+        match self {
+            Self::Latency(json_metric) => (LATENCY_NAME_ID.clone(), json_metric),
+            Self::Throughput(json_metric) => (THROUGHPUT_NAME_ID.clone(), json_metric),
+            Self::Allocations(json_metric) => (ALLOCATIONS_NAME_ID.clone(), json_metric),
+            Self::BytesAllocated(json_metric) => (BYTES_ALLOCATED_NAME_ID.clone(), json_metric),
+            Self::AllocationRate(json_metric) => (ALLOCATION_RATE_NAME_ID.clone(), json_metric),
+            Self::Rounds(json_metric) => (ROUNDS_NAME_ID.clone(), json_metric),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -91,6 +127,26 @@ pub enum IaiCallgrindMeasure {
 }
 
 impl AdapterResults {
+    /// The unit that the adapters report a measure's values in.
+    /// Only the measures that the adapters create have a declared unit.
+    pub fn measure_unit(measure: &MeasureNameId) -> Option<MeasureUnit> {
+        Some(match measure.as_ref() {
+            LATENCY_SLUG_STR => LATENCY_UNIT,
+            THROUGHPUT_SLUG_STR => THROUGHPUT_UNIT,
+            INSTRUCTIONS_SLUG_STR => INSTRUCTIONS_UNIT,
+            L1_ACCESSES_SLUG_STR => L1_ACCESSES_UNIT,
+            L2_ACCESSES_SLUG_STR => L2_ACCESSES_UNIT,
+            RAM_ACCESSES_SLUG_STR => RAM_ACCESSES_UNIT,
+            TOTAL_ACCESSES_SLUG_STR => TOTAL_ACCESSES_UNIT,
+            ESTIMATED_CYCLES_SLUG_STR => ESTIMATED_CYCLES_UNIT,
+            ALLOCATIONS_SLUG_STR => ALLOCATIONS_UNIT,
+            BYTES_ALLOCATED_SLUG_STR => BYTES_ALLOCATED_UNIT,
+            ALLOCATION_RATE_SLUG_STR => ALLOCATION_RATE_UNIT,
+            ROUNDS_SLUG_STR => ROUNDS_UNIT,
+            _ => return None,
+        })
+    }
+
     pub fn new(benchmark_metrics: Vec<(BenchmarkName, AdapterMeasure)>) -> Option<Self> {
         if benchmark_metrics.is_empty() {
             return None;
@@ -98,18 +154,10 @@ impl AdapterResults {
 
         let mut results_map = HashMap::new();
         for (benchmark_name, measure) in benchmark_metrics {
+            let (measure, json_metric) = measure.into_name_id_metric();
             let adapter_metrics = AdapterMetrics {
-                inner: match measure {
-                    AdapterMeasure::Latency(json_metric) => {
-                        hmap! {
-                            LATENCY_NAME_ID.clone() => json_metric
-                        }
-                    },
-                    AdapterMeasure::Throughput(json_metric) => {
-                        hmap! {
-                            THROUGHPUT_NAME_ID.clone() => json_metric
-                        }
-                    },
+                inner: hmap! {
+                    measure => json_metric
                 },
             };
             results_map.insert(benchmark_name, adapter_metrics);
@@ -118,6 +166,25 @@ impl AdapterResults {
         Some(results_map.into())
     }
 
+    pub fn new_multi(benchmark_metrics: Vec<(BenchmarkName, Vec<AdapterMeasure>)>) -> Option<Self> {
+        if benchmark_metrics.is_empty() {
+            return None;
+        }
+
+        let mut results_map = HashMap::new();
+        for (benchmark_name, measures) in benchmark_metrics {
+            let metrics_value = results_map
+                .entry(benchmark_name)
+                .or_insert_with(AdapterMetrics::default);
+            for measure in measures {
+                let (measure, json_metric) = measure.into_name_id_metric();
+                metrics_value.inner.insert(measure, json_metric);
+            }
+        }
+
+        Some(results_map.into())
+    }
+
     pub fn new_latency(benchmark_metrics: Vec<(BenchmarkName, JsonMetric)>) -> Option<Self> {
         Self::new(
             benchmark_metrics
@@ -1,16 +1,20 @@
 use bencher_json::{project::boundary::BoundaryLimit, Boundary, ModelTest, SampleSize};
 use slog::Logger;
 
+use crate::kolmogorov_smirnov::KolmogorovSmirnov;
 use crate::limits::{MetricsLimits, NormalTestKind};
 use crate::ln::Ln;
-use crate::mean::Mean;
+use crate::mean::{mean, Mean};
 use crate::quartiles::Quartiles;
+use crate::rank_sum::RankSum;
+use crate::welch_t::WelchT;
 use crate::{BoundaryError, MetricsData};
 
 #[derive(Debug, Default)]
 pub struct MetricsBoundary {
     pub limits: MetricsLimits,
     pub outlier: Option<BoundaryLimit>,
+    pub effect_size: Option<f64>,
 }
 
 impl MetricsBoundary {
@@ -22,10 +26,32 @@ impl MetricsBoundary {
         min_sample_size: Option<SampleSize>,
         lower_boundary: Option<Boundary>,
         upper_boundary: Option<Boundary>,
+    ) -> Result<Self, BoundaryError> {
+        Self::new_sample(
+            log,
+            &[datum],
+            metrics_data,
+            model_test,
+            min_sample_size,
+            lower_boundary,
+            upper_boundary,
+        )
+    }
+
+    // Two sample tests compare the distribution of the entire new sample against the historical data.
+    // All other tests compare the mean of the new sample against the historical data.
+    pub fn new_sample(
+        log: &Logger,
+        sample: &[f64],
+        metrics_data: &MetricsData,
+        model_test: ModelTest,
+        min_sample_size: Option<SampleSize>,
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
     ) -> Result<Self, BoundaryError> {
         Self::new_inner(
             log,
-            datum,
+            sample,
             metrics_data,
             model_test,
             min_sample_size,
@@ -37,7 +63,7 @@ impl MetricsBoundary {
 
     fn new_inner(
         log: &Logger,
-        datum: f64,
+        sample: &[f64],
         metrics_data: &MetricsData,
         model_test: ModelTest,
         min_sample_size: Option<SampleSize>,
@@ -68,6 +94,10 @@ impl MetricsBoundary {
             slog::debug!(log, "No data for threshold model test {model_test:?}");
             return Ok(None);
         }
+        let Some(datum) = mean(sample) else {
+            slog::debug!(log, "No new data for threshold model test {model_test:?}");
+            return Ok(None);
+        };
 
         match model_test {
             ModelTest::Static => Ok(Some(Self::new_static(
@@ -106,6 +136,15 @@ impl MetricsBoundary {
             ModelTest::DeltaIqr => {
                 Self::new_iqr(log, datum, data, true, lower_boundary, upper_boundary)
             },
+            ModelTest::MannWhitneyU => {
+                Self::new_rank_sum(log, sample, data, lower_boundary, upper_boundary)
+            },
+            ModelTest::WelchT => {
+                Self::new_welch_t(log, sample, data, lower_boundary, upper_boundary)
+            },
+            ModelTest::KolmogorovSmirnov => {
+                Self::new_kolmogorov_smirnov(log, sample, data, lower_boundary, upper_boundary)
+            },
         }
     }
 
@@ -117,7 +156,11 @@ impl MetricsBoundary {
         let limits = MetricsLimits::new_static(lower_boundary, upper_boundary);
         let outlier = limits.outlier(datum);
 
-        Self { limits, outlier }
+        Self {
+            limits,
+            outlier,
+            effect_size: None,
+        }
     }
 
     fn new_percentage(
@@ -144,7 +187,11 @@ impl MetricsBoundary {
         let limits = MetricsLimits::new_percentage(log, mean, lower_boundary, upper_boundary);
         let outlier = limits.outlier(datum);
 
-        Ok(Some(Self { limits, outlier }))
+        Ok(Some(Self {
+            limits,
+            outlier,
+            effect_size: None,
+        }))
     }
 
     fn new_normal(
@@ -183,7 +230,11 @@ impl MetricsBoundary {
         )?;
         let outlier = limits.outlier(datum);
 
-        Ok(Some(Self { limits, outlier }))
+        Ok(Some(Self {
+            limits,
+            outlier,
+            effect_size: None,
+        }))
     }
 
     fn new_log_normal(
@@ -209,7 +260,11 @@ impl MetricsBoundary {
         let limits = MetricsLimits::new_log_normal(log, ln, lower_boundary, upper_boundary)?;
         let outlier = limits.outlier(datum);
 
-        Ok(Some(Self { limits, outlier }))
+        Ok(Some(Self {
+            limits,
+            outlier,
+            effect_size: None,
+        }))
     }
 
     fn new_iqr(
@@ -251,6 +306,117 @@ impl MetricsBoundary {
         );
         let outlier = limits.outlier(datum);
 
-        Ok(Some(Self { limits, outlier }))
+        Ok(Some(Self {
+            limits,
+            outlier,
+            effect_size: None,
+        }))
+    }
+
+    // A single new datum is compared against the exact rank limits of the historical data.
+    // A new sample with more than one datum is ranked as a whole against the historical data.
+    fn new_rank_sum(
+        log: &Logger,
+        sample: &[f64],
+        data: &[f64],
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Result<Option<Self>, BoundaryError> {
+        let lower_boundary = lower_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+        let upper_boundary = upper_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+
+        let Some(rank_sum) = RankSum::new(data) else {
+            return Ok(None);
+        };
+
+        let limits = MetricsLimits::new_rank_sum(log, &rank_sum, lower_boundary, upper_boundary);
+        let outlier = if let [datum] = sample {
+            limits.outlier(*datum)
+        } else {
+            rank_sum.outlier(sample, lower_boundary, upper_boundary)
+        };
+
+        Ok(Some(Self {
+            limits,
+            outlier,
+            effect_size: rank_sum.effect_size(sample),
+        }))
+    }
+
+    fn new_welch_t(
+        log: &Logger,
+        sample: &[f64],
+        data: &[f64],
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Result<Option<Self>, BoundaryError> {
+        let lower_boundary = lower_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+        let upper_boundary = upper_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+
+        let Some(welch_t) = WelchT::new(data, sample) else {
+            return Ok(None);
+        };
+
+        // The limits are for the mean of the new sample.
+        let limits = MetricsLimits::new_normal(
+            log,
+            welch_t.mean,
+            welch_t.std_err,
+            NormalTestKind::T {
+                freedom: welch_t.freedom,
+            },
+            lower_boundary,
+            upper_boundary,
+        )?;
+        let outlier = limits.outlier(welch_t.sample_mean);
+
+        Ok(Some(Self {
+            limits,
+            outlier,
+            effect_size: welch_t.effect_size,
+        }))
+    }
+
+    fn new_kolmogorov_smirnov(
+        log: &Logger,
+        sample: &[f64],
+        data: &[f64],
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Result<Option<Self>, BoundaryError> {
+        let lower_boundary = lower_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+        let upper_boundary = upper_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+
+        let Some(ks) = KolmogorovSmirnov::new(data, sample) else {
+            return Ok(None);
+        };
+
+        let limits =
+            MetricsLimits::new_kolmogorov_smirnov(log, &ks, lower_boundary, upper_boundary);
+        let outlier = ks.outlier(lower_boundary, upper_boundary);
+
+        Ok(Some(Self {
+            limits,
+            outlier,
+            effect_size: Some(ks.effect_size()),
+        }))
     }
 }
@@ -0,0 +1,254 @@
+use crate::mean::{mean, std_deviation};
+
+// The minimum number of samples in a segment
+const MIN_SEGMENT_SIZE: usize = 2;
+// Median absolute deviation to standard deviation for normally distributed data
+const MAD_SCALE: f64 = 1.4826;
+// Relative tolerance for floating point error
+const RELATIVE_EPSILON: f64 = 1e-9;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Segment {
+    /// The index of the first sample in the segment (inclusive)
+    pub start: usize,
+    /// The index after the last sample in the segment (exclusive)
+    pub end: usize,
+    /// The mean of the samples in the segment
+    pub mean: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangePoints {
+    pub segments: Vec<Segment>,
+}
+
+impl ChangePoints {
+    // Pruned Exact Linear Time (PELT) change point detection for a change in mean
+    // https://arxiv.org/abs/1101.1438
+    // The cost of a segment is its sum of squared deviations from the segment mean,
+    // normalized by a robust estimate of the noise variance.
+    // Each additional change point is penalized using the Modified Bayesian Information Criterion (MBIC).
+    #[allow(clippy::cast_precision_loss, clippy::indexing_slicing)]
+    pub fn new(data: &[f64]) -> Option<Self> {
+        let len = data.len();
+        if len == 0 || data.iter().any(|datum| !datum.is_finite()) {
+            return None;
+        }
+        let data_mean = mean(data)?;
+        let single_segment = || Self {
+            segments: vec![Segment {
+                start: 0,
+                end: len,
+                mean: data_mean,
+            }],
+        };
+        if len < MIN_SEGMENT_SIZE * 2 {
+            return Some(single_segment());
+        }
+        let Some(variance) = noise_variance(data) else {
+            return Some(single_segment());
+        };
+        let penalty = 3.0 * (len as f64).ln();
+
+        // Center the data to reduce floating point error in the cumulative sums
+        let mut sum = Vec::with_capacity(len + 1);
+        let mut sum_sq = Vec::with_capacity(len + 1);
+        sum.push(0.0);
+        sum_sq.push(0.0);
+        for datum in data {
+            let centered = datum - data_mean;
+            sum.push(sum[sum.len() - 1] + centered);
+            sum_sq.push(sum_sq[sum_sq.len() - 1] + centered * centered);
+        }
+        let cost = |start: usize, end: usize| {
+            let segment_len = (end - start) as f64;
+            let segment_sum = sum[end] - sum[start];
+            let segment_sum_sq = sum_sq[end] - sum_sq[start];
+            (segment_sum_sq - segment_sum * segment_sum / segment_len) / variance
+        };
+
+        let mut total_cost = vec![f64::INFINITY; len + 1];
+        total_cost[0] = -penalty;
+        let mut last_change = vec![0; len + 1];
+        let mut candidates = vec![0];
+        for end in MIN_SEGMENT_SIZE..=len {
+            if end >= MIN_SEGMENT_SIZE * 2 {
+                candidates.push(end - MIN_SEGMENT_SIZE);
+            }
+            let (best_start, best_cost) = candidates
+                .iter()
+                .map(|&start| (start, total_cost[start] + cost(start, end) + penalty))
+                .min_by(|(_, l), (_, r)| l.partial_cmp(r).unwrap_or(std::cmp::Ordering::Equal))?;
+            total_cost[end] = best_cost;
+            last_change[end] = best_start;
+            // Prune any candidates that can never be optimal
+            candidates.retain(|&start| total_cost[start] + cost(start, end) <= best_cost);
+        }
+
+        let mut segments = Vec::new();
+        let mut end = len;
+        while end > 0 {
+            let start = last_change[end];
+            segments.push(Segment {
+                start,
+                end,
+                mean: mean(&data[start..end])?,
+            });
+            end = start;
+        }
+        segments.reverse();
+
+        Some(Self { segments })
+    }
+
+    /// The index of the first sample after each change point
+    pub fn indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.segments.iter().skip(1).map(|segment| segment.start)
+    }
+}
+
+// Estimate the noise variance from the median absolute deviation of the first differences.
+// Unlike the variance of the data itself, this is not inflated by the change points.
+// If the differences are mostly the same, fall back to the variance of the first differences.
+fn noise_variance(data: &[f64]) -> Option<f64> {
+    let mut diffs = data
+        .iter()
+        .zip(data.iter().skip(1))
+        .map(|(prev, next)| next - prev)
+        .collect::<Vec<_>>();
+    let diff_median = median(&mut diffs)?;
+    let mut abs_deviations = diffs
+        .iter()
+        .map(|diff| (diff - diff_median).abs())
+        .collect::<Vec<_>>();
+    let mad = median(&mut abs_deviations)?;
+    // Treat a median absolute deviation that is only floating point error as zero
+    let max_abs_deviation = abs_deviations.last().copied().unwrap_or_default();
+    let std_dev = if mad > RELATIVE_EPSILON * max_abs_deviation {
+        MAD_SCALE * mad / std::f64::consts::SQRT_2
+    } else {
+        std_deviation(mean(&diffs)?, &diffs)? / std::f64::consts::SQRT_2
+    };
+    let variance = std_dev * std_dev;
+    variance.is_normal().then_some(variance)
+}
+
+#[allow(clippy::indexing_slicing, clippy::integer_division)]
+fn median(data: &mut [f64]) -> Option<f64> {
+    if data.is_empty() {
+        return None;
+    }
+    data.sort_unstable_by(|x, y| x.partial_cmp(y).unwrap_or(std::cmp::Ordering::Equal));
+    let mid = data.len() / 2;
+    Some(if data.len() % 2 == 0 {
+        (data[mid - 1] + data[mid]) / 2.0
+    } else {
+        data[mid]
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp, clippy::panic, clippy::unwrap_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::{ChangePoints, Segment};
+
+    const DATA_ZERO: &[f64] = &[];
+    const DATA_ONE: &[f64] = &[1.0];
+    const DATA_FIVE_CONST: &[f64] = &[1.0, 1.0, 1.0, 1.0, 1.0];
+    const DATA_NOISE: &[f64] = &[
+        10.1, 9.9, 10.2, 9.8, 10.0, 10.1, 9.9, 10.3, 9.7, 10.0, 10.2, 9.8,
+    ];
+    const DATA_STEP: &[f64] = &[
+        10.1, 9.9, 10.2, 9.8, 10.0, 10.1, 20.1, 19.9, 20.2, 19.8, 20.0, 20.1,
+    ];
+    const DATA_STEP_CONST: &[f64] = &[5.0, 5.0, 5.0, 5.0, 5.0, 8.0, 8.0, 8.0, 8.0];
+    const DATA_TWO_STEPS: &[f64] = &[
+        1.0, 1.1, 0.9, 1.0, 1.1, 0.9, 5.0, 5.1, 4.9, 5.0, 5.1, 4.9, 1.0, 1.1, 0.9, 1.0, 1.1, 0.9,
+    ];
+
+    #[test]
+    fn test_change_points_zero() {
+        let change_points = ChangePoints::new(DATA_ZERO);
+        assert_eq!(change_points, None);
+    }
+
+    #[test]
+    fn test_change_points_nan() {
+        let change_points = ChangePoints::new(&[1.0, f64::NAN, 1.0, 1.0]);
+        assert_eq!(change_points, None);
+    }
+
+    #[test]
+    fn test_change_points_one() {
+        let change_points = ChangePoints::new(DATA_ONE).unwrap();
+        assert_eq!(
+            change_points.segments,
+            vec![Segment {
+                start: 0,
+                end: 1,
+                mean: 1.0
+            }]
+        );
+        assert_eq!(change_points.indices().count(), 0);
+    }
+
+    #[test]
+    fn test_change_points_const() {
+        let change_points = ChangePoints::new(DATA_FIVE_CONST).unwrap();
+        assert_eq!(
+            change_points.segments,
+            vec![Segment {
+                start: 0,
+                end: 5,
+                mean: 1.0
+            }]
+        );
+    }
+
+    #[test]
+    fn test_change_points_noise() {
+        let change_points = ChangePoints::new(DATA_NOISE).unwrap();
+        assert_eq!(change_points.indices().count(), 0);
+    }
+
+    #[test]
+    fn test_change_points_step() {
+        let change_points = ChangePoints::new(DATA_STEP).unwrap();
+        assert_eq!(change_points.indices().collect::<Vec<_>>(), vec![6]);
+        let [before, after] = change_points.segments.as_slice() else {
+            panic!("Expected two segments: {change_points:?}");
+        };
+        assert_eq!((before.start, before.end), (0, 6));
+        assert_eq!((after.start, after.end), (6, 12));
+        assert!((before.mean - 10.0).abs() < 0.1, "{before:?}");
+        assert!((after.mean - 20.0).abs() < 0.1, "{after:?}");
+    }
+
+    #[test]
+    fn test_change_points_step_const() {
+        let change_points = ChangePoints::new(DATA_STEP_CONST).unwrap();
+        assert_eq!(
+            change_points.segments,
+            vec![
+                Segment {
+                    start: 0,
+                    end: 5,
+                    mean: 5.0
+                },
+                Segment {
+                    start: 5,
+                    end: 9,
+                    mean: 8.0
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_change_points_two_steps() {
+        let change_points = ChangePoints::new(DATA_TWO_STEPS).unwrap();
+        assert_eq!(change_points.indices().collect::<Vec<_>>(), vec![6, 12]);
+    }
+}
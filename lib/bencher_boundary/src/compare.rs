@@ -0,0 +1,116 @@
+use statrs::distribution::{ContinuousCDF, StudentsT};
+
+use crate::{mean::mean, welch_t::WelchT};
+
+/// The p-value below which a difference between the means is significant.
+pub const SIGNIFICANCE_LEVEL: f64 = 0.05;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Comparison {
+    /// The mean of the base samples
+    pub base_mean: f64,
+    /// The mean of the head samples
+    pub head_mean: f64,
+    /// The difference between the head and base means
+    pub delta: f64,
+    /// The percent change from the base mean to the head mean
+    pub percent_change: Option<f64>,
+    /// The two-tailed Welch's t-test p-value for the difference between the means
+    pub p_value: Option<f64>,
+}
+
+impl Comparison {
+    // The p-value requires at least two samples on each side,
+    // otherwise the variance of that side can not be estimated.
+    pub fn new(base: &[f64], head: &[f64]) -> Option<Self> {
+        let base_mean = mean(base)?;
+        let head_mean = mean(head)?;
+        let delta = head_mean - base_mean;
+        let percent_change = base_mean
+            .is_normal()
+            .then(|| delta / base_mean.abs() * 100.0)
+            .filter(|percent_change| percent_change.is_finite());
+        let p_value = if base.len() < 2 || head.len() < 2 {
+            None
+        } else {
+            p_value(base, head)
+        };
+        Some(Self {
+            base_mean,
+            head_mean,
+            delta,
+            percent_change,
+            p_value,
+        })
+    }
+
+    /// Whether the difference between the means is statistically significant.
+    /// If there are not enough samples to tell, then `None` is returned.
+    pub fn is_significant(&self) -> Option<bool> {
+        self.p_value.map(|p_value| p_value < SIGNIFICANCE_LEVEL)
+    }
+}
+
+fn p_value(base: &[f64], head: &[f64]) -> Option<f64> {
+    let welch_t = WelchT::new(base, head)?;
+    let t = (welch_t.sample_mean - welch_t.mean) / welch_t.std_err;
+    let students_t = StudentsT::new(0.0, 1.0, welch_t.freedom).ok()?;
+    let p_value = 2.0 * (1.0 - students_t.cdf(t.abs()));
+    p_value.is_finite().then_some(p_value.clamp(0.0, 1.0))
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp, clippy::unwrap_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::Comparison;
+
+    const DATA_ZERO: &[f64] = &[];
+    const DATA_ONE: &[f64] = &[2.0];
+    const DATA_FIVE: &[f64] = &[1.0, 2.0, 3.0, 4.0, 5.0];
+    const DATA_FIVE_SHIFTED: &[f64] = &[101.0, 102.0, 103.0, 104.0, 105.0];
+    const DATA_FIVE_ZEROS: &[f64] = &[0.0, 0.0, 0.0, 0.0, 0.0];
+
+    #[test]
+    fn test_compare_zero() {
+        assert_eq!(Comparison::new(DATA_ZERO, DATA_FIVE), None);
+        assert_eq!(Comparison::new(DATA_FIVE, DATA_ZERO), None);
+    }
+
+    #[test]
+    fn test_compare_one() {
+        let comparison = Comparison::new(DATA_ONE, &[3.0]).unwrap();
+        assert_eq!(comparison.base_mean, 2.0);
+        assert_eq!(comparison.head_mean, 3.0);
+        assert_eq!(comparison.delta, 1.0);
+        assert_eq!(comparison.percent_change, Some(50.0));
+        assert_eq!(comparison.p_value, None);
+        assert_eq!(comparison.is_significant(), None);
+    }
+
+    #[test]
+    fn test_compare_same() {
+        let comparison = Comparison::new(DATA_FIVE, DATA_FIVE).unwrap();
+        assert_eq!(comparison.delta, 0.0);
+        assert_eq!(comparison.percent_change, Some(0.0));
+        assert_eq!(comparison.p_value, Some(1.0));
+        assert_eq!(comparison.is_significant(), Some(false));
+    }
+
+    #[test]
+    fn test_compare_shifted() {
+        let comparison = Comparison::new(DATA_FIVE, DATA_FIVE_SHIFTED).unwrap();
+        assert_eq!(comparison.delta, 100.0);
+        assert_eq!(comparison.percent_change, Some(100.0 / 3.0 * 100.0));
+        assert!(comparison.p_value.unwrap() < 0.001);
+        assert_eq!(comparison.is_significant(), Some(true));
+    }
+
+    #[test]
+    fn test_compare_zero_base() {
+        let comparison = Comparison::new(DATA_FIVE_ZEROS, DATA_FIVE).unwrap();
+        assert_eq!(comparison.delta, 3.0);
+        assert_eq!(comparison.percent_change, None);
+    }
+}
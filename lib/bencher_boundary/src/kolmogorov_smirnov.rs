@@ -0,0 +1,202 @@
+use bencher_json::{project::boundary::BoundaryLimit, CdfBoundary};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KolmogorovSmirnov {
+    /// The median of the historical data
+    pub median: f64,
+    /// The largest amount that the empirical CDF of the new sample is above the historical data.
+    /// That is, how much lower the new sample is than the historical data.
+    pub lower_statistic: f64,
+    /// The largest amount that the empirical CDF of the new sample is below the historical data.
+    /// That is, how much higher the new sample is than the historical data.
+    pub upper_statistic: f64,
+    pub data_len: usize,
+    pub sample_len: usize,
+}
+
+impl KolmogorovSmirnov {
+    #[allow(clippy::cast_precision_loss)]
+    pub fn new(data: &[f64], sample: &[f64]) -> Option<Self> {
+        let data = sorted(data)?;
+        let sample = sorted(sample)?;
+        let median = median(&data)?;
+
+        let data_len = data.len() as f64;
+        let sample_len = sample.len() as f64;
+        let (mut data_index, mut sample_index) = (0, 0);
+        let (mut lower_statistic, mut upper_statistic): (f64, f64) = (0.0, 0.0);
+        while let (Some(&data_datum), Some(&sample_datum)) =
+            (data.get(data_index), sample.get(sample_index))
+        {
+            // Step past all tied values in both samples before comparing the empirical CDFs
+            let datum = data_datum.min(sample_datum);
+            while data.get(data_index).is_some_and(|&d| d <= datum) {
+                data_index += 1;
+            }
+            while sample.get(sample_index).is_some_and(|&s| s <= datum) {
+                sample_index += 1;
+            }
+            let data_cdf = data_index as f64 / data_len;
+            let sample_cdf = sample_index as f64 / sample_len;
+            lower_statistic = lower_statistic.max(sample_cdf - data_cdf);
+            upper_statistic = upper_statistic.max(data_cdf - sample_cdf);
+        }
+
+        Some(Self {
+            median,
+            lower_statistic,
+            upper_statistic,
+            data_len: data.len(),
+            sample_len: sample.len(),
+        })
+    }
+
+    // The one-sided asymptotic critical value for the two sample test:
+    // `P(D > d) = exp(-2 * d^2 * n * m / (n + m))`
+    #[allow(clippy::cast_precision_loss)]
+    fn critical_value(&self, boundary: CdfBoundary) -> f64 {
+        let data_len = self.data_len as f64;
+        let sample_len = self.sample_len as f64;
+        let alpha = 1.0 - f64::from(boundary);
+        (-alpha.ln() / 2.0 * (data_len + sample_len) / (data_len * sample_len)).sqrt()
+    }
+
+    pub fn outlier(
+        &self,
+        lower_boundary: Option<CdfBoundary>,
+        upper_boundary: Option<CdfBoundary>,
+    ) -> Option<BoundaryLimit> {
+        let lower = lower_boundary
+            .is_some_and(|boundary| self.lower_statistic > self.critical_value(boundary));
+        let upper = upper_boundary
+            .is_some_and(|boundary| self.upper_statistic > self.critical_value(boundary));
+        match (lower, upper) {
+            (true, true) => Some(if self.lower_statistic > self.upper_statistic {
+                BoundaryLimit::Lower
+            } else {
+                BoundaryLimit::Upper
+            }),
+            (true, false) => Some(BoundaryLimit::Lower),
+            (false, true) => Some(BoundaryLimit::Upper),
+            (false, false) => None,
+        }
+    }
+
+    /// The signed Kolmogorov-Smirnov statistic.
+    /// It is negative if the new sample is lower and positive if it is higher.
+    pub fn effect_size(&self) -> f64 {
+        if self.lower_statistic > self.upper_statistic {
+            -self.lower_statistic
+        } else {
+            self.upper_statistic
+        }
+    }
+}
+
+fn sorted(data: &[f64]) -> Option<Vec<f64>> {
+    if data.is_empty() || data.iter().any(|datum| !datum.is_finite()) {
+        return None;
+    }
+    let mut sorted = data.to_vec();
+    sorted.sort_unstable_by(|x, y| x.partial_cmp(y).unwrap_or(std::cmp::Ordering::Equal));
+    Some(sorted)
+}
+
+#[allow(clippy::integer_division)]
+fn median(sorted_data: &[f64]) -> Option<f64> {
+    let len = sorted_data.len();
+    let mid = len / 2;
+    if len % 2 == 0 {
+        let lower = sorted_data.get(mid.checked_sub(1)?)?;
+        let upper = sorted_data.get(mid)?;
+        Some((lower + upper) / 2.0)
+    } else {
+        sorted_data.get(mid).copied()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp, clippy::unwrap_used)]
+mod test {
+    use bencher_json::{project::boundary::BoundaryLimit, CdfBoundary};
+    use once_cell::sync::Lazy;
+    use pretty_assertions::assert_eq;
+
+    use super::KolmogorovSmirnov;
+
+    const DATA_ZERO: &[f64] = &[];
+    const DATA_TEN: &[f64] = &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+    const SAMPLE_SAME: &[f64] = &[2.0, 4.0, 6.0, 8.0, 10.0];
+    const SAMPLE_HIGH: &[f64] = &[11.0, 12.0, 13.0, 14.0, 15.0];
+    const SAMPLE_LOW: &[f64] = &[-1.0, -2.0, -3.0, -4.0, -5.0];
+
+    static BOUNDARY_NINETY_FIVE: Lazy<CdfBoundary> = Lazy::new(|| 0.95.try_into().unwrap());
+
+    #[test]
+    fn test_ks_zero() {
+        assert_eq!(KolmogorovSmirnov::new(DATA_ZERO, SAMPLE_SAME), None);
+        assert_eq!(KolmogorovSmirnov::new(DATA_TEN, DATA_ZERO), None);
+    }
+
+    #[test]
+    fn test_ks_nan() {
+        assert_eq!(KolmogorovSmirnov::new(DATA_TEN, &[1.0, f64::NAN]), None);
+    }
+
+    #[test]
+    fn test_ks_same() {
+        let ks = KolmogorovSmirnov::new(DATA_TEN, SAMPLE_SAME).unwrap();
+        assert_eq!(ks.median, 5.5);
+        assert_eq!(ks.lower_statistic, 0.0);
+        assert_eq!(ks.upper_statistic, 0.1);
+        assert_eq!(
+            ks.outlier(Some(*BOUNDARY_NINETY_FIVE), Some(*BOUNDARY_NINETY_FIVE)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_ks_identical() {
+        let ks = KolmogorovSmirnov::new(DATA_TEN, DATA_TEN).unwrap();
+        assert_eq!(ks.lower_statistic, 0.0);
+        assert_eq!(ks.upper_statistic, 0.0);
+        assert_eq!(ks.effect_size(), 0.0);
+    }
+
+    #[test]
+    fn test_ks_high() {
+        let ks = KolmogorovSmirnov::new(DATA_TEN, SAMPLE_HIGH).unwrap();
+        assert_eq!(ks.lower_statistic, 0.0);
+        assert_eq!(ks.upper_statistic, 1.0);
+        assert_eq!(ks.effect_size(), 1.0);
+        assert_eq!(
+            ks.outlier(Some(*BOUNDARY_NINETY_FIVE), Some(*BOUNDARY_NINETY_FIVE)),
+            Some(BoundaryLimit::Upper)
+        );
+        assert_eq!(ks.outlier(Some(*BOUNDARY_NINETY_FIVE), None), None);
+    }
+
+    #[test]
+    fn test_ks_low() {
+        let ks = KolmogorovSmirnov::new(DATA_TEN, SAMPLE_LOW).unwrap();
+        assert_eq!(ks.lower_statistic, 1.0);
+        assert_eq!(ks.upper_statistic, 0.0);
+        assert_eq!(ks.effect_size(), -1.0);
+        assert_eq!(
+            ks.outlier(Some(*BOUNDARY_NINETY_FIVE), Some(*BOUNDARY_NINETY_FIVE)),
+            Some(BoundaryLimit::Lower)
+        );
+        assert_eq!(ks.outlier(None, Some(*BOUNDARY_NINETY_FIVE)), None);
+    }
+
+    #[test]
+    fn test_ks_single_datum() {
+        // A single datum can never be significant at the 95% level against ten historical data
+        let ks = KolmogorovSmirnov::new(DATA_TEN, &[100.0]).unwrap();
+        assert_eq!(ks.upper_statistic, 1.0);
+        assert_eq!(
+            ks.outlier(Some(*BOUNDARY_NINETY_FIVE), Some(*BOUNDARY_NINETY_FIVE)),
+            None
+        );
+    }
+}
@@ -1,11 +1,18 @@
 pub mod boundary;
+pub mod change_point;
+pub mod compare;
 mod error;
+mod kolmogorov_smirnov;
 pub mod limits;
 mod ln;
 mod mean;
 mod quartiles;
+mod rank_sum;
+mod welch_t;
 
 pub use boundary::MetricsBoundary;
+pub use change_point::ChangePoints;
+pub use compare::Comparison;
 pub use error::BoundaryError;
 
 #[derive(Debug, Clone)]
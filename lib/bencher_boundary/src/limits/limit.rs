@@ -1,6 +1,6 @@
-use bencher_json::{Boundary, IqrBoundary, PercentageBoundary};
+use bencher_json::{Boundary, CdfBoundary, IqrBoundary, PercentageBoundary};
 
-use crate::quartiles::Quartiles;
+use crate::{quartiles::Quartiles, rank_sum::RankSum};
 
 #[derive(Debug, PartialEq)]
 pub struct MetricsLimit {
@@ -70,6 +70,14 @@ impl MetricsLimit {
     fn delta(median: f64, delta_quartiles: Quartiles, boundary: IqrBoundary) -> f64 {
         median * delta_quartiles.iqr(boundary)
     }
+
+    pub fn rank_sum_lower(rank_sum: &RankSum, boundary: CdfBoundary) -> Option<Self> {
+        rank_sum.lower_limit(boundary).map(|value| Self { value })
+    }
+
+    pub fn rank_sum_upper(rank_sum: &RankSum, boundary: CdfBoundary) -> Option<Self> {
+        rank_sum.upper_limit(boundary).map(|value| Self { value })
+    }
 }
 
 impl From<MetricsLimit> for f64 {
@@ -91,7 +99,7 @@ impl From<Boundary> for MetricsLimit {
 mod test {
     use pretty_assertions::assert_eq;
 
-    use crate::{limits::MetricsLimit, quartiles::Quartiles};
+    use crate::{limits::MetricsLimit, quartiles::Quartiles, rank_sum::RankSum};
 
     #[test]
     fn test_limit_percentage_lower() {
@@ -181,4 +189,28 @@ mod test {
         let limit = MetricsLimit::delta_iqr_upper(quartiles, delta_quartiles, boundary);
         assert_eq!(limit.value, 5.0);
     }
+
+    #[test]
+    fn test_limit_rank_sum_lower() {
+        let rank_sum = RankSum::new(&[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+        let boundary = 0.8.try_into().unwrap();
+        let limit = MetricsLimit::rank_sum_lower(&rank_sum, boundary).unwrap();
+        assert_eq!(limit.value, 1.0);
+    }
+
+    #[test]
+    fn test_limit_rank_sum_upper() {
+        let rank_sum = RankSum::new(&[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+        let boundary = 0.8.try_into().unwrap();
+        let limit = MetricsLimit::rank_sum_upper(&rank_sum, boundary).unwrap();
+        assert_eq!(limit.value, 5.0);
+    }
+
+    #[test]
+    fn test_limit_rank_sum_too_few() {
+        let rank_sum = RankSum::new(&[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+        let boundary = 0.9.try_into().unwrap();
+        assert_eq!(MetricsLimit::rank_sum_lower(&rank_sum, boundary), None);
+        assert_eq!(MetricsLimit::rank_sum_upper(&rank_sum, boundary), None);
+    }
 }
@@ -4,7 +4,10 @@ use bencher_json::{
 use slog::{debug, Logger};
 use statrs::distribution::{ContinuousCDF, LogNormal, Normal, StudentsT};
 
-use crate::{ln::Ln, quartiles::Quartiles, BoundaryError};
+use crate::{
+    kolmogorov_smirnov::KolmogorovSmirnov, ln::Ln, quartiles::Quartiles, rank_sum::RankSum,
+    BoundaryError,
+};
 
 mod limit;
 
@@ -197,6 +200,61 @@ impl MetricsLimits {
         }
     }
 
+    pub fn new_rank_sum(
+        log: &Logger,
+        rank_sum: &RankSum,
+        lower_boundary: Option<CdfBoundary>,
+        upper_boundary: Option<CdfBoundary>,
+    ) -> Self {
+        if lower_boundary.is_none() && upper_boundary.is_none() {
+            return Self::default();
+        }
+
+        debug!(
+            log,
+            "Mann-Whitney U: median={median}, samples={samples}",
+            median = rank_sum.median,
+            samples = rank_sum.sorted_data.len()
+        );
+        let lower =
+            lower_boundary.and_then(|boundary| MetricsLimit::rank_sum_lower(rank_sum, boundary));
+        let upper =
+            upper_boundary.and_then(|boundary| MetricsLimit::rank_sum_upper(rank_sum, boundary));
+
+        Self {
+            baseline: Some(rank_sum.median),
+            lower,
+            upper,
+        }
+    }
+
+    // The Kolmogorov-Smirnov test compares the shape of the distributions,
+    // so there are no boundary limits in terms of a single datum.
+    pub fn new_kolmogorov_smirnov(
+        log: &Logger,
+        ks: &KolmogorovSmirnov,
+        lower_boundary: Option<CdfBoundary>,
+        upper_boundary: Option<CdfBoundary>,
+    ) -> Self {
+        if lower_boundary.is_none() && upper_boundary.is_none() {
+            return Self::default();
+        }
+
+        debug!(
+            log,
+            "Kolmogorov-Smirnov: median={median}, lower={lower}, upper={upper}",
+            median = ks.median,
+            lower = ks.lower_statistic,
+            upper = ks.upper_statistic
+        );
+
+        Self {
+            baseline: Some(ks.median),
+            lower: None,
+            upper: None,
+        }
+    }
+
     // An outlier occurs when the  datum exceeds a boundary limit.
     pub fn outlier(&self, datum: f64) -> Option<BoundaryLimit> {
         match (self.lower.as_ref(), self.upper.as_ref()) {
@@ -227,7 +285,7 @@ mod test {
     use ordered_float::OrderedFloat;
     use pretty_assertions::assert_eq;
 
-    use crate::{ln::Ln, quartiles::Quartiles};
+    use crate::{ln::Ln, quartiles::Quartiles, rank_sum::RankSum};
 
     use super::{MetricsLimit, MetricsLimits, NormalTestKind};
 
@@ -289,6 +347,14 @@ mod test {
     const IQR_NEGATIVE_LIMIT: f64 = -1.0;
     const IQR_POSITIVE_LIMIT: f64 = 5.0;
 
+    const RANK_SUM_DATA: &[f64] = &[
+        1.0, 1.1, 1.2, 1.3, 1.4, 10.0, 10.1, 10.2, 10.3, 10.4, 1.0, 1.1, 1.2, 1.3, 1.4, 10.0, 10.1,
+        10.2, 10.3,
+    ];
+    const RANK_SUM_MEDIAN: f64 = 1.4;
+    const RANK_SUM_LOWER_LIMIT: f64 = 1.1;
+    const RANK_SUM_UPPER_LIMIT: f64 = 10.3;
+
     const LOG_NORMAL_NEGATIVE_OUTLIER: f64 = -3.0;
     const NORMAL_NEGATIVE_OUTLIER: f64 = -1.5;
     const NORMAL_NEGATIVE: f64 = -1.0;
@@ -1353,4 +1419,73 @@ mod test {
         let side = limits.outlier(150.0);
         assert_eq!(side, Some(BoundaryLimit::Upper));
     }
+
+    #[test]
+    fn test_limits_rank_sum_none() {
+        let log = bootstrap_logger();
+        let rank_sum = RankSum::new(RANK_SUM_DATA).unwrap();
+        let limits = MetricsLimits::new_rank_sum(&log, &rank_sum, None, None);
+        assert_eq!(limits.baseline, None);
+        assert_eq!(limits.lower, None);
+        assert_eq!(limits.upper, None);
+
+        let side = limits.outlier(NORMAL_ZERO);
+        assert_eq!(side, None);
+
+        let side = limits.outlier(IQR_POSITIVE_OUTLIER);
+        assert_eq!(side, None);
+    }
+
+    #[test]
+    fn test_limits_rank_sum_both() {
+        let log = bootstrap_logger();
+        let rank_sum = RankSum::new(RANK_SUM_DATA).unwrap();
+        let limits =
+            MetricsLimits::new_rank_sum(&log, &rank_sum, Some(*PERCENTILE), Some(*PERCENTILE));
+        assert_eq!(
+            OrderedFloat::from(limits.baseline.unwrap()),
+            OrderedFloat::from(RANK_SUM_MEDIAN)
+        );
+        assert_eq!(
+            limits.lower,
+            Some(MetricsLimit {
+                value: RANK_SUM_LOWER_LIMIT
+            })
+        );
+        assert_eq!(
+            limits.upper,
+            Some(MetricsLimit {
+                value: RANK_SUM_UPPER_LIMIT
+            })
+        );
+
+        let side = limits.outlier(NORMAL_ZERO);
+        assert_eq!(side, Some(BoundaryLimit::Lower));
+
+        let side = limits.outlier(RANK_SUM_MEDIAN);
+        assert_eq!(side, None);
+
+        let side = limits.outlier(RANK_SUM_UPPER_LIMIT);
+        assert_eq!(side, None);
+
+        let side = limits.outlier(IQR_POSITIVE_OUTLIER * 2.0);
+        assert_eq!(side, Some(BoundaryLimit::Upper));
+    }
+
+    #[test]
+    fn test_limits_rank_sum_too_few() {
+        let log = bootstrap_logger();
+        let rank_sum = RankSum::new(&[IQR_Q1, IQR_Q2, IQR_Q3]).unwrap();
+        let limits =
+            MetricsLimits::new_rank_sum(&log, &rank_sum, Some(*PERCENTILE), Some(*PERCENTILE));
+        assert_eq!(
+            OrderedFloat::from(limits.baseline.unwrap()),
+            OrderedFloat::from(IQR_Q2)
+        );
+        assert_eq!(limits.lower, None);
+        assert_eq!(limits.upper, None);
+
+        let side = limits.outlier(IQR_POSITIVE_OUTLIER);
+        assert_eq!(side, None);
+    }
 }
@@ -0,0 +1,282 @@
+use bencher_json::{project::boundary::BoundaryLimit, CdfBoundary};
+use statrs::distribution::{ContinuousCDF, Normal};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankSum {
+    pub sorted_data: Vec<f64>,
+    pub median: f64,
+}
+
+impl RankSum {
+    pub fn new(data: &[f64]) -> Option<Self> {
+        if data.is_empty() || data.iter().any(|datum| !datum.is_finite()) {
+            return None;
+        }
+
+        let mut sorted_data = data.to_vec();
+        sorted_data.sort_unstable_by(|x, y| x.partial_cmp(y).unwrap_or(std::cmp::Ordering::Equal));
+        let median = Self::median(&sorted_data)?;
+
+        Some(Self {
+            sorted_data,
+            median,
+        })
+    }
+
+    #[allow(clippy::integer_division)]
+    fn median(sorted_data: &[f64]) -> Option<f64> {
+        let len = sorted_data.len();
+        let mid = len / 2;
+        if len % 2 == 0 {
+            let lower = sorted_data.get(mid.checked_sub(1)?)?;
+            let upper = sorted_data.get(mid)?;
+            Some((lower + upper) / 2.0)
+        } else {
+            sorted_data.get(mid).copied()
+        }
+    }
+
+    // For a single new datum compared against `n` historical samples,
+    // the Mann-Whitney U statistic is the number of historical samples less than the new datum.
+    // Under the null hypothesis that both come from the same distribution,
+    // U is uniformly distributed over `0..=n`, so `P(U >= k) = (n + 1 - k) / (n + 1)`.
+    // The critical rank is the smallest `k` for which that probability is within the boundary.
+    // If there are not enough historical samples to reach the boundary, then there is no critical rank.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    fn critical_rank(&self, boundary: CdfBoundary) -> Option<usize> {
+        const EPSILON: f64 = 1e-9;
+        let len = self.sorted_data.len();
+        let rank = ((len + 1) as f64 * f64::from(boundary) - EPSILON).ceil();
+        let rank = if rank < 1.0 { 1 } else { rank as usize };
+        (rank <= len).then_some(rank)
+    }
+
+    pub fn lower_limit(&self, boundary: CdfBoundary) -> Option<f64> {
+        let rank = self.critical_rank(boundary)?;
+        self.sorted_data.get(self.sorted_data.len() - rank).copied()
+    }
+
+    pub fn upper_limit(&self, boundary: CdfBoundary) -> Option<f64> {
+        let rank = self.critical_rank(boundary)?;
+        self.sorted_data.get(rank - 1).copied()
+    }
+
+    // The Mann-Whitney U statistic for a new sample against the historical data
+    // is the number of (sample, historical) pairs where the sample datum is greater,
+    // with ties counting as one half.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn u_statistic(&self, sample: &[f64]) -> f64 {
+        sample
+            .iter()
+            .map(|&datum| {
+                let less = self.sorted_data.partition_point(|&d| d < datum);
+                let less_or_equal = self.sorted_data.partition_point(|&d| d <= datum);
+                less as f64 + (less_or_equal - less) as f64 / 2.0
+            })
+            .sum()
+    }
+
+    /// The rank-biserial correlation of the new sample against the historical data.
+    /// It is `-1.0` if every new datum is lower, `1.0` if every new datum is higher,
+    /// and `0.0` if they are evenly ranked.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn effect_size(&self, sample: &[f64]) -> Option<f64> {
+        let pairs = (self.sorted_data.len() * sample.len()) as f64;
+        (pairs > 0.0).then(|| 2.0 * self.u_statistic(sample) / pairs - 1.0)
+    }
+
+    // For a new sample of more than one datum, the rank-sum of the entire sample is tested
+    // using the normal approximation of the U statistic with a correction for ties.
+    // The new sample is an outlier if the one-sided probability of its U statistic is beyond the boundary.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn outlier(
+        &self,
+        sample: &[f64],
+        lower_boundary: Option<CdfBoundary>,
+        upper_boundary: Option<CdfBoundary>,
+    ) -> Option<BoundaryLimit> {
+        if sample.is_empty() || sample.iter().any(|datum| !datum.is_finite()) {
+            return None;
+        }
+        let data_len = self.sorted_data.len() as f64;
+        let sample_len = sample.len() as f64;
+        let total_len = data_len + sample_len;
+
+        let mut combined = self.sorted_data.clone();
+        combined.extend_from_slice(sample);
+        combined.sort_unstable_by(|x, y| x.partial_cmp(y).unwrap_or(std::cmp::Ordering::Equal));
+        let mut ties = 0.0;
+        let mut index = 0;
+        while let Some(&datum) = combined.get(index) {
+            let run_len = combined
+                .get(index..)
+                .map_or(1, |rest| rest.partition_point(|&d| d <= datum));
+            ties += (run_len as f64).powi(3) - run_len as f64;
+            index += run_len;
+        }
+
+        let variance = data_len * sample_len / 12.0
+            * ((total_len + 1.0) - ties / (total_len * (total_len - 1.0)));
+        let std_dev = variance.sqrt();
+        if !std_dev.is_normal() {
+            return None;
+        }
+        let z = (self.u_statistic(sample) - data_len * sample_len / 2.0) / std_dev;
+        let normal = Normal::new(0.0, 1.0).ok()?;
+
+        let lower = lower_boundary.is_some_and(|boundary| normal.cdf(-z) > f64::from(boundary));
+        let upper = upper_boundary.is_some_and(|boundary| normal.cdf(z) > f64::from(boundary));
+        match (lower, upper) {
+            (true, _) => Some(BoundaryLimit::Lower),
+            (false, true) => Some(BoundaryLimit::Upper),
+            (false, false) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp, clippy::unwrap_used)]
+mod test {
+    use bencher_json::{project::boundary::BoundaryLimit, CdfBoundary};
+    use once_cell::sync::Lazy;
+    use pretty_assertions::assert_eq;
+
+    use super::RankSum;
+
+    const DATA_ZERO: &[f64] = &[];
+    const DATA_ONE: &[f64] = &[1.0];
+    const DATA_FOUR: &[f64] = &[4.0, 1.0, 3.0, 2.0];
+    const DATA_FIVE: &[f64] = &[1.0, 2.0, 3.0, 4.0, 5.0];
+    const DATA_FIVE_DESC: &[f64] = &[5.0, 4.0, 3.0, 2.0, 1.0];
+    const DATA_SIX_BI: &[f64] = &[-6.0, -6.0, 1.0, 1.0, 9.0, 9.0];
+    const DATA_NAN: &[f64] = &[1.0, f64::NAN, 3.0];
+
+    static BOUNDARY_FIFTY: Lazy<CdfBoundary> = Lazy::new(|| 0.5.try_into().unwrap());
+    static BOUNDARY_EIGHTY: Lazy<CdfBoundary> = Lazy::new(|| 0.8.try_into().unwrap());
+    static BOUNDARY_NINETY_NINE: Lazy<CdfBoundary> = Lazy::new(|| 0.99.try_into().unwrap());
+
+    #[test]
+    fn test_rank_sum_zero() {
+        let rank_sum = RankSum::new(DATA_ZERO);
+        assert_eq!(rank_sum, None);
+    }
+
+    #[test]
+    fn test_rank_sum_nan() {
+        let rank_sum = RankSum::new(DATA_NAN);
+        assert_eq!(rank_sum, None);
+    }
+
+    #[test]
+    fn test_rank_sum_one() {
+        let rank_sum = RankSum::new(DATA_ONE).unwrap();
+        assert_eq!(rank_sum.median, 1.0);
+        assert_eq!(rank_sum.lower_limit(*BOUNDARY_FIFTY), Some(1.0));
+        assert_eq!(rank_sum.upper_limit(*BOUNDARY_FIFTY), Some(1.0));
+        assert_eq!(rank_sum.lower_limit(*BOUNDARY_EIGHTY), None);
+        assert_eq!(rank_sum.upper_limit(*BOUNDARY_EIGHTY), None);
+    }
+
+    #[test]
+    fn test_rank_sum_four() {
+        let rank_sum = RankSum::new(DATA_FOUR).unwrap();
+        assert_eq!(rank_sum.sorted_data, vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(rank_sum.median, 2.5);
+        assert_eq!(rank_sum.lower_limit(*BOUNDARY_EIGHTY), Some(1.0));
+        assert_eq!(rank_sum.upper_limit(*BOUNDARY_EIGHTY), Some(4.0));
+        assert_eq!(rank_sum.lower_limit(*BOUNDARY_NINETY_NINE), None);
+        assert_eq!(rank_sum.upper_limit(*BOUNDARY_NINETY_NINE), None);
+    }
+
+    #[test]
+    fn test_rank_sum_five() {
+        let rank_sum = RankSum::new(DATA_FIVE).unwrap();
+        assert_eq!(rank_sum.median, 3.0);
+        assert_eq!(rank_sum.lower_limit(*BOUNDARY_FIFTY), Some(3.0));
+        assert_eq!(rank_sum.upper_limit(*BOUNDARY_FIFTY), Some(3.0));
+        assert_eq!(rank_sum.lower_limit(*BOUNDARY_EIGHTY), Some(1.0));
+        assert_eq!(rank_sum.upper_limit(*BOUNDARY_EIGHTY), Some(5.0));
+    }
+
+    #[test]
+    fn test_rank_sum_five_desc() {
+        let rank_sum = RankSum::new(DATA_FIVE_DESC).unwrap();
+        assert_eq!(rank_sum, RankSum::new(DATA_FIVE).unwrap());
+    }
+
+    #[test]
+    fn test_rank_sum_six_bi() {
+        let rank_sum = RankSum::new(DATA_SIX_BI).unwrap();
+        assert_eq!(rank_sum.median, 1.0);
+        assert_eq!(rank_sum.lower_limit(*BOUNDARY_FIFTY), Some(1.0));
+        assert_eq!(rank_sum.upper_limit(*BOUNDARY_FIFTY), Some(1.0));
+        assert_eq!(rank_sum.lower_limit(*BOUNDARY_EIGHTY), Some(-6.0));
+        assert_eq!(rank_sum.upper_limit(*BOUNDARY_EIGHTY), Some(9.0));
+    }
+
+    #[test]
+    fn test_rank_sum_hundred() {
+        let data = (1..=99).map(f64::from).collect::<Vec<_>>();
+        let rank_sum = RankSum::new(&data).unwrap();
+        assert_eq!(rank_sum.median, 50.0);
+        assert_eq!(rank_sum.lower_limit(*BOUNDARY_NINETY_NINE), Some(1.0));
+        assert_eq!(rank_sum.upper_limit(*BOUNDARY_NINETY_NINE), Some(99.0));
+        assert_eq!(rank_sum.lower_limit(*BOUNDARY_EIGHTY), Some(20.0));
+        assert_eq!(rank_sum.upper_limit(*BOUNDARY_EIGHTY), Some(80.0));
+    }
+
+    #[test]
+    fn test_rank_sum_u_statistic() {
+        let rank_sum = RankSum::new(DATA_FIVE).unwrap();
+        assert_eq!(rank_sum.u_statistic(&[0.0]), 0.0);
+        assert_eq!(rank_sum.u_statistic(&[3.0]), 2.5);
+        assert_eq!(rank_sum.u_statistic(&[6.0]), 5.0);
+        assert_eq!(rank_sum.u_statistic(&[0.0, 3.0, 6.0]), 7.5);
+    }
+
+    #[test]
+    fn test_rank_sum_effect_size() {
+        let rank_sum = RankSum::new(DATA_FIVE).unwrap();
+        assert_eq!(rank_sum.effect_size(&[]), None);
+        assert_eq!(rank_sum.effect_size(&[0.0, -1.0]), Some(-1.0));
+        assert_eq!(rank_sum.effect_size(&[3.0]), Some(0.0));
+        assert_eq!(rank_sum.effect_size(&[6.0, 7.0]), Some(1.0));
+    }
+
+    #[test]
+    fn test_rank_sum_outlier_sample() {
+        let data = (1..=99).map(f64::from).collect::<Vec<_>>();
+        let rank_sum = RankSum::new(&data).unwrap();
+        let boundary = Some(*BOUNDARY_NINETY_NINE);
+
+        let higher = [150.0, 160.0, 170.0, 180.0, 190.0];
+        assert_eq!(
+            rank_sum.outlier(&higher, boundary, boundary),
+            Some(BoundaryLimit::Upper)
+        );
+        assert_eq!(rank_sum.outlier(&higher, boundary, None), None);
+
+        let lower = [-1.0, -2.0, -3.0];
+        assert_eq!(
+            rank_sum.outlier(&lower, boundary, boundary),
+            Some(BoundaryLimit::Lower)
+        );
+        assert_eq!(rank_sum.outlier(&lower, None, boundary), None);
+
+        let middle = [49.0, 50.0, 51.0];
+        assert_eq!(rank_sum.outlier(&middle, boundary, boundary), None);
+    }
+
+    #[test]
+    fn test_rank_sum_outlier_sample_ties() {
+        let rank_sum = RankSum::new(&[1.0, 1.0, 1.0]).unwrap();
+        let boundary = Some(*BOUNDARY_FIFTY);
+        assert_eq!(rank_sum.outlier(&[1.0, 1.0], boundary, boundary), None);
+        assert_eq!(rank_sum.outlier(&[], boundary, boundary), None);
+        assert_eq!(rank_sum.outlier(DATA_NAN, boundary, boundary), None);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::mean::mean;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WelchT {
+    /// The mean of the historical data
+    pub mean: f64,
+    /// The mean of the new sample
+    pub sample_mean: f64,
+    /// The standard error of the difference between the means
+    pub std_err: f64,
+    /// The Welch-Satterthwaite degrees of freedom
+    pub freedom: f64,
+    /// Cohen's d for the difference between the means
+    pub effect_size: Option<f64>,
+}
+
+impl WelchT {
+    // If the new sample only has a single datum, then its variance can not be estimated.
+    // In that case, the historical variance is used for both the historical data and the new sample.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn new(data: &[f64], sample: &[f64]) -> Option<Self> {
+        let data_len = data.len() as f64;
+        let sample_len = sample.len() as f64;
+
+        let data_mean = mean(data)?;
+        let sample_mean = mean(sample)?;
+        let data_variance = sample_variance(data_mean, data)?;
+        let sample_variance = if sample.len() < 2 {
+            data_variance
+        } else {
+            sample_variance(sample_mean, sample)?
+        };
+
+        let data_err = data_variance / data_len;
+        let sample_err = sample_variance / sample_len;
+        let std_err = (data_err + sample_err).sqrt();
+        if !std_err.is_normal() {
+            return None;
+        }
+
+        let freedom = if sample.len() < 2 {
+            data_len - 1.0
+        } else {
+            (data_err + sample_err).powi(2)
+                / (data_err.powi(2) / (data_len - 1.0) + sample_err.powi(2) / (sample_len - 1.0))
+        };
+        if !freedom.is_normal() {
+            return None;
+        }
+
+        let pooled_std_dev = if sample.len() < 2 {
+            data_variance.sqrt()
+        } else {
+            (((data_len - 1.0) * data_variance + (sample_len - 1.0) * sample_variance)
+                / (data_len + sample_len - 2.0))
+                .sqrt()
+        };
+        let effect_size = pooled_std_dev
+            .is_normal()
+            .then(|| (sample_mean - data_mean) / pooled_std_dev);
+
+        Some(Self {
+            mean: data_mean,
+            sample_mean,
+            std_err,
+            freedom,
+            effect_size,
+        })
+    }
+}
+
+// The unbiased sample variance, using Bessel's correction
+fn sample_variance(location: f64, data: &[f64]) -> Option<f64> {
+    if data.len() < 2 {
+        return None;
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let variance = data
+        .iter()
+        .map(|&value| (value - location).powi(2))
+        .sum::<f64>()
+        / (data.len() - 1) as f64;
+    variance.is_finite().then_some(variance)
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp, clippy::unwrap_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::WelchT;
+
+    const DATA_ZERO: &[f64] = &[];
+    const DATA_ONE: &[f64] = &[1.0];
+    const DATA_FIVE: &[f64] = &[1.0, 2.0, 3.0, 4.0, 5.0];
+    const DATA_FIVE_CONST: &[f64] = &[1.0, 1.0, 1.0, 1.0, 1.0];
+    const SAMPLE_THREE: &[f64] = &[4.0, 5.0, 6.0];
+
+    #[test]
+    fn test_welch_t_zero() {
+        assert_eq!(WelchT::new(DATA_ZERO, SAMPLE_THREE), None);
+        assert_eq!(WelchT::new(DATA_FIVE, DATA_ZERO), None);
+    }
+
+    #[test]
+    fn test_welch_t_one() {
+        assert_eq!(WelchT::new(DATA_ONE, SAMPLE_THREE), None);
+    }
+
+    #[test]
+    fn test_welch_t_const() {
+        assert_eq!(WelchT::new(DATA_FIVE_CONST, DATA_FIVE_CONST), None);
+    }
+
+    #[test]
+    fn test_welch_t_single_datum() {
+        let welch_t = WelchT::new(DATA_FIVE, &[5.0]).unwrap();
+        assert_eq!(welch_t.mean, 3.0);
+        assert_eq!(welch_t.sample_mean, 5.0);
+        // sqrt(2.5 / 5 + 2.5 / 1)
+        assert_eq!(welch_t.std_err, f64::sqrt(3.0));
+        assert_eq!(welch_t.freedom, 4.0);
+        assert_eq!(welch_t.effect_size, Some(2.0 / f64::sqrt(2.5)));
+    }
+
+    #[test]
+    fn test_welch_t_sample() {
+        let welch_t = WelchT::new(DATA_FIVE, SAMPLE_THREE).unwrap();
+        assert_eq!(welch_t.mean, 3.0);
+        assert_eq!(welch_t.sample_mean, 5.0);
+        // sqrt(2.5 / 5 + 1.0 / 3)
+        assert!((welch_t.std_err - f64::sqrt(5.0 / 6.0)).abs() < f64::EPSILON);
+        // (1/2 + 1/3)^2 / ((1/2)^2 / 4 + (1/3)^2 / 2)
+        assert!((welch_t.freedom - 3600.0 / 612.0).abs() < 1e-9);
+        // 2 / sqrt((4 * 2.5 + 2 * 1.0) / 6)
+        assert!((welch_t.effect_size.unwrap() - f64::sqrt(2.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_welch_t_sample_const() {
+        let welch_t = WelchT::new(DATA_FIVE, &[3.0, 3.0, 3.0]).unwrap();
+        assert_eq!(welch_t.sample_mean, 3.0);
+        assert_eq!(welch_t.freedom, 4.0);
+        assert_eq!(welch_t.effect_size, Some(0.0));
+    }
+}
@@ -1,4 +1,6 @@
 use bencher_json::{Jwt, BENCHER_API_URL};
+use futures_util::StreamExt;
+use progenitor_client::ByteStream;
 use serde::{de::DeserializeOwned, Serialize};
 use tokio::time::{sleep, Duration};
 
@@ -156,24 +158,9 @@ impl BencherClient {
         Json: DeserializeOwned + Serialize + TryFrom<T, Error = E>,
         E: std::error::Error + Send + Sync + 'static,
     {
-        let timeout = std::time::Duration::from_secs(15);
-        let mut client_builder = reqwest::ClientBuilder::new()
-            .connect_timeout(timeout)
-            .timeout(timeout);
-
-        if let Some(token) = &self.token {
-            let mut headers = reqwest::header::HeaderMap::new();
-            let bearer_token = reqwest::header::HeaderValue::from_str(&format!("Bearer {token}"))
-                .map_err(ClientError::HeaderValue)?;
-            headers.insert("Authorization", bearer_token);
-            client_builder = client_builder.default_headers(headers);
-        }
-
-        let reqwest_client = client_builder.build().map_err(ClientError::BuildClient)?;
-        let client = crate::codegen::Client::new_with_client(self.host.as_ref(), reqwest_client);
+        let client = self.codegen_client()?;
 
         let attempts = self.attempts;
-        let max_attempts = attempts.checked_sub(1).unwrap_or_default();
         let mut retry_after = self.retry_after;
 
         for attempt in 0..attempts {
@@ -186,18 +173,8 @@ impl BencherClient {
                     self.log(&json_response)?;
                     return Ok(json_response);
                 },
-                #[allow(clippy::print_stderr)]
                 Err(crate::codegen::Error::CommunicationError(e)) => {
-                    if self.log {
-                        eprintln!("\nSend attempt #{}/{attempts}: {e}", attempt + 1);
-                    }
-                    if attempt != max_attempts {
-                        if self.log {
-                            eprintln!("Will retry after {retry_after} second(s).");
-                        }
-                        sleep(Duration::from_secs(retry_after)).await;
-                        retry_after *= 2;
-                    }
+                    self.retry(attempt, &e, &mut retry_after).await;
                 },
                 Err(crate::codegen::Error::InvalidRequest(e)) => {
                     return Err(ClientError::InvalidRequest(e))
@@ -259,6 +236,113 @@ impl BencherClient {
         Err(ClientError::SendTimeout(attempts))
     }
 
+    /// Send a request to the Bencher API that responds with raw bytes, such as an image
+    ///
+    /// # Parameters
+    ///
+    /// - `sender`: A function that takes a `codegen::Client` and returns a `Future` that resolves
+    ///  to a `Result` containing a `ResponseValue` byte stream or an `Error`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the response bytes or an `Error`
+    pub async fn send_bytes<F, R>(&self, sender: F) -> Result<bytes::Bytes, ClientError>
+    where
+        F: Fn(crate::codegen::Client) -> R,
+        R: std::future::Future<
+            Output = Result<
+                progenitor_client::ResponseValue<ByteStream>,
+                crate::codegen::Error<ByteStream>,
+            >,
+        >,
+    {
+        let client = self.codegen_client()?;
+
+        let attempts = self.attempts;
+        let mut retry_after = self.retry_after;
+
+        for attempt in 0..attempts {
+            match sender(client.clone()).await {
+                Ok(response_value) => return collect_bytes(response_value.into_inner()).await,
+                Err(crate::codegen::Error::CommunicationError(e)) => {
+                    self.retry(attempt, &e, &mut retry_after).await;
+                },
+                Err(crate::codegen::Error::InvalidRequest(e)) => {
+                    return Err(ClientError::InvalidRequest(e))
+                },
+                Err(crate::codegen::Error::PreHookError(e)) => {
+                    return Err(ClientError::PreHookError(e))
+                },
+                Err(crate::codegen::Error::ErrorResponse(e)) => {
+                    let status = e.status();
+                    let headers = e.headers().clone();
+                    let bytes = collect_bytes(e.into_inner()).await?;
+                    let http_error: crate::codegen::types::Error =
+                        serde_json::from_slice(&bytes)
+                            .map_err(ClientError::InvalidResponsePayload)?;
+                    return Err(ClientError::ErrorResponse(ErrorResponse {
+                        status,
+                        headers,
+                        request_id: http_error.request_id,
+                        error_code: http_error.error_code,
+                        message: http_error.message,
+                    }));
+                },
+                Err(crate::codegen::Error::InvalidUpgrade(e)) => {
+                    return Err(ClientError::InvalidUpgrade(e))
+                },
+                Err(crate::codegen::Error::ResponseBodyError(e)) => {
+                    return Err(ClientError::ResponseBodyError(e))
+                },
+                Err(crate::codegen::Error::InvalidResponsePayload(_bytes, e)) => {
+                    return Err(ClientError::InvalidResponsePayload(e))
+                },
+                Err(crate::codegen::Error::UnexpectedResponse(response)) => {
+                    return Err(ClientError::UnexpectedResponseErr(response))
+                },
+            }
+        }
+
+        Err(ClientError::SendTimeout(attempts))
+    }
+
+    fn codegen_client(&self) -> Result<crate::codegen::Client, ClientError> {
+        let timeout = std::time::Duration::from_secs(15);
+        let mut client_builder = reqwest::ClientBuilder::new()
+            .connect_timeout(timeout)
+            .timeout(timeout);
+
+        if let Some(token) = &self.token {
+            let mut headers = reqwest::header::HeaderMap::new();
+            let bearer_token = reqwest::header::HeaderValue::from_str(&format!("Bearer {token}"))
+                .map_err(ClientError::HeaderValue)?;
+            headers.insert("Authorization", bearer_token);
+            client_builder = client_builder.default_headers(headers);
+        }
+
+        let reqwest_client = client_builder.build().map_err(ClientError::BuildClient)?;
+        Ok(crate::codegen::Client::new_with_client(
+            self.host.as_ref(),
+            reqwest_client,
+        ))
+    }
+
+    // Wait before the next attempt, unless this was the last attempt
+    #[allow(clippy::print_stderr)]
+    async fn retry(&self, attempt: usize, err: &reqwest::Error, retry_after: &mut u64) {
+        let attempts = self.attempts;
+        if self.log {
+            eprintln!("\nSend attempt #{}/{attempts}: {err}", attempt + 1);
+        }
+        if attempt != attempts.checked_sub(1).unwrap_or_default() {
+            if self.log {
+                eprintln!("Will retry after {retry_after} second(s).");
+            }
+            sleep(Duration::from_secs(*retry_after)).await;
+            *retry_after *= 2;
+        }
+    }
+
     fn log<T>(&self, response: &T) -> Result<(), ClientError>
     where
         T: Serialize,
@@ -274,6 +358,15 @@ impl BencherClient {
     }
 }
 
+async fn collect_bytes(byte_stream: ByteStream) -> Result<bytes::Bytes, ClientError> {
+    let mut stream = byte_stream.into_inner();
+    let mut bytes = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        bytes.extend_from_slice(&chunk.map_err(ClientError::ResponseBodyError)?);
+    }
+    Ok(bytes.into())
+}
+
 #[derive(Debug)]
 pub struct ErrorResponse {
     pub status: reqwest::StatusCode,
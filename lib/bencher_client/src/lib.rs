@@ -28,17 +28,20 @@ macro_rules! from_client {
 }
 
 from_client!(
+    BenchmarkGroup,
     BenchmarkName,
     Boundary,
     BranchName,
     Email,
     GitHash,
     Jwt,
+    MeasureExpression,
     NameId,
     NonEmpty,
     ResourceId,
     ResourceName,
     SampleSize,
+    Secret,
     Slug,
     Url,
     UserName,
@@ -56,8 +59,10 @@ from_client!(
     ThresholdUuid,
     ModelUuid,
     AlertUuid,
+    SilenceUuid,
     UserUuid,
-    TokenUuid
+    TokenUuid,
+    WebhookUuid
 );
 
 #[cfg(feature = "plus")]
@@ -109,6 +114,8 @@ try_from_client!(
     JsonAlerts,
     JsonAlertStats,
     JsonAlert,
+    JsonSilences,
+    JsonSilence,
     JsonBenchmarks,
     JsonBenchmark,
     JsonBranches,
@@ -117,7 +124,10 @@ try_from_client!(
     JsonMeasure,
     JsonProjects,
     JsonProject,
+    JsonProjectArchive,
     JsonPerf,
+    JsonPerfChangePoints,
+    JsonCompare,
     JsonReports,
     JsonReport,
     JsonModel,
@@ -125,6 +135,11 @@ try_from_client!(
     JsonTestbed,
     JsonThresholds,
     JsonThreshold,
+    JsonWebhooks,
+    JsonWebhook,
+    JsonWebhookDeliveries,
+    JsonRetention,
+    JsonRetentionPrune,
     JsonSignup,
     JsonLogin,
     JsonConfirm,
@@ -6,7 +6,7 @@ use bencher_json::{
         perf::{LOWER_BOUNDARY, UPPER_BOUNDARY},
     },
     AlertUuid, BenchmarkName, BenchmarkUuid, BranchUuid, DateTime, JsonBoundary, JsonPerfQuery,
-    JsonReport, MeasureUuid, ResourceName, Slug, TestbedUuid,
+    JsonReport, MeasureUnit, MeasureUuid, ResourceName, Slug, TestbedUuid,
 };
 use url::Url;
 
@@ -72,6 +72,25 @@ impl ReportComment {
         html
     }
 
+    // A Markdown variant of the HTML comment for CI providers that render Markdown notes, like GitLab.
+    pub fn markdown(
+        &self,
+        with_metrics: bool,
+        require_threshold: bool,
+        id: Option<&str>,
+    ) -> String {
+        let mut markdown = String::new();
+        let markdown_mut = &mut markdown;
+        let public_links = self.json_report.project.visibility.is_public();
+        self.markdown_header(markdown_mut);
+        self.markdown_report_table(markdown_mut, public_links);
+        self.markdown_benchmarks(markdown_mut, with_metrics, require_threshold, public_links);
+        self.markdown_footer(markdown_mut);
+        // DO NOT MOVE: The Bencher tag must be the last thing in the Markdown for updates to work
+        markdown.push_str(&self.bencher_tag(id));
+        markdown
+    }
+
     fn html_header(&self, html: &mut String) {
         html.push_str(&format!(
             r#"<h1><a href="{console_url}"><img src="https://bencher.dev/favicon.svg" width="32" height="32" alt="🐰" /></a>Bencher</h1>"#,
@@ -79,9 +98,8 @@ impl ReportComment {
         ));
     }
 
-    fn html_report_table(&self, html: &mut String, public_links: bool) {
-        html.push_str("<table>");
-        for (row, name, path) in [
+    fn report_table_rows(&self, public_links: bool) -> [(&'static str, String, Option<Url>); 4] {
+        [
             (
                 "Report",
                 self.json_report
@@ -119,10 +137,20 @@ impl ReportComment {
                     self.project_slug, self.json_report.testbed.slug
                 )),
             ),
-        ] {
-            if let Some(path) = path {
+        ]
+        .map(|(row, name, path)| {
+            let url = path.map(|path| {
                 let url = self.console_url.clone();
-                let url = url.join(&path).unwrap_or(url);
+                url.join(&path).unwrap_or(url)
+            });
+            (row, name, url)
+        })
+    }
+
+    fn html_report_table(&self, html: &mut String, public_links: bool) {
+        html.push_str("<table>");
+        for (row, name, url) in self.report_table_rows(public_links) {
+            if let Some(url) = url {
                 html.push_str(&format!(
                     r#"<tr><td>{row}</td><td><a href="{url}">{name}</a></td></tr>"#
                 ));
@@ -174,15 +202,7 @@ impl ReportComment {
 
     // Check to see if any measure has a threshold set
     fn html_no_threshold_warning(&self, html: &mut String, measures: &MeasuresMap) {
-        let no_threshold = measures.iter().fold(
-            Vec::new(),
-            |mut nt, (measure, MeasureData { boundary, .. })| {
-                if boundary.is_none() {
-                    nt.push(measure);
-                }
-                nt
-            },
-        );
+        let no_threshold = no_threshold_measures(measures);
         if no_threshold.is_empty() {
             return;
         }
@@ -263,6 +283,7 @@ impl ReportComment {
                 data.value,
                 data.boundary,
                 Some(alert.limit),
+                measure.unit,
                 true,
             );
             html.push_str("</tr>");
@@ -331,21 +352,14 @@ impl ReportComment {
     ) {
         let name = &measure.name;
         let units = &measure.units;
-        // If there is a boundary then we will show the percentage difference
-        if boundary.is_some() {
-            html.push_str(&format!("<th>{name} Results<br/>{units} | (Δ%)</th>",));
-        } else {
-            html.push_str(&format!("<th>{name} Results<br/>{units}</th>",));
-        }
-
-        let Some(boundary) = boundary else {
-            return;
-        };
-        if boundary.lower_limit.is_some() {
-            html.push_str(&format!("<th>{name} Lower Boundary<br/>{units} | (%)</th>"));
-        }
-        if boundary.upper_limit.is_some() {
-            html.push_str(&format!("<th>{name} Upper Boundary<br/>{units} | (%)</th>"));
+        for (column, percent) in metric_boundary_header(boundary) {
+            if let Some(percent) = percent {
+                html.push_str(&format!(
+                    "<th>{name} {column}<br/>{units} | ({percent})</th>"
+                ));
+            } else {
+                html.push_str(&format!("<th>{name} {column}<br/>{units}</th>"));
+            }
         }
     }
 
@@ -421,7 +435,14 @@ impl ReportComment {
                 html.push_str(&format!(r#"<td>{row}</td>"#));
 
                 if with_metrics {
-                    Self::html_metric_boundary_cells(html, *value, *boundary, limit, false);
+                    Self::html_metric_boundary_cells(
+                        html,
+                        *value,
+                        *boundary,
+                        limit,
+                        measure.unit,
+                        false,
+                    );
                 }
             }
             html.push_str("</tr>");
@@ -433,83 +454,332 @@ impl ReportComment {
         value: f64,
         boundary: Option<Boundary>,
         limit: Option<BoundaryLimit>,
+        unit: Option<MeasureUnit>,
         pad: bool,
     ) {
-        // If there is a boundary with a baseline then show the percentage difference
-        if let Some(Boundary {
-            baseline: Some(baseline),
-            ..
-        }) = boundary
-        {
-            let value_percent = if value.is_normal() && baseline.is_normal() {
-                ((value - baseline) / baseline) * 100.0
+        for MetricCell { text, bold } in metric_boundary_cells(value, boundary, limit, unit, pad) {
+            if bold {
+                html.push_str(&format!("<td><b>{text}</b></td>"));
             } else {
-                0.0
-            };
-            let value_plus = if value_percent > 0.0 { "+" } else { "" };
+                html.push_str(&format!("<td>{text}</td>"));
+            }
+        }
+    }
 
-            let bold = limit.is_some();
-            html.push_str(&format!(
-                "<td>{}{} ({value_plus}{}%){}</td>",
-                if bold { "<b>" } else { "" },
-                format_number(value),
-                format_number(value_percent),
-                if bold { "</b>" } else { "" },
-            ));
+    fn html_footer(&self, html: &mut String) {
+        html.push_str(&format!(r#"<br/><small><a href="https://bencher.dev">Bencher - Continuous Benchmarking</a></small>{}<br/><small><a href="https://bencher.dev/docs/">Docs</a> | <a href="https://bencher.dev/repo/">Repo</a> | <a href="https://bencher.dev/chat/">Chat</a> | <a href="https://bencher.dev/help/">Help</a></small>"#,
+        if self.json_report.project.visibility.is_public() {
+            let path = format!("/perf/{}", self.project_slug);
+            let url = self.console_url.clone();
+            let url = url.join(&path).unwrap_or(url);
+            format!(r#"<br/><small><a href="{url}">View Public Perf Page</a></small>"#)
         } else {
-            html.push_str(&format!("<td>{}</td>", format_number(value)));
+            String::new()
         }
+        ));
+    }
 
-        let Some(boundary) = boundary else {
+    fn markdown_header(&self, markdown: &mut String) {
+        markdown.push_str(&format!(
+            "## 🐰 [Bencher]({console_url})\n\n",
+            console_url = self.console_url,
+        ));
+    }
+
+    fn markdown_report_table(&self, markdown: &mut String, public_links: bool) {
+        for (i, (row, name, url)) in self.report_table_rows(public_links).into_iter().enumerate() {
+            let name = escape_markdown(&name);
+            if let Some(url) = url {
+                markdown.push_str(&format!("| {row} | [{name}]({url}) |\n"));
+            } else {
+                markdown.push_str(&format!("| {row} | {name} |\n"));
+            }
+            // Markdown tables require a header row, so the first row is used as the header
+            if i == 0 {
+                markdown.push_str("| --- | --- |\n");
+            }
+        }
+        markdown.push('\n');
+    }
+
+    fn markdown_benchmarks(
+        &self,
+        markdown: &mut String,
+        with_metrics: bool,
+        require_threshold: bool,
+        public_links: bool,
+    ) {
+        let Some((_benchmark, measures)) = self.benchmark_urls.0.first_key_value() else {
+            markdown.push_str("> **⚠️ WARNING:** No benchmarks found!\n\n");
             return;
         };
-        if let Some(lower_limit) = boundary.lower_limit {
-            let limit_percent = if value.is_normal() && lower_limit.is_normal() {
-                (lower_limit / value) * 100.0
+        self.markdown_no_threshold_warning(markdown, measures);
+
+        let alerts_len = self.alert_urls.0.len();
+        if alerts_len > 0 {
+            let (capital, lower) = if alerts_len == 1 {
+                ("", "")
             } else {
-                0.0
+                ("S", "s")
             };
-            let bold = matches!(limit, Some(BoundaryLimit::Lower));
-            html.push_str(&format!(
-                "<td>{}{} ({}%){}</td>",
-                if bold { "<b>" } else { "" },
-                format_number(lower_limit),
-                format_number(limit_percent),
-                if bold { "</b>" } else { "" },
+            markdown.push_str(&format!(
+                "> **🚨 {alerts_len} ALERT{capital}:** Threshold Boundary Limit{lower} exceeded!\n\n",
             ));
-        } else if pad {
-            html.push_str("<td></td>");
+            self.markdown_alerts_table(markdown, public_links);
         }
-        if let Some(upper_limit) = boundary.upper_limit {
-            let limit_percent = if value.is_normal() && upper_limit.is_normal() {
-                (value / upper_limit) * 100.0
-            } else {
-                0.0
-            };
-            let bold = matches!(limit, Some(BoundaryLimit::Upper));
-            html.push_str(&format!(
-                "<td>{}{} ({}%){}</td>",
-                if bold { "<b>" } else { "" },
-                format_number(upper_limit),
-                format_number(limit_percent),
-                if bold { "</b>" } else { "" },
+
+        // A blank line is required after the summary for the Markdown table to be rendered
+        markdown.push_str("<details><summary>Click to view all benchmark results</summary>\n\n");
+        self.markdown_benchmarks_table(
+            markdown,
+            measures,
+            with_metrics,
+            require_threshold,
+            public_links,
+        );
+        markdown.push_str("\n</details>\n\n");
+    }
+
+    fn markdown_no_threshold_warning(&self, markdown: &mut String, measures: &MeasuresMap) {
+        let no_threshold = no_threshold_measures(measures);
+        if no_threshold.is_empty() {
+            return;
+        }
+        let plural_measure = if no_threshold.len() == 1 {
+            "Measure does"
+        } else {
+            "Measures do"
+        };
+        markdown.push_str(&format!("> **⚠️ WARNING:** The following {plural_measure} not have a Threshold. Without a Threshold, no Alerts will ever be generated!\n>\n"));
+        for measure in no_threshold {
+            markdown.push_str(&format!(
+                "> - {name} ({slug})\n",
+                name = escape_markdown(measure.name.as_ref()),
+                slug = measure.slug
             ));
-        } else if pad {
-            html.push_str("<td></td>");
         }
+        markdown.push_str(&format!(
+            ">\n> [Click here to create a new Threshold]({console_url}console/projects/{project}/thresholds/add)\n>\n",
+            console_url = self.console_url,
+            project = self.project_slug
+        ));
+        markdown.push_str("> For more information, see [the Threshold documentation](https://bencher.dev/docs/explanation/thresholds/).\n>\n");
+        markdown.push_str("> To ignore this warning, set [the `--ci-ignore-no-threshold` CLI flag](https://bencher.dev/docs/explanation/bencher-run/#--ci-ignore-no-threshold).\n\n");
     }
 
-    fn html_footer(&self, html: &mut String) {
-        html.push_str(&format!(r#"<br/><small><a href="https://bencher.dev">Bencher - Continuous Benchmarking</a></small>{}<br/><small><a href="https://bencher.dev/docs/">Docs</a> | <a href="https://bencher.dev/repo/">Repo</a> | <a href="https://bencher.dev/chat/">Chat</a> | <a href="https://bencher.dev/help/">Help</a></small>"#,
+    fn markdown_alerts_table(&self, markdown: &mut String, public_links: bool) {
+        markdown.push_str(
+            "| Benchmark | Measure (units) | View | Value | Lower Boundary | Upper Boundary |\n",
+        );
+        markdown.push_str("| --- | --- | --- | --- | --- | --- |\n");
+        for ((benchmark, measure), alert) in &self.alert_urls.0 {
+            let Some(data) = self
+                .benchmark_urls
+                .0
+                .get(benchmark)
+                .and_then(|m| m.get(measure))
+            else {
+                continue;
+            };
+
+            let benchmark_name = escape_markdown(benchmark.name.as_ref());
+            let measure_name = escape_markdown(measure.name.as_ref());
+            let units = escape_markdown(measure.units.as_ref());
+            markdown.push('|');
+            if public_links {
+                markdown.push_str(&format!(" {benchmark_name} |"));
+                markdown.push_str(&format!(" {measure_name} ({units}) |"));
+                markdown.push_str(&format!(
+                    " 🚨 ([view plot]({}) \\| [view alert]({})) |",
+                    data.public_url, alert.public_url,
+                ));
+            } else {
+                let url = self.console_path_url(&format!(
+                    "/console/projects/{}/benchmarks/{}",
+                    self.project_slug, benchmark.slug
+                ));
+                markdown.push_str(&format!(" [{benchmark_name}]({url}) |"));
+                let url = self.console_path_url(&format!(
+                    "/console/projects/{}/measures/{}",
+                    self.project_slug, measure.slug
+                ));
+                markdown.push_str(&format!(" [{measure_name}]({url}) ({units}) |"));
+                markdown.push_str(&format!(
+                    " 🚨 ([view plot]({}) \\| [view alert]({})) |",
+                    data.console_url, alert.console_url,
+                ));
+            }
+            Self::markdown_metric_boundary_cells(
+                markdown,
+                data.value,
+                data.boundary,
+                Some(alert.limit),
+                measure.unit,
+                true,
+            );
+            markdown.push('\n');
+        }
+        markdown.push('\n');
+    }
+
+    fn markdown_benchmarks_table(
+        &self,
+        markdown: &mut String,
+        measures: &MeasuresMap,
+        with_metrics: bool,
+        require_threshold: bool,
+        public_links: bool,
+    ) {
+        self.markdown_benchmarks_table_header(
+            markdown,
+            measures,
+            with_metrics,
+            require_threshold,
+            public_links,
+        );
+        self.markdown_benchmarks_table_body(
+            markdown,
+            with_metrics,
+            require_threshold,
+            public_links,
+        );
+    }
+
+    fn markdown_benchmarks_table_header(
+        &self,
+        markdown: &mut String,
+        measures: &MeasuresMap,
+        with_metrics: bool,
+        require_threshold: bool,
+        public_links: bool,
+    ) {
+        let mut header = vec!["Benchmark".to_owned()];
+        for (measure, MeasureData { boundary, .. }) in measures {
+            if require_threshold && boundary.is_none() {
+                continue;
+            }
+            let measure_name = escape_markdown(measure.name.as_ref());
+            if public_links {
+                header.push(measure_name.clone());
+            } else {
+                let url = self.console_path_url(&format!(
+                    "/console/projects/{}/measures/{}",
+                    self.project_slug, measure.slug
+                ));
+                header.push(format!("[{measure_name}]({url})"));
+            }
+
+            if with_metrics {
+                let units = escape_markdown(measure.units.as_ref());
+                for (column, percent) in metric_boundary_header(*boundary) {
+                    header.push(if let Some(percent) = percent {
+                        format!("{measure_name} {column}<br/>{units} \\| ({percent})")
+                    } else {
+                        format!("{measure_name} {column}<br/>{units}")
+                    });
+                }
+            }
+        }
+        markdown.push_str(&format!("| {} |\n", header.join(" | ")));
+        markdown.push_str(&format!("|{}\n", " --- |".repeat(header.len())));
+    }
+
+    fn markdown_benchmarks_table_body(
+        &self,
+        markdown: &mut String,
+        with_metrics: bool,
+        require_threshold: bool,
+        public_links: bool,
+    ) {
+        for (benchmark, measures) in &self.benchmark_urls.0 {
+            let benchmark_name = escape_markdown(benchmark.name.as_ref());
+            markdown.push('|');
+            if public_links {
+                markdown.push_str(&format!(" {benchmark_name} |"));
+            } else {
+                let url = self.console_path_url(&format!(
+                    "/console/projects/{}/benchmarks/{}",
+                    self.project_slug, benchmark.slug
+                ));
+                markdown.push_str(&format!(" [{benchmark_name}]({url}) |"));
+            }
+            for (
+                measure,
+                MeasureData {
+                    public_url,
+                    console_url,
+                    value,
+                    boundary,
+                },
+            ) in measures
+            {
+                if require_threshold && boundary.is_none() {
+                    continue;
+                }
+                let plot_url = if public_links {
+                    public_url
+                } else {
+                    console_url
+                };
+                let alert = self.alert_urls.0.get(&(benchmark.clone(), measure.clone()));
+                let row = if let Some(alert) = alert {
+                    let alert_url = if public_links {
+                        &alert.public_url
+                    } else {
+                        &alert.console_url
+                    };
+                    format!("🚨 ([view plot]({plot_url}) \\| [view alert]({alert_url}))")
+                } else if boundary.is_some() {
+                    format!("✅ ([view plot]({plot_url}))")
+                } else {
+                    format!("➖ ([view plot]({plot_url}))")
+                };
+                markdown.push_str(&format!(" {row} |"));
+
+                if with_metrics {
+                    Self::markdown_metric_boundary_cells(
+                        markdown,
+                        *value,
+                        *boundary,
+                        alert.map(|alert| alert.limit),
+                        measure.unit,
+                        false,
+                    );
+                }
+            }
+            markdown.push('\n');
+        }
+    }
+
+    fn markdown_metric_boundary_cells(
+        markdown: &mut String,
+        value: f64,
+        boundary: Option<Boundary>,
+        limit: Option<BoundaryLimit>,
+        unit: Option<MeasureUnit>,
+        pad: bool,
+    ) {
+        for MetricCell { text, bold } in metric_boundary_cells(value, boundary, limit, unit, pad) {
+            if bold {
+                markdown.push_str(&format!(" **{text}** |"));
+            } else {
+                markdown.push_str(&format!(" {text} |"));
+            }
+        }
+    }
+
+    fn markdown_footer(&self, markdown: &mut String) {
+        markdown.push_str("[Bencher - Continuous Benchmarking](https://bencher.dev)\n\n");
         if self.json_report.project.visibility.is_public() {
-            let path = format!("/perf/{}", self.project_slug);
-            let url = self.console_url.clone();
-            let url = url.join(&path).unwrap_or(url);
-            format!(r#"<br/><small><a href="{url}">View Public Perf Page</a></small>"#)
-        } else {
-            String::new()
+            let url = self.console_path_url(&format!("/perf/{}", self.project_slug));
+            markdown.push_str(&format!("[View Public Perf Page]({url})\n\n"));
         }
-        ));
+        markdown.push_str("[Docs](https://bencher.dev/docs/) | [Repo](https://bencher.dev/repo/) | [Chat](https://bencher.dev/chat/) | [Help](https://bencher.dev/help/)\n\n");
+    }
+
+    fn console_path_url(&self, path: &str) -> Url {
+        let url = self.console_url.clone();
+        url.join(path).unwrap_or(url)
     }
 
     fn html_bencher_tag(&self, html: &mut String, id: Option<&str>) {
@@ -558,6 +828,7 @@ pub struct Measure {
     name: ResourceName,
     slug: Slug,
     units: ResourceName,
+    unit: Option<MeasureUnit>,
 }
 
 #[derive(Clone)]
@@ -586,6 +857,7 @@ impl BenchmarkUrls {
                     name: result.measure.name.clone(),
                     slug: result.measure.slug.clone(),
                     units: result.measure.units.clone(),
+                    unit: result.measure.unit,
                 };
                 for benchmark_metric in &result.benchmarks {
                     let benchmark = Benchmark {
@@ -691,6 +963,16 @@ impl BenchmarkUrl {
             measures: vec![measure],
             start_time: Some((self.start_time.into_inner() - DEFAULT_REPORT_HISTORY).into()),
             end_time: Some(self.end_time),
+            start_hash: None,
+            end_hash: None,
+            start_version: None,
+            end_version: None,
+            archived: None,
+            aggregate: None,
+            smoothing: None,
+            smoothing_window: None,
+            group: None,
+            tag: None,
         };
 
         let mut url = self.console_url.clone();
@@ -766,6 +1048,7 @@ impl AlertUrls {
                 name: alert.threshold.measure.name.clone(),
                 slug: alert.threshold.measure.slug.clone(),
                 units: alert.threshold.measure.units.clone(),
+                unit: alert.threshold.measure.unit,
             };
             let public_url =
                 Self::to_public_url(console_url.clone(), &json_report.project.slug, alert.uuid);
@@ -793,6 +1076,152 @@ impl AlertUrls {
     }
 }
 
+// The metric and boundary columns for a Measure
+// along with the percentage that is shown if there is a boundary
+fn metric_boundary_header(boundary: Option<Boundary>) -> Vec<(&'static str, Option<&'static str>)> {
+    // If there is a boundary then we will show the percentage difference
+    let mut header = vec![("Results", boundary.is_some().then_some("Δ%"))];
+    let Some(boundary) = boundary else {
+        return header;
+    };
+    if boundary.lower_limit.is_some() {
+        header.push(("Lower Boundary", Some("%")));
+    }
+    if boundary.upper_limit.is_some() {
+        header.push(("Upper Boundary", Some("%")));
+    }
+    header
+}
+
+struct MetricCell {
+    text: String,
+    bold: bool,
+}
+
+impl MetricCell {
+    fn new(text: String, bold: bool) -> Self {
+        Self { text, bold }
+    }
+
+    fn empty() -> Self {
+        Self::new(String::new(), false)
+    }
+}
+
+fn metric_boundary_cells(
+    value: f64,
+    boundary: Option<Boundary>,
+    limit: Option<BoundaryLimit>,
+    unit: Option<MeasureUnit>,
+    pad: bool,
+) -> Vec<MetricCell> {
+    let mut cells = Vec::new();
+    // If there is a boundary with a baseline then show the percentage difference
+    if let Some(Boundary {
+        baseline: Some(baseline),
+        ..
+    }) = boundary
+    {
+        let value_percent = if value.is_normal() && baseline.is_normal() {
+            ((value - baseline) / baseline) * 100.0
+        } else {
+            0.0
+        };
+        let value_plus = if value_percent > 0.0 { "+" } else { "" };
+        cells.push(MetricCell::new(
+            format!(
+                "{} ({value_plus}{}%)",
+                format_measure_number(value, unit),
+                format_number(value_percent),
+            ),
+            limit.is_some(),
+        ));
+    } else {
+        cells.push(MetricCell::new(format_measure_number(value, unit), false));
+    }
+
+    let Some(boundary) = boundary else {
+        return cells;
+    };
+    if let Some(lower_limit) = boundary.lower_limit {
+        let limit_percent = if value.is_normal() && lower_limit.is_normal() {
+            (lower_limit / value) * 100.0
+        } else {
+            0.0
+        };
+        cells.push(MetricCell::new(
+            format!(
+                "{} ({}%)",
+                format_measure_number(lower_limit, unit),
+                format_number(limit_percent),
+            ),
+            matches!(limit, Some(BoundaryLimit::Lower)),
+        ));
+    } else if pad {
+        cells.push(MetricCell::empty());
+    }
+    if let Some(upper_limit) = boundary.upper_limit {
+        let limit_percent = if value.is_normal() && upper_limit.is_normal() {
+            (value / upper_limit) * 100.0
+        } else {
+            0.0
+        };
+        cells.push(MetricCell::new(
+            format!(
+                "{} ({}%)",
+                format_measure_number(upper_limit, unit),
+                format_number(limit_percent),
+            ),
+            matches!(limit, Some(BoundaryLimit::Upper)),
+        ));
+    } else if pad {
+        cells.push(MetricCell::empty());
+    }
+    cells
+}
+
+fn no_threshold_measures(measures: &MeasuresMap) -> Vec<&Measure> {
+    measures.iter().fold(
+        Vec::new(),
+        |mut nt, (measure, MeasureData { boundary, .. })| {
+            if boundary.is_none() {
+                nt.push(measure);
+            }
+            nt
+        },
+    )
+}
+
+// Escape any characters that would otherwise be treated as Markdown or HTML,
+// including the pipes that separate Markdown table cells
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '#'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+// Scale a value to the most readable unit in its dimension and append the unit symbol
+fn format_measure_number(number: f64, unit: Option<MeasureUnit>) -> String {
+    let Some(unit) = unit else {
+        return format_number(number);
+    };
+    let (scaled, unit) = unit.scale(number);
+    let symbol = unit.symbol();
+    if symbol.is_empty() {
+        format_number(scaled)
+    } else {
+        format!("{} {symbol}", format_number(scaled))
+    }
+}
+
 enum Position {
     Whole(usize),
     Point,
@@ -1,8 +1,11 @@
+#[cfg(feature = "db")]
+#[doc(hidden)]
+pub use bencher_valid::to_sql_converted;
 pub use bencher_valid::{
-    BenchmarkName, Boundary, BranchName, CdfBoundary, DateTime, DateTimeMillis, Email, GitHash,
-    IqrBoundary, Jwt, Model, ModelTest, NameId, NameIdKind, NonEmpty, PercentageBoundary,
-    ResourceId, ResourceIdKind, ResourceName, SampleSize, Sanitize, Secret, Slug, Url, UserName,
-    ValidError, Window,
+    BenchmarkGroup, BenchmarkName, Boundary, BranchName, CdfBoundary, DateTime, DateTimeMillis,
+    Email, GitHash, IqrBoundary, Jwt, Model, ModelTest, NameId, NameIdKind, NonEmpty,
+    PercentageBoundary, ResourceId, ResourceIdKind, ResourceName, SampleSize, Sanitize, Secret,
+    Slug, Url, UserName, ValidError, Window, BENCHMARK_GROUP_SEPARATORS,
 };
 #[cfg(feature = "plus")]
 pub use bencher_valid::{
@@ -33,17 +36,31 @@ pub use organization::{
 };
 pub use pagination::{JsonDirection, JsonPagination};
 pub use project::{
-    alert::{AlertUuid, JsonAlert, JsonAlertStats, JsonAlerts},
+    alert::{
+        AlertUuid, JsonAlert, JsonAlertQuery, JsonAlertQueryParams, JsonAlertStats, JsonAlerts,
+    },
+    archive::JsonProjectArchive,
     benchmark::{BenchmarkUuid, JsonBenchmark, JsonBenchmarks},
     boundary::{BoundaryUuid, JsonBoundaries, JsonBoundary},
     branch::{BranchUuid, JsonBranch, JsonBranches, JsonNewBranch, JsonStartPoint, VersionUuid},
-    measure::{JsonMeasure, JsonMeasures, JsonNewMeasure, MeasureUuid},
+    compare::JsonCompare,
+    measure::{
+        expression::MeasureExpression,
+        unit::{MeasureUnit, UnitDimension, UnitValue},
+        JsonMeasure, JsonMeasures, JsonNewMeasure, MeasureUuid,
+    },
     metric::{JsonMetric, JsonMetricsMap, JsonResultsMap, MeasureNameId, MetricUuid},
     model::{JsonModel, ModelUuid},
-    perf::{JsonPerf, JsonPerfQuery, ReportBenchmarkUuid},
+    perf::{JsonPerf, JsonPerfChangePoints, JsonPerfQuery, ReportBenchmarkUuid},
     report::{JsonNewReport, JsonReport, JsonReports, ReportUuid},
+    retention::{JsonNewRetention, JsonRetention, JsonRetentionPrune},
+    silence::{JsonNewSilence, JsonSilence, JsonSilences, SilenceUuid},
     testbed::{JsonNewTestbed, JsonTestbed, JsonTestbeds, TestbedUuid},
     threshold::{JsonNewThreshold, JsonThreshold, JsonThresholds, ThresholdUuid},
+    webhook::{
+        JsonNewWebhook, JsonWebhook, JsonWebhookDeliveries, JsonWebhooks, WebhookDeliveryUuid,
+        WebhookUuid,
+    },
     JsonNewProject, JsonProject, JsonProjects, ProjectUuid,
 };
 #[cfg(feature = "plus")]
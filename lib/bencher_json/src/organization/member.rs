@@ -107,17 +107,15 @@ mod organization_role {
     impl<DB> diesel::serialize::ToSql<diesel::sql_types::Text, DB> for OrganizationRole
     where
         DB: diesel::backend::Backend,
-        for<'a> String: diesel::serialize::ToSql<diesel::sql_types::Text, DB>
-            + Into<<DB::BindCollector<'a> as diesel::query_builder::BindCollector<'a, DB>>::Buffer>,
+        str: diesel::serialize::ToSql<diesel::sql_types::Text, DB>,
     {
         fn to_sql<'b>(
             &'b self,
             out: &mut diesel::serialize::Output<'b, '_, DB>,
         ) -> diesel::serialize::Result {
             match self {
-                Self::Leader => out.set_value(LEADER_ROLE.to_owned()),
+                Self::Leader => LEADER_ROLE.to_sql(out),
             }
-            Ok(diesel::serialize::IsNull::No)
         }
     }
 
@@ -2,7 +2,12 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::{BigInt, DateTime, JsonThreshold};
+use bencher_valid::{DateTimeMillis, NameId, ResourceId, Slug, UserName};
+
+use crate::{
+    urlencoded::{from_urlencoded, UrlEncodedError},
+    BigInt, DateTime, JsonThreshold, UserUuid,
+};
 
 use super::{
     benchmark::JsonBenchmarkMetric, boundary::BoundaryLimit, report::Iteration, report::ReportUuid,
@@ -27,12 +32,26 @@ pub struct JsonAlert {
     pub benchmark: JsonBenchmarkMetric,
     pub limit: BoundaryLimit,
     pub status: AlertStatus,
+    pub assignee: Option<JsonAlertAssignee>,
+    pub note: Option<String>,
     pub created: DateTime,
     pub modified: DateTime,
 }
 
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonAlertAssignee {
+    pub uuid: UserUuid,
+    pub name: UserName,
+    pub slug: Slug,
+}
+
 const ACTIVE_INT: i32 = 0;
 const DISMISSED_INT: i32 = 1;
+const ACKNOWLEDGED_INT: i32 = 2;
+const SILENCED_INT: i32 = 3;
+const RESOLVED_INT: i32 = 4;
 
 #[typeshare::typeshare]
 #[derive(Debug, Clone, Copy, Default, derive_more::Display, Serialize, Deserialize)]
@@ -42,14 +61,24 @@ const DISMISSED_INT: i32 = 1;
 #[serde(rename_all = "snake_case")]
 #[repr(i32)]
 pub enum AlertStatus {
+    /// The alert is active and needs attention.
     #[default]
     Active = ACTIVE_INT,
+    /// The alert has been dismissed by a user.
     Dismissed = DISMISSED_INT,
+    /// The alert has been acknowledged by a user but is still being worked on.
+    Acknowledged = ACKNOWLEDGED_INT,
+    /// The alert matched an alert silence when it was created.
+    Silenced = SILENCED_INT,
+    /// A subsequent report for the same benchmark returned inside of the boundary limits.
+    Resolved = RESOLVED_INT,
 }
 
 #[cfg(feature = "db")]
 mod alert_status {
-    use super::{AlertStatus, ACTIVE_INT, DISMISSED_INT};
+    use super::{
+        AlertStatus, ACKNOWLEDGED_INT, ACTIVE_INT, DISMISSED_INT, RESOLVED_INT, SILENCED_INT,
+    };
 
     #[derive(Debug, thiserror::Error)]
     pub enum AlertStatusError {
@@ -69,6 +98,9 @@ mod alert_status {
             match self {
                 Self::Active => ACTIVE_INT.to_sql(out),
                 Self::Dismissed => DISMISSED_INT.to_sql(out),
+                Self::Acknowledged => ACKNOWLEDGED_INT.to_sql(out),
+                Self::Silenced => SILENCED_INT.to_sql(out),
+                Self::Resolved => RESOLVED_INT.to_sql(out),
             }
         }
     }
@@ -82,12 +114,112 @@ mod alert_status {
             match i32::from_sql(bytes)? {
                 ACTIVE_INT => Ok(Self::Active),
                 DISMISSED_INT => Ok(Self::Dismissed),
+                ACKNOWLEDGED_INT => Ok(Self::Acknowledged),
+                SILENCED_INT => Ok(Self::Silenced),
+                RESOLVED_INT => Ok(Self::Resolved),
                 value => Err(Box::new(AlertStatusError::Invalid(value))),
             }
         }
     }
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonAlertQueryParams {
+    /// Filter by alert status.
+    pub status: Option<AlertStatus>,
+    /// Filter by the slug or UUID of the user the alert is assigned to.
+    pub assignee: Option<ResourceId>,
+    /// Filter by branch UUID, slug, or name.
+    pub branch: Option<String>,
+    /// Filter by testbed UUID, slug, or name.
+    pub testbed: Option<String>,
+    /// Filter by benchmark UUID, slug, or name.
+    pub benchmark: Option<String>,
+    /// Filter by measure UUID, slug, or name.
+    pub measure: Option<String>,
+    /// Filter by the side of the boundary limit that was exceeded.
+    pub limit: Option<BoundaryLimit>,
+    /// Filter by report UUID.
+    pub report: Option<ReportUuid>,
+    /// Only return alerts for reports that started at or after this time.
+    pub start_time: Option<DateTimeMillis>,
+    /// Only return alerts for reports that started at or before this time.
+    pub end_time: Option<DateTimeMillis>,
+    /// Search by benchmark name, slug, or UUID or by alert UUID.
+    pub search: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct JsonAlertQuery {
+    pub status: Option<AlertStatus>,
+    pub assignee: Option<ResourceId>,
+    pub branch: Option<NameId>,
+    pub testbed: Option<NameId>,
+    pub benchmark: Option<NameId>,
+    pub measure: Option<NameId>,
+    pub limit: Option<BoundaryLimit>,
+    pub report: Option<ReportUuid>,
+    pub start_time: Option<DateTime>,
+    pub end_time: Option<DateTime>,
+    pub search: Option<String>,
+}
+
+impl TryFrom<JsonAlertQueryParams> for JsonAlertQuery {
+    type Error = UrlEncodedError;
+
+    fn try_from(query_params: JsonAlertQueryParams) -> Result<Self, Self::Error> {
+        let JsonAlertQueryParams {
+            status,
+            assignee,
+            branch,
+            testbed,
+            benchmark,
+            measure,
+            limit,
+            report,
+            start_time,
+            end_time,
+            search,
+        } = query_params;
+
+        let branch = if let Some(branch) = branch {
+            Some(from_urlencoded(&branch)?)
+        } else {
+            None
+        };
+        let testbed = if let Some(testbed) = testbed {
+            Some(from_urlencoded(&testbed)?)
+        } else {
+            None
+        };
+        let benchmark = if let Some(benchmark) = benchmark {
+            Some(from_urlencoded(&benchmark)?)
+        } else {
+            None
+        };
+        let measure = if let Some(measure) = measure {
+            Some(from_urlencoded(&measure)?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            status,
+            assignee,
+            branch,
+            testbed,
+            benchmark,
+            measure,
+            limit,
+            report,
+            start_time: start_time.map(Into::into),
+            end_time: end_time.map(Into::into),
+            search,
+        })
+    }
+}
+
 #[typeshare::typeshare]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -101,6 +233,15 @@ pub struct JsonAlertStats {
 pub struct JsonUpdateAlert {
     /// The new status of the alert.
     pub status: Option<AlertStatus>,
+    /// The UUID or slug of the user to assign the alert to.
+    /// The user must be a member of the project's organization.
+    pub assignee: Option<ResourceId>,
+    /// Set to `true` to remove the current assignee from the alert.
+    /// This cannot be used along with `assignee`.
+    pub unassign: Option<bool>,
+    /// A free-text note for the alert.
+    /// Set to an empty string to remove the current note.
+    pub note: Option<String>,
 }
 
 #[typeshare::typeshare]
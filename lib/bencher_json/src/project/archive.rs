@@ -0,0 +1,235 @@
+use bencher_valid::{
+    BenchmarkGroup, BenchmarkName, Boundary, BranchName, DateTime, GitHash, ModelTest,
+    ResourceName, SampleSize, Slug, Url, Window,
+};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    project::{
+        alert::AlertStatus,
+        boundary::BoundaryLimit,
+        branch::VersionNumber,
+        report::{Adapter, Iteration},
+        Visibility,
+    },
+    AlertUuid, BenchmarkUuid, BoundaryUuid, BranchUuid, MeasureExpression, MeasureUnit,
+    MeasureUuid, MetricUuid, ModelUuid, ProjectUuid, ReportBenchmarkUuid, ReportUuid, SilenceUuid,
+    TestbedUuid, ThresholdUuid, VersionUuid,
+};
+
+/// The current version of the project archive format.
+pub const PROJECT_ARCHIVE_VERSION: u32 = 1;
+
+/// A portable archive of all of the data for a project.
+/// All references between the items in the archive are made by UUID.
+/// Webhooks are not included, since they contain the secrets used to sign their payloads.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonProjectArchive {
+    /// The version of the project archive format.
+    pub archive_version: u32,
+    /// The date time that the project was exported.
+    pub exported: DateTime,
+    pub project: JsonArchiveProject,
+    pub branches: Vec<JsonArchiveBranch>,
+    pub versions: Vec<JsonArchiveVersion>,
+    pub testbeds: Vec<JsonArchiveTestbed>,
+    pub benchmarks: Vec<JsonArchiveBenchmark>,
+    pub measures: Vec<JsonArchiveMeasure>,
+    pub thresholds: Vec<JsonArchiveThreshold>,
+    pub reports: Vec<JsonArchiveReport>,
+    #[serde(default)]
+    pub silences: Vec<JsonArchiveSilence>,
+    /// The retention policy for the project, if any.
+    #[serde(default)]
+    pub retention: Option<JsonArchiveRetention>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonArchiveProject {
+    pub uuid: ProjectUuid,
+    pub name: ResourceName,
+    pub slug: Slug,
+    pub url: Option<Url>,
+    pub visibility: Visibility,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonArchiveBranch {
+    pub uuid: BranchUuid,
+    pub name: BranchName,
+    pub slug: Slug,
+    pub start_point: Option<JsonArchiveStartPoint>,
+    pub created: DateTime,
+    pub modified: DateTime,
+    pub archived: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonArchiveStartPoint {
+    pub branch: BranchUuid,
+    pub version: VersionUuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonArchiveVersion {
+    pub uuid: VersionUuid,
+    pub number: VersionNumber,
+    pub hash: Option<GitHash>,
+    /// The branches that the version belongs to.
+    pub branches: Vec<BranchUuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonArchiveTestbed {
+    pub uuid: TestbedUuid,
+    pub name: ResourceName,
+    pub slug: Slug,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonArchiveBenchmark {
+    pub uuid: BenchmarkUuid,
+    pub name: BenchmarkName,
+    pub slug: Slug,
+    /// The explicitly set group for the benchmark, if any.
+    pub group: Option<BenchmarkGroup>,
+    #[serde(default)]
+    pub tags: Vec<Slug>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonArchiveMeasure {
+    pub uuid: MeasureUuid,
+    pub name: ResourceName,
+    pub slug: Slug,
+    pub units: ResourceName,
+    pub unit: Option<MeasureUnit>,
+    pub expression: Option<MeasureExpression>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonArchiveThreshold {
+    pub uuid: ThresholdUuid,
+    pub branch: BranchUuid,
+    pub testbed: TestbedUuid,
+    pub measure: MeasureUuid,
+    pub group: Option<BenchmarkGroup>,
+    /// The current threshold model, if any.
+    pub model: Option<ModelUuid>,
+    /// The full history of threshold models, including the current one.
+    pub models: Vec<JsonArchiveModel>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonArchiveModel {
+    pub uuid: ModelUuid,
+    pub test: ModelTest,
+    pub min_sample_size: Option<SampleSize>,
+    pub max_sample_size: Option<SampleSize>,
+    pub window: Option<Window>,
+    pub lower_boundary: Option<Boundary>,
+    pub upper_boundary: Option<Boundary>,
+    pub created: DateTime,
+    pub replaced: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonArchiveReport {
+    pub uuid: ReportUuid,
+    pub branch: BranchUuid,
+    pub version: VersionUuid,
+    pub testbed: TestbedUuid,
+    pub adapter: Adapter,
+    pub start_time: DateTime,
+    pub end_time: DateTime,
+    pub results: Vec<JsonArchiveReportBenchmark>,
+    pub created: DateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonArchiveReportBenchmark {
+    pub uuid: ReportBenchmarkUuid,
+    pub iteration: Iteration,
+    pub benchmark: BenchmarkUuid,
+    pub metrics: Vec<JsonArchiveMetric>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonArchiveMetric {
+    pub uuid: MetricUuid,
+    pub measure: MeasureUuid,
+    pub value: f64,
+    pub lower_value: Option<f64>,
+    pub upper_value: Option<f64>,
+    pub boundary: Option<JsonArchiveBoundary>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonArchiveBoundary {
+    pub uuid: BoundaryUuid,
+    pub threshold: ThresholdUuid,
+    pub model: ModelUuid,
+    pub baseline: Option<f64>,
+    pub lower_limit: Option<f64>,
+    pub upper_limit: Option<f64>,
+    pub effect_size: Option<f64>,
+    pub alert: Option<JsonArchiveAlert>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonArchiveAlert {
+    pub uuid: AlertUuid,
+    pub limit: BoundaryLimit,
+    pub status: AlertStatus,
+    pub modified: DateTime,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonArchiveSilence {
+    pub uuid: SilenceUuid,
+    pub benchmark: Option<BenchmarkUuid>,
+    pub measure: Option<MeasureUuid>,
+    pub testbed: Option<TestbedUuid>,
+    pub note: Option<String>,
+    pub expires: Option<DateTime>,
+    pub created: DateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonArchiveRetention {
+    pub raw_window: Window,
+    pub downsample: bool,
+    pub protected_branch: Option<BranchUuid>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
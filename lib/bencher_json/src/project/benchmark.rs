@@ -1,6 +1,6 @@
 use std::fmt;
 
-use bencher_valid::{BenchmarkName, DateTime, Slug};
+use bencher_valid::{BenchmarkGroup, BenchmarkName, DateTime, Slug};
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -25,6 +25,11 @@ pub struct JsonBenchmark {
     pub project: ProjectUuid,
     pub name: BenchmarkName,
     pub slug: Slug,
+    /// The group that the benchmark belongs to.
+    /// Unless explicitly set, this is parsed from the benchmark name.
+    pub group: Option<BenchmarkGroup>,
+    #[serde(default)]
+    pub tags: Vec<Slug>,
     pub created: DateTime,
     pub modified: DateTime,
 }
@@ -43,6 +48,9 @@ pub struct JsonBenchmarkMetric {
     pub project: ProjectUuid,
     pub name: BenchmarkName,
     pub slug: Slug,
+    pub group: Option<BenchmarkGroup>,
+    #[serde(default)]
+    pub tags: Vec<Slug>,
     pub metric: JsonMetric,
     pub boundary: Option<JsonBoundary>,
     pub created: DateTime,
@@ -60,6 +68,12 @@ pub struct JsonNewBenchmark {
     /// If the provided or generated slug is already in use, a unique slug will be generated.
     /// Maximum length is 64 characters.
     pub slug: Option<Slug>,
+    /// The group for the benchmark.
+    /// If not provided, the group will be parsed from the name,
+    /// using `::` and `/` as separators.
+    pub group: Option<BenchmarkGroup>,
+    /// The tags for the benchmark.
+    pub tags: Option<Vec<Slug>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -71,4 +85,10 @@ pub struct JsonUpdateBenchmark {
     /// The preferred new slug for the benchmark.
     /// Maximum length is 64 characters.
     pub slug: Option<Slug>,
+    /// The new group for the benchmark.
+    /// Once set, the group is no longer parsed from the name.
+    pub group: Option<BenchmarkGroup>,
+    /// The new tags for the benchmark.
+    /// These replace all of the existing tags.
+    pub tags: Option<Vec<Slug>>,
 }
@@ -18,6 +18,8 @@ pub struct JsonBoundary {
     pub baseline: Option<OrderedFloat<f64>>,
     pub lower_limit: Option<OrderedFloat<f64>>,
     pub upper_limit: Option<OrderedFloat<f64>>,
+    /// The effect size of a two sample threshold model test.
+    pub effect_size: Option<OrderedFloat<f64>>,
 }
 
 const LOWER_BOOL: bool = false;
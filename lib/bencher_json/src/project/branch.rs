@@ -1,6 +1,6 @@
 use std::fmt;
 
-use bencher_valid::{BranchName, DateTime, GitHash, NameId, Slug};
+use bencher_valid::{BranchName, DateTime, GitHash, NameId, Slug, Window};
 use once_cell::sync::Lazy;
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
@@ -92,6 +92,9 @@ pub struct JsonBranch {
     pub start_point: Option<JsonStartPoint>,
     pub created: DateTime,
     pub modified: DateTime,
+    /// The date time that the branch was archived.
+    /// Archived branches are excluded from branch listings and perf queries by default.
+    pub archived: Option<DateTime>,
 }
 
 impl fmt::Display for JsonBranch {
@@ -142,15 +145,32 @@ pub struct JsonUpdateBranch {
     /// This is useful in cases where there may be a race condition
     /// when a start point branch has been updated but its benchmarks have not yet finished.
     pub hash: Option<GitHash>,
+    /// Set to `true` to archive the branch or `false` to unarchive it.
+    /// A branch is automatically unarchived when a new report is created for it,
+    /// but not when historical reports are imported for it.
+    pub archived: Option<bool>,
 }
 
 impl JsonUpdateBranch {
     // Check whether only the `git` hash is being updated.
     pub fn is_hash_only(&self) -> bool {
-        self.name.is_none() && self.slug.is_none() && self.hash.is_some()
+        self.name.is_none() && self.slug.is_none() && self.archived.is_none() && self.hash.is_some()
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonArchiveBranches {
+    /// Archive all branches that have not had a new report within this time window in seconds.
+    /// Branches without any reports are archived if they were created before the time window.
+    pub stale: Option<Window>,
+    /// The names of branches to archive, such as branches whose `git` ref has been merged.
+    /// Names that do not match a branch in the project are ignored.
+    pub branches: Option<Vec<BranchName>>,
+    /// If set to `true`, the branches that would be archived are returned without archiving them.
+    pub dry_run: Option<bool>,
+}
+
 #[typeshare::typeshare]
 #[derive(
     Debug, Clone, Copy, Default, PartialEq, Eq, Hash, derive_more::Display, Serialize, Deserialize,
@@ -176,20 +196,9 @@ mod version_number {
         }
     }
 
-    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for VersionNumber
-    where
-        DB: diesel::backend::Backend,
-        for<'a> i32: diesel::serialize::ToSql<diesel::sql_types::Integer, DB>
-            + Into<<DB::BindCollector<'a> as diesel::query_builder::BindCollector<'a, DB>>::Buffer>,
-    {
-        fn to_sql<'b>(
-            &'b self,
-            out: &mut diesel::serialize::Output<'b, '_, DB>,
-        ) -> diesel::serialize::Result {
-            out.set_value(i32::try_from(self.0)?);
-            Ok(diesel::serialize::IsNull::No)
-        }
-    }
+    bencher_valid::to_sql_converted!(diesel::sql_types::Integer, VersionNumber, |value| {
+        i32::try_from(value.0)?
+    });
 
     impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Integer, DB> for VersionNumber
     where
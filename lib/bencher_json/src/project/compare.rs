@@ -0,0 +1,222 @@
+use std::fmt;
+
+use ordered_float::OrderedFloat;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    urlencoded::{from_urlencoded, to_urlencoded, UrlEncodedError},
+    DateTime, JsonBenchmark, JsonMeasure, JsonTestbed, NameId, ProjectUuid, ReportUuid,
+};
+
+use super::branch::JsonBranchVersion;
+
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonCompareQueryParams {
+    /// The UUID for the base report.
+    /// If not provided, the latest report for the base branch on the testbed is used.
+    pub base_report: Option<ReportUuid>,
+    /// The UUID for the head report.
+    /// If not provided, the latest report for the head branch on the testbed is used.
+    pub head_report: Option<ReportUuid>,
+    /// The UUID, slug, or name of the base branch.
+    pub base_branch: Option<String>,
+    /// The UUID, slug, or name of the head branch.
+    pub head_branch: Option<String>,
+    /// The UUID, slug, or name of the testbed for the base and head branches.
+    pub testbed: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct JsonCompareQuery {
+    pub base_report: Option<ReportUuid>,
+    pub head_report: Option<ReportUuid>,
+    pub base_branch: Option<NameId>,
+    pub head_branch: Option<NameId>,
+    pub testbed: Option<NameId>,
+}
+
+impl TryFrom<JsonCompareQueryParams> for JsonCompareQuery {
+    type Error = UrlEncodedError;
+
+    fn try_from(query_params: JsonCompareQueryParams) -> Result<Self, Self::Error> {
+        let JsonCompareQueryParams {
+            base_report,
+            head_report,
+            base_branch,
+            head_branch,
+            testbed,
+        } = query_params;
+
+        let base_branch = if let Some(base_branch) = base_branch {
+            Some(from_urlencoded(&base_branch)?)
+        } else {
+            None
+        };
+        let head_branch = if let Some(head_branch) = head_branch {
+            Some(from_urlencoded(&head_branch)?)
+        } else {
+            None
+        };
+        let testbed = if let Some(testbed) = testbed {
+            Some(from_urlencoded(&testbed)?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            base_report,
+            head_report,
+            base_branch,
+            head_branch,
+            testbed,
+        })
+    }
+}
+
+impl JsonCompareQuery {
+    pub fn base_branch(&self) -> Option<String> {
+        self.base_branch.as_ref().map(to_urlencoded)
+    }
+
+    pub fn head_branch(&self) -> Option<String> {
+        self.head_branch.as_ref().map(to_urlencoded)
+    }
+
+    pub fn testbed(&self) -> Option<String> {
+        self.testbed.as_ref().map(to_urlencoded)
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonCompare {
+    pub project: ProjectUuid,
+    /// The report being compared against.
+    pub base: JsonCompareReport,
+    /// The report being compared.
+    pub head: JsonCompareReport,
+    /// The comparison for each benchmark and measure in either report.
+    pub results: Vec<JsonCompareResult>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonCompareReport {
+    pub report: ReportUuid,
+    pub branch: JsonBranchVersion,
+    pub testbed: JsonTestbed,
+    pub start_time: DateTime,
+    pub end_time: DateTime,
+}
+
+impl fmt::Display for JsonCompareReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{branch} @ {version}",
+            branch = self.branch.name,
+            version = self.branch.version.number,
+        )?;
+        if let Some(hash) = &self.branch.version.hash {
+            write!(f, " ({hash})")?;
+        }
+        write!(
+            f,
+            " on {testbed}: {report}",
+            testbed = self.testbed.name,
+            report = self.report
+        )
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonCompareResult {
+    pub benchmark: JsonBenchmark,
+    pub measure: JsonMeasure,
+    /// The base metric, if the benchmark and measure are in the base report.
+    pub base: Option<JsonCompareMetric>,
+    /// The head metric, if the benchmark and measure are in the head report.
+    pub head: Option<JsonCompareMetric>,
+    /// The difference between the head and base values.
+    pub delta: Option<OrderedFloat<f64>>,
+    /// The percent change from the base value to the head value.
+    pub percent_change: Option<OrderedFloat<f64>>,
+    /// The two-tailed Welch's t-test p-value for the difference between the iteration samples.
+    /// This requires at least two iterations in both reports.
+    pub p_value: Option<OrderedFloat<f64>>,
+    /// Whether the difference is statistically significant, using a significance level of 0.05.
+    /// This requires at least two iterations in both reports.
+    pub significant: Option<bool>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonCompareMetric {
+    /// The mean value across all of the iterations.
+    pub value: OrderedFloat<f64>,
+    /// The number of iterations.
+    pub iterations: u32,
+}
+
+#[cfg(feature = "table")]
+pub mod table {
+    use ordered_float::OrderedFloat;
+    use tabled::{Table, Tabled};
+
+    use crate::{
+        project::perf::table::DisplayOption, JsonBenchmark, JsonCompare, JsonMeasure, UnitValue,
+    };
+
+    impl From<JsonCompare> for Table {
+        fn from(json_compare: JsonCompare) -> Self {
+            Self::new(json_compare.results.into_iter().map(|result| {
+                let unit = result.measure.unit;
+                let unit_value = |value: OrderedFloat<f64>| UnitValue::new(value, unit);
+                CompareTable {
+                    benchmark: result.benchmark,
+                    measure: result.measure,
+                    base: DisplayOption(result.base.map(|base| unit_value(base.value))),
+                    head: DisplayOption(result.head.map(|head| unit_value(head.value))),
+                    delta: DisplayOption(result.delta.map(unit_value)),
+                    percent_change: DisplayOption(result.percent_change),
+                    p_value: DisplayOption(result.p_value),
+                    significant: DisplayOption(result.significant.map(|significant| {
+                        if significant {
+                            "yes"
+                        } else {
+                            "no"
+                        }
+                    })),
+                }
+            }))
+        }
+    }
+
+    #[derive(Tabled)]
+    pub struct CompareTable {
+        #[tabled(rename = "Benchmark")]
+        pub benchmark: JsonBenchmark,
+        #[tabled(rename = "Measure")]
+        pub measure: JsonMeasure,
+        #[tabled(rename = "Base")]
+        pub base: DisplayOption<UnitValue>,
+        #[tabled(rename = "Head")]
+        pub head: DisplayOption<UnitValue>,
+        #[tabled(rename = "Delta")]
+        pub delta: DisplayOption<UnitValue>,
+        #[tabled(rename = "Change (%)")]
+        pub percent_change: DisplayOption<OrderedFloat<f64>>,
+        #[tabled(rename = "p-value")]
+        pub p_value: DisplayOption<OrderedFloat<f64>>,
+        #[tabled(rename = "Significant")]
+        pub significant: DisplayOption<&'static str>,
+    }
+}
@@ -0,0 +1,485 @@
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    iter::Peekable,
+    str::Chars,
+    str::FromStr,
+};
+
+use bencher_valid::Slug;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// An arithmetic expression over the slugs of other measures in the same project.
+/// It is evaluated for each benchmark in a report to compute a derived measure,
+/// ie `instructions / estimated-cycles`.
+/// Supported operators are `+`, `-`, `*`, `/`, and parentheses.
+/// A `-` between two slugs must be surrounded by whitespace,
+/// as it is otherwise part of the slug.
+/// The expression is parsed once when it is created,
+/// and it is serialized as the original string.
+#[typeshare::typeshare(serialized_as = "String")]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Text))]
+pub struct MeasureExpression {
+    expression: String,
+    expr: Expr,
+}
+
+// Expressions are limited in length and nesting depth,
+// so parsing and evaluating them can't overflow the stack.
+const MAX_LENGTH: usize = 1024;
+const MAX_DEPTH: usize = 64;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Number(f64),
+    Measure(Slug),
+    Negate(Box<Expr>),
+    Binary(Box<Expr>, Operator, Box<Expr>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Measure(Slug),
+    Operator(Operator),
+    Open,
+    Close,
+}
+
+impl MeasureExpression {
+    /// The slugs of the measures that the expression depends on, in order of first use.
+    pub fn measures(&self) -> Vec<Slug> {
+        let mut measures = Vec::new();
+        self.expr.measures(&mut measures);
+        measures
+    }
+
+    /// Evaluate the expression using the given measure values.
+    /// If any measure value is missing or the result is not finite (ie division by zero),
+    /// then there is no value.
+    pub fn evaluate<F>(&self, value: F) -> Option<f64>
+    where
+        F: Fn(&Slug) -> Option<f64>,
+    {
+        self.expr
+            .evaluate(&value)
+            .filter(|result| result.is_finite())
+    }
+}
+
+impl Expr {
+    fn measures(&self, measures: &mut Vec<Slug>) {
+        match self {
+            Self::Number(_) => {},
+            Self::Measure(slug) => {
+                if !measures.contains(slug) {
+                    measures.push(slug.clone());
+                }
+            },
+            Self::Negate(expr) => expr.measures(measures),
+            Self::Binary(left, _, right) => {
+                left.measures(measures);
+                right.measures(measures);
+            },
+        }
+    }
+
+    fn evaluate<F>(&self, value: &F) -> Option<f64>
+    where
+        F: Fn(&Slug) -> Option<f64>,
+    {
+        Some(match self {
+            Self::Number(number) => *number,
+            Self::Measure(slug) => value(slug)?,
+            Self::Negate(expr) => -expr.evaluate(value)?,
+            Self::Binary(left, operator, right) => {
+                let left = left.evaluate(value)?;
+                let right = right.evaluate(value)?;
+                match operator {
+                    Operator::Add => left + right,
+                    Operator::Subtract => left - right,
+                    Operator::Multiply => left * right,
+                    Operator::Divide => left / right,
+                }
+            },
+        })
+    }
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = expression.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            },
+            '+' | '-' | '*' | '/' => {
+                chars.next();
+                tokens.push(Token::Operator(match c {
+                    '+' => Operator::Add,
+                    '-' => Operator::Subtract,
+                    '*' => Operator::Multiply,
+                    _ => Operator::Divide,
+                }));
+            },
+            '(' => {
+                chars.next();
+                tokens.push(Token::Open);
+            },
+            ')' => {
+                chars.next();
+                tokens.push(Token::Close);
+            },
+            '0'..='9' | '.' => {
+                let number = take_while(&mut chars, |c| c.is_ascii_digit() || c == '.');
+                tokens.push(Token::Number(
+                    number
+                        .parse()
+                        .map_err(|_e| format!("Invalid number: {number}"))?,
+                ));
+            },
+            c if c.is_ascii_alphabetic() => {
+                let slug = take_while(&mut chars, |c| {
+                    c.is_ascii_alphanumeric() || c == '-' || c == '_'
+                });
+                tokens.push(Token::Measure(
+                    slug.parse()
+                        .map_err(|_e| format!("Invalid measure slug: {slug}"))?,
+                ));
+            },
+            c => return Err(format!("Unexpected character: {c}")),
+        }
+    }
+    Ok(tokens)
+}
+
+fn take_while<F>(chars: &mut Peekable<Chars<'_>>, f: F) -> String
+where
+    F: Fn(char) -> bool,
+{
+    let mut token = String::new();
+    while let Some(&c) = chars.peek() {
+        if !f(c) {
+            break;
+        }
+        token.push(c);
+        chars.next();
+    }
+    token
+}
+
+fn parse(expression: &str) -> Result<Expr, String> {
+    if expression.len() > MAX_LENGTH {
+        return Err(format!("Expression is longer than {MAX_LENGTH} characters"));
+    }
+    let tokens = tokenize(expression)?;
+    let mut parser = Parser {
+        tokens,
+        position: 0,
+        depth: 0,
+    };
+    let expr = parser.expr()?;
+    if let Some(token) = parser.peek() {
+        return Err(format!("Unexpected token: {token:?}"));
+    }
+    Ok(expr)
+}
+
+// A recursive descent parser with the usual operator precedence:
+// expr = term (('+' | '-') term)*
+// term = factor (('*' | '/') factor)*
+// factor = '-' factor | number | slug | '(' expr ')'
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn expr(&mut self) -> Result<Expr, String> {
+        let mut expr = self.term()?;
+        while let Some(Token::Operator(operator @ (Operator::Add | Operator::Subtract))) =
+            self.peek()
+        {
+            let operator = *operator;
+            self.next();
+            expr = Expr::Binary(Box::new(expr), operator, Box::new(self.term()?));
+        }
+        Ok(expr)
+    }
+
+    fn term(&mut self) -> Result<Expr, String> {
+        let mut expr = self.factor()?;
+        while let Some(Token::Operator(operator @ (Operator::Multiply | Operator::Divide))) =
+            self.peek()
+        {
+            let operator = *operator;
+            self.next();
+            expr = Expr::Binary(Box::new(expr), operator, Box::new(self.factor()?));
+        }
+        Ok(expr)
+    }
+
+    fn factor(&mut self) -> Result<Expr, String> {
+        match self.next() {
+            Some(Token::Operator(Operator::Subtract)) => {
+                self.nest(|parser| Ok(Expr::Negate(Box::new(parser.factor()?))))
+            },
+            Some(Token::Number(number)) => Ok(Expr::Number(number)),
+            Some(Token::Measure(slug)) => Ok(Expr::Measure(slug)),
+            Some(Token::Open) => self.nest(|parser| {
+                let expr = parser.expr()?;
+                match parser.next() {
+                    Some(Token::Close) => Ok(expr),
+                    _ => Err("Missing closing parenthesis".into()),
+                }
+            }),
+            Some(token) => Err(format!("Unexpected token: {token:?}")),
+            None => Err("Unexpected end of expression".into()),
+        }
+    }
+
+    fn nest<F>(&mut self, f: F) -> Result<Expr, String>
+    where
+        F: FnOnce(&mut Self) -> Result<Expr, String>,
+    {
+        if self.depth >= MAX_DEPTH {
+            return Err(format!(
+                "Expression is nested more than {MAX_DEPTH} levels deep"
+            ));
+        }
+        self.depth += 1;
+        let expr = f(self);
+        self.depth -= 1;
+        expr
+    }
+}
+
+impl FromStr for MeasureExpression {
+    type Err = String;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        let expression = expression.trim();
+        let expr = parse(expression)?;
+        let mut measures = Vec::new();
+        expr.measures(&mut measures);
+        if measures.is_empty() {
+            return Err(format!(
+                "Expression does not use any measures: {expression}"
+            ));
+        }
+        Ok(Self {
+            expression: expression.into(),
+            expr,
+        })
+    }
+}
+
+// The parsed expression always follows from the string,
+// so only the string is compared and hashed.
+impl PartialEq for MeasureExpression {
+    fn eq(&self, other: &Self) -> bool {
+        self.expression == other.expression
+    }
+}
+
+impl Eq for MeasureExpression {}
+
+impl Hash for MeasureExpression {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.expression.hash(state);
+    }
+}
+
+impl fmt::Display for MeasureExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.expression)
+    }
+}
+
+impl AsRef<str> for MeasureExpression {
+    fn as_ref(&self) -> &str {
+        &self.expression
+    }
+}
+
+impl From<MeasureExpression> for String {
+    fn from(expression: MeasureExpression) -> Self {
+        expression.expression
+    }
+}
+
+impl Serialize for MeasureExpression {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.expression)
+    }
+}
+
+#[cfg(feature = "schema")]
+impl JsonSchema for MeasureExpression {
+    fn schema_name() -> String {
+        "MeasureExpression".into()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(gen)
+    }
+}
+
+impl<'de> Deserialize<'de> for MeasureExpression {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "db")]
+mod measure_expression {
+    use super::MeasureExpression;
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Text, DB> for MeasureExpression
+    where
+        DB: diesel::backend::Backend,
+        str: diesel::serialize::ToSql<diesel::sql_types::Text, DB>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            self.expression.as_str().to_sql(out)
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Text, DB> for MeasureExpression
+    where
+        DB: diesel::backend::Backend,
+        String: diesel::deserialize::FromSql<diesel::sql_types::Text, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            Ok(String::from_sql(bytes)?.parse()?)
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use std::collections::HashMap;
+
+    use bencher_valid::Slug;
+
+    use super::MeasureExpression;
+
+    fn evaluate(expression: &str, values: &[(&str, f64)]) -> Option<f64> {
+        let values = values
+            .iter()
+            .map(|(slug, value)| (slug.parse::<Slug>().unwrap(), *value))
+            .collect::<HashMap<_, _>>();
+        expression
+            .parse::<MeasureExpression>()
+            .unwrap()
+            .evaluate(|slug| values.get(slug).copied())
+    }
+
+    #[test]
+    fn test_expression_parse() {
+        for expression in [
+            "instructions",
+            "instructions / estimated-cycles",
+            "(l1-accesses + l2-accesses) / total-accesses * 100",
+            "-latency",
+            " a - b ",
+        ] {
+            assert!(
+                expression.parse::<MeasureExpression>().is_ok(),
+                "{expression}"
+            );
+        }
+
+        for expression in [
+            "",
+            "1 + 2",
+            "instructions /",
+            "(instructions",
+            "instructions)",
+            "instructions % cycles",
+            "instructions cycles",
+            "Instructions",
+            "1.2.3 * instructions",
+        ] {
+            assert!(
+                expression.parse::<MeasureExpression>().is_err(),
+                "{expression}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_expression_limits() {
+        let nested = format!("{}a{}", "(".repeat(64), ")".repeat(64));
+        assert!(nested.parse::<MeasureExpression>().is_ok());
+        let nested = format!("{}a{}", "(".repeat(65), ")".repeat(65));
+        assert!(nested.parse::<MeasureExpression>().is_err());
+        let negated = format!("{}a", "-".repeat(65));
+        assert!(negated.parse::<MeasureExpression>().is_err());
+        let negated = format!("{}a", "-".repeat(100_000));
+        assert!(negated.parse::<MeasureExpression>().is_err());
+        let long = vec!["a"; 1000].join(" + ");
+        assert!(long.parse::<MeasureExpression>().is_err());
+    }
+
+    #[test]
+    fn test_expression_measures() {
+        let expression: MeasureExpression = "(a-b + c) / a-b - 2 * d".parse().unwrap();
+        let measures = expression
+            .measures()
+            .into_iter()
+            .map(String::from)
+            .collect::<Vec<_>>();
+        assert_eq!(measures, ["a-b", "c", "d"]);
+    }
+
+    #[test]
+    fn test_expression_evaluate() {
+        let values = [("instructions", 300.0), ("cycles", 100.0), ("zero", 0.0)];
+        assert_eq!(evaluate("instructions / cycles", &values), Some(3.0));
+        assert_eq!(evaluate("instructions - cycles * 2", &values), Some(100.0));
+        assert_eq!(
+            evaluate("(instructions - cycles) * 2", &values),
+            Some(400.0)
+        );
+        assert_eq!(evaluate("-cycles / 4 + 1", &values), Some(-24.0));
+        assert_eq!(evaluate("instructions / missing", &values), None);
+        assert_eq!(evaluate("instructions / zero", &values), None);
+    }
+}
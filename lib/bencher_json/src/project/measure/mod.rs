@@ -0,0 +1,537 @@
+#![allow(clippy::expect_used)]
+
+use std::fmt;
+
+use bencher_valid::{DateTime, ResourceName, Slug};
+use once_cell::sync::Lazy;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{de::Visitor, Deserialize, Deserializer, Serialize};
+
+use crate::ProjectUuid;
+
+pub mod expression;
+pub mod unit;
+
+use expression::MeasureExpression;
+use unit::{MeasureUnit, UnitDimension};
+
+const MEASURE_NAME_ERROR: &str = "Failed to parse measure name.";
+const MEASURE_SLUG_ERROR: &str = "Failed to parse measure slug.";
+const MEASURE_UNITS_ERROR: &str = "Failed to parse measure units.";
+
+pub const LATENCY_NAME_STR: &str = "Latency";
+pub const LATENCY_SLUG_STR: &str = "latency";
+pub const LATENCY_UNITS_STR: &str = "nanoseconds (ns)";
+pub const LATENCY_UNIT: MeasureUnit = MeasureUnit::Nanoseconds;
+
+static LATENCY_NAME: Lazy<ResourceName> =
+    Lazy::new(|| LATENCY_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static LATENCY_SLUG: Lazy<Option<Slug>> =
+    Lazy::new(|| Some(LATENCY_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR)));
+static LATENCY_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| LATENCY_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+pub const THROUGHPUT_NAME_STR: &str = "Throughput";
+pub const THROUGHPUT_SLUG_STR: &str = "throughput";
+pub const THROUGHPUT_UNITS_STR: &str = "operations / second (ops/s)";
+pub const THROUGHPUT_UNIT: MeasureUnit = MeasureUnit::OperationsPerSecond;
+
+static THROUGHPUT_NAME: Lazy<ResourceName> =
+    Lazy::new(|| THROUGHPUT_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static THROUGHPUT_SLUG: Lazy<Option<Slug>> =
+    Lazy::new(|| Some(THROUGHPUT_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR)));
+static THROUGHPUT_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| THROUGHPUT_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+// Iai measures
+
+pub const INSTRUCTIONS_NAME_STR: &str = "Instructions";
+pub const INSTRUCTIONS_SLUG_STR: &str = "instructions";
+pub const INSTRUCTIONS_UNITS_STR: &str = "instructions";
+pub const INSTRUCTIONS_UNIT: MeasureUnit = MeasureUnit::Count;
+
+static INSTRUCTIONS_NAME: Lazy<ResourceName> =
+    Lazy::new(|| INSTRUCTIONS_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static INSTRUCTIONS_SLUG: Lazy<Option<Slug>> =
+    Lazy::new(|| Some(INSTRUCTIONS_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR)));
+static INSTRUCTIONS_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| INSTRUCTIONS_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+pub const L1_ACCESSES_NAME_STR: &str = "L1 Accesses";
+pub const L1_ACCESSES_SLUG_STR: &str = "l1-accesses";
+pub const L1_ACCESSES_UNITS_STR: &str = "accesses";
+pub const L1_ACCESSES_UNIT: MeasureUnit = MeasureUnit::Count;
+
+static L1_ACCESSES_NAME: Lazy<ResourceName> =
+    Lazy::new(|| L1_ACCESSES_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static L1_ACCESSES_SLUG: Lazy<Option<Slug>> =
+    Lazy::new(|| Some(L1_ACCESSES_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR)));
+static L1_ACCESSES_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| L1_ACCESSES_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+pub const L2_ACCESSES_NAME_STR: &str = "L2 Accesses";
+pub const L2_ACCESSES_SLUG_STR: &str = "l2-accesses";
+pub const L2_ACCESSES_UNITS_STR: &str = "accesses";
+pub const L2_ACCESSES_UNIT: MeasureUnit = MeasureUnit::Count;
+
+static L2_ACCESSES_NAME: Lazy<ResourceName> =
+    Lazy::new(|| L2_ACCESSES_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static L2_ACCESSES_SLUG: Lazy<Option<Slug>> =
+    Lazy::new(|| Some(L2_ACCESSES_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR)));
+static L2_ACCESSES_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| L2_ACCESSES_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+pub const RAM_ACCESSES_NAME_STR: &str = "RAM Accesses";
+pub const RAM_ACCESSES_SLUG_STR: &str = "ram-accesses";
+pub const RAM_ACCESSES_UNITS_STR: &str = "accesses";
+pub const RAM_ACCESSES_UNIT: MeasureUnit = MeasureUnit::Count;
+
+static RAM_ACCESSES_NAME: Lazy<ResourceName> =
+    Lazy::new(|| RAM_ACCESSES_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static RAM_ACCESSES_SLUG: Lazy<Option<Slug>> =
+    Lazy::new(|| Some(RAM_ACCESSES_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR)));
+static RAM_ACCESSES_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| RAM_ACCESSES_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+pub const TOTAL_ACCESSES_NAME_STR: &str = "Total Accesses";
+pub const TOTAL_ACCESSES_SLUG_STR: &str = "total-accesses";
+pub const TOTAL_ACCESSES_UNITS_STR: &str = "accesses";
+pub const TOTAL_ACCESSES_UNIT: MeasureUnit = MeasureUnit::Count;
+
+static TOTAL_ACCESSES_NAME: Lazy<ResourceName> =
+    Lazy::new(|| TOTAL_ACCESSES_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static TOTAL_ACCESSES_SLUG: Lazy<Option<Slug>> =
+    Lazy::new(|| Some(TOTAL_ACCESSES_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR)));
+static TOTAL_ACCESSES_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| TOTAL_ACCESSES_SLUG_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+pub const ESTIMATED_CYCLES_NAME_STR: &str = "Estimated Cycles";
+pub const ESTIMATED_CYCLES_SLUG_STR: &str = "estimated-cycles";
+pub const ESTIMATED_CYCLES_UNITS_STR: &str = "estimated cycles";
+pub const ESTIMATED_CYCLES_UNIT: MeasureUnit = MeasureUnit::Count;
+
+static ESTIMATED_CYCLES_NAME: Lazy<ResourceName> =
+    Lazy::new(|| ESTIMATED_CYCLES_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static ESTIMATED_CYCLES_SLUG: Lazy<Option<Slug>> =
+    Lazy::new(|| Some(ESTIMATED_CYCLES_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR)));
+static ESTIMATED_CYCLES_UNITS: Lazy<ResourceName> = Lazy::new(|| {
+    ESTIMATED_CYCLES_UNITS_STR
+        .parse()
+        .expect(MEASURE_UNITS_ERROR)
+});
+
+// Memory measures
+
+pub const ALLOCATIONS_NAME_STR: &str = "Allocations";
+pub const ALLOCATIONS_SLUG_STR: &str = "allocations";
+pub const ALLOCATIONS_UNITS_STR: &str = "allocations / operation";
+pub const ALLOCATIONS_UNIT: MeasureUnit = MeasureUnit::Count;
+
+static ALLOCATIONS_NAME: Lazy<ResourceName> =
+    Lazy::new(|| ALLOCATIONS_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static ALLOCATIONS_SLUG: Lazy<Option<Slug>> =
+    Lazy::new(|| Some(ALLOCATIONS_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR)));
+static ALLOCATIONS_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| ALLOCATIONS_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+pub const BYTES_ALLOCATED_NAME_STR: &str = "Bytes Allocated";
+pub const BYTES_ALLOCATED_SLUG_STR: &str = "bytes-allocated";
+pub const BYTES_ALLOCATED_UNITS_STR: &str = "bytes / operation (B/op)";
+pub const BYTES_ALLOCATED_UNIT: MeasureUnit = MeasureUnit::Bytes;
+
+static BYTES_ALLOCATED_NAME: Lazy<ResourceName> =
+    Lazy::new(|| BYTES_ALLOCATED_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static BYTES_ALLOCATED_SLUG: Lazy<Option<Slug>> =
+    Lazy::new(|| Some(BYTES_ALLOCATED_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR)));
+static BYTES_ALLOCATED_UNITS: Lazy<ResourceName> = Lazy::new(|| {
+    BYTES_ALLOCATED_UNITS_STR
+        .parse()
+        .expect(MEASURE_UNITS_ERROR)
+});
+
+pub const ALLOCATION_RATE_NAME_STR: &str = "Allocation Rate";
+pub const ALLOCATION_RATE_SLUG_STR: &str = "allocation-rate";
+pub const ALLOCATION_RATE_UNITS_STR: &str = "megabytes / second (MB/s)";
+pub const ALLOCATION_RATE_UNIT: MeasureUnit = MeasureUnit::MegabytesPerSecond;
+
+static ALLOCATION_RATE_NAME: Lazy<ResourceName> =
+    Lazy::new(|| ALLOCATION_RATE_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static ALLOCATION_RATE_SLUG: Lazy<Option<Slug>> =
+    Lazy::new(|| Some(ALLOCATION_RATE_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR)));
+static ALLOCATION_RATE_UNITS: Lazy<ResourceName> = Lazy::new(|| {
+    ALLOCATION_RATE_UNITS_STR
+        .parse()
+        .expect(MEASURE_UNITS_ERROR)
+});
+
+// Sample measures
+
+pub const ROUNDS_NAME_STR: &str = "Rounds";
+pub const ROUNDS_SLUG_STR: &str = "rounds";
+pub const ROUNDS_UNITS_STR: &str = "rounds";
+pub const ROUNDS_UNIT: MeasureUnit = MeasureUnit::Count;
+
+static ROUNDS_NAME: Lazy<ResourceName> =
+    Lazy::new(|| ROUNDS_NAME_STR.parse().expect(MEASURE_NAME_ERROR));
+static ROUNDS_SLUG: Lazy<Option<Slug>> =
+    Lazy::new(|| Some(ROUNDS_SLUG_STR.parse().expect(MEASURE_SLUG_ERROR)));
+static ROUNDS_UNITS: Lazy<ResourceName> =
+    Lazy::new(|| ROUNDS_UNITS_STR.parse().expect(MEASURE_UNITS_ERROR));
+
+crate::typed_uuid::typed_uuid!(MeasureUuid);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewMeasure {
+    /// The name of the measure.
+    /// Maximum length is 64 characters.
+    pub name: ResourceName,
+    /// The preferred slug for the measure.
+    /// If not provided, the slug will be generated from the name.
+    /// If the provided or generated slug is already in use, a unique slug will be generated.
+    /// Maximum length is 64 characters.
+    pub slug: Option<Slug>,
+    /// The units of measure.
+    /// Maximum length is 64 characters.
+    pub units: ResourceName,
+    /// The base unit that the measure's values are stored in.
+    /// If set, reported values are converted to this unit
+    /// and scaled to a human readable unit when displayed.
+    pub unit: Option<MeasureUnit>,
+    /// An expression over the slugs of other measures in the project,
+    /// ie `instructions / estimated-cycles`.
+    /// If set, the measure is derived and its value is computed for each benchmark in a report
+    /// from the values of the other measures.
+    /// A derived measure can not be reported directly.
+    pub expression: Option<MeasureExpression>,
+}
+
+impl JsonNewMeasure {
+    pub fn latency() -> Self {
+        Self {
+            name: LATENCY_NAME.clone(),
+            slug: LATENCY_SLUG.clone(),
+            units: LATENCY_UNITS.clone(),
+            unit: Some(LATENCY_UNIT),
+            expression: None,
+        }
+    }
+
+    pub fn throughput() -> Self {
+        Self {
+            name: THROUGHPUT_NAME.clone(),
+            slug: THROUGHPUT_SLUG.clone(),
+            units: THROUGHPUT_UNITS.clone(),
+            unit: Some(THROUGHPUT_UNIT),
+            expression: None,
+        }
+    }
+
+    pub fn instructions() -> Self {
+        Self {
+            name: INSTRUCTIONS_NAME.clone(),
+            slug: INSTRUCTIONS_SLUG.clone(),
+            units: INSTRUCTIONS_UNITS.clone(),
+            unit: Some(INSTRUCTIONS_UNIT),
+            expression: None,
+        }
+    }
+
+    pub fn l1_accesses() -> Self {
+        Self {
+            name: L1_ACCESSES_NAME.clone(),
+            slug: L1_ACCESSES_SLUG.clone(),
+            units: L1_ACCESSES_UNITS.clone(),
+            unit: Some(L1_ACCESSES_UNIT),
+            expression: None,
+        }
+    }
+
+    pub fn l2_accesses() -> Self {
+        Self {
+            name: L2_ACCESSES_NAME.clone(),
+            slug: L2_ACCESSES_SLUG.clone(),
+            units: L2_ACCESSES_UNITS.clone(),
+            unit: Some(L2_ACCESSES_UNIT),
+            expression: None,
+        }
+    }
+
+    pub fn ram_accesses() -> Self {
+        Self {
+            name: RAM_ACCESSES_NAME.clone(),
+            slug: RAM_ACCESSES_SLUG.clone(),
+            units: RAM_ACCESSES_UNITS.clone(),
+            unit: Some(RAM_ACCESSES_UNIT),
+            expression: None,
+        }
+    }
+
+    pub fn total_accesses() -> Self {
+        Self {
+            name: TOTAL_ACCESSES_NAME.clone(),
+            slug: TOTAL_ACCESSES_SLUG.clone(),
+            units: TOTAL_ACCESSES_UNITS.clone(),
+            unit: Some(TOTAL_ACCESSES_UNIT),
+            expression: None,
+        }
+    }
+
+    pub fn estimated_cycles() -> Self {
+        Self {
+            name: ESTIMATED_CYCLES_NAME.clone(),
+            slug: ESTIMATED_CYCLES_SLUG.clone(),
+            units: ESTIMATED_CYCLES_UNITS.clone(),
+            unit: Some(ESTIMATED_CYCLES_UNIT),
+            expression: None,
+        }
+    }
+
+    pub fn allocations() -> Self {
+        Self {
+            name: ALLOCATIONS_NAME.clone(),
+            slug: ALLOCATIONS_SLUG.clone(),
+            units: ALLOCATIONS_UNITS.clone(),
+            unit: Some(ALLOCATIONS_UNIT),
+            expression: None,
+        }
+    }
+
+    pub fn bytes_allocated() -> Self {
+        Self {
+            name: BYTES_ALLOCATED_NAME.clone(),
+            slug: BYTES_ALLOCATED_SLUG.clone(),
+            units: BYTES_ALLOCATED_UNITS.clone(),
+            unit: Some(BYTES_ALLOCATED_UNIT),
+            expression: None,
+        }
+    }
+
+    pub fn allocation_rate() -> Self {
+        Self {
+            name: ALLOCATION_RATE_NAME.clone(),
+            slug: ALLOCATION_RATE_SLUG.clone(),
+            units: ALLOCATION_RATE_UNITS.clone(),
+            unit: Some(ALLOCATION_RATE_UNIT),
+            expression: None,
+        }
+    }
+
+    pub fn rounds() -> Self {
+        Self {
+            name: ROUNDS_NAME.clone(),
+            slug: ROUNDS_SLUG.clone(),
+            units: ROUNDS_UNITS.clone(),
+            unit: Some(ROUNDS_UNIT),
+            expression: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonMeasures(pub Vec<JsonMeasure>);
+
+crate::from_vec!(JsonMeasures[JsonMeasure]);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonMeasure {
+    pub uuid: MeasureUuid,
+    pub project: ProjectUuid,
+    pub name: ResourceName,
+    pub slug: Slug,
+    pub units: ResourceName,
+    pub dimension: Option<UnitDimension>,
+    pub unit: Option<MeasureUnit>,
+    pub expression: Option<MeasureExpression>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl fmt::Display for JsonMeasure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.name, self.units)
+    }
+}
+
+// The `expression` field is nullable, so this uses the same top-level, untagged enum as `JsonUpdateProject`.
+// See `JsonUpdateProject` for why this can't just be an `Option<Option<MeasureExpression>>`.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(untagged)]
+pub enum JsonUpdateMeasure {
+    Patch(JsonMeasurePatch),
+    Null(JsonMeasurePatchNull),
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonMeasurePatch {
+    /// The new name of the measure.
+    /// Maximum length is 64 characters.
+    pub name: Option<ResourceName>,
+    /// The preferred new slug for the measure.
+    /// Maximum length is 64 characters.
+    pub slug: Option<Slug>,
+    /// The new units of measure.
+    /// Maximum length is 64 characters.
+    pub units: Option<ResourceName>,
+    /// The new base unit of the measure.
+    /// Existing metrics are not converted.
+    pub unit: Option<MeasureUnit>,
+    /// The new expression of the derived measure.
+    /// Existing metrics are not recomputed.
+    /// Set to `null` to remove the current expression.
+    pub expression: Option<MeasureExpression>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonMeasurePatchNull {
+    pub name: Option<ResourceName>,
+    pub slug: Option<Slug>,
+    pub units: Option<ResourceName>,
+    pub unit: Option<MeasureUnit>,
+    pub expression: (),
+}
+
+impl<'de> Deserialize<'de> for JsonUpdateMeasure {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const NAME_FIELD: &str = "name";
+        const SLUG_FIELD: &str = "slug";
+        const UNITS_FIELD: &str = "units";
+        const UNIT_FIELD: &str = "unit";
+        const EXPRESSION_FIELD: &str = "expression";
+        const FIELDS: &[&str] = &[
+            NAME_FIELD,
+            SLUG_FIELD,
+            UNITS_FIELD,
+            UNIT_FIELD,
+            EXPRESSION_FIELD,
+        ];
+
+        #[derive(Deserialize)]
+        #[serde(field_identifier, rename_all = "snake_case")]
+        enum Field {
+            Name,
+            Slug,
+            Units,
+            Unit,
+            Expression,
+        }
+
+        struct UpdateMeasureVisitor;
+
+        impl<'de> Visitor<'de> for UpdateMeasureVisitor {
+            type Value = JsonUpdateMeasure;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("JsonUpdateMeasure")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+            where
+                V: serde::de::MapAccess<'de>,
+            {
+                let mut name = None;
+                let mut slug = None;
+                let mut units = None;
+                let mut unit = None;
+                let mut expression = None;
+
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        Field::Name => {
+                            if name.is_some() {
+                                return Err(serde::de::Error::duplicate_field(NAME_FIELD));
+                            }
+                            name = Some(map.next_value()?);
+                        },
+                        Field::Slug => {
+                            if slug.is_some() {
+                                return Err(serde::de::Error::duplicate_field(SLUG_FIELD));
+                            }
+                            slug = Some(map.next_value()?);
+                        },
+                        Field::Units => {
+                            if units.is_some() {
+                                return Err(serde::de::Error::duplicate_field(UNITS_FIELD));
+                            }
+                            units = Some(map.next_value()?);
+                        },
+                        Field::Unit => {
+                            if unit.is_some() {
+                                return Err(serde::de::Error::duplicate_field(UNIT_FIELD));
+                            }
+                            unit = Some(map.next_value()?);
+                        },
+                        Field::Expression => {
+                            if expression.is_some() {
+                                return Err(serde::de::Error::duplicate_field(EXPRESSION_FIELD));
+                            }
+                            expression = Some(map.next_value()?);
+                        },
+                    }
+                }
+
+                Ok(match expression {
+                    Some(Some(expression)) => Self::Value::Patch(JsonMeasurePatch {
+                        name,
+                        slug,
+                        units,
+                        unit,
+                        expression: Some(expression),
+                    }),
+                    Some(None) => Self::Value::Null(JsonMeasurePatchNull {
+                        name,
+                        slug,
+                        units,
+                        unit,
+                        expression: (),
+                    }),
+                    None => Self::Value::Patch(JsonMeasurePatch {
+                        name,
+                        slug,
+                        units,
+                        unit,
+                        expression: None,
+                    }),
+                })
+            }
+        }
+
+        deserializer.deserialize_struct("JsonUpdateMeasure", FIELDS, UpdateMeasureVisitor)
+    }
+}
+
+impl JsonUpdateMeasure {
+    pub fn name(&self) -> Option<&ResourceName> {
+        match self {
+            Self::Patch(patch) => patch.name.as_ref(),
+            Self::Null(patch) => patch.name.as_ref(),
+        }
+    }
+
+    pub fn slug(&self) -> Option<&Slug> {
+        match self {
+            Self::Patch(patch) => patch.slug.as_ref(),
+            Self::Null(patch) => patch.slug.as_ref(),
+        }
+    }
+
+    /// The new expression, `Some(None)` if the expression is removed,
+    /// or `None` if the expression is unchanged.
+    #[allow(clippy::option_option)]
+    pub fn expression(&self) -> Option<Option<&MeasureExpression>> {
+        match self {
+            Self::Patch(patch) => patch.expression.as_ref().map(Some),
+            Self::Null(_) => Some(None),
+        }
+    }
+}
@@ -0,0 +1,361 @@
+use std::{fmt, str::FromStr};
+
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const NANOSECONDS: &str = "nanoseconds";
+const MICROSECONDS: &str = "microseconds";
+const MILLISECONDS: &str = "milliseconds";
+const SECONDS: &str = "seconds";
+const BYTES: &str = "bytes";
+const KIBIBYTES: &str = "kibibytes";
+const MEBIBYTES: &str = "mebibytes";
+const GIBIBYTES: &str = "gibibytes";
+const BYTES_PER_SECOND: &str = "bytes_per_second";
+const KILOBYTES_PER_SECOND: &str = "kilobytes_per_second";
+const MEGABYTES_PER_SECOND: &str = "megabytes_per_second";
+const GIGABYTES_PER_SECOND: &str = "gigabytes_per_second";
+const OPERATIONS_PER_SECOND: &str = "operations_per_second";
+const COUNT: &str = "count";
+
+const TIME_UNITS: &[MeasureUnit] = &[
+    MeasureUnit::Nanoseconds,
+    MeasureUnit::Microseconds,
+    MeasureUnit::Milliseconds,
+    MeasureUnit::Seconds,
+];
+const DATA_UNITS: &[MeasureUnit] = &[
+    MeasureUnit::Bytes,
+    MeasureUnit::Kibibytes,
+    MeasureUnit::Mebibytes,
+    MeasureUnit::Gibibytes,
+];
+const DATA_RATE_UNITS: &[MeasureUnit] = &[
+    MeasureUnit::BytesPerSecond,
+    MeasureUnit::KilobytesPerSecond,
+    MeasureUnit::MegabytesPerSecond,
+    MeasureUnit::GigabytesPerSecond,
+];
+const THROUGHPUT_UNITS: &[MeasureUnit] = &[MeasureUnit::OperationsPerSecond];
+const COUNT_UNITS: &[MeasureUnit] = &[MeasureUnit::Count];
+
+/// The physical dimension of a measure.
+/// Values can only be converted between units of the same dimension.
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum UnitDimension {
+    Time,
+    Data,
+    DataRate,
+    Throughput,
+    Count,
+}
+
+impl UnitDimension {
+    /// All of the units for the dimension, from smallest to largest.
+    pub fn units(self) -> &'static [MeasureUnit] {
+        match self {
+            Self::Time => TIME_UNITS,
+            Self::Data => DATA_UNITS,
+            Self::DataRate => DATA_RATE_UNITS,
+            Self::Throughput => THROUGHPUT_UNITS,
+            Self::Count => COUNT_UNITS,
+        }
+    }
+}
+
+impl fmt::Display for UnitDimension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Time => "time",
+                Self::Data => "data",
+                Self::DataRate => "data rate",
+                Self::Throughput => "throughput",
+                Self::Count => "count",
+            }
+        )
+    }
+}
+
+/// The base unit that the values of a measure are stored in.
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Text))]
+#[serde(rename_all = "snake_case")]
+pub enum MeasureUnit {
+    Nanoseconds,
+    Microseconds,
+    Milliseconds,
+    Seconds,
+    Bytes,
+    Kibibytes,
+    Mebibytes,
+    Gibibytes,
+    BytesPerSecond,
+    KilobytesPerSecond,
+    MegabytesPerSecond,
+    GigabytesPerSecond,
+    OperationsPerSecond,
+    Count,
+}
+
+impl MeasureUnit {
+    pub fn dimension(self) -> UnitDimension {
+        match self {
+            Self::Nanoseconds | Self::Microseconds | Self::Milliseconds | Self::Seconds => {
+                UnitDimension::Time
+            },
+            Self::Bytes | Self::Kibibytes | Self::Mebibytes | Self::Gibibytes => {
+                UnitDimension::Data
+            },
+            Self::BytesPerSecond
+            | Self::KilobytesPerSecond
+            | Self::MegabytesPerSecond
+            | Self::GigabytesPerSecond => UnitDimension::DataRate,
+            Self::OperationsPerSecond => UnitDimension::Throughput,
+            Self::Count => UnitDimension::Count,
+        }
+    }
+
+    /// The abbreviated symbol for the unit.
+    /// A count has no symbol.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Nanoseconds => "ns",
+            Self::Microseconds => "µs",
+            Self::Milliseconds => "ms",
+            Self::Seconds => "s",
+            Self::Bytes => "B",
+            Self::Kibibytes => "KiB",
+            Self::Mebibytes => "MiB",
+            Self::Gibibytes => "GiB",
+            Self::BytesPerSecond => "B/s",
+            Self::KilobytesPerSecond => "KB/s",
+            Self::MegabytesPerSecond => "MB/s",
+            Self::GigabytesPerSecond => "GB/s",
+            Self::OperationsPerSecond => "ops/s",
+            Self::Count => "",
+        }
+    }
+
+    // The size of the unit in terms of the smallest unit of its dimension
+    fn factor(self) -> f64 {
+        match self {
+            Self::Nanoseconds
+            | Self::Bytes
+            | Self::BytesPerSecond
+            | Self::OperationsPerSecond
+            | Self::Count => 1.0,
+            Self::Microseconds | Self::KilobytesPerSecond => 1_000.0,
+            Self::Milliseconds | Self::MegabytesPerSecond => 1_000_000.0,
+            Self::Seconds | Self::GigabytesPerSecond => 1_000_000_000.0,
+            Self::Kibibytes => 1_024.0,
+            Self::Mebibytes => 1_048_576.0,
+            Self::Gibibytes => 1_073_741_824.0,
+        }
+    }
+
+    /// Convert a value from this unit to another unit.
+    /// Returns `None` if the units are not of the same dimension.
+    pub fn convert(self, value: f64, to: Self) -> Option<f64> {
+        (self.dimension() == to.dimension()).then(|| value * self.factor() / to.factor())
+    }
+
+    /// The largest unit of the same dimension
+    /// that the value can be expressed in without dropping below one.
+    #[must_use]
+    pub fn human(self, value: f64) -> Self {
+        if !value.is_normal() {
+            return self;
+        }
+        let base = value.abs() * self.factor();
+        self.dimension()
+            .units()
+            .iter()
+            .copied()
+            .take_while(|unit| unit.factor() <= base)
+            .last()
+            .unwrap_or(self)
+    }
+
+    /// Scale the value to its human readable unit.
+    pub fn scale(self, value: f64) -> (f64, Self) {
+        let human = self.human(value);
+        (value * self.factor() / human.factor(), human)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Nanoseconds => NANOSECONDS,
+            Self::Microseconds => MICROSECONDS,
+            Self::Milliseconds => MILLISECONDS,
+            Self::Seconds => SECONDS,
+            Self::Bytes => BYTES,
+            Self::Kibibytes => KIBIBYTES,
+            Self::Mebibytes => MEBIBYTES,
+            Self::Gibibytes => GIBIBYTES,
+            Self::BytesPerSecond => BYTES_PER_SECOND,
+            Self::KilobytesPerSecond => KILOBYTES_PER_SECOND,
+            Self::MegabytesPerSecond => MEGABYTES_PER_SECOND,
+            Self::GigabytesPerSecond => GIGABYTES_PER_SECOND,
+            Self::OperationsPerSecond => OPERATIONS_PER_SECOND,
+            Self::Count => COUNT,
+        }
+    }
+}
+
+impl FromStr for MeasureUnit {
+    type Err = String;
+
+    fn from_str(unit: &str) -> Result<Self, Self::Err> {
+        Ok(match unit {
+            NANOSECONDS => Self::Nanoseconds,
+            MICROSECONDS => Self::Microseconds,
+            MILLISECONDS => Self::Milliseconds,
+            SECONDS => Self::Seconds,
+            BYTES => Self::Bytes,
+            KIBIBYTES => Self::Kibibytes,
+            MEBIBYTES => Self::Mebibytes,
+            GIBIBYTES => Self::Gibibytes,
+            BYTES_PER_SECOND => Self::BytesPerSecond,
+            KILOBYTES_PER_SECOND => Self::KilobytesPerSecond,
+            MEGABYTES_PER_SECOND => Self::MegabytesPerSecond,
+            GIGABYTES_PER_SECOND => Self::GigabytesPerSecond,
+            OPERATIONS_PER_SECOND => Self::OperationsPerSecond,
+            COUNT => Self::Count,
+            _ => return Err(unit.into()),
+        })
+    }
+}
+
+impl fmt::Display for MeasureUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A value that is displayed scaled to its human readable unit.
+/// If there is no unit, then the raw value is displayed.
+#[derive(Debug, Clone, Copy)]
+pub struct UnitValue {
+    pub value: f64,
+    pub unit: Option<MeasureUnit>,
+}
+
+impl UnitValue {
+    pub fn new<T>(value: T, unit: Option<MeasureUnit>) -> Self
+    where
+        T: Into<f64>,
+    {
+        Self {
+            value: value.into(),
+            unit,
+        }
+    }
+}
+
+impl fmt::Display for UnitValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.unit.map(|unit| unit.scale(self.value)) {
+            Some((value, unit)) if !unit.symbol().is_empty() => {
+                write!(f, "{value:.2} {}", unit.symbol())
+            },
+            _ => write!(f, "{}", self.value),
+        }
+    }
+}
+
+#[cfg(feature = "db")]
+mod measure_unit {
+    use super::MeasureUnit;
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Text, DB> for MeasureUnit
+    where
+        DB: diesel::backend::Backend,
+        str: diesel::serialize::ToSql<diesel::sql_types::Text, DB>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            self.as_str().to_sql(out)
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Text, DB> for MeasureUnit
+    where
+        DB: diesel::backend::Backend,
+        String: diesel::deserialize::FromSql<diesel::sql_types::Text, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            Ok(String::from_sql(bytes)?.parse()?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{MeasureUnit, UnitValue};
+
+    #[test]
+    fn test_convert() {
+        assert_eq!(
+            MeasureUnit::Milliseconds.convert(1.5, MeasureUnit::Nanoseconds),
+            Some(1_500_000.0)
+        );
+        assert_eq!(
+            MeasureUnit::Mebibytes.convert(1.0, MeasureUnit::Kibibytes),
+            Some(1_024.0)
+        );
+        assert_eq!(MeasureUnit::Seconds.convert(1.0, MeasureUnit::Bytes), None);
+    }
+
+    #[test]
+    fn test_human() {
+        assert_eq!(
+            MeasureUnit::Nanoseconds.human(999.0),
+            MeasureUnit::Nanoseconds
+        );
+        assert_eq!(
+            MeasureUnit::Nanoseconds.human(1_000.0),
+            MeasureUnit::Microseconds
+        );
+        assert_eq!(
+            MeasureUnit::Nanoseconds.human(123_456_789.0),
+            MeasureUnit::Milliseconds
+        );
+        assert_eq!(
+            MeasureUnit::Nanoseconds.human(5_000_000_000_000.0),
+            MeasureUnit::Seconds
+        );
+        assert_eq!(MeasureUnit::Seconds.human(0.002), MeasureUnit::Milliseconds);
+        assert_eq!(MeasureUnit::Bytes.human(2_048.0), MeasureUnit::Kibibytes);
+        assert_eq!(MeasureUnit::Bytes.human(0.0), MeasureUnit::Bytes);
+        assert_eq!(MeasureUnit::Count.human(1_000_000.0), MeasureUnit::Count);
+    }
+
+    #[test]
+    fn test_unit_value() {
+        assert_eq!(
+            UnitValue::new(123_456_789.0, Some(MeasureUnit::Nanoseconds)).to_string(),
+            "123.46 ms"
+        );
+        assert_eq!(
+            UnitValue::new(3_145_728.0, Some(MeasureUnit::Bytes)).to_string(),
+            "3.00 MiB"
+        );
+        assert_eq!(
+            UnitValue::new(42.0, Some(MeasureUnit::Count)).to_string(),
+            "42"
+        );
+        assert_eq!(UnitValue::new(42.5, None).to_string(), "42.5");
+    }
+}
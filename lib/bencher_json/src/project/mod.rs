@@ -9,16 +9,21 @@ use serde::{de::Visitor, Deserialize, Deserializer, Serialize};
 use crate::OrganizationUuid;
 
 pub mod alert;
+pub mod archive;
 pub mod benchmark;
 pub mod boundary;
 pub mod branch;
+pub mod compare;
 pub mod measure;
 pub mod metric;
 pub mod model;
 pub mod perf;
 pub mod report;
+pub mod retention;
+pub mod silence;
 pub mod testbed;
 pub mod threshold;
+pub mod webhook;
 
 crate::typed_uuid::typed_uuid!(ProjectUuid);
 
@@ -366,17 +371,15 @@ mod organization_role {
     impl<DB> diesel::serialize::ToSql<diesel::sql_types::Text, DB> for ProjectRole
     where
         DB: diesel::backend::Backend,
-        for<'a> String: diesel::serialize::ToSql<diesel::sql_types::Text, DB>
-            + Into<<DB::BindCollector<'a> as diesel::query_builder::BindCollector<'a, DB>>::Buffer>,
+        str: diesel::serialize::ToSql<diesel::sql_types::Text, DB>,
     {
         fn to_sql<'b>(
             &'b self,
             out: &mut diesel::serialize::Output<'b, '_, DB>,
         ) -> diesel::serialize::Result {
             match self {
-                Self::Maintainer => out.set_value(MAINTAINER_ROLE.to_owned()),
+                Self::Maintainer => MAINTAINER_ROLE.to_sql(out),
             }
-            Ok(diesel::serialize::IsNull::No)
         }
     }
 
@@ -1,3 +1,4 @@
+use ordered_float::OrderedFloat;
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::ser::{self, SerializeStruct};
@@ -6,13 +7,14 @@ use url::Url;
 
 use crate::urlencoded::{from_urlencoded_list, to_urlencoded, to_urlencoded_list, UrlEncodedError};
 use crate::{
-    BenchmarkUuid, BranchUuid, DateTime, DateTimeMillis, JsonBenchmark, JsonBranch, JsonMeasure,
-    JsonProject, JsonTestbed, MeasureUuid, ReportUuid, TestbedUuid,
+    BenchmarkGroup, BenchmarkUuid, BranchUuid, DateTime, DateTimeMillis, GitHash, JsonBenchmark,
+    JsonBranch, JsonMeasure, JsonProject, JsonTestbed, MeasureUuid, ReportUuid, SampleSize, Slug,
+    TestbedUuid,
 };
 
 use super::alert::JsonPerfAlert;
 use super::boundary::JsonBoundary;
-use super::branch::JsonVersion;
+use super::branch::{JsonVersion, VersionNumber};
 use super::metric::JsonMetric;
 use super::report::Iteration;
 use super::threshold::JsonThresholdModel;
@@ -32,6 +34,7 @@ pub struct JsonPerfQueryParams {
     /// A comma separated list of testbed UUIDs to query.
     pub testbeds: String,
     /// A comma separated list of benchmark UUIDs to query.
+    /// This may be empty if a benchmark `group` or `tag` is provided.
     pub benchmarks: String,
     /// A comma separated list of measure UUIDs to query.
     pub measures: String,
@@ -39,6 +42,30 @@ pub struct JsonPerfQueryParams {
     pub start_time: Option<DateTimeMillis>,
     /// Search for metrics before the given date time in milliseconds.
     pub end_time: Option<DateTimeMillis>,
+    /// Search for metrics at or after the branch version with the given `git` hash.
+    pub start_hash: Option<GitHash>,
+    /// Search for metrics at or before the branch version with the given `git` hash.
+    pub end_hash: Option<GitHash>,
+    /// Search for metrics at or after the given branch version number.
+    pub start_version: Option<VersionNumber>,
+    /// Search for metrics at or before the given branch version number.
+    pub end_version: Option<VersionNumber>,
+    /// If set to `true`, archived branches can be included.
+    /// Otherwise, including an archived branch is an error.
+    pub archived: Option<bool>,
+    /// Aggregate the metrics into a single point per time bucket or version.
+    /// If not provided, every metric is returned.
+    pub aggregate: Option<PerfAggregate>,
+    /// Smooth the (aggregated) metrics using a rolling window.
+    /// If not provided, the metrics are not smoothed.
+    pub smoothing: Option<PerfSmoothing>,
+    /// The number of points in the rolling smoothing window.
+    /// If not provided, a window of 5 points is used.
+    pub smoothing_window: Option<SampleSize>,
+    /// Also query all benchmarks in the given benchmark group and its subgroups.
+    pub group: Option<BenchmarkGroup>,
+    /// Also query all benchmarks with the given tag.
+    pub tag: Option<Slug>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -47,11 +74,21 @@ pub struct JsonPerfImgQueryParams {
     /// The title for the perf plot.
     /// If not provided, the project name will be used.
     pub title: Option<String>,
+    /// The format for the perf plot.
+    /// If not provided, a JPEG image will be generated.
+    pub format: Option<PerfImgFormat>,
+    /// If set to `true`, shade the band between the lower and upper boundary limits.
+    pub boundaries: Option<bool>,
+    /// If set to `true`, circle the metrics that generated an alert.
+    pub alerts: Option<bool>,
+    /// If set to `true`, mark where each new branch version starts.
+    pub versions: Option<bool>,
     /// A comma separated list of branch UUIDs to query.
     pub branches: String,
     /// A comma separated list of testbed UUIDs to query.
     pub testbeds: String,
     /// A comma separated list of benchmark UUIDs to query.
+    /// This may be empty if a benchmark `group` or `tag` is provided.
     pub benchmarks: String,
     /// A comma separated list of measure UUIDs to query.
     pub measures: String,
@@ -59,18 +96,56 @@ pub struct JsonPerfImgQueryParams {
     pub start_time: Option<DateTimeMillis>,
     /// Search for metrics before the given date time in milliseconds.
     pub end_time: Option<DateTimeMillis>,
+    /// Search for metrics at or after the branch version with the given `git` hash.
+    pub start_hash: Option<GitHash>,
+    /// Search for metrics at or before the branch version with the given `git` hash.
+    pub end_hash: Option<GitHash>,
+    /// Search for metrics at or after the given branch version number.
+    pub start_version: Option<VersionNumber>,
+    /// Search for metrics at or before the given branch version number.
+    pub end_version: Option<VersionNumber>,
+    /// If set to `true`, archived branches can be included.
+    /// Otherwise, including an archived branch is an error.
+    pub archived: Option<bool>,
+    /// Aggregate the metrics into a single point per time bucket or version.
+    /// If not provided, every metric is returned.
+    pub aggregate: Option<PerfAggregate>,
+    /// Smooth the (aggregated) metrics using a rolling window.
+    /// If not provided, the metrics are not smoothed.
+    pub smoothing: Option<PerfSmoothing>,
+    /// The number of points in the rolling smoothing window.
+    /// If not provided, a window of 5 points is used.
+    pub smoothing_window: Option<SampleSize>,
+    /// Also query all benchmarks in the given benchmark group and its subgroups.
+    pub group: Option<BenchmarkGroup>,
+    /// Also query all benchmarks with the given tag.
+    pub tag: Option<Slug>,
 }
 
 impl From<JsonPerfImgQueryParams> for JsonPerfQueryParams {
     fn from(query: JsonPerfImgQueryParams) -> Self {
         let JsonPerfImgQueryParams {
             title: _,
+            format: _,
+            boundaries: _,
+            alerts: _,
+            versions: _,
             branches,
             testbeds,
             benchmarks,
             measures,
             start_time,
             end_time,
+            start_hash,
+            end_hash,
+            start_version,
+            end_version,
+            archived,
+            aggregate,
+            smoothing,
+            smoothing_window,
+            group,
+            tag,
         } = query;
         Self {
             branches,
@@ -79,6 +154,16 @@ impl From<JsonPerfImgQueryParams> for JsonPerfQueryParams {
             measures,
             start_time,
             end_time,
+            start_hash,
+            end_hash,
+            start_version,
+            end_version,
+            archived,
+            aggregate,
+            smoothing,
+            smoothing_window,
+            group,
+            tag,
         }
     }
 }
@@ -94,6 +179,16 @@ pub struct JsonPerfQuery {
     pub measures: Vec<MeasureUuid>,
     pub start_time: Option<DateTime>,
     pub end_time: Option<DateTime>,
+    pub start_hash: Option<GitHash>,
+    pub end_hash: Option<GitHash>,
+    pub start_version: Option<VersionNumber>,
+    pub end_version: Option<VersionNumber>,
+    pub archived: Option<bool>,
+    pub aggregate: Option<PerfAggregate>,
+    pub smoothing: Option<PerfSmoothing>,
+    pub smoothing_window: Option<SampleSize>,
+    pub group: Option<BenchmarkGroup>,
+    pub tag: Option<Slug>,
 }
 
 impl TryFrom<JsonPerfQueryParams> for JsonPerfQuery {
@@ -107,6 +202,16 @@ impl TryFrom<JsonPerfQueryParams> for JsonPerfQuery {
             measures,
             start_time,
             end_time,
+            start_hash,
+            end_hash,
+            start_version,
+            end_version,
+            archived,
+            aggregate,
+            smoothing,
+            smoothing_window,
+            group,
+            tag,
         } = query_params;
 
         if branches.is_empty() {
@@ -115,7 +220,7 @@ impl TryFrom<JsonPerfQueryParams> for JsonPerfQuery {
         if testbeds.is_empty() {
             return Err(UrlEncodedError::EmptyTestbeds);
         }
-        if benchmarks.is_empty() {
+        if benchmarks.is_empty() && group.is_none() && tag.is_none() {
             return Err(UrlEncodedError::EmptyBenchmarks);
         }
         if measures.is_empty() {
@@ -124,7 +229,11 @@ impl TryFrom<JsonPerfQueryParams> for JsonPerfQuery {
 
         let branches = from_urlencoded_list(&branches)?;
         let testbeds = from_urlencoded_list(&testbeds)?;
-        let benchmarks = from_urlencoded_list(&benchmarks)?;
+        let benchmarks = if benchmarks.is_empty() {
+            Vec::new()
+        } else {
+            from_urlencoded_list(&benchmarks)?
+        };
         let measures = from_urlencoded_list(&measures)?;
 
         Ok(Self {
@@ -134,6 +243,16 @@ impl TryFrom<JsonPerfQueryParams> for JsonPerfQuery {
             measures,
             start_time: start_time.map(Into::into),
             end_time: end_time.map(Into::into),
+            start_hash,
+            end_hash,
+            start_version,
+            end_version,
+            archived,
+            aggregate,
+            smoothing,
+            smoothing_window,
+            group,
+            tag,
         })
     }
 }
@@ -174,7 +293,7 @@ impl JsonPerfQuery {
         serde_urlencoded::to_string(query).map_err(Into::into)
     }
 
-    fn urlencoded(&self) -> Result<[(&'static str, Option<String>); 6], UrlEncodedError> {
+    fn urlencoded(&self) -> Result<[(&'static str, Option<String>); 16], UrlEncodedError> {
         QUERY_KEYS
             .into_iter()
             .zip([
@@ -184,6 +303,16 @@ impl JsonPerfQuery {
                 Some(self.measures()),
                 self.start_time_str(),
                 self.end_time_str(),
+                self.start_hash_str(),
+                self.end_hash_str(),
+                self.start_version_str(),
+                self.end_version_str(),
+                self.archived_str(),
+                self.aggregate_str(),
+                self.smoothing_str(),
+                self.smoothing_window_str(),
+                self.group_str(),
+                self.tag_str(),
             ])
             .collect::<Vec<_>>()
             .try_into()
@@ -221,6 +350,103 @@ impl JsonPerfQuery {
     fn end_time_str(&self) -> Option<String> {
         self.end_time().as_ref().map(to_urlencoded)
     }
+
+    fn start_hash_str(&self) -> Option<String> {
+        self.start_hash.as_ref().map(to_urlencoded)
+    }
+
+    fn end_hash_str(&self) -> Option<String> {
+        self.end_hash.as_ref().map(to_urlencoded)
+    }
+
+    fn start_version_str(&self) -> Option<String> {
+        self.start_version.as_ref().map(to_urlencoded)
+    }
+
+    fn end_version_str(&self) -> Option<String> {
+        self.end_version.as_ref().map(to_urlencoded)
+    }
+
+    fn archived_str(&self) -> Option<String> {
+        self.archived.as_ref().map(to_urlencoded)
+    }
+
+    fn aggregate_str(&self) -> Option<String> {
+        self.aggregate.as_ref().map(to_urlencoded)
+    }
+
+    fn smoothing_str(&self) -> Option<String> {
+        self.smoothing.as_ref().map(to_urlencoded)
+    }
+
+    fn smoothing_window_str(&self) -> Option<String> {
+        self.smoothing_window.as_ref().map(to_urlencoded)
+    }
+
+    fn group_str(&self) -> Option<String> {
+        self.group.as_ref().map(to_urlencoded)
+    }
+
+    fn tag_str(&self) -> Option<String> {
+        self.tag.as_ref().map(to_urlencoded)
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PerfAggregate {
+    /// Do not aggregate the metrics.
+    #[display(fmt = "none")]
+    None,
+    /// Aggregate the metrics per hour.
+    #[display(fmt = "hour")]
+    Hour,
+    /// Aggregate the metrics per day.
+    #[display(fmt = "day")]
+    Day,
+    /// Aggregate the metrics per week, starting on Monday.
+    #[display(fmt = "week")]
+    Week,
+    /// Aggregate the metrics per branch version.
+    #[display(fmt = "version")]
+    Version,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PerfSmoothing {
+    /// Do not smooth the metrics.
+    #[display(fmt = "none")]
+    None,
+    /// Use the rolling mean of the smoothing window.
+    #[display(fmt = "mean")]
+    Mean,
+    /// Use the rolling median of the smoothing window.
+    #[display(fmt = "median")]
+    Median,
+}
+
+#[typeshare::typeshare]
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, derive_more::Display, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PerfImgFormat {
+    /// A JPEG raster image.
+    #[default]
+    #[display(fmt = "jpeg")]
+    Jpeg,
+    /// A scalable vector graphic (SVG) image.
+    #[display(fmt = "svg")]
+    Svg,
+    /// A self-contained HTML page with the SVG image and hover tooltips for each metric.
+    #[display(fmt = "html")]
+    Html,
 }
 
 #[typeshare::typeshare]
@@ -234,6 +460,16 @@ pub enum PerfQueryKey {
     Measures,
     StartTime,
     EndTime,
+    StartHash,
+    EndHash,
+    StartVersion,
+    EndVersion,
+    Archived,
+    Aggregate,
+    Smoothing,
+    SmoothingWindow,
+    Group,
+    Tag,
     // Console Keys
     LowerBoundary,
     UpperBoundary,
@@ -245,8 +481,33 @@ pub const BENCHMARKS: &str = "benchmarks";
 pub const MEASURES: &str = "measures";
 pub const START_TIME: &str = "start_time";
 pub const END_TIME: &str = "end_time";
-const QUERY_KEYS: [&str; 6] = [
-    BRANCHES, TESTBEDS, BENCHMARKS, MEASURES, START_TIME, END_TIME,
+pub const START_HASH: &str = "start_hash";
+pub const END_HASH: &str = "end_hash";
+pub const START_VERSION: &str = "start_version";
+pub const END_VERSION: &str = "end_version";
+pub const ARCHIVED: &str = "archived";
+pub const AGGREGATE: &str = "aggregate";
+pub const SMOOTHING: &str = "smoothing";
+pub const SMOOTHING_WINDOW: &str = "smoothing_window";
+pub const GROUP: &str = "group";
+pub const TAG: &str = "tag";
+const QUERY_KEYS: [&str; 16] = [
+    BRANCHES,
+    TESTBEDS,
+    BENCHMARKS,
+    MEASURES,
+    START_TIME,
+    END_TIME,
+    START_HASH,
+    END_HASH,
+    START_VERSION,
+    END_VERSION,
+    ARCHIVED,
+    AGGREGATE,
+    SMOOTHING,
+    SMOOTHING_WINDOW,
+    GROUP,
+    TAG,
 ];
 // Console Keys
 pub const LOWER_BOUNDARY: &str = "lower_boundary";
@@ -287,6 +548,56 @@ pub struct JsonPerfMetric {
     pub metric: JsonMetric,
     pub boundary: Option<JsonBoundary>,
     pub alert: Option<JsonPerfAlert>,
+    /// The range of the metrics that were aggregated or smoothed into this metric.
+    /// This is only present if the perf query used aggregation or smoothing.
+    pub envelope: Option<JsonPerfEnvelope>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonPerfEnvelope {
+    /// The minimum metric value.
+    pub min: OrderedFloat<f64>,
+    /// The maximum metric value.
+    pub max: OrderedFloat<f64>,
+    /// The number of metrics.
+    pub count: u32,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonPerfChangePoints {
+    pub project: JsonProject,
+    pub start_time: Option<DateTime>,
+    pub end_time: Option<DateTime>,
+    pub results: Vec<JsonPerfChangePointMetrics>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonPerfChangePointMetrics {
+    pub branch: JsonBranch,
+    pub testbed: JsonTestbed,
+    pub benchmark: JsonBenchmark,
+    pub measure: JsonMeasure,
+    pub change_points: Vec<JsonChangePoint>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonChangePoint {
+    /// The last metric before the change point.
+    pub before: JsonPerfMetric,
+    /// The first metric after the change point.
+    pub after: JsonPerfMetric,
+    /// The mean of the metrics between the previous change point and this change point.
+    pub before_mean: OrderedFloat<f64>,
+    /// The mean of the metrics between this change point and the next change point.
+    pub after_mean: OrderedFloat<f64>,
 }
 
 #[cfg(feature = "table")]
@@ -299,29 +610,25 @@ pub mod table {
 
     use crate::{
         project::{branch::VersionNumber, report::Iteration},
-        DateTime, JsonBenchmark, JsonBranch, JsonMeasure, JsonMetric, JsonPerf, JsonProject,
-        JsonTestbed,
+        DateTime, JsonBenchmark, JsonBranch, JsonMeasure, JsonPerf, JsonPerfChangePoints,
+        JsonProject, JsonTestbed, UnitValue,
     };
 
     impl From<JsonPerf> for Table {
         fn from(json_perf: JsonPerf) -> Self {
             let mut perf_table = Vec::new();
             for result in json_perf.results {
+                let unit = result.measure.unit;
+                let unit_value = |value: OrderedFloat<f64>| UnitValue::new(value, unit);
                 for metric in result.metrics {
                     let (baseline, lower_limit, upper_limit) =
-                        if let Some(boundary) = metric.boundary {
-                            (
-                                DisplayOption(boundary.baseline),
-                                DisplayOption(boundary.lower_limit),
-                                DisplayOption(boundary.upper_limit),
-                            )
-                        } else {
+                        metric.boundary.map_or((None, None, None), |boundary| {
                             (
-                                DisplayOption::default(),
-                                DisplayOption::default(),
-                                DisplayOption::default(),
+                                boundary.baseline,
+                                boundary.lower_limit,
+                                boundary.upper_limit,
                             )
-                        };
+                        });
                     perf_table.push(PerfTable {
                         project: json_perf.project.clone(),
                         branch: result.branch.clone(),
@@ -333,10 +640,10 @@ pub mod table {
                         end_time: metric.end_time,
                         version_number: metric.version.number,
                         version_hash: DisplayOption(metric.version.hash),
-                        metric: metric.metric,
-                        baseline,
-                        lower_limit,
-                        upper_limit,
+                        metric: unit_value(metric.metric.value),
+                        baseline: DisplayOption(baseline.map(unit_value)),
+                        lower_limit: DisplayOption(lower_limit.map(unit_value)),
+                        upper_limit: DisplayOption(upper_limit.map(unit_value)),
                     });
                 }
             }
@@ -367,17 +674,78 @@ pub mod table {
         #[tabled(rename = "Version Hash")]
         pub version_hash: DisplayOption<GitHash>,
         #[tabled(rename = "Metric Value")]
-        pub metric: JsonMetric,
+        pub metric: UnitValue,
         #[tabled(rename = "Boundary Baseline")]
-        pub baseline: DisplayOption<OrderedFloat<f64>>,
+        pub baseline: DisplayOption<UnitValue>,
         #[tabled(rename = "Lower Boundary Limit")]
-        pub lower_limit: DisplayOption<OrderedFloat<f64>>,
+        pub lower_limit: DisplayOption<UnitValue>,
         #[tabled(rename = "Upper Boundary Limit")]
-        pub upper_limit: DisplayOption<OrderedFloat<f64>>,
+        pub upper_limit: DisplayOption<UnitValue>,
+    }
+
+    impl From<JsonPerfChangePoints> for Table {
+        fn from(json_change_points: JsonPerfChangePoints) -> Self {
+            let mut change_points_table = Vec::new();
+            for result in json_change_points.results {
+                let unit = result.measure.unit;
+                for change_point in result.change_points {
+                    let before_mean = change_point.before_mean.into_inner();
+                    let after_mean = change_point.after_mean.into_inner();
+                    let percent_change = (before_mean.is_normal() && after_mean.is_finite())
+                        .then(|| OrderedFloat((after_mean - before_mean) / before_mean * 100.0));
+                    change_points_table.push(ChangePointTable {
+                        project: json_change_points.project.clone(),
+                        branch: result.branch.clone(),
+                        testbed: result.testbed.clone(),
+                        benchmark: result.benchmark.clone(),
+                        measure: result.measure.clone(),
+                        before_version_number: change_point.before.version.number,
+                        before_version_hash: DisplayOption(change_point.before.version.hash),
+                        after_version_number: change_point.after.version.number,
+                        after_version_hash: DisplayOption(change_point.after.version.hash),
+                        after_start_time: change_point.after.start_time,
+                        before_mean: UnitValue::new(change_point.before_mean, unit),
+                        after_mean: UnitValue::new(change_point.after_mean, unit),
+                        percent_change: DisplayOption(percent_change),
+                    });
+                }
+            }
+            Self::new(change_points_table)
+        }
+    }
+
+    #[derive(Tabled)]
+    pub struct ChangePointTable {
+        #[tabled(rename = "Project")]
+        pub project: JsonProject,
+        #[tabled(rename = "Branch")]
+        pub branch: JsonBranch,
+        #[tabled(rename = "Testbed")]
+        pub testbed: JsonTestbed,
+        #[tabled(rename = "Benchmark")]
+        pub benchmark: JsonBenchmark,
+        #[tabled(rename = "Measure")]
+        pub measure: JsonMeasure,
+        #[tabled(rename = "Before Version Number")]
+        pub before_version_number: VersionNumber,
+        #[tabled(rename = "Before Version Hash")]
+        pub before_version_hash: DisplayOption<GitHash>,
+        #[tabled(rename = "After Version Number")]
+        pub after_version_number: VersionNumber,
+        #[tabled(rename = "After Version Hash")]
+        pub after_version_hash: DisplayOption<GitHash>,
+        #[tabled(rename = "After Start Time")]
+        pub after_start_time: DateTime,
+        #[tabled(rename = "Before Mean")]
+        pub before_mean: UnitValue,
+        #[tabled(rename = "After Mean")]
+        pub after_mean: UnitValue,
+        #[tabled(rename = "Change (%)")]
+        pub percent_change: DisplayOption<OrderedFloat<f64>>,
     }
 
     #[derive(Default)]
-    pub struct DisplayOption<T>(Option<T>);
+    pub struct DisplayOption<T>(pub(crate) Option<T>);
 
     impl<T> fmt::Display for DisplayOption<T>
     where
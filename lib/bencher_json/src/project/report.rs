@@ -58,6 +58,8 @@ const RUST_BENCH_INT: i32 = 21;
 const RUST_CRITERION_INT: i32 = 22;
 const RUST_IAI_INT: i32 = 23;
 const RUST_IAI_CALLGRIND_INT: i32 = 24;
+const RUST_DIVAN_INT: i32 = 25;
+const RUST_CRITERION_JSON_INT: i32 = 26;
 const CPP_INT: i32 = 30;
 const CPP_GOOGLE_INT: i32 = 31;
 const CPP_CATCH2_INT: i32 = 32;
@@ -94,6 +96,8 @@ pub enum Adapter {
     RustCriterion = RUST_CRITERION_INT,
     RustIai = RUST_IAI_INT,
     RustIaiCallgrind = RUST_IAI_CALLGRIND_INT,
+    RustDivan = RUST_DIVAN_INT,
+    RustCriterionJson = RUST_CRITERION_JSON_INT,
     Cpp = CPP_INT,
     CppGoogle = CPP_GOOGLE_INT,
     CppCatch2 = CPP_CATCH2_INT,
@@ -125,6 +129,8 @@ impl fmt::Display for Adapter {
             Self::RustCriterion => write!(f, "rust_criterion"),
             Self::RustIai => write!(f, "rust_iai"),
             Self::RustIaiCallgrind => write!(f, "rust_iai_callgrind"),
+            Self::RustDivan => write!(f, "rust_divan"),
+            Self::RustCriterionJson => write!(f, "rust_criterion_json"),
             Self::Cpp => write!(f, "cpp"),
             Self::CppGoogle => write!(f, "cpp_google"),
             Self::CppCatch2 => write!(f, "cpp_catch2"),
@@ -154,8 +160,8 @@ mod adapter {
         Adapter, CPP_CATCH2_INT, CPP_GOOGLE_INT, CPP_INT, C_SHARP_DOT_NET_INT, C_SHARP_INT,
         GO_BENCH_INT, GO_INT, JAVA_INT, JAVA_JMH_INT, JSON_INT, JS_BENCHMARK_INT, JS_INT,
         JS_TIME_INT, MAGIC_INT, PYTHON_ASV_INT, PYTHON_INT, PYTHON_PYTEST_INT, RUBY_BENCHMARK_INT,
-        RUBY_INT, RUST_BENCH_INT, RUST_CRITERION_INT, RUST_IAI_CALLGRIND_INT, RUST_IAI_INT,
-        RUST_INT, SHELL_HYPERFINE_INT, SHELL_INT,
+        RUBY_INT, RUST_BENCH_INT, RUST_CRITERION_INT, RUST_CRITERION_JSON_INT, RUST_DIVAN_INT,
+        RUST_IAI_CALLGRIND_INT, RUST_IAI_INT, RUST_INT, SHELL_HYPERFINE_INT, SHELL_INT,
     };
 
     #[derive(Debug, thiserror::Error)]
@@ -181,6 +187,8 @@ mod adapter {
                 Self::RustCriterion => RUST_CRITERION_INT.to_sql(out),
                 Self::RustIai => RUST_IAI_INT.to_sql(out),
                 Self::RustIaiCallgrind => RUST_IAI_CALLGRIND_INT.to_sql(out),
+                Self::RustDivan => RUST_DIVAN_INT.to_sql(out),
+                Self::RustCriterionJson => RUST_CRITERION_JSON_INT.to_sql(out),
                 Self::Cpp => CPP_INT.to_sql(out),
                 Self::CppGoogle => CPP_GOOGLE_INT.to_sql(out),
                 Self::CppCatch2 => CPP_CATCH2_INT.to_sql(out),
@@ -218,6 +226,8 @@ mod adapter {
                 RUST_CRITERION_INT => Ok(Self::RustCriterion),
                 RUST_IAI_INT => Ok(Self::RustIai),
                 RUST_IAI_CALLGRIND_INT => Ok(Self::RustIaiCallgrind),
+                RUST_DIVAN_INT => Ok(Self::RustDivan),
+                RUST_CRITERION_JSON_INT => Ok(Self::RustCriterionJson),
                 CPP_INT => Ok(Self::Cpp),
                 CPP_GOOGLE_INT => Ok(Self::CppGoogle),
                 CPP_CATCH2_INT => Ok(Self::CppCatch2),
@@ -394,20 +404,9 @@ mod iteration {
         }
     }
 
-    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for Iteration
-    where
-        DB: diesel::backend::Backend,
-        for<'a> i32: diesel::serialize::ToSql<diesel::sql_types::Integer, DB>
-            + Into<<DB::BindCollector<'a> as diesel::query_builder::BindCollector<'a, DB>>::Buffer>,
-    {
-        fn to_sql<'b>(
-            &'b self,
-            out: &mut diesel::serialize::Output<'b, '_, DB>,
-        ) -> diesel::serialize::Result {
-            out.set_value(i32::try_from(self.0)?);
-            Ok(diesel::serialize::IsNull::No)
-        }
-    }
+    bencher_valid::to_sql_converted!(diesel::sql_types::Integer, Iteration, |value| {
+        i32::try_from(value.0)?
+    });
 
     impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Integer, DB> for Iteration
     where
@@ -0,0 +1,65 @@
+use std::fmt;
+
+use bencher_valid::{DateTime, NameId, Window};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{BranchUuid, ProjectUuid};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewRetention {
+    /// The time window in seconds to keep raw metrics for.
+    /// Metrics from reports that started before this window are subject to retention.
+    pub raw_window: Window,
+    /// Downsample old metrics to a single aggregated point
+    /// per day per branch, testbed, benchmark, and measure.
+    /// If set to `false`, old metrics are deleted instead.
+    /// If not provided, old metrics are downsampled.
+    pub downsample: Option<bool>,
+    /// The UUID, slug, or name of a branch to always keep the data for its tagged versions.
+    /// A tagged version is a version with a git hash.
+    /// This is usually the main branch of the project.
+    pub protected_branch: Option<NameId>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonRetention {
+    pub project: ProjectUuid,
+    pub raw_window: Window,
+    pub downsample: bool,
+    pub protected_branch: Option<BranchUuid>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl fmt::Display for JsonRetention {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} seconds", self.raw_window)
+    }
+}
+
+/// A summary of the data removed by enforcing a project's retention policy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonRetentionPrune {
+    pub project: ProjectUuid,
+    /// If `true`, then nothing was actually removed.
+    /// The counts are what would be removed.
+    pub dry_run: bool,
+    /// Metrics from reports that started before this date time are subject to retention.
+    pub cutoff: DateTime,
+    /// The number of metrics removed.
+    pub metrics: u64,
+    /// The number of aggregated metrics that the downsampled metrics were merged into.
+    pub downsampled: u64,
+    /// The number of boundaries removed along with their metrics.
+    pub boundaries: u64,
+    /// The number of alerts removed along with their boundaries.
+    pub alerts: u64,
+    /// The number of reports removed because all of their metrics were removed.
+    pub reports: u64,
+}
@@ -0,0 +1,65 @@
+use std::fmt;
+
+use bencher_valid::{DateTime, ResourceId};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{JsonBenchmark, JsonMeasure, JsonTestbed, ProjectUuid};
+
+crate::typed_uuid::typed_uuid!(SilenceUuid);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewSilence {
+    /// The UUID or slug of the benchmark to silence.
+    /// If not provided, then alerts for all benchmarks are silenced.
+    pub benchmark: Option<ResourceId>,
+    /// The UUID or slug of the measure to silence.
+    /// If not provided, then alerts for all measures are silenced.
+    pub measure: Option<ResourceId>,
+    /// The UUID or slug of the testbed to silence.
+    /// If not provided, then alerts for all testbeds are silenced.
+    pub testbed: Option<ResourceId>,
+    /// A note explaining why the alerts are silenced.
+    pub note: Option<String>,
+    /// When the silence expires.
+    /// If not provided, then the silence never expires.
+    pub expires: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonSilences(pub Vec<JsonSilence>);
+
+crate::from_vec!(JsonSilences[JsonSilence]);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonSilence {
+    pub uuid: SilenceUuid,
+    pub project: ProjectUuid,
+    pub benchmark: Option<JsonBenchmark>,
+    pub measure: Option<JsonMeasure>,
+    pub testbed: Option<JsonTestbed>,
+    pub note: Option<String>,
+    pub expires: Option<DateTime>,
+    pub created: DateTime,
+}
+
+impl fmt::Display for JsonSilence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut dimensions = Vec::with_capacity(3);
+        if let Some(benchmark) = &self.benchmark {
+            dimensions.push(benchmark.name.to_string());
+        }
+        if let Some(measure) = &self.measure {
+            dimensions.push(measure.name.to_string());
+        }
+        if let Some(testbed) = &self.testbed {
+            dimensions.push(testbed.name.to_string());
+        }
+        write!(f, "{}", dimensions.join(" / "))
+    }
+}
@@ -1,4 +1,4 @@
-use bencher_valid::{DateTime, Model, NameId};
+use bencher_valid::{BenchmarkGroup, DateTime, Model, NameId};
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -19,6 +19,10 @@ pub struct JsonNewThreshold {
     pub testbed: NameId,
     /// The UUID, slug, or name of the threshold measure.
     pub measure: NameId,
+    /// The benchmark group for the threshold.
+    /// If provided, the threshold applies to all benchmarks in the group and its subgroups.
+    /// Otherwise, the threshold applies to all benchmarks without a more specific group threshold.
+    pub group: Option<BenchmarkGroup>,
     #[serde(flatten)]
     pub model: Model,
 }
@@ -38,6 +42,7 @@ pub struct JsonThreshold {
     pub branch: JsonBranch,
     pub testbed: JsonTestbed,
     pub measure: JsonMeasure,
+    pub group: Option<BenchmarkGroup>,
     // TODO remove in due time
     pub statistic: Option<JsonModel>,
     pub model: JsonModel,
@@ -66,6 +71,8 @@ pub struct JsonThresholdQueryParams {
     pub testbed: Option<String>,
     /// Filter by measure name, exact match.
     pub measure: Option<String>,
+    /// Filter by benchmark group, exact match.
+    pub group: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -73,6 +80,7 @@ pub struct JsonThresholdQuery {
     pub branch: Option<NameId>,
     pub testbed: Option<NameId>,
     pub measure: Option<NameId>,
+    pub group: Option<BenchmarkGroup>,
 }
 
 impl TryFrom<JsonThresholdQueryParams> for JsonThresholdQuery {
@@ -83,6 +91,7 @@ impl TryFrom<JsonThresholdQueryParams> for JsonThresholdQuery {
             branch,
             testbed,
             measure,
+            group,
         } = query_params;
 
         let branch = if let Some(branch) = branch {
@@ -100,11 +109,17 @@ impl TryFrom<JsonThresholdQueryParams> for JsonThresholdQuery {
         } else {
             None
         };
+        let group = if let Some(group) = group {
+            Some(from_urlencoded(&group)?)
+        } else {
+            None
+        };
 
         Ok(Self {
             branch,
             testbed,
             measure,
+            group,
         })
     }
 }
@@ -121,6 +136,10 @@ impl JsonThresholdQuery {
     pub fn measure(&self) -> Option<String> {
         self.measure.as_ref().map(to_urlencoded)
     }
+
+    pub fn group(&self) -> Option<String> {
+        self.group.as_ref().map(to_urlencoded)
+    }
 }
 
 #[derive(Debug, Clone, Copy, Deserialize, Serialize)]
@@ -0,0 +1,175 @@
+use std::fmt;
+
+use bencher_valid::{DateTime, Secret, Url};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{JsonAlert, JsonReport, ProjectUuid};
+
+crate::typed_uuid::typed_uuid!(WebhookUuid);
+crate::typed_uuid::typed_uuid!(WebhookDeliveryUuid);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewWebhook {
+    /// The URL to `POST` the webhook payload to.
+    pub url: Url,
+    /// The secret used to sign the webhook payload.
+    /// The signature is sent in the `X-Bencher-Signature` header
+    /// as the hex encoded HMAC-SHA256 of the request body (ie `sha256=<signature>`).
+    pub secret: Secret,
+    /// The events to send to the webhook.
+    /// If not provided, then all events will be sent.
+    pub events: Option<Vec<WebhookEvent>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhooks(pub Vec<JsonWebhook>);
+
+crate::from_vec!(JsonWebhooks[JsonWebhook]);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhook {
+    pub uuid: WebhookUuid,
+    pub project: ProjectUuid,
+    pub url: Url,
+    pub events: Vec<WebhookEvent>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl fmt::Display for JsonWebhook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.url)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonUpdateWebhook {
+    /// The new URL to `POST` the webhook payload to.
+    pub url: Option<Url>,
+    /// The new secret used to sign the webhook payload.
+    pub secret: Option<Secret>,
+    /// The new events to send to the webhook.
+    pub events: Option<Vec<WebhookEvent>>,
+}
+
+const ALERT_CREATED_INT: i32 = 0;
+const ALERT_DISMISSED_INT: i32 = 1;
+const REPORT_CREATED_INT: i32 = 2;
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Integer))]
+#[serde(rename_all = "snake_case")]
+#[repr(i32)]
+pub enum WebhookEvent {
+    /// An alert was generated for a new report.
+    #[display(fmt = "alert_created")]
+    AlertCreated = ALERT_CREATED_INT,
+    /// An alert was dismissed.
+    #[display(fmt = "alert_dismissed")]
+    AlertDismissed = ALERT_DISMISSED_INT,
+    /// A new report was created.
+    #[display(fmt = "report_created")]
+    ReportCreated = REPORT_CREATED_INT,
+}
+
+impl WebhookEvent {
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self::AlertCreated,
+            Self::AlertDismissed,
+            Self::ReportCreated,
+        ]
+    }
+}
+
+#[cfg(feature = "db")]
+mod webhook_event {
+    use super::{WebhookEvent, ALERT_CREATED_INT, ALERT_DISMISSED_INT, REPORT_CREATED_INT};
+
+    #[derive(Debug, thiserror::Error)]
+    pub enum WebhookEventError {
+        #[error("Invalid webhook event value: {0}")]
+        Invalid(i32),
+    }
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for WebhookEvent
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::serialize::ToSql<diesel::sql_types::Integer, DB>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            match self {
+                Self::AlertCreated => ALERT_CREATED_INT.to_sql(out),
+                Self::AlertDismissed => ALERT_DISMISSED_INT.to_sql(out),
+                Self::ReportCreated => REPORT_CREATED_INT.to_sql(out),
+            }
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Integer, DB> for WebhookEvent
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::deserialize::FromSql<diesel::sql_types::Integer, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            match i32::from_sql(bytes)? {
+                ALERT_CREATED_INT => Ok(Self::AlertCreated),
+                ALERT_DISMISSED_INT => Ok(Self::AlertDismissed),
+                REPORT_CREATED_INT => Ok(Self::ReportCreated),
+                value => Err(Box::new(WebhookEventError::Invalid(value))),
+            }
+        }
+    }
+}
+
+/// The payload that is `POST`ed to a webhook URL.
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhookPayload {
+    pub delivery: WebhookDeliveryUuid,
+    pub event: WebhookEvent,
+    /// The alert for `alert_created` and `alert_dismissed` events.
+    pub alert: Option<JsonAlert>,
+    /// The report, including its project, branch, and testbed.
+    pub report: JsonReport,
+    pub created: DateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhookDeliveries(pub Vec<JsonWebhookDelivery>);
+
+crate::from_vec!(JsonWebhookDeliveries[JsonWebhookDelivery]);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhookDelivery {
+    pub uuid: WebhookDeliveryUuid,
+    pub webhook: WebhookUuid,
+    pub event: WebhookEvent,
+    /// The number of delivery attempts made so far.
+    pub attempts: u32,
+    /// The HTTP status code of the most recent delivery attempt, if a response was received.
+    pub status_code: Option<u16>,
+    /// The error from the most recent delivery attempt, if it failed.
+    pub error: Option<String>,
+    /// The time of the successful delivery, if it has been delivered.
+    pub delivered: Option<DateTime>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
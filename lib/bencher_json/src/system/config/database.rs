@@ -8,17 +8,38 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonDatabase {
+    /// SQLite database file, which is ignored if `postgres` is set
     pub file: PathBuf,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub postgres: Option<JsonPostgres>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub data_store: Option<DataStore>,
 }
 
 impl Sanitize for JsonDatabase {
     fn sanitize(&mut self) {
+        self.postgres.sanitize();
         self.data_store.sanitize();
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonPostgres {
+    /// PostgreSQL connection URL
+    /// postgres://<user>:<password>@<host>:<port>/<database>
+    pub url: Secret,
+    /// Number of connections to keep open to PostgreSQL (default 10)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pool_size: Option<u32>,
+}
+
+impl Sanitize for JsonPostgres {
+    fn sanitize(&mut self) {
+        self.url.sanitize();
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "service", rename_all = "snake_case")]
@@ -13,7 +13,7 @@ mod server;
 mod smtp;
 
 pub use console::JsonConsole;
-pub use database::{DataStore, JsonDatabase};
+pub use database::{DataStore, JsonDatabase, JsonPostgres};
 pub use logging::{IfExists, JsonLogging, LogLevel, ServerLog};
 #[cfg(feature = "plus")]
 pub use plus::{
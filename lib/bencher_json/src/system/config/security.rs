@@ -9,6 +9,8 @@ pub struct JsonSecurity {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub issuer: Option<String>,
     pub secret_key: Secret,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_private_webhooks: Option<bool>,
 }
 
 impl Sanitize for JsonSecurity {
@@ -70,23 +70,7 @@ macro_rules! typed_uuid {
         }
 
         #[cfg(feature = "db")]
-        impl<DB> diesel::serialize::ToSql<diesel::sql_types::Text, DB> for $name
-        where
-            DB: diesel::backend::Backend,
-            for<'a> String: diesel::serialize::ToSql<diesel::sql_types::Text, DB>
-                + Into<
-                    <DB::BindCollector<'a> as diesel::query_builder::BindCollector<'a, DB>>::Buffer,
-                >,
-        {
-            fn to_sql<'b>(
-                &'b self,
-                out: &mut diesel::serialize::Output<'b, '_, DB>,
-            ) -> diesel::serialize::Result {
-                // https://docs.rs/diesel/latest/diesel/serialize/trait.ToSql.html#examples
-                out.set_value(self.to_string());
-                Ok(diesel::serialize::IsNull::No)
-            }
-        }
+        bencher_valid::to_sql_converted!(diesel::sql_types::Text, $name, |value| value.to_string());
 
         #[cfg(feature = "db")]
         impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Text, DB> for $name
@@ -1,18 +1,26 @@
-use std::{io::Cursor, ops::Range};
+use std::{collections::HashMap, fmt::Write, io::Cursor, ops::Range};
 
-use bencher_json::{project::perf::JsonPerfMetrics, JsonPerf};
+use bencher_json::{
+    project::{
+        alert::AlertStatus,
+        branch::{JsonVersion, VersionNumber},
+        perf::{JsonPerfMetric, JsonPerfMetrics},
+    },
+    BranchUuid, JsonPerf, UnitValue,
+};
 use chrono::{DateTime, Duration, Utc};
 use image::{GenericImageView, ImageBuffer};
 use once_cell::sync::Lazy;
 use ordered_float::OrderedFloat;
 use plotters::{
-    coord::types::RangedCoordf64,
+    coord::{types::RangedCoordf64, Shift},
     prelude::{
-        BitMapBackend, BitMapElement, ChartBuilder, IntoDrawingArea, MultiLineText, Ranged,
-        Rectangle,
+        BitMapBackend, BitMapElement, ChartBuilder, Circle, DrawingArea, DrawingAreaErrorKind,
+        DrawingBackend, IntoDrawingArea, MultiLineText, PathElement, Polygon, Ranged, Rectangle,
+        SVGBackend, Text,
     },
     series::LineSeries,
-    style::{Color, FontFamily, RGBColor, ShapeStyle, WHITE},
+    style::{Color, FontFamily, RGBColor, ShapeStyle, TextStyle, BLACK, WHITE},
 };
 
 use crate::PlotError;
@@ -34,6 +42,23 @@ const BUFFER_SIZE: usize = IMG_WIDTH as usize * IMG_HEIGHT as usize * 3;
 
 const MAX_LINES: usize = 8;
 
+const BOUNDARY_OPACITY: f64 = 0.16;
+const LIMIT_OPACITY: f64 = 0.6;
+const ALERT_RADIUS: u32 = 7;
+const ACTIVE_ALERT_COLOR: RGBColor = RGBColor(220, 38, 38);
+const INACTIVE_ALERT_COLOR: RGBColor = RGBColor(128, 128, 128);
+const VERSION_OPACITY: f64 = 0.4;
+const VERSION_LABEL_GAP: i32 = 96;
+const SHORT_HASH_LEN: usize = 7;
+
+const POINT_RADIUS: u32 = 5;
+const HTML_HEAD: &str = "<!DOCTYPE html><html lang=\"en\"><head><meta charset=\"utf-8\">";
+const HTML_STYLE: &str = "<style>body{margin:0;display:flex;justify-content:center}\
+    svg{max-width:100%;height:auto}\
+    .points circle{fill:transparent;stroke-opacity:0;stroke-width:3;cursor:pointer}\
+    .points circle:hover{fill:white;stroke-opacity:1}</style></head><body>";
+const HTML_TAIL: &str = "</body></html>";
+
 pub const BENCHER_WORDMARK: &[u8; 4910] = include_bytes!("../wordmark.png");
 #[allow(clippy::expect_used)]
 static WORDMARK_ELEMENT: Lazy<BitMapElement<(i32, i32)>> = Lazy::new(|| {
@@ -48,6 +73,9 @@ static WORDMARK_ELEMENT: Lazy<BitMapElement<(i32, i32)>> = Lazy::new(|| {
 pub struct LinePlot {
     width: u32,
     height: u32,
+    boundaries: bool,
+    alerts: bool,
+    versions: bool,
 }
 
 impl Default for LinePlot {
@@ -55,6 +83,9 @@ impl Default for LinePlot {
         Self {
             width: IMG_WIDTH,
             height: IMG_HEIGHT,
+            boundaries: false,
+            alerts: false,
+            versions: false,
         }
     }
 }
@@ -64,170 +95,387 @@ impl LinePlot {
         Self::default()
     }
 
-    #[allow(clippy::too_many_lines, clippy::items_after_statements)]
+    /// Shade the band between the lower and upper boundary limits of each line.
+    #[must_use]
+    pub fn with_boundaries(mut self, boundaries: bool) -> Self {
+        self.boundaries = boundaries;
+        self
+    }
+
+    /// Circle each metric that generated an alert.
+    #[must_use]
+    pub fn with_alerts(mut self, alerts: bool) -> Self {
+        self.alerts = alerts;
+        self
+    }
+
+    /// Mark where each new branch version starts along the x-axis.
+    #[must_use]
+    pub fn with_versions(mut self, versions: bool) -> Self {
+        self.versions = versions;
+        self
+    }
+
     pub fn draw(&self, title: Option<&str>, json_perf: &JsonPerf) -> Result<Vec<u8>, PlotError> {
         let mut plot_buffer = vec![0; BUFFER_SIZE];
 
-        // Use a closure that gets immediately executed here
-        // This provides early return control flow and avoids the lifetime complexity
-        || -> Result<(), PlotError> {
+        {
             let root_area = BitMapBackend::with_buffer(&mut plot_buffer, (self.width, self.height))
                 .into_drawing_area();
-            root_area.fill(&WHITE)?;
-
-            // Bencher Wordmark
-            root_area.draw(&*WORDMARK_ELEMENT)?;
+            let _points = self.draw_area(&root_area, title, json_perf)?;
+        }
 
-            // Split header and plot areas
-            let (header, plot_area) = root_area.split_vertically(TITLE_HEIGHT);
+        let image_buffer: ImageBuffer<image::Rgb<u8>, Vec<u8>> =
+            ImageBuffer::from_vec(self.width, self.height, plot_buffer)
+                .ok_or(PlotError::ImageBuffer)?;
+        let mut image_cursor = Cursor::new(Vec::with_capacity(BUFFER_SIZE));
+        image_buffer.write_to(&mut image_cursor, image::ImageFormat::Jpeg)?;
 
-            // Adaptive title sizing
-            let title = title.unwrap_or(json_perf.project.name.as_ref());
-            let title_len = title.len();
-            let size = if title_len > MAX_TITLE_LEN {
-                let diff = title_len - MAX_TITLE_LEN;
-                std::cmp::max(TITLE_HEIGHT - u32::try_from(diff)?, 12)
-            } else {
-                TITLE_HEIGHT
-            };
-            header.titled(title, (FontFamily::Monospace, size))?;
-
-            // Marshal the perf data into a plot-able form
-            let perf_data = PerfData::new(json_perf);
-
-            let Some(perf_data) = perf_data else {
-                // Return an informative message if there is no perf data found
-                let _chart_context = ChartBuilder::on(&plot_area)
-                    .margin_top(TITLE_HEIGHT)
-                    .caption(
-                        format!("No Data Found: {}", Utc::now().format(DATE_TIME_FMT)),
-                        (FontFamily::Monospace, 32),
-                    )
-                    .build_cartesian_2d(PerfData::default_x_range(), PerfData::default_y_range())?;
-
-                return root_area.present().map_err(Into::into);
-            };
+        Ok(image_cursor.into_inner())
+    }
 
-            let (plot_area, key_area) = plot_area.split_vertically(PLOT_HEIGHT);
-
-            let mut chart_context = ChartBuilder::on(&plot_area)
-                .x_label_area_size(40)
-                .y_label_area_size(perf_data.y_label_area_size()?)
-                .margin_left(8)
-                .margin_right(32)
-                .margin_bottom(8)
-                .build_cartesian_2d(perf_data.x_range(), perf_data.y_range())?;
-
-            chart_context
-                .configure_mesh()
-                .axis_desc_style((FontFamily::Monospace, 20))
-                .x_desc("Benchmark Date and Time")
-                .x_labels(usize::try_from(X_LABELS)?)
-                .x_label_style((FontFamily::Monospace, 16))
-                .x_label_formatter(&|x| perf_data.x_label_fmt(x))
-                .y_desc(&perf_data.y_desc)
-                .y_labels(Y_LABELS)
-                .y_label_style((FontFamily::Monospace, 12))
-                .y_label_formatter(&|&y| PerfData::y_label_fmt(y))
-                .max_light_lines(4)
-                .draw()?;
-
-            const KEY_LEFT_MARGIN: usize = 48;
-            const BOX_GAP: usize = 12;
-            let lines_len = perf_data.lines.len();
-            let (box_x_left, box_width, box_gap) = if lines_len > 3 {
-                const MIN_GAP: usize = 4;
-                let extra_lines = lines_len - 4;
-                let box_x_left = std::cmp::max(MIN_GAP, KEY_LEFT_MARGIN - (extra_lines * 8));
-                let box_gap = std::cmp::max(MIN_GAP, BOX_GAP - extra_lines);
-                let box_gaps = lines_len * box_gap;
-                #[allow(clippy::integer_division)]
-                let width = (usize::try_from(IMG_WIDTH)? - box_x_left - box_gaps) / lines_len;
-                (box_x_left, width, box_gap)
-            } else {
-                (KEY_LEFT_MARGIN, 256, BOX_GAP)
-            };
+    pub fn draw_svg(&self, title: Option<&str>, json_perf: &JsonPerf) -> Result<String, PlotError> {
+        self.draw_svg_points(title, json_perf)
+            .map(|(svg, _points)| svg)
+    }
 
-            const BOX_HEIGHT: i32 = 24;
-            const TEXT_START: i32 = BOX_HEIGHT + 4;
-            let max_text_end = i32::try_from(KEY_HEIGHT)? - TEXT_START - 48;
-            let text_width = u32::try_from(box_width)?;
-            let (mut box_x_left, box_width, box_gap) = (
-                i32::try_from(box_x_left)?,
-                i32::try_from(box_width)?,
-                i32::try_from(box_gap)?,
+    // The HTML page inlines the SVG plot and adds a hover target for each plotted metric.
+    pub fn draw_html(
+        &self,
+        title: Option<&str>,
+        json_perf: &JsonPerf,
+    ) -> Result<String, PlotError> {
+        let (svg, points) = self.draw_svg_points(title, json_perf)?;
+        let svg_end = svg.rfind("</svg>").unwrap_or(svg.len());
+        let (svg_body, svg_close) = svg.split_at(svg_end);
+
+        let mut html = String::with_capacity(svg.len() + points.len() * 256);
+        html.push_str(HTML_HEAD);
+        let _ = write!(
+            html,
+            "<title>{}</title>",
+            escape_html(title.unwrap_or(json_perf.project.name.as_ref()))
+        );
+        html.push_str(HTML_STYLE);
+        html.push_str(svg_body);
+        html.push_str("<g class=\"points\">");
+        for point in points {
+            let _ = write!(
+                html,
+                "<circle cx=\"{x}\" cy=\"{y}\" r=\"{POINT_RADIUS}\" stroke=\"#{r:02x}{g:02x}{b:02x}\"><title>{tooltip}</title></circle>",
+                x = point.coord.0,
+                y = point.coord.1,
+                r = point.color.0,
+                g = point.color.1,
+                b = point.color.2,
+                tooltip = escape_html(&point.tooltip),
             );
+        }
+        html.push_str("</g>");
+        html.push_str(svg_close);
+        html.push_str(HTML_TAIL);
 
-            for LineData {
-                data,
-                color,
-                dimensions,
-            } in perf_data.lines
-            {
-                let _series = chart_context.draw_series(
-                    LineSeries::new(data.into_iter().map(|(x, y)| (x, y.into())), color.filled())
-                        .point_size(2),
-                )?;
-
-                let box_x_right = box_x_left + box_width;
-
-                let points = [(box_x_left, 0), (box_x_right, BOX_HEIGHT)];
-                let shape_style = ShapeStyle::from(color).filled();
-                let rectangle = Rectangle::new(points, shape_style);
-                key_area.draw(&rectangle)?;
-
-                let mut font = 16;
-                let text = loop {
-                    let text = MultiLineText::from_str(
-                        dimensions.as_str(),
-                        (box_x_left, TEXT_START),
-                        (FontFamily::Monospace, font),
-                        text_width,
-                    );
-                    let (_, text_height) = text.estimate_dimension().map_err(PlotError::Font)?;
-                    if text_height < max_text_end || font == 8 {
-                        break text;
+        Ok(html)
+    }
+
+    fn draw_svg_points(
+        &self,
+        title: Option<&str>,
+        json_perf: &JsonPerf,
+    ) -> Result<(String, Vec<PointData>), PlotError> {
+        let mut svg = String::new();
+        let points = {
+            let root_area =
+                SVGBackend::with_string(&mut svg, (self.width, self.height)).into_drawing_area();
+            self.draw_area(&root_area, title, json_perf)?
+        };
+        // The SVG backend embeds the wordmark without a valid media type
+        let svg = svg.replace("href=\"data:png;", "href=\"data:image/png;");
+        Ok((svg, points))
+    }
+
+    // Returns the backend coordinates of every plotted metric along with its tooltip.
+    #[allow(clippy::too_many_lines, clippy::items_after_statements)]
+    fn draw_area<DB>(
+        &self,
+        root_area: &DrawingArea<DB, Shift>,
+        title: Option<&str>,
+        json_perf: &JsonPerf,
+    ) -> Result<Vec<PointData>, PlotError>
+    where
+        DB: DrawingBackend,
+        PlotError: From<DrawingAreaErrorKind<DB::ErrorType>>,
+    {
+        root_area.fill(&WHITE)?;
+
+        // Bencher Wordmark
+        root_area.draw(&*WORDMARK_ELEMENT)?;
+
+        // Split header and plot areas
+        let (header, plot_area) = root_area.split_vertically(TITLE_HEIGHT);
+
+        // Adaptive title sizing
+        let title = title.unwrap_or(json_perf.project.name.as_ref());
+        let title_len = title.len();
+        let size = if title_len > MAX_TITLE_LEN {
+            let diff = title_len - MAX_TITLE_LEN;
+            std::cmp::max(TITLE_HEIGHT - u32::try_from(diff)?, 12)
+        } else {
+            TITLE_HEIGHT
+        };
+        header.titled(title, (FontFamily::Monospace, size))?;
+
+        // Marshal the perf data into a plot-able form
+        let perf_data = PerfData::new(json_perf, self.boundaries);
+
+        let Some(perf_data) = perf_data else {
+            // Return an informative message if there is no perf data found
+            let _chart_context = ChartBuilder::on(&plot_area)
+                .margin_top(TITLE_HEIGHT)
+                .caption(
+                    format!("No Data Found: {}", Utc::now().format(DATE_TIME_FMT)),
+                    (FontFamily::Monospace, 32),
+                )
+                .build_cartesian_2d(PerfData::default_x_range(), PerfData::default_y_range())?;
+
+            root_area.present()?;
+            return Ok(Vec::new());
+        };
+
+        let (plot_area, key_area) = plot_area.split_vertically(PLOT_HEIGHT);
+
+        let mut chart_context = ChartBuilder::on(&plot_area)
+            .x_label_area_size(40)
+            .y_label_area_size(perf_data.y_label_area_size()?)
+            .margin_left(8)
+            .margin_right(32)
+            .margin_bottom(8)
+            .build_cartesian_2d(perf_data.x_range(), perf_data.y_range())?;
+
+        chart_context
+            .configure_mesh()
+            .axis_desc_style((FontFamily::Monospace, 20))
+            .x_desc("Benchmark Date and Time")
+            .x_labels(usize::try_from(X_LABELS)?)
+            .x_label_style((FontFamily::Monospace, 16))
+            .x_label_formatter(&|x| perf_data.x_label_fmt(x))
+            .y_desc(&perf_data.y_desc)
+            .y_labels(Y_LABELS)
+            .y_label_style((FontFamily::Monospace, 12))
+            .y_label_formatter(&|&y| PerfData::y_label_fmt(y))
+            .max_light_lines(4)
+            .draw()?;
+
+        let y_range = perf_data.y_range();
+        if self.versions {
+            let version_style = BLACK.mix(VERSION_OPACITY);
+            let label_style = TextStyle::from((FontFamily::Monospace, 12)).color(&version_style);
+            let mut last_x = None;
+            for VersionMark { x, label } in &perf_data.versions {
+                let (x, top) = chart_context.backend_coord(&(*x, y_range.end));
+                let (_, bottom) =
+                    chart_context.backend_coord(&(DateTime::default(), y_range.start));
+                // Skip any versions that would overlap the previous label
+                if last_x.is_some_and(|last_x| x - last_x < VERSION_LABEL_GAP) {
+                    continue;
+                }
+                last_x = Some(x);
+                root_area.draw(&PathElement::new(
+                    vec![(x, top), (x, bottom)],
+                    version_style,
+                ))?;
+                root_area.draw(&Text::new(label.as_str(), (x + 4, top + 4), &label_style))?;
+            }
+        }
+
+        const KEY_LEFT_MARGIN: usize = 48;
+        const BOX_GAP: usize = 12;
+        let lines_len = perf_data.lines.len();
+        let (box_x_left, box_width, box_gap) = if lines_len > 3 {
+            const MIN_GAP: usize = 4;
+            let extra_lines = lines_len - 4;
+            let box_x_left = std::cmp::max(MIN_GAP, KEY_LEFT_MARGIN - (extra_lines * 8));
+            let box_gap = std::cmp::max(MIN_GAP, BOX_GAP - extra_lines);
+            let box_gaps = lines_len * box_gap;
+            #[allow(clippy::integer_division)]
+            let width = (usize::try_from(IMG_WIDTH)? - box_x_left - box_gaps) / lines_len;
+            (box_x_left, width, box_gap)
+        } else {
+            (KEY_LEFT_MARGIN, 256, BOX_GAP)
+        };
+
+        const BOX_HEIGHT: i32 = 24;
+        const TEXT_START: i32 = BOX_HEIGHT + 4;
+        let max_text_end = i32::try_from(KEY_HEIGHT)? - TEXT_START - 48;
+        let text_width = u32::try_from(box_width)?;
+        let (mut box_x_left, box_width, box_gap) = (
+            i32::try_from(box_x_left)?,
+            i32::try_from(box_width)?,
+            i32::try_from(box_gap)?,
+        );
+
+        let mut points = Vec::new();
+        for LineData {
+            data,
+            tooltips,
+            limits,
+            alerts,
+            color,
+            dimensions,
+        } in perf_data.lines
+        {
+            if self.boundaries {
+                let bands = runs(data.iter().zip(&limits).map(|(&(x, _), &(lower, upper))| {
+                    let band = (lower.is_some() || upper.is_some()).then(|| {
+                        (
+                            lower.map_or(y_range.start, Into::into),
+                            upper.map_or(y_range.end, Into::into),
+                        )
+                    });
+                    (x, band)
+                }));
+                let _series = chart_context.draw_series(bands.into_iter().map(|band| {
+                    let polygon = band
+                        .iter()
+                        .map(|&(x, (_, upper))| (x, upper))
+                        .chain(band.iter().rev().map(|&(x, (lower, _))| (x, lower)))
+                        .collect::<Vec<_>>();
+                    Polygon::new(polygon, color.mix(BOUNDARY_OPACITY).filled())
+                }))?;
+                for limit in [
+                    runs(
+                        data.iter()
+                            .zip(&limits)
+                            .map(|(&(x, _), &(lower, _))| (x, lower)),
+                    ),
+                    runs(
+                        data.iter()
+                            .zip(&limits)
+                            .map(|(&(x, _), &(_, upper))| (x, upper)),
+                    ),
+                ] {
+                    for line in limit {
+                        let _series = chart_context.draw_series(LineSeries::new(
+                            line.into_iter().map(|(x, y)| (x, y.into())),
+                            color.mix(LIMIT_OPACITY),
+                        ))?;
                     }
-                    font -= 1;
-                };
-                key_area.draw(&text)?;
+                }
+            }
 
-                box_x_left = box_x_right + box_gap;
+            for (&(x, y), tooltip) in data.iter().zip(tooltips) {
+                points.push(PointData {
+                    coord: chart_context.backend_coord(&(x, y.into())),
+                    color,
+                    tooltip,
+                });
+            }
+            let _series = chart_context.draw_series(
+                LineSeries::new(data.iter().map(|&(x, y)| (x, y.into())), color.filled())
+                    .point_size(2),
+            )?;
+
+            if self.alerts {
+                let _series = chart_context.draw_series(data.iter().zip(&alerts).filter_map(
+                    |(&(x, y), alert)| {
+                        let alert_color = match alert.as_ref()? {
+                            AlertStatus::Active | AlertStatus::Acknowledged => ACTIVE_ALERT_COLOR,
+                            AlertStatus::Dismissed
+                            | AlertStatus::Silenced
+                            | AlertStatus::Resolved => INACTIVE_ALERT_COLOR,
+                        };
+                        Some(Circle::new(
+                            (x, y.into()),
+                            ALERT_RADIUS,
+                            alert_color.stroke_width(2),
+                        ))
+                    },
+                ))?;
             }
 
-            root_area.present().map_err(Into::into)
-        }()?;
+            let box_x_right = box_x_left + box_width;
+
+            let points = [(box_x_left, 0), (box_x_right, BOX_HEIGHT)];
+            let shape_style = ShapeStyle::from(color).filled();
+            let rectangle = Rectangle::new(points, shape_style);
+            key_area.draw(&rectangle)?;
+
+            let mut font = 16;
+            let text = loop {
+                let text = MultiLineText::from_str(
+                    dimensions.as_str(),
+                    (box_x_left, TEXT_START),
+                    (FontFamily::Monospace, font),
+                    text_width,
+                );
+                let (_, text_height) = text.estimate_dimension().map_err(PlotError::Font)?;
+                if text_height < max_text_end || font == 8 {
+                    break text;
+                }
+                font -= 1;
+            };
+            key_area.draw(&text)?;
 
-        let image_buffer: ImageBuffer<image::Rgb<u8>, Vec<u8>> =
-            ImageBuffer::from_vec(self.width, self.height, plot_buffer)
-                .ok_or(PlotError::ImageBuffer)?;
-        let mut image_cursor = Cursor::new(Vec::with_capacity(BUFFER_SIZE));
-        image_buffer.write_to(&mut image_cursor, image::ImageFormat::Jpeg)?;
+            box_x_left = box_x_right + box_gap;
+        }
 
-        Ok(image_cursor.into_inner())
+        root_area.present()?;
+        Ok(points)
     }
 }
 
 struct PerfData {
     lines: Vec<LineData>,
+    versions: Vec<VersionMark>,
     x: (DateTime<Utc>, DateTime<Utc>),
     y: (OrderedFloat<f64>, OrderedFloat<f64>),
     x_time: bool,
     y_desc: String,
 }
 
+// The lower and upper boundary limits for a metric
+type Limits = (Option<OrderedFloat<f64>>, Option<OrderedFloat<f64>>);
+
 struct LineData {
     data: Vec<(DateTime<Utc>, OrderedFloat<f64>)>,
+    tooltips: Vec<String>,
+    limits: Vec<Limits>,
+    alerts: Vec<Option<AlertStatus>>,
     color: RGBColor,
     dimensions: String,
 }
 
+struct VersionMark {
+    x: DateTime<Utc>,
+    label: String,
+}
+
+impl VersionMark {
+    fn label(version: &JsonVersion) -> String {
+        let mut label = format!("#{}", version.number);
+        if let Some(hash) = &version.hash {
+            let hash = hash.as_ref();
+            let _ = write!(label, " {}", hash.get(..SHORT_HASH_LEN).unwrap_or(hash));
+        }
+        label
+    }
+}
+
+struct PointData {
+    coord: (i32, i32),
+    color: RGBColor,
+    tooltip: String,
+}
+
 impl PerfData {
-    fn new(json_perf: &JsonPerf) -> Option<PerfData> {
+    // If the boundaries are plotted, then the y-axis is widened to include their limits.
+    fn new(json_perf: &JsonPerf, boundaries: bool) -> Option<PerfData> {
         let mut min_x = None;
         let mut max_x = None;
         let mut min_y = None;
         let mut max_y = None;
+        let mut versions =
+            HashMap::<(BranchUuid, VersionNumber), (DateTime<Utc>, &JsonVersion)>::new();
 
         let lines = json_perf
             .results
@@ -240,6 +488,10 @@ impl PerfData {
                     .iter()
                     .map(|metric| {
                         let x_value = metric.start_time.into_inner();
+                        versions
+                            .entry((result.branch.uuid, metric.version.number))
+                            .and_modify(|(x, _)| *x = std::cmp::min(*x, x_value))
+                            .or_insert((x_value, &metric.version));
                         min_x = min_x
                             .map(|min| std::cmp::min(min, x_value))
                             .or(Some(x_value));
@@ -247,35 +499,65 @@ impl PerfData {
                             .map(|max| std::cmp::max(max, x_value))
                             .or(Some(x_value));
                         let y_value = metric.metric.value;
-                        min_y = min_y
-                            .map(|min| std::cmp::min(min, y_value))
-                            .or(Some(y_value));
-                        max_y = max_y
-                            .map(|max| std::cmp::max(max, y_value))
-                            .or(Some(y_value));
+                        let limits = metric
+                            .boundary
+                            .filter(|_| boundaries)
+                            .map(|boundary| [boundary.lower_limit, boundary.upper_limit])
+                            .unwrap_or_default();
+                        for y in std::iter::once(y_value).chain(limits.into_iter().flatten()) {
+                            min_y = min_y.map(|min| std::cmp::min(min, y)).or(Some(y));
+                            max_y = max_y.map(|max| std::cmp::max(max, y)).or(Some(y));
+                        }
                         (x_value, y_value)
                     })
                     .collect();
+                let tooltips = result
+                    .metrics
+                    .iter()
+                    .map(|metric| LineData::tooltip(result, metric))
+                    .collect();
+                let limits = result
+                    .metrics
+                    .iter()
+                    .map(|metric| {
+                        metric.boundary.map_or((None, None), |boundary| {
+                            (boundary.lower_limit, boundary.upper_limit)
+                        })
+                    })
+                    .collect();
+                let alerts = result
+                    .metrics
+                    .iter()
+                    .map(|metric| metric.alert.as_ref().map(|alert| alert.status))
+                    .collect();
                 let color = LineData::color(index);
                 let dimensions = LineData::dimensions(result);
                 LineData {
                     data,
+                    tooltips,
+                    limits,
+                    alerts,
                     color,
                     dimensions,
                 }
             })
-            .collect();
+            .collect::<Vec<_>>();
 
         if let (Some(min_x), Some(max_x), Some(min_y), Some(max_y)) = (min_x, max_x, min_y, max_y) {
             let x_time = max_x - min_x < Duration::days(X_LABELS);
-            let y_desc = json_perf
-                .results
-                .first()
-                .map_or("Measure: unitless".to_owned(), |result| {
-                    result.measure.to_string()
-                });
+            let (lines, min_y, max_y, symbol) = Self::scale(json_perf, lines, min_y, max_y);
+            let y_desc = Self::y_desc(json_perf, symbol);
+            let mut versions = versions
+                .into_values()
+                .map(|(x, version)| VersionMark {
+                    x,
+                    label: VersionMark::label(version),
+                })
+                .collect::<Vec<_>>();
+            versions.sort_by_key(|version| version.x);
             Some(PerfData {
                 lines,
+                versions,
                 x: (min_x, max_x),
                 y: (min_y, max_y),
                 x_time,
@@ -286,6 +568,61 @@ impl PerfData {
         }
     }
 
+    fn y_desc(json_perf: &JsonPerf, symbol: Option<&str>) -> String {
+        json_perf
+            .results
+            .first()
+            .map_or("Measure: unitless".to_owned(), |result| {
+                if let Some(symbol) = symbol {
+                    format!("{}: {symbol}", result.measure.name)
+                } else {
+                    result.measure.to_string()
+                }
+            })
+    }
+
+    // If every line shares the same measure unit, then scale the y-axis to the most readable unit.
+    // Returns the scaled lines and y-axis range along with the symbol of the displayed unit.
+    fn scale(
+        json_perf: &JsonPerf,
+        mut lines: Vec<LineData>,
+        min_y: OrderedFloat<f64>,
+        max_y: OrderedFloat<f64>,
+    ) -> (
+        Vec<LineData>,
+        OrderedFloat<f64>,
+        OrderedFloat<f64>,
+        Option<&'static str>,
+    ) {
+        let mut units = json_perf
+            .results
+            .iter()
+            .take(MAX_LINES)
+            .map(|result| result.measure.unit);
+        let Some(Some(unit)) = units.next() else {
+            return (lines, min_y, max_y, None);
+        };
+        if units.any(|other| other != Some(unit)) {
+            return (lines, min_y, max_y, None);
+        }
+        let human = unit.human(max_y.0.abs().max(min_y.0.abs()));
+        let symbol = Some(human.symbol()).filter(|symbol| !symbol.is_empty());
+        let factor = match unit.convert(1.0, human) {
+            Some(factor) if human != unit => factor,
+            _ => return (lines, min_y, max_y, symbol),
+        };
+        for line in &mut lines {
+            for (_, y) in &mut line.data {
+                *y *= factor;
+            }
+            for (lower, upper) in &mut line.limits {
+                *lower = lower.map(|y| y * factor);
+                *upper = upper.map(|y| y * factor);
+            }
+        }
+        (lines, min_y * factor, max_y * factor, symbol)
+    }
+
     #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
     fn x_range(&self) -> Range<DateTime<Utc>> {
         let diff = Duration::seconds(((self.x.1 - self.x.0).num_seconds() as f64 * 0.04) as i64);
@@ -434,6 +771,78 @@ impl LineData {
             result.branch.name, result.testbed.name, result.benchmark.name
         )
     }
+
+    fn tooltip(result: &JsonPerfMetrics, metric: &JsonPerfMetric) -> String {
+        let mut tooltip = format!(
+            "{benchmark}\n{measure}: {value}\nBranch: {branch}\nTestbed: {testbed}\nVersion: {number}",
+            benchmark = result.benchmark.name,
+            measure = result.measure.name,
+            value = UnitValue::new(metric.metric.value, result.measure.unit),
+            branch = result.branch.name,
+            testbed = result.testbed.name,
+            number = metric.version.number,
+        );
+        if let Some(hash) = &metric.version.hash {
+            let _ = write!(tooltip, "\nHash: {hash}");
+        }
+        let _ = write!(
+            tooltip,
+            "\nReport: {report}\nDate: {start_time}",
+            report = metric.report,
+            start_time = metric.start_time.into_inner().format(DATE_TIME_FMT),
+        );
+        if let Some(boundary) = &metric.boundary {
+            for (name, limit) in [
+                ("Lower Boundary", boundary.lower_limit),
+                ("Baseline", boundary.baseline),
+                ("Upper Boundary", boundary.upper_limit),
+            ] {
+                if let Some(limit) = limit {
+                    let limit = UnitValue::new(limit, result.measure.unit);
+                    let _ = write!(tooltip, "\n{name}: {limit}");
+                }
+            }
+        }
+        if metric.alert.is_some() {
+            tooltip.push_str("\nAlert");
+        }
+        tooltip
+    }
+}
+
+// Split the points into runs of consecutive points that all have a value.
+fn runs<I, T>(points: I) -> Vec<Vec<(DateTime<Utc>, T)>>
+where
+    I: Iterator<Item = (DateTime<Utc>, Option<T>)>,
+{
+    let mut runs = Vec::new();
+    let mut run = Vec::new();
+    for (x, value) in points {
+        if let Some(value) = value {
+            run.push((x, value));
+        } else if !run.is_empty() {
+            runs.push(std::mem::take(&mut run));
+        }
+    }
+    if !run.is_empty() {
+        runs.push(run);
+    }
+    runs
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
 }
 
 #[cfg(test)]
@@ -441,9 +850,13 @@ impl LineData {
 mod test {
     use std::{fs::File, io::Write};
 
-    use bencher_json::JsonPerf;
+    use bencher_json::{
+        project::{boundary::JsonBoundary, branch::VersionNumber},
+        JsonPerf, MeasureUnit,
+    };
     use once_cell::sync::Lazy;
 
+    use super::PerfData;
     use crate::LinePlot;
 
     pub const PERF_DOT_JSON: &str = include_str!("../perf.json");
@@ -460,6 +873,11 @@ mod test {
         file.write_all(jpeg).unwrap();
     }
 
+    fn save_file(contents: &str, name: &str) {
+        let mut file = File::create(name).unwrap();
+        file.write_all(contents.as_bytes()).unwrap();
+    }
+
     #[test]
     fn test_plot() {
         let plot = LinePlot::new();
@@ -478,6 +896,108 @@ mod test {
         save_jpeg(&plot_buffer, "decimal");
     }
 
+    #[test]
+    fn test_plot_svg() {
+        let plot = LinePlot::new();
+        let svg = plot
+            .draw_svg(Some("Benchmark Adapter Comparison"), &JSON_PERF)
+            .unwrap();
+        assert!(svg.starts_with("<svg"));
+        save_file(&svg, "perf.svg");
+    }
+
+    #[test]
+    fn test_plot_html() {
+        let plot = LinePlot::new();
+        let html = plot
+            .draw_html(Some("Benchmark Adapter Comparison"), &JSON_PERF)
+            .unwrap();
+        let metrics = JSON_PERF
+            .results
+            .iter()
+            .map(|result| result.metrics.len())
+            .sum::<usize>();
+        assert_eq!(html.matches("</title></circle>").count(), metrics);
+        let report = JSON_PERF
+            .results
+            .first()
+            .and_then(|result| result.metrics.first())
+            .unwrap()
+            .report;
+        assert!(html.contains(&report.to_string()));
+        save_file(&html, "perf.html");
+    }
+
+    #[test]
+    fn test_plot_boundaries() {
+        let mut json_perf = JSON_PERF.clone();
+        for result in &mut json_perf.results {
+            for (index, metric) in result.metrics.iter_mut().enumerate() {
+                let value = metric.metric.value;
+                metric.version.number = VersionNumber(u32::try_from(index >> 1).unwrap());
+                metric.boundary = Some(JsonBoundary {
+                    baseline: Some(value),
+                    lower_limit: Some(value * 0.9),
+                    upper_limit: (index != 3).then_some(value * 1.1),
+                    effect_size: None,
+                });
+                if index == 5 {
+                    metric.alert = Some(
+                        serde_json::from_value(serde_json::json!({
+                            "uuid": "a5d5b7b0-1f53-4c3e-8a8f-1d2c3e4f5a6b",
+                            "limit": "upper",
+                            "status": "active",
+                            "modified": "2023-07-02T12:53:33Z",
+                        }))
+                        .unwrap(),
+                    );
+                }
+            }
+        }
+
+        let plot = LinePlot::new()
+            .with_boundaries(true)
+            .with_alerts(true)
+            .with_versions(true);
+        let plot_buffer = plot
+            .draw(Some("Benchmark Adapter Comparison"), &json_perf)
+            .unwrap();
+        save_jpeg(&plot_buffer, "boundaries");
+        let svg = plot
+            .draw_svg(Some("Benchmark Adapter Comparison"), &json_perf)
+            .unwrap();
+        assert!(svg.contains("<polygon"));
+        assert!(svg.contains("\n#3\n</text>"));
+        save_file(&svg, "boundaries.svg");
+    }
+
+    #[test]
+    fn test_plot_units() {
+        let mut json_perf = JSON_PERF.clone();
+        for result in &mut json_perf.results {
+            result.measure.unit = Some(MeasureUnit::Nanoseconds);
+            for metric in &mut result.metrics {
+                metric.metric.value *= 1_000.0;
+            }
+        }
+
+        let perf_data = PerfData::new(&json_perf, false).unwrap();
+        assert!(perf_data.y_desc.ends_with(": µs"));
+        assert!(perf_data.y.1 .0 < 1_000.0);
+        let tooltip = perf_data
+            .lines
+            .first()
+            .and_then(|line| line.tooltips.first())
+            .unwrap();
+        assert!(tooltip.contains(" µs\n"));
+
+        let plot = LinePlot::new();
+        let plot_buffer = plot
+            .draw(Some("Benchmark Adapter Comparison"), &json_perf)
+            .unwrap();
+        save_jpeg(&plot_buffer, "units");
+    }
+
     #[test]
     fn test_plot_empty() {
         let plot = LinePlot::new();
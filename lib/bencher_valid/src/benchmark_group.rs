@@ -0,0 +1,187 @@
+use derive_more::Display;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use std::{fmt, str::FromStr};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
+
+use crate::{benchmark_name::MAX_BENCHMARK_NAME_LEN, BenchmarkName, ValidError};
+
+/// The separators used to split a benchmark name into its group path,
+/// ie `parser::json::large_file` or `BM_Parse/1024/threads:8`.
+pub const BENCHMARK_GROUP_SEPARATORS: [&str; 2] = ["::", "/"];
+
+#[typeshare::typeshare]
+#[derive(Debug, Display, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Text))]
+pub struct BenchmarkGroup(String);
+
+#[cfg(feature = "db")]
+crate::typed_string!(BenchmarkGroup);
+
+impl BenchmarkGroup {
+    /// Parse the group path from a benchmark name.
+    /// The group is everything before the last separator.
+    /// Returns `None` if the benchmark name does not have a group.
+    pub fn from_name(benchmark_name: &BenchmarkName) -> Option<Self> {
+        let name = benchmark_name.as_ref();
+        let index = BENCHMARK_GROUP_SEPARATORS
+            .iter()
+            .filter_map(|separator| name.rfind(separator))
+            .max()?;
+        name.get(..index)?.parse().ok()
+    }
+
+    /// Returns `true` if the other group is this group or one of its subgroups.
+    pub fn contains(&self, other: &Self) -> bool {
+        other.0.strip_prefix(&self.0).is_some_and(|rest| {
+            rest.is_empty()
+                || BENCHMARK_GROUP_SEPARATORS
+                    .iter()
+                    .any(|separator| rest.starts_with(separator))
+        })
+    }
+
+    /// The number of separators in the group path.
+    /// A deeper group is more specific.
+    pub fn depth(&self) -> usize {
+        BENCHMARK_GROUP_SEPARATORS
+            .iter()
+            .map(|separator| self.0.matches(separator).count())
+            .sum()
+    }
+}
+
+impl FromStr for BenchmarkGroup {
+    type Err = ValidError;
+
+    fn from_str(benchmark_group: &str) -> Result<Self, Self::Err> {
+        if is_valid_benchmark_group(benchmark_group) {
+            Ok(Self(benchmark_group.into()))
+        } else {
+            Err(ValidError::BenchmarkGroup(benchmark_group.into()))
+        }
+    }
+}
+
+impl AsRef<str> for BenchmarkGroup {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<BenchmarkGroup> for String {
+    fn from(benchmark_group: BenchmarkGroup) -> Self {
+        benchmark_group.0
+    }
+}
+
+impl<'de> Deserialize<'de> for BenchmarkGroup {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(BenchmarkGroupVisitor)
+    }
+}
+
+struct BenchmarkGroupVisitor;
+
+impl Visitor<'_> for BenchmarkGroupVisitor {
+    type Value = BenchmarkGroup;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a valid benchmark group")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        value.parse().map_err(E::custom)
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn is_valid_benchmark_group(benchmark_group: &str) -> bool {
+    !benchmark_group.is_empty()
+        && benchmark_group.len() <= MAX_BENCHMARK_NAME_LEN
+        && !BENCHMARK_GROUP_SEPARATORS.iter().any(|separator| {
+            benchmark_group.starts_with(separator) || benchmark_group.ends_with(separator)
+        })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use crate::{BenchmarkGroup, BenchmarkName};
+
+    use super::is_valid_benchmark_group;
+    use pretty_assertions::assert_eq;
+
+    fn from_name(name: &str) -> Option<String> {
+        BenchmarkGroup::from_name(&name.parse::<BenchmarkName>().unwrap()).map(Into::into)
+    }
+
+    #[test]
+    fn test_benchmark_group() {
+        assert_eq!(true, is_valid_benchmark_group("a"));
+        assert_eq!(true, is_valid_benchmark_group("parser::json"));
+        assert_eq!(true, is_valid_benchmark_group("BM_Parse/1024"));
+        assert_eq!(true, is_valid_benchmark_group("a:b"));
+
+        assert_eq!(false, is_valid_benchmark_group(""));
+        assert_eq!(false, is_valid_benchmark_group("::parser"));
+        assert_eq!(false, is_valid_benchmark_group("parser::"));
+        assert_eq!(false, is_valid_benchmark_group("/parser"));
+        assert_eq!(false, is_valid_benchmark_group("parser/"));
+    }
+
+    #[test]
+    fn test_benchmark_group_from_name() {
+        assert_eq!(from_name("large_file"), None);
+        assert_eq!(
+            from_name("parser::json::large_file"),
+            Some("parser::json".into())
+        );
+        assert_eq!(
+            from_name("BM_Parse/1024/threads:8"),
+            Some("BM_Parse/1024".into())
+        );
+        assert_eq!(from_name("parser::json/1024"), Some("parser::json".into()));
+        assert_eq!(from_name("parser/json::1024"), Some("parser/json".into()));
+        assert_eq!(from_name("::large_file"), None);
+        assert_eq!(from_name("/large_file"), None);
+    }
+
+    #[test]
+    fn test_benchmark_group_contains() {
+        let parser: BenchmarkGroup = "parser".parse().unwrap();
+        let parser_json: BenchmarkGroup = "parser::json".parse().unwrap();
+        let parser_1024: BenchmarkGroup = "parser/1024".parse().unwrap();
+        let parsers: BenchmarkGroup = "parsers".parse().unwrap();
+
+        assert!(parser.contains(&parser));
+        assert!(parser.contains(&parser_json));
+        assert!(parser.contains(&parser_1024));
+        assert!(!parser.contains(&parsers));
+        assert!(!parser_json.contains(&parser));
+
+        assert_eq!(parser.depth(), 0);
+        assert_eq!(parser_json.depth(), 1);
+        assert_eq!(
+            "BM_Parse/1024::threads"
+                .parse::<BenchmarkGroup>()
+                .unwrap()
+                .depth(),
+            2
+        );
+    }
+}
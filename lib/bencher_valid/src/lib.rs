@@ -3,6 +3,7 @@ use std::str::FromStr;
 #[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
 
+mod benchmark_group;
 mod benchmark_name;
 mod branch_name;
 mod date_time;
@@ -25,6 +26,7 @@ mod user_name;
 pub use crate::git_hash::GitHash;
 pub use crate::slug::Slug;
 pub use crate::url::Url;
+pub use benchmark_group::{BenchmarkGroup, BENCHMARK_GROUP_SEPARATORS};
 pub use benchmark_name::BenchmarkName;
 pub use branch_name::BranchName;
 pub use date_time::{DateTime, DateTimeMillis};
@@ -94,17 +96,13 @@ macro_rules! typed_string {
         impl<DB> diesel::serialize::ToSql<diesel::sql_types::Text, DB> for $name
         where
             DB: diesel::backend::Backend,
-            for<'a> String: diesel::serialize::ToSql<diesel::sql_types::Text, DB>
-                + Into<
-                    <DB::BindCollector<'a> as diesel::query_builder::BindCollector<'a, DB>>::Buffer,
-                >,
+            str: diesel::serialize::ToSql<diesel::sql_types::Text, DB>,
         {
             fn to_sql<'b>(
                 &'b self,
                 out: &mut diesel::serialize::Output<'b, '_, DB>,
             ) -> diesel::serialize::Result {
-                out.set_value(self.to_string());
-                Ok(diesel::serialize::IsNull::No)
+                self.0.as_str().to_sql(out)
             }
         }
 
@@ -126,6 +124,39 @@ macro_rules! typed_string {
 #[cfg(feature = "db")]
 pub(crate) use typed_string;
 
+// SQLite takes ownership of bind values while PostgreSQL serializes them to bytes,
+// so types that have to be converted before they are bound need an implementation for each backend.
+#[cfg(feature = "db")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! to_sql_converted {
+    ($sql_type:ty, $name:ty, |$value:ident| $converted:expr) => {
+        impl diesel::serialize::ToSql<$sql_type, diesel::sqlite::Sqlite> for $name {
+            fn to_sql<'b>(
+                &'b self,
+                out: &mut diesel::serialize::Output<'b, '_, diesel::sqlite::Sqlite>,
+            ) -> diesel::serialize::Result {
+                let $value = self;
+                out.set_value($converted);
+                Ok(diesel::serialize::IsNull::No)
+            }
+        }
+
+        impl diesel::serialize::ToSql<$sql_type, diesel::pg::Pg> for $name {
+            fn to_sql<'b>(
+                &'b self,
+                out: &mut diesel::serialize::Output<'b, '_, diesel::pg::Pg>,
+            ) -> diesel::serialize::Result {
+                let $value = self;
+                diesel::serialize::ToSql::<$sql_type, diesel::pg::Pg>::to_sql(
+                    &$converted,
+                    &mut out.reborrow(),
+                )
+            }
+        }
+    };
+}
+
 #[cfg(test)]
 pub mod test {
 
@@ -10,6 +10,9 @@ const T_TEST_INT: i32 = 1;
 const LOG_NORMAL_INT: i32 = 10;
 const IQR_INT: i32 = 40;
 const DELTA_IQR_INT: i32 = 41;
+const MANN_WHITNEY_U_INT: i32 = 50;
+const WELCH_T_INT: i32 = 60;
+const KOLMOGOROV_SMIRNOV_INT: i32 = 61;
 
 #[typeshare::typeshare]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display, Serialize, Deserialize)]
@@ -28,13 +31,28 @@ pub enum ModelTest {
     LogNormal = LOG_NORMAL_INT,
     Iqr = IQR_INT,
     DeltaIqr = DELTA_IQR_INT,
+    MannWhitneyU = MANN_WHITNEY_U_INT,
+    WelchT = WELCH_T_INT,
+    #[serde(alias = "ks")]
+    KolmogorovSmirnov = KOLMOGOROV_SMIRNOV_INT,
+}
+
+impl ModelTest {
+    /// Two sample tests compare all of the new metrics for a benchmark against the historical metrics at once,
+    /// instead of comparing each new metric independently.
+    pub fn is_two_sample(self) -> bool {
+        matches!(
+            self,
+            Self::MannWhitneyU | Self::WelchT | Self::KolmogorovSmirnov
+        )
+    }
 }
 
 #[cfg(feature = "db")]
 mod db {
     use super::{
-        ModelTest, DELTA_IQR_INT, IQR_INT, LOG_NORMAL_INT, PERCENTAGE_INT, STATIC_INT, T_TEST_INT,
-        Z_SCORE_INT,
+        ModelTest, DELTA_IQR_INT, IQR_INT, KOLMOGOROV_SMIRNOV_INT, LOG_NORMAL_INT,
+        MANN_WHITNEY_U_INT, PERCENTAGE_INT, STATIC_INT, T_TEST_INT, WELCH_T_INT, Z_SCORE_INT,
     };
 
     #[derive(Debug, thiserror::Error)]
@@ -60,6 +78,9 @@ mod db {
                 Self::LogNormal => LOG_NORMAL_INT.to_sql(out),
                 Self::Iqr => IQR_INT.to_sql(out),
                 Self::DeltaIqr => DELTA_IQR_INT.to_sql(out),
+                Self::MannWhitneyU => MANN_WHITNEY_U_INT.to_sql(out),
+                Self::WelchT => WELCH_T_INT.to_sql(out),
+                Self::KolmogorovSmirnov => KOLMOGOROV_SMIRNOV_INT.to_sql(out),
             }
         }
     }
@@ -78,6 +99,9 @@ mod db {
                 LOG_NORMAL_INT => Ok(Self::LogNormal),
                 IQR_INT => Ok(Self::Iqr),
                 DELTA_IQR_INT => Ok(Self::DeltaIqr),
+                MANN_WHITNEY_U_INT => Ok(Self::MannWhitneyU),
+                WELCH_T_INT => Ok(Self::WelchT),
+                KOLMOGOROV_SMIRNOV_INT => Ok(Self::KolmogorovSmirnov),
                 value => Err(Box::new(ModelTestError::Invalid(value))),
             }
         }
@@ -49,6 +49,7 @@ impl From<SampleSize> for usize {
 
 impl SampleSize {
     pub const MIN: Self = Self(2);
+    pub const FIVE: Self = Self(5);
     pub const THIRTY: Self = Self(30);
     pub const TWO_FIFTY_FIVE: Self = Self(u8::MAX as u32);
     pub const MAX: Self = Self(u32::MAX);
@@ -99,20 +100,9 @@ impl Visitor<'_> for SampleSizeVisitor {
 mod db {
     use super::SampleSize;
 
-    impl<DB> diesel::serialize::ToSql<diesel::sql_types::BigInt, DB> for SampleSize
-    where
-        DB: diesel::backend::Backend,
-        for<'a> i64: diesel::serialize::ToSql<diesel::sql_types::BigInt, DB>
-            + Into<<DB::BindCollector<'a> as diesel::query_builder::BindCollector<'a, DB>>::Buffer>,
-    {
-        fn to_sql<'b>(
-            &'b self,
-            out: &mut diesel::serialize::Output<'b, '_, DB>,
-        ) -> diesel::serialize::Result {
-            out.set_value(i64::from(*self));
-            Ok(diesel::serialize::IsNull::No)
-        }
-    }
+    crate::to_sql_converted!(diesel::sql_types::BigInt, SampleSize, |value| i64::from(
+        *value
+    ));
 
     impl<DB> diesel::deserialize::FromSql<diesel::sql_types::BigInt, DB> for SampleSize
     where
@@ -0,0 +1,2 @@
+ALTER TABLE branch
+ADD COLUMN archived BIGINT;
@@ -0,0 +1,20 @@
+ALTER TABLE alert
+ADD COLUMN assignee_id INTEGER REFERENCES user (id) ON DELETE
+SET NULL;
+ALTER TABLE alert
+ADD COLUMN note TEXT;
+CREATE TABLE silence (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    benchmark_id INTEGER,
+    measure_id INTEGER,
+    testbed_id INTEGER,
+    note TEXT,
+    expires BIGINT,
+    created BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    FOREIGN KEY (benchmark_id) REFERENCES benchmark (id) ON DELETE CASCADE,
+    FOREIGN KEY (measure_id) REFERENCES measure (id) ON DELETE CASCADE,
+    FOREIGN KEY (testbed_id) REFERENCES testbed (id) ON DELETE CASCADE
+);
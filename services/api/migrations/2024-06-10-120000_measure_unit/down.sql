@@ -0,0 +1,37 @@
+PRAGMA foreign_keys = off;
+CREATE TABLE down_measure (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    slug TEXT NOT NULL,
+    units TEXT NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    UNIQUE(project_id, name),
+    UNIQUE(project_id, slug)
+);
+INSERT INTO down_measure(
+        id,
+        uuid,
+        project_id,
+        name,
+        slug,
+        units,
+        created,
+        modified
+    )
+SELECT id,
+    uuid,
+    project_id,
+    name,
+    slug,
+    units,
+    created,
+    modified
+FROM measure;
+DROP TABLE measure;
+ALTER TABLE down_measure
+    RENAME TO measure;
+PRAGMA foreign_keys = on;
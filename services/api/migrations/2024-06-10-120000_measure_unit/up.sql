@@ -0,0 +1,47 @@
+ALTER TABLE measure
+ADD COLUMN unit TEXT;
+-- Default measures
+-- Only measures that still have both the default slug and the default units are backfilled.
+-- Any other measures are left without a unit.
+UPDATE measure
+SET unit = 'nanoseconds'
+WHERE slug = 'latency'
+    AND units = 'nanoseconds (ns)';
+UPDATE measure
+SET unit = 'operations_per_second'
+WHERE slug = 'throughput'
+    AND units = 'operations / second (ops/s)';
+UPDATE measure
+SET unit = 'count'
+WHERE (
+        slug = 'instructions'
+        AND units = 'instructions'
+    )
+    OR (
+        slug IN ('l1-accesses', 'l2-accesses', 'ram-accesses')
+        AND units = 'accesses'
+    )
+    OR (
+        slug = 'total-accesses'
+        AND units = 'total-accesses'
+    )
+    OR (
+        slug = 'estimated-cycles'
+        AND units = 'estimated cycles'
+    )
+    OR (
+        slug = 'allocations'
+        AND units = 'allocations / operation'
+    )
+    OR (
+        slug = 'rounds'
+        AND units = 'rounds'
+    );
+UPDATE measure
+SET unit = 'bytes'
+WHERE slug = 'bytes-allocated'
+    AND units = 'bytes / operation (B/op)';
+UPDATE measure
+SET unit = 'megabytes_per_second'
+WHERE slug = 'allocation-rate'
+    AND units = 'megabytes / second (MB/s)';
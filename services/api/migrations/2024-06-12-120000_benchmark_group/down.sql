@@ -0,0 +1,100 @@
+PRAGMA foreign_keys = off;
+-- Group thresholds can not be represented without a benchmark group
+DELETE FROM alert
+WHERE boundary_id IN (
+        SELECT boundary.id
+        FROM boundary
+            INNER JOIN threshold ON boundary.threshold_id = threshold.id
+        WHERE threshold.benchmark_group IS NOT NULL
+    );
+DELETE FROM boundary
+WHERE threshold_id IN (
+        SELECT id
+        FROM threshold
+        WHERE benchmark_group IS NOT NULL
+    );
+UPDATE threshold
+SET model_id = NULL
+WHERE benchmark_group IS NOT NULL;
+DELETE FROM model
+WHERE threshold_id IN (
+        SELECT id
+        FROM threshold
+        WHERE benchmark_group IS NOT NULL
+    );
+DELETE FROM threshold
+WHERE benchmark_group IS NOT NULL;
+CREATE TABLE down_threshold (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    branch_id INTEGER NOT NULL,
+    testbed_id INTEGER NOT NULL,
+    measure_id INTEGER NOT NULL,
+    model_id INTEGER,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    FOREIGN KEY (branch_id) REFERENCES branch (id),
+    FOREIGN KEY (testbed_id) REFERENCES testbed (id),
+    FOREIGN KEY (measure_id) REFERENCES measure (id),
+    FOREIGN KEY (model_id) REFERENCES model (id),
+    UNIQUE(branch_id, testbed_id, measure_id)
+);
+INSERT INTO down_threshold(
+        id,
+        uuid,
+        project_id,
+        branch_id,
+        testbed_id,
+        measure_id,
+        model_id,
+        created,
+        modified
+    )
+SELECT id,
+    uuid,
+    project_id,
+    branch_id,
+    testbed_id,
+    measure_id,
+    model_id,
+    created,
+    modified
+FROM threshold;
+DROP TABLE threshold;
+ALTER TABLE down_threshold
+    RENAME TO threshold;
+CREATE TABLE down_benchmark (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    slug TEXT NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    UNIQUE(project_id, name),
+    UNIQUE(project_id, slug)
+);
+INSERT INTO down_benchmark(
+        id,
+        uuid,
+        project_id,
+        name,
+        slug,
+        created,
+        modified
+    )
+SELECT id,
+    uuid,
+    project_id,
+    name,
+    slug,
+    created,
+    modified
+FROM benchmark;
+DROP TABLE benchmark;
+ALTER TABLE down_benchmark
+    RENAME TO benchmark;
+PRAGMA foreign_keys = on;
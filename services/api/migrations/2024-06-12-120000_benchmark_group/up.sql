@@ -0,0 +1,51 @@
+ALTER TABLE benchmark
+ADD COLUMN benchmark_group TEXT;
+ALTER TABLE benchmark
+ADD COLUMN tags TEXT;
+PRAGMA foreign_keys = off;
+CREATE TABLE up_threshold (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    branch_id INTEGER NOT NULL,
+    testbed_id INTEGER NOT NULL,
+    measure_id INTEGER NOT NULL,
+    benchmark_group TEXT,
+    model_id INTEGER,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    FOREIGN KEY (branch_id) REFERENCES branch (id),
+    FOREIGN KEY (testbed_id) REFERENCES testbed (id),
+    FOREIGN KEY (measure_id) REFERENCES measure (id),
+    FOREIGN KEY (model_id) REFERENCES model (id),
+    UNIQUE(branch_id, testbed_id, measure_id, benchmark_group)
+);
+INSERT INTO up_threshold(
+        id,
+        uuid,
+        project_id,
+        branch_id,
+        testbed_id,
+        measure_id,
+        model_id,
+        created,
+        modified
+    )
+SELECT id,
+    uuid,
+    project_id,
+    branch_id,
+    testbed_id,
+    measure_id,
+    model_id,
+    created,
+    modified
+FROM threshold;
+DROP TABLE threshold;
+ALTER TABLE up_threshold
+    RENAME TO threshold;
+-- NULL values are distinct in a UNIQUE constraint
+CREATE UNIQUE INDEX threshold_no_group ON threshold(branch_id, testbed_id, measure_id)
+WHERE benchmark_group IS NULL;
+PRAGMA foreign_keys = on;
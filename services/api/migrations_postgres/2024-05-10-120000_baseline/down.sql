@@ -0,0 +1,26 @@
+DROP TABLE webhook_delivery;
+DROP TABLE webhook;
+DROP TABLE server;
+DROP TABLE alert;
+DROP VIEW metric_boundary;
+DROP TABLE boundary;
+DROP TABLE metric;
+DROP TABLE report_benchmark;
+DROP TABLE report;
+ALTER TABLE threshold DROP CONSTRAINT threshold_model_id_fkey;
+DROP TABLE model;
+DROP TABLE threshold;
+DROP TABLE measure;
+DROP TABLE benchmark;
+DROP TABLE testbed;
+ALTER TABLE branch DROP CONSTRAINT branch_start_point_id_fkey;
+DROP TABLE branch_version;
+DROP TABLE version;
+DROP TABLE branch;
+DROP TABLE project_role;
+DROP TABLE project;
+DROP TABLE plan;
+DROP TABLE organization_role;
+DROP TABLE organization;
+DROP TABLE token;
+DROP TABLE "user";
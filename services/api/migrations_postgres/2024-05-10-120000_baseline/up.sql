@@ -0,0 +1,320 @@
+-- The PostgreSQL schema starts from the same state as all of the SQLite migrations up to and including `2024-05-01-120000_webhook`.
+-- All future migrations must be added to both `migrations` and `migrations_postgres`.
+CREATE TABLE "user" (
+    id SERIAL PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    name TEXT NOT NULL,
+    slug TEXT NOT NULL UNIQUE,
+    email TEXT NOT NULL UNIQUE,
+    admin BOOLEAN NOT NULL,
+    locked BOOLEAN NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL
+);
+CREATE TABLE token (
+    id SERIAL PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    user_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    jwt TEXT NOT NULL,
+    creation BIGINT NOT NULL,
+    expiration BIGINT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES "user" (id)
+);
+CREATE TABLE organization (
+    id SERIAL PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    name TEXT NOT NULL,
+    slug TEXT NOT NULL UNIQUE,
+    license TEXT UNIQUE,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL
+);
+CREATE TABLE organization_role (
+    id SERIAL PRIMARY KEY,
+    user_id INTEGER NOT NULL,
+    organization_id INTEGER NOT NULL,
+    role TEXT NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES "user" (id),
+    FOREIGN KEY (organization_id) REFERENCES organization (id) ON DELETE CASCADE,
+    UNIQUE(user_id, organization_id)
+);
+CREATE TABLE plan (
+    id SERIAL PRIMARY KEY,
+    organization_id INTEGER NOT NULL UNIQUE,
+    metered_plan TEXT UNIQUE,
+    licensed_plan TEXT UNIQUE,
+    license TEXT UNIQUE,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    CONSTRAINT only_one_plan CHECK (
+        metered_plan IS NOT NULL
+        OR licensed_plan IS NOT NULL
+        AND NOT (
+            metered_plan IS NOT NULL
+            AND licensed_plan IS NOT NULL
+        )
+    ),
+    CONSTRAINT metered_plan_no_license CHECK (
+        NOT (
+            metered_plan IS NOT NULL
+            AND license IS NOT NULL
+        )
+    ),
+    CONSTRAINT licensed_plan_has_license CHECK (
+        NOT (
+            licensed_plan IS NOT NULL
+            AND license IS NULL
+        )
+    )
+);
+CREATE TABLE project (
+    id SERIAL PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    organization_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    slug TEXT NOT NULL UNIQUE,
+    url TEXT,
+    visibility INTEGER NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (organization_id) REFERENCES organization (id) ON DELETE CASCADE,
+    UNIQUE(organization_id, name)
+);
+CREATE TABLE project_role (
+    id SERIAL PRIMARY KEY,
+    user_id INTEGER NOT NULL,
+    project_id INTEGER NOT NULL,
+    role TEXT NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES "user" (id),
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    UNIQUE(user_id, project_id)
+);
+CREATE TABLE branch (
+    id SERIAL PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    slug TEXT NOT NULL,
+    start_point_id INTEGER,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    UNIQUE(project_id, name),
+    UNIQUE(project_id, slug)
+);
+CREATE TABLE version (
+    id SERIAL PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    number INTEGER NOT NULL,
+    hash TEXT,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE
+);
+CREATE TABLE branch_version (
+    id SERIAL PRIMARY KEY,
+    branch_id INTEGER NOT NULL,
+    version_id INTEGER NOT NULL,
+    FOREIGN KEY (branch_id) REFERENCES branch (id) ON DELETE CASCADE,
+    FOREIGN KEY (version_id) REFERENCES version (id) ON DELETE CASCADE,
+    UNIQUE(branch_id, version_id)
+);
+-- A branch and its start point branch version reference each other
+ALTER TABLE branch
+ADD FOREIGN KEY (start_point_id) REFERENCES branch_version (id) ON DELETE
+SET NULL;
+CREATE TABLE testbed (
+    id SERIAL PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    slug TEXT NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    UNIQUE(project_id, name),
+    UNIQUE(project_id, slug)
+);
+CREATE TABLE benchmark (
+    id SERIAL PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    slug TEXT NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    UNIQUE(project_id, name),
+    UNIQUE(project_id, slug)
+);
+CREATE TABLE measure (
+    id SERIAL PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    slug TEXT NOT NULL,
+    units TEXT NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    UNIQUE(project_id, name),
+    UNIQUE(project_id, slug)
+);
+CREATE TABLE threshold (
+    id SERIAL PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    branch_id INTEGER NOT NULL,
+    testbed_id INTEGER NOT NULL,
+    measure_id INTEGER NOT NULL,
+    model_id INTEGER,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    FOREIGN KEY (branch_id) REFERENCES branch (id),
+    FOREIGN KEY (testbed_id) REFERENCES testbed (id),
+    FOREIGN KEY (measure_id) REFERENCES measure (id),
+    UNIQUE(branch_id, testbed_id, measure_id)
+);
+CREATE TABLE model (
+    id SERIAL PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    threshold_id INTEGER NOT NULL,
+    test INTEGER NOT NULL,
+    min_sample_size BIGINT,
+    max_sample_size BIGINT,
+    "window" BIGINT,
+    lower_boundary DOUBLE PRECISION,
+    upper_boundary DOUBLE PRECISION,
+    created BIGINT NOT NULL,
+    replaced BIGINT,
+    FOREIGN KEY (threshold_id) REFERENCES threshold (id) ON DELETE CASCADE
+);
+-- A threshold and its current model reference each other
+ALTER TABLE threshold
+ADD FOREIGN KEY (model_id) REFERENCES model (id);
+CREATE TABLE report (
+    id SERIAL PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    user_id INTEGER NOT NULL,
+    project_id INTEGER NOT NULL,
+    -- Connect to the branch and version individually and not to their branch_version
+    -- This is necessary in order for cloned branches to work
+    -- Cloned branches will *not* have a report tied to their specific branch_version
+    -- So we don't want to have to query through the branch_version table
+    -- to filter on the branch and list all of the versions
+    branch_id INTEGER NOT NULL,
+    version_id INTEGER NOT NULL,
+    testbed_id INTEGER NOT NULL,
+    adapter INTEGER NOT NULL,
+    start_time BIGINT NOT NULL,
+    end_time BIGINT NOT NULL,
+    created BIGINT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES "user" (id),
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    FOREIGN KEY (branch_id) REFERENCES branch (id),
+    FOREIGN KEY (version_id) REFERENCES version (id),
+    FOREIGN KEY (testbed_id) REFERENCES testbed (id)
+);
+CREATE INDEX index_report_testbed_end_time ON report(testbed_id, end_time);
+CREATE TABLE report_benchmark (
+    id SERIAL PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    report_id INTEGER NOT NULL,
+    iteration INTEGER NOT NULL,
+    benchmark_id INTEGER NOT NULL,
+    FOREIGN KEY (report_id) REFERENCES report (id) ON DELETE CASCADE,
+    FOREIGN KEY (benchmark_id) REFERENCES benchmark (id),
+    UNIQUE(report_id, iteration, benchmark_id)
+);
+CREATE INDEX index_report_benchmark ON report_benchmark(report_id, benchmark_id);
+CREATE TABLE metric (
+    id SERIAL PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    report_benchmark_id INTEGER NOT NULL,
+    measure_id INTEGER NOT NULL,
+    value DOUBLE PRECISION NOT NULL,
+    lower_value DOUBLE PRECISION,
+    upper_value DOUBLE PRECISION,
+    FOREIGN KEY (report_benchmark_id) REFERENCES report_benchmark (id) ON DELETE CASCADE,
+    FOREIGN KEY (measure_id) REFERENCES measure (id),
+    UNIQUE(report_benchmark_id, measure_id)
+);
+CREATE TABLE boundary (
+    id SERIAL PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    threshold_id INTEGER NOT NULL,
+    model_id INTEGER NOT NULL,
+    metric_id INTEGER NOT NULL UNIQUE,
+    baseline DOUBLE PRECISION,
+    lower_limit DOUBLE PRECISION,
+    upper_limit DOUBLE PRECISION,
+    effect_size DOUBLE PRECISION,
+    FOREIGN KEY (threshold_id) REFERENCES threshold (id),
+    FOREIGN KEY (model_id) REFERENCES model (id),
+    FOREIGN KEY (metric_id) REFERENCES metric (id) ON DELETE CASCADE
+);
+CREATE VIEW metric_boundary AS
+SELECT metric.id AS metric_id,
+    metric.uuid AS metric_uuid,
+    metric.report_benchmark_id,
+    metric.measure_id,
+    metric.value,
+    metric.lower_value,
+    metric.upper_value,
+    boundary.id AS boundary_id,
+    boundary.uuid AS boundary_uuid,
+    boundary.threshold_id,
+    boundary.model_id,
+    boundary.baseline,
+    boundary.lower_limit,
+    boundary.upper_limit,
+    boundary.effect_size
+FROM metric
+    LEFT OUTER JOIN boundary ON (boundary.metric_id = metric.id);
+CREATE TABLE alert (
+    id SERIAL PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    boundary_id INTEGER NOT NULL,
+    boundary_limit BOOLEAN NOT NULL,
+    status INTEGER NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (boundary_id) REFERENCES boundary (id) ON DELETE CASCADE
+);
+CREATE INDEX index_alert_boundary ON alert(boundary_id);
+CREATE TABLE server (
+    id INTEGER PRIMARY KEY DEFAULT 1,
+    uuid TEXT NOT NULL UNIQUE,
+    created BIGINT NOT NULL,
+    CONSTRAINT only_one_server CHECK (id = 1)
+);
+CREATE TABLE webhook (
+    id SERIAL PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    url TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    alert_created BOOLEAN NOT NULL,
+    alert_dismissed BOOLEAN NOT NULL,
+    report_created BOOLEAN NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE
+);
+CREATE TABLE webhook_delivery (
+    id SERIAL PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    webhook_id INTEGER NOT NULL,
+    event INTEGER NOT NULL,
+    attempts INTEGER NOT NULL,
+    status_code INTEGER,
+    error TEXT,
+    delivered BIGINT,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (webhook_id) REFERENCES webhook (id) ON DELETE CASCADE
+);
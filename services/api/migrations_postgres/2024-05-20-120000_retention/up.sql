@@ -0,0 +1,11 @@
+CREATE TABLE retention (
+    id SERIAL PRIMARY KEY,
+    project_id INTEGER NOT NULL UNIQUE,
+    raw_window BIGINT NOT NULL,
+    downsample BOOLEAN NOT NULL,
+    protected_branch_id INTEGER,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    FOREIGN KEY (protected_branch_id) REFERENCES branch (id) ON DELETE SET NULL
+);
@@ -0,0 +1,3 @@
+DROP TABLE silence;
+ALTER TABLE alert DROP COLUMN note;
+ALTER TABLE alert DROP COLUMN assignee_id;
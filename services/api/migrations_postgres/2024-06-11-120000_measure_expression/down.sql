@@ -0,0 +1 @@
+ALTER TABLE measure DROP COLUMN expression;
@@ -0,0 +1,2 @@
+ALTER TABLE measure
+ADD COLUMN expression TEXT;
@@ -0,0 +1,32 @@
+-- Group thresholds can not be represented without a benchmark group
+DELETE FROM alert
+WHERE boundary_id IN (
+        SELECT boundary.id
+        FROM boundary
+            INNER JOIN threshold ON boundary.threshold_id = threshold.id
+        WHERE threshold.benchmark_group IS NOT NULL
+    );
+DELETE FROM boundary
+WHERE threshold_id IN (
+        SELECT id
+        FROM threshold
+        WHERE benchmark_group IS NOT NULL
+    );
+UPDATE threshold
+SET model_id = NULL
+WHERE benchmark_group IS NOT NULL;
+DELETE FROM model
+WHERE threshold_id IN (
+        SELECT id
+        FROM threshold
+        WHERE benchmark_group IS NOT NULL
+    );
+DELETE FROM threshold
+WHERE benchmark_group IS NOT NULL;
+DROP INDEX threshold_no_group;
+ALTER TABLE threshold DROP CONSTRAINT threshold_branch_id_testbed_id_measure_id_benchmark_group_key;
+ALTER TABLE threshold DROP COLUMN benchmark_group;
+ALTER TABLE threshold
+ADD CONSTRAINT threshold_branch_id_testbed_id_measure_id_key UNIQUE(branch_id, testbed_id, measure_id);
+ALTER TABLE benchmark DROP COLUMN tags;
+ALTER TABLE benchmark DROP COLUMN benchmark_group;
@@ -0,0 +1,12 @@
+ALTER TABLE benchmark
+ADD COLUMN benchmark_group TEXT;
+ALTER TABLE benchmark
+ADD COLUMN tags TEXT;
+ALTER TABLE threshold
+ADD COLUMN benchmark_group TEXT;
+ALTER TABLE threshold DROP CONSTRAINT threshold_branch_id_testbed_id_measure_id_key;
+ALTER TABLE threshold
+ADD CONSTRAINT threshold_branch_id_testbed_id_measure_id_benchmark_group_key UNIQUE(branch_id, testbed_id, measure_id, benchmark_group);
+-- NULL values are distinct in a UNIQUE constraint
+CREATE UNIQUE INDEX threshold_no_group ON threshold(branch_id, testbed_id, measure_id)
+WHERE benchmark_group IS NULL;
@@ -1,17 +1,17 @@
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
 
 #[cfg(feature = "plus")]
 use bencher_json::system::config::JsonPlus;
 use bencher_json::{
     system::config::{
-        IfExists, JsonConsole, JsonDatabase, JsonLogging, JsonSecurity, JsonServer, JsonSmtp,
-        JsonTls, LogLevel, ServerLog,
+        IfExists, JsonConsole, JsonDatabase, JsonLogging, JsonPostgres, JsonSecurity, JsonServer,
+        JsonSmtp, JsonTls, LogLevel, ServerLog,
     },
     JsonConfig,
 };
 use bencher_rbac::init_rbac;
 use bencher_token::TokenKey;
-use diesel::{connection::SimpleConnection, Connection};
+use diesel::{connection::SimpleConnection, Connection, PgConnection, SqliteConnection};
 use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
 use dropshot::{
     ApiDescription, ConfigDropshot, ConfigLogging, ConfigLoggingIfExists, ConfigLoggingLevel,
@@ -21,7 +21,7 @@ use slog::{debug, error, info, Logger};
 use tokio::sync::mpsc::Sender;
 
 use crate::{
-    context::{ApiContext, Database, DbConnection, Email, Messenger},
+    context::{ApiContext, Database, DbConnection, DbPool, Email, Messenger},
     endpoints::Api,
 };
 
@@ -31,6 +31,11 @@ use super::{Config, DEFAULT_SMTP_PORT};
 
 const DATABASE_URL: &str = "DATABASE_URL";
 const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
+const POSTGRES_MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations_postgres");
+const POSTGRES: &str = "PostgreSQL";
+const DEFAULT_POSTGRES_POOL_SIZE: u32 = 10;
+// The key for the PostgreSQL advisory lock held while running migrations (`bencher` in ASCII)
+const POSTGRES_MIGRATIONS_LOCK: i64 = 0x0062_656e_6368_6572;
 
 pub struct ConfigTx {
     pub config: Config,
@@ -45,12 +50,16 @@ pub enum ConfigTxError {
     Migrations(Box<dyn std::error::Error + Send + Sync>),
     #[error("Failed to run database pragma: {0}")]
     Pragma(diesel::result::Error),
+    #[error("Failed to lock database for migrations: {0}")]
+    MigrationsLock(diesel::result::Error),
     #[error("Failed to parse role based access control (RBAC) rules: {0}")]
     Polar(oso::OsoError),
     #[error("Invalid endpoint URL: {0}")]
     Endpoint(bencher_json::ValidError),
     #[error("Failed to connect to database ({0}): {1}")]
     DatabaseConnection(String, diesel::ConnectionError),
+    #[error("Failed to create database pool: {0}")]
+    DatabasePool(crate::context::DbPoolError),
     #[error("Failed to parse data store: {0}")]
     DataStore(crate::context::DataStoreError),
     #[error("Failed to register endpoint: {0}")]
@@ -113,11 +122,16 @@ impl ConfigTx {
         });
         let config_dropshot = into_config_dropshot(server);
 
+        crate::model::project::retention::QueryRetention::spawn_prune(
+            log.clone(),
+            context.database.pool.clone(),
+        );
+
         #[cfg(feature = "plus")]
         {
-            let conn = context.database.connection.clone();
+            let pool = context.database.pool.clone();
             let query_server =
-                crate::model::server::QueryServer::get_or_create(&mut *conn.lock().await)
+                crate::model::server::QueryServer::get_or_create(&mut *pool.get().await)
                     .map_err(ConfigTxError::ServerId)?;
             info!(log, "Bencher API Server ID: {}", query_server.uuid);
 
@@ -129,7 +143,7 @@ impl ConfigTx {
             } else {
                 (Some(context.licensor.clone()), None)
             };
-            query_server.spawn_stats(log.clone(), conn, context.stats, licensor, messenger);
+            query_server.spawn_stats(log.clone(), pool, context.stats, licensor, messenger);
         }
 
         let mut api = ApiDescription::new();
@@ -160,15 +174,11 @@ fn into_context(
     #[cfg(feature = "plus")] plus: Option<JsonPlus>,
 ) -> Result<ApiContext, ConfigTxError> {
     let console_url: url::Url = console.url.try_into().map_err(ConfigTxError::Endpoint)?;
-    let database_path = json_database.file.to_string_lossy();
-    diesel_database_url(log, &database_path);
-
-    info!(&log, "Connecting to database: {database_path}");
-    let mut database_connection = DbConnection::establish(&database_path)
-        .map_err(|e| ConfigTxError::DatabaseConnection(database_path.to_string(), e))?;
-
-    info!(&log, "Running database migrations");
-    run_migrations(&mut database_connection)?;
+    let pool = if let Some(postgres) = json_database.postgres {
+        into_postgres_pool(log, postgres)?
+    } else {
+        into_sqlite_pool(log, &json_database.file)?
+    };
     let data_store = if let Some(data_store) = json_database.data_store {
         Some(data_store.try_into().map_err(ConfigTxError::DataStore)?)
     } else {
@@ -176,6 +186,7 @@ fn into_context(
     };
 
     info!(&log, "Loading secret key");
+    let allow_private_webhooks = security.allow_private_webhooks.unwrap_or_default();
     let token_key = TokenKey::new(
         security.issuer.unwrap_or_else(|| console_url.to_string()),
         &security.secret_key,
@@ -202,10 +213,11 @@ fn into_context(
         messenger: into_messenger(smtp),
         database: Database {
             path: json_database.file,
-            connection: Arc::new(tokio::sync::Mutex::new(database_connection)),
+            pool: Arc::new(pool),
             data_store,
         },
         restart_tx,
+        allow_private_webhooks,
         #[cfg(feature = "plus")]
         github,
         #[cfg(feature = "plus")]
@@ -221,6 +233,46 @@ fn into_context(
     })
 }
 
+fn into_sqlite_pool(log: &Logger, database_file: &Path) -> Result<DbPool, ConfigTxError> {
+    let database_path = database_file.to_string_lossy();
+    diesel_database_url(log, &database_path);
+
+    info!(&log, "Connecting to database: {database_path}");
+    let mut database_connection = SqliteConnection::establish(&database_path)
+        .map_err(|e| ConfigTxError::DatabaseConnection(database_path.to_string(), e))?;
+
+    info!(&log, "Running database migrations");
+    run_migrations(&mut database_connection)?;
+
+    DbPool::new(vec![DbConnection::Sqlite(database_connection)])
+        .map_err(ConfigTxError::DatabasePool)
+}
+
+fn into_postgres_pool(log: &Logger, postgres: JsonPostgres) -> Result<DbPool, ConfigTxError> {
+    let JsonPostgres { url, pool_size } = postgres;
+    let pool_size = pool_size.unwrap_or(DEFAULT_POSTGRES_POOL_SIZE).max(1);
+    // The connection URL contains the database password, so it is never logged.
+    let establish = || {
+        PgConnection::establish(url.as_ref())
+            .map_err(|e| ConfigTxError::DatabaseConnection(POSTGRES.to_owned(), e))
+    };
+
+    info!(
+        &log,
+        "Connecting to {POSTGRES} database with a pool size of {pool_size}"
+    );
+    let mut database_connection = establish()?;
+
+    info!(&log, "Running database migrations");
+    run_postgres_migrations(&mut database_connection)?;
+
+    let mut connections = vec![DbConnection::Postgres(database_connection)];
+    for _ in 1..pool_size {
+        connections.push(DbConnection::Postgres(establish()?));
+    }
+    DbPool::new(connections).map_err(ConfigTxError::DatabasePool)
+}
+
 // Set the diesel `DATABASE_URL` env var to the database path
 fn diesel_database_url(log: &Logger, database_path: &str) {
     if let Ok(database_url) = std::env::var(DATABASE_URL) {
@@ -238,7 +290,7 @@ fn diesel_database_url(log: &Logger, database_path: &str) {
     std::env::set_var(DATABASE_URL, database_path);
 }
 
-fn run_migrations(database: &mut DbConnection) -> Result<(), ConfigTxError> {
+fn run_migrations(database: &mut SqliteConnection) -> Result<(), ConfigTxError> {
     // It is not possible to enable or disable foreign key constraints in the middle of a multi-statement transaction
     // (when SQLite is not in autocommit mode).
     // Attempting to do so does not return an error; it simply has no effect.
@@ -258,6 +310,27 @@ fn run_migrations(database: &mut DbConnection) -> Result<(), ConfigTxError> {
     Ok(())
 }
 
+fn run_postgres_migrations(database: &mut PgConnection) -> Result<(), ConfigTxError> {
+    // Multiple API servers may share the same database,
+    // so only one of them is allowed to run the migrations at a time.
+    // https://www.postgresql.org/docs/current/explicit-locking.html#ADVISORY-LOCKS
+    database
+        .batch_execute(&format!(
+            "SELECT pg_advisory_lock({POSTGRES_MIGRATIONS_LOCK})"
+        ))
+        .map_err(ConfigTxError::MigrationsLock)?;
+    let migrations = database
+        .run_pending_migrations(POSTGRES_MIGRATIONS)
+        .map(|_| ())
+        .map_err(ConfigTxError::Migrations);
+    database
+        .batch_execute(&format!(
+            "SELECT pg_advisory_unlock({POSTGRES_MIGRATIONS_LOCK})"
+        ))
+        .map_err(ConfigTxError::MigrationsLock)?;
+    migrations
+}
+
 fn into_messenger(smtp: Option<JsonSmtp>) -> Messenger {
     smtp.map_or(
         Messenger::StdOut,
@@ -200,6 +200,7 @@ impl Default for Config {
             security: JsonSecurity {
                 issuer: Some(DEFAULT_CONSOLE_URL.to_string()),
                 secret_key: DEFAULT_SECRET_KEY.clone(),
+                allow_private_webhooks: None,
             },
             server: JsonServer {
                 bind_address: *DEFAULT_BIND_ADDRESS,
@@ -208,6 +209,7 @@ impl Default for Config {
             },
             database: JsonDatabase {
                 file: DEFAULT_DB_PATH.into(),
+                postgres: None,
                 data_store: None,
             },
             smtp: None,
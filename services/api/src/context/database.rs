@@ -1,18 +1,169 @@
 use std::{
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
 };
 
 use bencher_json::{system::config::DataStore as DataStoreConfig, Secret};
+use diesel::connection::SimpleConnection;
+use tokio::sync::{Mutex, MutexGuard};
 
-pub type DbConnection = diesel::SqliteConnection;
+use crate::model::project::ProjectId;
+
+// The first key for project advisory locks.
+// Two key advisory locks do not overlap with the single key advisory lock used for migrations.
+const PROJECT_LOCK: i32 = 0x6265_6e63;
+
+#[derive(diesel::MultiConnection)]
+pub enum DbConnection {
+    Sqlite(diesel::SqliteConnection),
+    Postgres(diesel::PgConnection),
+}
+
+pub type DbBackend = MultiBackend;
+
+// The `MultiConnection` derive does not list the columns for `Insertable` values.
+// SQLite leaves out columns without a value, while PostgreSQL inserts them as `DEFAULT`.
+impl<Col, Expr> diesel::insertable::InsertValues<Col::Table, MultiBackend>
+    for diesel::insertable::DefaultableColumnInsertValue<
+        diesel::insertable::ColumnInsertValue<Col, Expr>,
+    >
+where
+    Col: diesel::Column,
+    Expr: diesel::Expression<SqlType = Col::SqlType>
+        + diesel::AppearsOnTable<diesel::internal::derives::multiconnection::NoFromClause>,
+    Self: diesel::query_builder::QueryFragment<MultiBackend>,
+{
+    fn column_names(
+        &self,
+        mut out: diesel::query_builder::AstPass<'_, '_, MultiBackend>,
+    ) -> diesel::QueryResult<()> {
+        if matches!(out.backend(), MultiBackend::Postgres(_)) || matches!(self, Self::Expression(_))
+        {
+            out.push_identifier(Col::NAME)?;
+        }
+        Ok(())
+    }
+}
+
+// Types that are converted before they are bound implement `ToSql` for each backend individually.
+// These implementations forward them on to the backend of the current connection.
+macro_rules! multi_backend_to_sql {
+    ($sql_type:ident => $($name:ty),*) => {
+        $(
+            impl diesel::serialize::ToSql<diesel::sql_types::$sql_type, MultiBackend> for $name {
+                fn to_sql<'b>(
+                    &'b self,
+                    out: &mut diesel::serialize::Output<'b, '_, MultiBackend>,
+                ) -> diesel::serialize::Result {
+                    out.set_value((diesel::sql_types::$sql_type, self));
+                    Ok(diesel::serialize::IsNull::No)
+                }
+            }
+        )*
+    };
+}
+
+multi_backend_to_sql!(BigInt => bencher_json::DateTime, bencher_json::SampleSize, bencher_json::Window);
+multi_backend_to_sql!(Integer => bencher_json::project::report::Iteration, bencher_json::project::branch::VersionNumber);
+multi_backend_to_sql!(Text =>
+    crate::util::search::Search,
+    crate::model::project::benchmark::BenchmarkTags,
+    crate::model::project::benchmark::TagSearch,
+    bencher_json::AlertUuid,
+    bencher_json::BenchmarkUuid,
+    bencher_json::BoundaryUuid,
+    bencher_json::BranchUuid,
+    bencher_json::MeasureUuid,
+    bencher_json::MetricUuid,
+    bencher_json::ModelUuid,
+    bencher_json::OrganizationUuid,
+    bencher_json::ProjectUuid,
+    bencher_json::ReportBenchmarkUuid,
+    bencher_json::ReportUuid,
+    bencher_json::SilenceUuid,
+    bencher_json::TestbedUuid,
+    bencher_json::ThresholdUuid,
+    bencher_json::TokenUuid,
+    bencher_json::UserUuid,
+    bencher_json::VersionUuid,
+    bencher_json::WebhookDeliveryUuid,
+    bencher_json::WebhookUuid
+);
+#[cfg(feature = "plus")]
+multi_backend_to_sql!(Text => bencher_json::ServerUuid);
 
 pub struct Database {
     pub path: PathBuf,
-    pub connection: Arc<tokio::sync::Mutex<DbConnection>>,
+    pub pool: Arc<DbPool>,
     pub data_store: Option<DataStore>,
 }
 
+// SQLite only allows a single writer, so it always has a single connection in its pool.
+// PostgreSQL has a connection for each concurrent request,
+// so each use of `conn_lock!` may get a different connection.
+// Any read-then-write flow that must not interleave with another request,
+// such as incrementing a code version number,
+// has to run in a single transaction on a single connection
+// and take a lock with `DbConnection::lock_project` first.
+pub struct DbPool {
+    connections: Vec<Mutex<DbConnection>>,
+    next: AtomicUsize,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DbPoolError {
+    #[error("Database pool must have at least one connection")]
+    Empty,
+}
+
+impl DbPool {
+    pub fn new(connections: Vec<DbConnection>) -> Result<Self, DbPoolError> {
+        if connections.is_empty() {
+            return Err(DbPoolError::Empty);
+        }
+        Ok(Self {
+            connections: connections.into_iter().map(Mutex::new).collect(),
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    pub async fn get(&self) -> MutexGuard<'_, DbConnection> {
+        // Use the first idle connection, if there is one
+        for connection in &self.connections {
+            if let Ok(connection) = connection.try_lock() {
+                return connection;
+            }
+        }
+        // Otherwise, wait on the connections in a round-robin fashion
+        let index = self
+            .next
+            .fetch_add(1, Ordering::Relaxed)
+            .checked_rem(self.connections.len())
+            .unwrap_or_default();
+        // The pool always has at least one connection
+        #[allow(clippy::indexing_slicing)]
+        self.connections[index].lock().await
+    }
+}
+
+impl DbConnection {
+    // Lock a project until the end of the current transaction.
+    // SQLite only has a single connection, so its transactions are already serialized.
+    // PostgreSQL uses a transaction level advisory lock on the project instead.
+    // https://www.postgresql.org/docs/current/explicit-locking.html#ADVISORY-LOCKS
+    pub fn lock_project(&mut self, project_id: ProjectId) -> diesel::QueryResult<()> {
+        match self {
+            Self::Sqlite(_) => Ok(()),
+            Self::Postgres(conn) => conn.batch_execute(&format!(
+                "SELECT pg_advisory_xact_lock({PROJECT_LOCK}, {project_id})"
+            )),
+        }
+    }
+}
+
 pub enum DataStore {
     AwsS3(AwsS3),
 }
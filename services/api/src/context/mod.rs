@@ -19,7 +19,7 @@ mod indexer;
 mod messenger;
 mod rbac;
 
-pub use database::{DataStoreError, Database, DbConnection};
+pub use database::{DataStoreError, Database, DbBackend, DbConnection, DbPool, DbPoolError};
 #[cfg(feature = "plus")]
 pub use indexer::Indexer;
 #[cfg(feature = "plus")]
@@ -34,6 +34,7 @@ pub struct ApiContext {
     pub messenger: Messenger,
     pub database: Database,
     pub restart_tx: Sender<()>,
+    pub allow_private_webhooks: bool,
     #[cfg(feature = "plus")]
     pub github: Option<GitHub>,
     #[cfg(feature = "plus")]
@@ -63,7 +64,7 @@ macro_rules! conn_lock {
 
 impl ApiContext {
     pub async fn conn(&self) -> tokio::sync::MutexGuard<DbConnection> {
-        self.database.connection.lock().await
+        self.database.pool.get().await
     }
 
     #[cfg(feature = "plus")]
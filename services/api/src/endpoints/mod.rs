@@ -82,6 +82,12 @@ impl Api {
         api.register(organization::projects::org_projects_get)?;
         api.register(organization::projects::org_project_post)?;
 
+        // Organization Project Restore
+        if http_options {
+            api.register(organization::projects::org_project_restore_options)?;
+        }
+        api.register(organization::projects::org_project_restore_post)?;
+
         #[cfg(feature = "plus")]
         {
             // Organization Plan
@@ -119,6 +125,12 @@ impl Api {
         }
         api.register(project::allowed::proj_allowed_get)?;
 
+        // Project Export
+        if http_options {
+            api.register(project::archive::proj_export_options)?;
+        }
+        api.register(project::archive::proj_export_get)?;
+
         // Reports
         if http_options {
             api.register(project::reports::proj_reports_options)?;
@@ -129,6 +141,12 @@ impl Api {
         api.register(project::reports::proj_report_get)?;
         api.register(project::reports::proj_report_delete)?;
 
+        // Import
+        if http_options {
+            api.register(project::reports::proj_import_options)?;
+        }
+        api.register(project::reports::proj_import_post)?;
+
         // Perf
         if http_options {
             api.register(project::perf::proj_perf_options)?;
@@ -141,16 +159,36 @@ impl Api {
         }
         api.register(project::perf::img::proj_perf_img_get)?;
 
+        // Perf Change Points
+        if http_options {
+            api.register(project::perf::change_points::proj_perf_change_points_options)?;
+        }
+        api.register(project::perf::change_points::proj_perf_change_points_get)?;
+
+        // Compare
+        if http_options {
+            api.register(project::compare::proj_compare_options)?;
+        }
+        api.register(project::compare::proj_compare_get)?;
+
+        // Metrics
+        if http_options {
+            api.register(project::metrics::proj_metrics_options)?;
+        }
+        api.register(project::metrics::proj_metrics_get)?;
+
         // Branches
         if http_options {
             api.register(project::branches::proj_branches_options)?;
             api.register(project::branches::proj_branch_options)?;
+            api.register(project::branches::proj_branches_archive_options)?;
         }
         api.register(project::branches::proj_branches_get)?;
         api.register(project::branches::proj_branch_post)?;
         api.register(project::branches::proj_branch_get)?;
         api.register(project::branches::proj_branch_patch)?;
         api.register(project::branches::proj_branch_delete)?;
+        api.register(project::branches::proj_branches_archive_post)?;
 
         // Testbeds
         if http_options {
@@ -207,6 +245,40 @@ impl Api {
         api.register(project::thresholds::alerts::proj_alert_patch)?;
         api.register(project::thresholds::alerts::proj_alert_stats_get)?;
 
+        // Alert Silences
+        if http_options {
+            api.register(project::thresholds::silences::proj_silences_options)?;
+            api.register(project::thresholds::silences::proj_silence_options)?;
+        }
+        api.register(project::thresholds::silences::proj_silences_get)?;
+        api.register(project::thresholds::silences::proj_silence_post)?;
+        api.register(project::thresholds::silences::proj_silence_get)?;
+        api.register(project::thresholds::silences::proj_silence_delete)?;
+
+        // Webhooks
+        if http_options {
+            api.register(project::webhooks::proj_webhooks_options)?;
+            api.register(project::webhooks::proj_webhook_options)?;
+            api.register(project::webhooks::proj_webhook_deliveries_options)?;
+        }
+        api.register(project::webhooks::proj_webhooks_get)?;
+        api.register(project::webhooks::proj_webhook_post)?;
+        api.register(project::webhooks::proj_webhook_get)?;
+        api.register(project::webhooks::proj_webhook_patch)?;
+        api.register(project::webhooks::proj_webhook_delete)?;
+        api.register(project::webhooks::proj_webhook_deliveries_get)?;
+
+        // Retention
+        if http_options {
+            api.register(project::retention::proj_retention_options)?;
+            api.register(project::retention::proj_retention_prune_options)?;
+        }
+        api.register(project::retention::proj_retention_get)?;
+        api.register(project::retention::proj_retention_put)?;
+        api.register(project::retention::proj_retention_delete)?;
+        api.register(project::retention::proj_retention_prune_get)?;
+        api.register(project::retention::proj_retention_prune_post)?;
+
         // Users
         if http_options {
             api.register(user::users::users_options)?;
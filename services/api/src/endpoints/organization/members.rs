@@ -31,7 +31,7 @@ use crate::{
         user::UserId,
     },
     schema,
-    util::search::Search,
+    util::search::{lower, Search},
 };
 
 // TODO Custom max TTL
@@ -138,10 +138,10 @@ async fn get_ls_inner(
     }
     if let Some(search) = query_params.search.as_ref() {
         query = query.filter(
-            schema::user::name
+            lower(schema::user::name)
                 .like(search)
-                .or(schema::user::slug.like(search))
-                .or(schema::user::uuid.like(search)),
+                .or(lower(schema::user::slug).like(search))
+                .or(lower(schema::user::uuid).like(search)),
         );
     }
 
@@ -30,7 +30,7 @@ use crate::{
         },
     },
     schema,
-    util::search::Search,
+    util::search::{lower, Search},
 };
 
 pub type OrganizationsPagination = JsonPagination<OrganizationsSort>;
@@ -110,10 +110,10 @@ async fn get_ls_inner(
     }
     if let Some(search) = query_params.search.as_ref() {
         query = query.filter(
-            schema::organization::name
+            lower(schema::organization::name)
                 .like(search)
-                .or(schema::organization::slug.like(search))
-                .or(schema::organization::uuid.like(search)),
+                .or(lower(schema::organization::slug).like(search))
+                .or(lower(schema::organization::uuid).like(search)),
         );
     }
 
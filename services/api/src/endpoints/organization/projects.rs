@@ -1,6 +1,6 @@
 use bencher_json::{
     project::ProjectRole, DateTime, JsonDirection, JsonNewProject, JsonPagination, JsonProject,
-    JsonProjects, ResourceId, ResourceName,
+    JsonProjectArchive, JsonProjects, ResourceId, ResourceName,
 };
 use bencher_rbac::organization::Permission;
 use diesel::{
@@ -23,6 +23,7 @@ use crate::{
     model::{
         organization::QueryOrganization,
         project::{
+            archive::ProjectRestore,
             branch::{InsertBranch, QueryBranch},
             measure::{InsertMeasure, QueryMeasure},
             project_role::InsertProjectRole,
@@ -33,7 +34,7 @@ use crate::{
         user::auth::{AuthUser, BearerToken},
     },
     schema,
-    util::search::Search,
+    util::search::{lower, Search},
 };
 
 #[derive(Deserialize, JsonSchema)]
@@ -125,10 +126,10 @@ async fn get_ls_inner(
     }
     if let Some(search) = query_params.search.as_ref() {
         query = query.filter(
-            schema::project::name
+            lower(schema::project::name)
                 .like(search)
-                .or(schema::project::slug.like(search))
-                .or(schema::project::uuid.like(search)),
+                .or(lower(schema::project::slug).like(search))
+                .or(lower(schema::project::uuid).like(search)),
         );
     }
 
@@ -297,3 +298,109 @@ async fn post_inner(
 
     query_project.into_json(conn_lock!(context))
 }
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/organizations/{organization}/projects/restore",
+    tags = ["organizations", "projects"]
+}]
+pub async fn org_project_restore_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<OrgProjectsParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Post.into()]))
+}
+
+/// Restore a project for an organization
+///
+/// Restore a project for an organization from a project archive.
+/// The user must have `create` permissions for the organization.
+/// A project archive can be created by exporting a project from any Bencher server.
+/// If the project already exists on this server, then the restored project will have all new UUIDs.
+/// If the project slug is already in use, then a unique slug will be generated.
+/// All of the restored reports are attributed to the user restoring the project.
+/// Restoring a large project archive may require increasing the server's `request_body_max_bytes`.
+/// ➕ Bencher Plus: The project visibility must be `public` unless the organization has a valid Bencher Plus subscription.
+#[endpoint {
+    method = POST,
+    path =  "/v0/organizations/{organization}/projects/restore",
+    tags = ["organizations", "projects"]
+}]
+pub async fn org_project_restore_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<OrgProjectsParams>,
+    body: TypedBody<JsonProjectArchive>,
+) -> Result<ResponseCreated<JsonProject>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = restore_inner(
+        &rqctx.log,
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Post::auth_response_created(json))
+}
+
+async fn restore_inner(
+    log: &Logger,
+    context: &ApiContext,
+    path_params: OrgProjectsParams,
+    json_archive: JsonProjectArchive,
+    auth_user: &AuthUser,
+) -> Result<JsonProject, HttpError> {
+    let query_organization =
+        QueryOrganization::from_resource_id(conn_lock!(context), &path_params.organization)?;
+
+    let project_restore =
+        ProjectRestore::new(conn_lock!(context), &query_organization, json_archive)?;
+
+    // Check project visibility
+    #[cfg(not(feature = "plus"))]
+    QueryProject::is_visibility_public(Some(project_restore.visibility()))?;
+    #[cfg(feature = "plus")]
+    crate::model::organization::plan::PlanKind::new(
+        conn_lock!(context),
+        context.biller.as_ref(),
+        &context.licensor,
+        &query_organization,
+        project_restore.visibility(),
+    )
+    .await?;
+
+    // Check to see if user has permission to create a project within the organization
+    context
+        .rbac
+        .is_allowed_organization(
+            auth_user,
+            Permission::Create,
+            project_restore.insert_project(),
+        )
+        .map_err(forbidden_error)?;
+
+    let query_project = project_restore.restore(conn_lock!(context), auth_user.id())?;
+    slog::debug!(log, "Restored project: {query_project:?}");
+
+    let timestamp = DateTime::now();
+    // Connect the user to the project as a `Maintainer`
+    let insert_proj_role = InsertProjectRole {
+        user_id: auth_user.id(),
+        project_id: query_project.id,
+        role: ProjectRole::Maintainer,
+        created: timestamp,
+        modified: timestamp,
+    };
+    diesel::insert_into(schema::project_role::table)
+        .values(&insert_proj_role)
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(ProjectRole, insert_proj_role))?;
+    slog::debug!(log, "Added project role: {insert_proj_role:?}");
+
+    #[cfg(feature = "plus")]
+    context.update_index(log, &query_project).await;
+
+    query_project.into_json(conn_lock!(context))
+}
@@ -0,0 +1,76 @@
+use bencher_json::{JsonProjectArchive, ResourceId};
+use bencher_rbac::project::Permission;
+use dropshot::{endpoint, HttpError, Path, RequestContext};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    endpoints::{
+        endpoint::{CorsResponse, Get, ResponseOk},
+        Endpoint,
+    },
+    model::{
+        project::{archive, QueryProject},
+        user::auth::{AuthUser, BearerToken},
+    },
+};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjExportParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/export",
+    tags = ["projects"]
+}]
+pub async fn proj_export_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjExportParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// Export a project
+///
+/// Export all of the data for a project as a portable archive.
+/// The user must have `view` permissions for the project.
+/// The archive includes the project's branches, versions, testbeds, benchmarks, measures,
+/// thresholds with their model history, reports, metrics, boundaries, and alerts.
+/// The archive can be restored into any organization on any Bencher server.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/export",
+    tags = ["projects"]
+}]
+pub async fn proj_export_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjExportParams>,
+) -> Result<ResponseOk<JsonProjectArchive>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_inner(
+    context: &ApiContext,
+    path_params: ProjExportParams,
+    auth_user: &AuthUser,
+) -> Result<JsonProjectArchive, HttpError> {
+    conn_lock!(context, |conn| {
+        let query_project = QueryProject::is_allowed(
+            conn,
+            &context.rbac,
+            &path_params.project,
+            auth_user,
+            Permission::View,
+        )?;
+        archive::export(conn, &query_project)
+    })
+}
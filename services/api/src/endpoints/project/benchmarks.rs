@@ -1,6 +1,7 @@
 use bencher_json::{
     project::benchmark::{JsonNewBenchmark, JsonUpdateBenchmark},
-    BenchmarkName, JsonBenchmark, JsonBenchmarks, JsonDirection, JsonPagination, ResourceId,
+    BenchmarkGroup, BenchmarkName, JsonBenchmark, JsonBenchmarks, JsonDirection, JsonPagination,
+    ResourceId, Slug,
 };
 use bencher_rbac::project::Permission;
 use diesel::{
@@ -23,13 +24,13 @@ use crate::{
     error::{resource_conflict_err, resource_not_found_err},
     model::{
         project::{
-            benchmark::{InsertBenchmark, QueryBenchmark, UpdateBenchmark},
+            benchmark::{self, InsertBenchmark, QueryBenchmark, TagSearch, UpdateBenchmark},
             QueryProject,
         },
         user::auth::{AuthUser, BearerToken, PubBearerToken},
     },
     schema,
-    util::search::Search,
+    util::search::{lower, Search},
 };
 
 #[derive(Deserialize, JsonSchema)]
@@ -54,6 +55,10 @@ pub struct ProjBenchmarksQuery {
     pub name: Option<BenchmarkName>,
     /// Search by benchmark name, slug, or UUID.
     pub search: Option<Search>,
+    /// Filter by benchmark tag.
+    pub tag: Option<Slug>,
+    /// Filter by benchmark group, including all of its subgroups.
+    pub group: Option<BenchmarkGroup>,
 }
 
 #[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
@@ -121,12 +126,18 @@ async fn get_ls_inner(
     }
     if let Some(search) = query_params.search.as_ref() {
         query = query.filter(
-            schema::benchmark::name
+            lower(schema::benchmark::name)
                 .like(search)
-                .or(schema::benchmark::slug.like(search))
-                .or(schema::benchmark::uuid.like(search)),
+                .or(lower(schema::benchmark::slug).like(search))
+                .or(lower(schema::benchmark::uuid).like(search)),
         );
     }
+    if let Some(tag) = query_params.tag {
+        query = query.filter(schema::benchmark::tags.like(TagSearch::from(tag)));
+    }
+    if let Some(group) = query_params.group.as_ref() {
+        query = benchmark::filter_group(query, group);
+    }
 
     query = match pagination_params.order() {
         ProjBenchmarksSort::Name => match pagination_params.direction {
@@ -1,6 +1,7 @@
 use bencher_json::{
-    project::branch::JsonUpdateBranch, BranchName, JsonBranch, JsonBranches, JsonDirection,
-    JsonNewBranch, JsonPagination, ResourceId,
+    project::branch::{JsonArchiveBranches, JsonUpdateBranch},
+    BranchName, DateTime, JsonBranch, JsonBranches, JsonDirection, JsonNewBranch, JsonPagination,
+    ResourceId,
 };
 use bencher_rbac::project::Permission;
 use diesel::{
@@ -31,7 +32,7 @@ use crate::{
         user::auth::{AuthUser, BearerToken, PubBearerToken},
     },
     schema,
-    util::search::Search,
+    util::search::{lower, Search},
 };
 
 #[derive(Deserialize, JsonSchema)]
@@ -56,6 +57,10 @@ pub struct ProjBranchesQuery {
     pub name: Option<BranchName>,
     /// Search by branch name, slug, or UUID.
     pub search: Option<Search>,
+    /// If set to `true`, only return archived branches.
+    /// If set to `false`, only return branches that are not archived.
+    /// Otherwise, archived branches are only returned when filtering by exact `name`.
+    pub archived: Option<bool>,
 }
 
 #[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
@@ -123,12 +128,21 @@ async fn get_ls_inner(
     }
     if let Some(search) = query_params.search.as_ref() {
         query = query.filter(
-            schema::branch::name
+            lower(schema::branch::name)
                 .like(search)
-                .or(schema::branch::slug.like(search))
-                .or(schema::branch::uuid.like(search)),
+                .or(lower(schema::branch::slug).like(search))
+                .or(lower(schema::branch::uuid).like(search)),
         );
     }
+    // An exact name lookup also finds an archived branch,
+    // so that an archived branch is not recreated when it is looked up by name.
+    match (query_params.archived, query_params.name.is_some()) {
+        (Some(true), _) => query = query.filter(schema::branch::archived.is_not_null()),
+        (Some(false), _) | (None, false) => {
+            query = query.filter(schema::branch::archived.is_null());
+        },
+        (None, true) => {},
+    }
 
     query = match pagination_params.order() {
         ProjBranchesSort::Name => match pagination_params.direction {
@@ -430,3 +444,87 @@ async fn delete_inner(
 
     Ok(())
 }
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/archive/branches",
+    tags = ["projects", "branches"]
+}]
+pub async fn proj_branches_archive_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjBranchesParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Post.into()]))
+}
+
+/// Archive branches
+///
+/// Archive stale and/or named branches for a project.
+/// The user must have `edit` permissions for the project.
+/// Archived branches are hidden from branch listings and perf queries by default.
+/// The project's main branch and the retention policy protected branch are never archived.
+/// An archived branch is automatically unarchived when a new report is created for it,
+/// but not when historical reports are imported for it.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/archive/branches",
+    tags = ["projects", "branches"]
+}]
+pub async fn proj_branches_archive_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjBranchesParams>,
+    body: TypedBody<JsonArchiveBranches>,
+) -> Result<ResponseOk<JsonBranches>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = archive_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Post::auth_response_ok(json))
+}
+
+async fn archive_inner(
+    context: &ApiContext,
+    path_params: ProjBranchesParams,
+    json_archive: JsonArchiveBranches,
+    auth_user: &AuthUser,
+) -> Result<JsonBranches, HttpError> {
+    conn_lock!(context, |conn| {
+        let query_project = QueryProject::is_allowed(
+            conn,
+            &context.rbac,
+            &path_params.project,
+            auth_user,
+            Permission::Edit,
+        )?;
+
+        let branches = QueryBranch::archivable(conn, &query_project, &json_archive)?;
+        if !json_archive.dry_run.unwrap_or_default() {
+            let archived = DateTime::now();
+            for branch in &branches {
+                diesel::update(schema::branch::table.filter(schema::branch::id.eq(branch.id)))
+                    .set((
+                        schema::branch::archived.eq(Some(archived)),
+                        schema::branch::modified.eq(archived),
+                    ))
+                    .execute(conn)
+                    .map_err(resource_conflict_err!(Branch, branch))?;
+            }
+        }
+
+        branches
+            .into_iter()
+            .map(|branch| {
+                let branch = QueryBranch::get(conn, branch.id)
+                    .map_err(resource_not_found_err!(Branch, branch))?;
+                branch.into_json_for_project(conn, &query_project)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Into::into)
+    })
+}
@@ -0,0 +1,283 @@
+use std::collections::BTreeMap;
+
+use bencher_boundary::Comparison;
+use bencher_json::{
+    project::compare::{
+        JsonCompareMetric, JsonCompareQuery, JsonCompareQueryParams, JsonCompareReport,
+        JsonCompareResult,
+    },
+    JsonCompare, NameId, ReportUuid, ResourceId,
+};
+use diesel::{BelongingToDsl, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext};
+use ordered_float::OrderedFloat;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    conn_lock,
+    context::{ApiContext, DbConnection},
+    endpoints::{
+        endpoint::{CorsResponse, Get, ResponseOk},
+        Endpoint,
+    },
+    error::{bad_request_error, resource_not_found_err},
+    model::{
+        project::{
+            benchmark::QueryBenchmark, branch::QueryBranch, measure::QueryMeasure,
+            report::QueryReport, testbed::QueryTestbed, QueryProject,
+        },
+        user::auth::{AuthUser, PubBearerToken},
+    },
+    schema,
+    util::name_id::{filter_branch_name_id, filter_testbed_name_id},
+};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjCompareParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/compare",
+    tags = ["projects", "perf"]
+}]
+pub async fn proj_compare_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjCompareParams>,
+    _query_params: Query<JsonCompareQueryParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// Compare two reports
+///
+/// Compare the metrics of a head report against those of a base report.
+/// Each side is either a report UUID or the latest report for a branch on a testbed.
+/// For each benchmark and measure, the mean of the iterations for each report is compared.
+/// The difference is significant if the two-tailed Welch's t-test p-value is below 0.05.
+/// This requires at least two iterations in both reports.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/compare",
+    tags = ["projects", "perf"]
+}]
+pub async fn proj_compare_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubBearerToken,
+    path_params: Path<ProjCompareParams>,
+    query_params: Query<JsonCompareQueryParams>,
+) -> Result<ResponseOk<JsonCompare>, HttpError> {
+    // Second round of marshaling
+    let json_compare_query = query_params
+        .into_inner()
+        .try_into()
+        .map_err(bad_request_error)?;
+
+    let auth_user = AuthUser::from_pub_token(rqctx.context(), bearer_token).await?;
+    let json = get_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        json_compare_query,
+        auth_user.as_ref(),
+    )
+    .await?;
+    Ok(Get::response_ok(json, auth_user.is_some()))
+}
+
+async fn get_inner(
+    context: &ApiContext,
+    path_params: ProjCompareParams,
+    json_compare_query: JsonCompareQuery,
+    auth_user: Option<&AuthUser>,
+) -> Result<JsonCompare, HttpError> {
+    let project = QueryProject::is_allowed_public(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+    )?;
+
+    let JsonCompareQuery {
+        base_report,
+        head_report,
+        base_branch,
+        head_branch,
+        testbed,
+    } = json_compare_query;
+
+    let conn = conn_lock!(context);
+    let base_report = compare_report(
+        conn,
+        &project,
+        "base",
+        base_report,
+        base_branch.as_ref(),
+        testbed.as_ref(),
+    )?;
+    let head_report = compare_report(
+        conn,
+        &project,
+        "head",
+        head_report,
+        head_branch.as_ref(),
+        testbed.as_ref(),
+    )?;
+
+    let mut metrics = BTreeMap::new();
+    for (benchmark, measure, value) in report_metrics(conn, &project, &base_report)? {
+        metrics
+            .entry((benchmark.name.to_string(), measure.name.to_string()))
+            .or_insert_with(|| CompareMetrics::new(benchmark, measure))
+            .base
+            .push(value);
+    }
+    for (benchmark, measure, value) in report_metrics(conn, &project, &head_report)? {
+        metrics
+            .entry((benchmark.name.to_string(), measure.name.to_string()))
+            .or_insert_with(|| CompareMetrics::new(benchmark, measure))
+            .head
+            .push(value);
+    }
+    let results = metrics
+        .into_values()
+        .map(|compare_metrics| compare_metrics.into_json(&project))
+        .collect();
+
+    Ok(JsonCompare {
+        base: compare_report_json(conn, &project, &base_report)?,
+        head: compare_report_json(conn, &project, &head_report)?,
+        project: project.uuid,
+        results,
+    })
+}
+
+/// Find the report for one side of the comparison.
+/// If a report UUID is not given, then the latest report for the branch on the testbed is used.
+fn compare_report(
+    conn: &mut DbConnection,
+    project: &QueryProject,
+    side: &str,
+    report: Option<ReportUuid>,
+    branch: Option<&NameId>,
+    testbed: Option<&NameId>,
+) -> Result<QueryReport, HttpError> {
+    if let Some(report) = report {
+        return QueryReport::belonging_to(project)
+            .filter(schema::report::uuid.eq(report.to_string()))
+            .first::<QueryReport>(conn)
+            .map_err(resource_not_found_err!(Report, (project, report)));
+    }
+
+    let (Some(branch), Some(testbed)) = (branch, testbed) else {
+        return Err(bad_request_error(format!(
+            "Either a {side} report or both a {side} branch and a testbed are required."
+        )));
+    };
+    let mut query = QueryReport::belonging_to(project)
+        .inner_join(schema::branch::table)
+        .inner_join(schema::testbed::table)
+        .into_boxed();
+    filter_branch_name_id!(query, branch);
+    filter_testbed_name_id!(query, testbed);
+    query
+        .order((
+            schema::report::start_time.desc(),
+            schema::report::end_time.desc(),
+            schema::report::created.desc(),
+            schema::report::id.desc(),
+        ))
+        .select(QueryReport::as_select())
+        .first::<QueryReport>(conn)
+        .map_err(resource_not_found_err!(Report, (project, branch, testbed)))
+}
+
+fn compare_report_json(
+    conn: &mut DbConnection,
+    project: &QueryProject,
+    report: &QueryReport,
+) -> Result<JsonCompareReport, HttpError> {
+    Ok(JsonCompareReport {
+        report: report.uuid,
+        branch: QueryBranch::get_branch_version_json(conn, report.branch_id, report.version_id)?,
+        testbed: QueryTestbed::get(conn, report.testbed_id)?.into_json_for_project(project),
+        start_time: report.start_time,
+        end_time: report.end_time,
+    })
+}
+
+fn report_metrics(
+    conn: &mut DbConnection,
+    project: &QueryProject,
+    report: &QueryReport,
+) -> Result<Vec<(QueryBenchmark, QueryMeasure, f64)>, HttpError> {
+    schema::metric::table
+        .inner_join(schema::report_benchmark::table.inner_join(schema::benchmark::table))
+        .inner_join(schema::measure::table)
+        .filter(schema::report_benchmark::report_id.eq(report.id))
+        .order((
+            schema::benchmark::name,
+            schema::measure::name,
+            schema::report_benchmark::iteration,
+        ))
+        .select((
+            QueryBenchmark::as_select(),
+            QueryMeasure::as_select(),
+            schema::metric::value,
+        ))
+        .load::<(QueryBenchmark, QueryMeasure, f64)>(conn)
+        .map_err(resource_not_found_err!(Metric, (project, report.uuid)))
+}
+
+struct CompareMetrics {
+    benchmark: QueryBenchmark,
+    measure: QueryMeasure,
+    base: Vec<f64>,
+    head: Vec<f64>,
+}
+
+impl CompareMetrics {
+    fn new(benchmark: QueryBenchmark, measure: QueryMeasure) -> Self {
+        Self {
+            benchmark,
+            measure,
+            base: Vec::new(),
+            head: Vec::new(),
+        }
+    }
+
+    fn into_json(self, project: &QueryProject) -> JsonCompareResult {
+        let comparison = Comparison::new(&self.base, &self.head);
+        JsonCompareResult {
+            benchmark: self.benchmark.into_json_for_project(project),
+            measure: self.measure.into_json_for_project(project),
+            base: compare_metric(&self.base),
+            head: compare_metric(&self.head),
+            delta: comparison.map(|comparison| comparison.delta.into()),
+            percent_change: comparison
+                .and_then(|comparison| comparison.percent_change)
+                .map(Into::into),
+            p_value: comparison
+                .and_then(|comparison| comparison.p_value)
+                .map(Into::into),
+            significant: comparison.and_then(|comparison| comparison.is_significant()),
+        }
+    }
+}
+
+fn compare_metric(values: &[f64]) -> Option<JsonCompareMetric> {
+    if values.is_empty() {
+        return None;
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let value = values.iter().sum::<f64>() / values.len() as f64;
+    Some(JsonCompareMetric {
+        value: OrderedFloat(value),
+        iterations: u32::try_from(values.len()).unwrap_or(u32::MAX),
+    })
+}
@@ -24,13 +24,16 @@ use crate::{
     model::user::auth::{AuthUser, PubBearerToken},
     model::{
         project::{
-            measure::{InsertMeasure, QueryMeasure, UpdateMeasure},
+            measure::{
+                validate_dependents, validate_expression, InsertMeasure, QueryMeasure,
+                UpdateMeasure,
+            },
             QueryProject,
         },
         user::auth::BearerToken,
     },
     schema,
-    util::search::Search,
+    util::search::{lower, Search},
 };
 
 #[derive(Deserialize, JsonSchema)]
@@ -122,10 +125,10 @@ async fn get_ls_inner(
     }
     if let Some(search) = query_params.search.as_ref() {
         query = query.filter(
-            schema::measure::name
+            lower(schema::measure::name)
                 .like(search)
-                .or(schema::measure::slug.like(search))
-                .or(schema::measure::uuid.like(search)),
+                .or(lower(schema::measure::slug).like(search))
+                .or(lower(schema::measure::uuid).like(search)),
         );
     }
 
@@ -316,6 +319,18 @@ async fn patch_inner(
         query_project.id,
         &path_params.measure,
     )?;
+    let slug = json_measure.slug().unwrap_or(&query_measure.slug);
+    let expression = json_measure
+        .expression()
+        .unwrap_or(query_measure.expression.as_ref());
+    validate_expression(
+        conn_lock!(context),
+        query_project.id,
+        json_measure.name().unwrap_or(&query_measure.name),
+        slug,
+        expression,
+    )?;
+    validate_dependents(conn_lock!(context), &query_measure, slug, expression)?;
     let update_measure = UpdateMeasure::from(json_measure.clone());
     diesel::update(schema::measure::table.filter(schema::measure::id.eq(query_measure.id)))
         .set(&update_measure)
@@ -0,0 +1,377 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use bencher_json::{DateTime, ResourceId};
+use diesel::RunQueryDsl;
+use dropshot::{endpoint, HttpError, Path, RequestContext};
+use http::{Response, StatusCode};
+use hyper::Body;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    conn_lock,
+    context::{ApiContext, DbConnection},
+    endpoints::{
+        endpoint::{CorsResponse, Get},
+        Endpoint,
+    },
+    error::resource_not_found_err,
+    model::{
+        project::{threshold::alert::QueryAlert, QueryProject},
+        user::auth::{AuthUser, PubBearerToken},
+    },
+};
+
+const OPEN_METRICS_CONTENT_TYPE: &str =
+    "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjMetricsParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/metrics",
+    tags = ["projects", "perf"]
+}]
+pub async fn proj_metrics_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjMetricsParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// Scrape the latest project metrics
+///
+/// Scrape the most recent metric for every branch, testbed, benchmark, and measure in a project,
+/// along with the number of active alerts for the project.
+/// The response is in the `OpenMetrics` text format, so it can be scraped by Prometheus compatible monitoring systems.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/metrics",
+    tags = ["projects", "perf"]
+}]
+pub async fn proj_metrics_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubBearerToken,
+    path_params: Path<ProjMetricsParams>,
+) -> Result<Response<Body>, HttpError> {
+    let auth_user = AuthUser::from_pub_token(rqctx.context(), bearer_token).await?;
+    let open_metrics = get_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        auth_user.as_ref(),
+    )
+    .await?;
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(http::header::CONTENT_TYPE, OPEN_METRICS_CONTENT_TYPE)
+        .header(http::header::CACHE_CONTROL, "private, max-age=0, no-cache")
+        .body(open_metrics.into())
+        .map_err(Into::into)
+}
+
+async fn get_inner(
+    context: &ApiContext,
+    path_params: ProjMetricsParams,
+    auth_user: Option<&AuthUser>,
+) -> Result<String, HttpError> {
+    let query_project = QueryProject::is_allowed_public(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+    )?;
+
+    let conn = conn_lock!(context);
+    let latest_metrics = latest_metrics(conn, &query_project)?;
+    let active_alerts = QueryAlert::active_count(conn, &query_project)?;
+
+    Ok(open_metrics(&latest_metrics, active_alerts))
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct MetricLabels {
+    branch: String,
+    testbed: String,
+    benchmark: String,
+    measure: String,
+}
+
+struct LatestMetric {
+    units: String,
+    value: f64,
+    lower_value: Option<f64>,
+    upper_value: Option<f64>,
+    end_time: DateTime,
+}
+
+#[derive(diesel::QueryableByName)]
+struct LatestMetricRow {
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    branch: String,
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    testbed: String,
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    benchmark: String,
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    measure: String,
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    units: String,
+    #[diesel(sql_type = diesel::sql_types::Double)]
+    value: f64,
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Double>)]
+    lower_value: Option<f64>,
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Double>)]
+    upper_value: Option<f64>,
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    end_time: DateTime,
+}
+
+// Find the newest code version for each branch, testbed, benchmark, and measure,
+// and select only the metrics from that code version for each of them,
+// so a scrape never has to load the entire history of the project.
+// The query builder can't join against a subquery, so this is written in SQL.
+// The project ID is an integer from the database, so it is safe to format into the query,
+// and that avoids the bind parameter syntax differing between SQLite and PostgreSQL.
+fn latest_metrics(
+    conn: &mut DbConnection,
+    query_project: &QueryProject,
+) -> Result<BTreeMap<MetricLabels, LatestMetric>, HttpError> {
+    let project_id = query_project.id;
+    let rows = diesel::sql_query(format!(
+        "SELECT branch.slug AS branch,
+            testbed.slug AS testbed,
+            benchmark.name AS benchmark,
+            measure.slug AS measure,
+            measure.units AS units,
+            metric.value AS value,
+            metric.lower_value AS lower_value,
+            metric.upper_value AS upper_value,
+            report.end_time AS end_time
+        FROM metric
+        INNER JOIN report_benchmark ON report_benchmark.id = metric.report_benchmark_id
+        INNER JOIN report ON report.id = report_benchmark.report_id
+        INNER JOIN version ON version.id = report.version_id
+        INNER JOIN branch_version ON branch_version.version_id = version.id
+        INNER JOIN branch ON branch.id = branch_version.branch_id
+        INNER JOIN testbed ON testbed.id = report.testbed_id
+        INNER JOIN benchmark ON benchmark.id = report_benchmark.benchmark_id
+        INNER JOIN measure ON measure.id = metric.measure_id
+        INNER JOIN (
+            SELECT branch_version.branch_id AS branch_id,
+                report.testbed_id AS testbed_id,
+                report_benchmark.benchmark_id AS benchmark_id,
+                metric.measure_id AS measure_id,
+                MAX(version.number) AS number
+            FROM metric
+            INNER JOIN report_benchmark ON report_benchmark.id = metric.report_benchmark_id
+            INNER JOIN report ON report.id = report_benchmark.report_id
+            INNER JOIN version ON version.id = report.version_id
+            INNER JOIN branch_version ON branch_version.version_id = version.id
+            WHERE report.project_id = {project_id}
+            GROUP BY branch_version.branch_id,
+                report.testbed_id,
+                report_benchmark.benchmark_id,
+                metric.measure_id
+        ) latest ON latest.branch_id = branch_version.branch_id
+            AND latest.testbed_id = report.testbed_id
+            AND latest.benchmark_id = report_benchmark.benchmark_id
+            AND latest.measure_id = metric.measure_id
+            AND latest.number = version.number
+        WHERE report.project_id = {project_id}
+        ORDER BY report.start_time DESC, report_benchmark.iteration DESC"
+    ))
+    .load::<LatestMetricRow>(conn)
+    .map_err(resource_not_found_err!(Metric, query_project))?;
+
+    // The newest metric is the first one in the same order as the perf query.
+    let mut latest_metrics = BTreeMap::new();
+    for LatestMetricRow {
+        branch,
+        testbed,
+        benchmark,
+        measure,
+        units,
+        value,
+        lower_value,
+        upper_value,
+        end_time,
+    } in rows
+    {
+        latest_metrics
+            .entry(MetricLabels {
+                branch,
+                testbed,
+                benchmark,
+                measure,
+            })
+            .or_insert(LatestMetric {
+                units,
+                value,
+                lower_value,
+                upper_value,
+                end_time,
+            });
+    }
+
+    Ok(latest_metrics)
+}
+
+fn open_metrics(
+    latest_metrics: &BTreeMap<MetricLabels, LatestMetric>,
+    active_alerts: u64,
+) -> String {
+    let mut open_metrics = String::new();
+
+    write_family(
+        &mut open_metrics,
+        "bencher_metric",
+        "The most recent metric value.",
+        latest_metrics,
+        |metric| Some(metric.value),
+    );
+    write_family(
+        &mut open_metrics,
+        "bencher_metric_lower_value",
+        "The most recent metric lower value.",
+        latest_metrics,
+        |metric| metric.lower_value,
+    );
+    write_family(
+        &mut open_metrics,
+        "bencher_metric_upper_value",
+        "The most recent metric upper value.",
+        latest_metrics,
+        |metric| metric.upper_value,
+    );
+    #[allow(clippy::cast_precision_loss)]
+    write_family(
+        &mut open_metrics,
+        "bencher_metric_timestamp_seconds",
+        "The end time of the report for the most recent metric.",
+        latest_metrics,
+        |metric| Some(metric.end_time.timestamp() as f64),
+    );
+
+    let _ = writeln!(open_metrics, "# TYPE bencher_alerts_active gauge");
+    let _ = writeln!(
+        open_metrics,
+        "# HELP bencher_alerts_active The number of active alerts."
+    );
+    let _ = writeln!(open_metrics, "bencher_alerts_active {active_alerts}");
+
+    open_metrics.push_str("# EOF\n");
+    open_metrics
+}
+
+fn write_family<F>(
+    open_metrics: &mut String,
+    name: &str,
+    help: &str,
+    latest_metrics: &BTreeMap<MetricLabels, LatestMetric>,
+    value: F,
+) where
+    F: Fn(&LatestMetric) -> Option<f64>,
+{
+    let _ = writeln!(open_metrics, "# TYPE {name} gauge");
+    let _ = writeln!(open_metrics, "# HELP {name} {help}");
+    for (labels, metric) in latest_metrics {
+        let Some(value) = value(metric) else {
+            continue;
+        };
+        let _ = writeln!(
+            open_metrics,
+            "{name}{{branch=\"{branch}\",testbed=\"{testbed}\",benchmark=\"{benchmark}\",measure=\"{measure}\",units=\"{units}\"}} {value}",
+            branch = escape_label(&labels.branch),
+            testbed = escape_label(&labels.testbed),
+            benchmark = escape_label(&labels.benchmark),
+            measure = escape_label(&labels.measure),
+            units = escape_label(&metric.units),
+        );
+    }
+}
+
+// https://github.com/OpenObservability/OpenMetrics/blob/main/specification/OpenMetrics.md#escaping
+fn escape_label(label: &str) -> String {
+    label
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use bencher_json::DateTime;
+    use pretty_assertions::assert_eq;
+
+    use super::{open_metrics, LatestMetric, MetricLabels};
+
+    fn labels(benchmark: &str) -> MetricLabels {
+        MetricLabels {
+            branch: "main".to_owned(),
+            testbed: "localhost".to_owned(),
+            benchmark: benchmark.to_owned(),
+            measure: "latency".to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_open_metrics() {
+        let mut latest_metrics = BTreeMap::new();
+        latest_metrics.insert(
+            labels("tests::\"quoted\""),
+            LatestMetric {
+                units: "nanoseconds (ns)".to_owned(),
+                value: 1.5,
+                lower_value: None,
+                upper_value: None,
+                end_time: DateTime::try_from(2_000).unwrap(),
+            },
+        );
+        latest_metrics.insert(
+            labels("tests::bench"),
+            LatestMetric {
+                units: "nanoseconds (ns)".to_owned(),
+                value: 3.0,
+                lower_value: Some(2.0),
+                upper_value: Some(4.0),
+                end_time: DateTime::try_from(1_000).unwrap(),
+            },
+        );
+
+        let quoted = r#"branch="main",testbed="localhost",benchmark="tests::\"quoted\"",measure="latency",units="nanoseconds (ns)""#;
+        let bench = r#"branch="main",testbed="localhost",benchmark="tests::bench",measure="latency",units="nanoseconds (ns)""#;
+        let expected = format!(
+            "# TYPE bencher_metric gauge
+# HELP bencher_metric The most recent metric value.
+bencher_metric{{{quoted}}} 1.5
+bencher_metric{{{bench}}} 3
+# TYPE bencher_metric_lower_value gauge
+# HELP bencher_metric_lower_value The most recent metric lower value.
+bencher_metric_lower_value{{{bench}}} 2
+# TYPE bencher_metric_upper_value gauge
+# HELP bencher_metric_upper_value The most recent metric upper value.
+bencher_metric_upper_value{{{bench}}} 4
+# TYPE bencher_metric_timestamp_seconds gauge
+# HELP bencher_metric_timestamp_seconds The end time of the report for the most recent metric.
+bencher_metric_timestamp_seconds{{{quoted}}} 2000
+bencher_metric_timestamp_seconds{{{bench}}} 1000
+# TYPE bencher_alerts_active gauge
+# HELP bencher_alerts_active The number of active alerts.
+bencher_alerts_active 2
+# EOF
+"
+        );
+        assert_eq!(open_metrics(&latest_metrics, 2), expected);
+    }
+}
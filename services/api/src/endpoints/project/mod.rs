@@ -1,9 +1,14 @@
 pub mod allowed;
+pub mod archive;
 pub mod benchmarks;
 pub mod branches;
+pub mod compare;
 pub mod measures;
+pub mod metrics;
 pub mod perf;
 pub mod projects;
 pub mod reports;
+pub mod retention;
 pub mod testbeds;
 pub mod thresholds;
+pub mod webhooks;
@@ -0,0 +1,416 @@
+use std::collections::BTreeMap;
+
+use bencher_json::{
+    project::{
+        metric::{Mean, Median},
+        perf::{JsonPerfEnvelope, JsonPerfMetric, PerfAggregate, PerfSmoothing},
+    },
+    JsonMetric, SampleSize,
+};
+use ordered_float::OrderedFloat;
+
+const HOUR: i64 = 60 * 60;
+const DAY: i64 = HOUR * 24;
+const WEEK: i64 = DAY * 7;
+// The Unix epoch was on a Thursday, so shift weeks to start on a Monday.
+const WEEK_OFFSET: i64 = DAY * 3;
+
+/// The default number of points in a rolling smoothing window.
+pub const DEFAULT_SMOOTHING_WINDOW: SampleSize = SampleSize::FIVE;
+
+/// Aggregate and then smooth the metrics for a single branch, testbed, benchmark, and measure.
+/// If neither an aggregate nor a smoothing is requested, then the metrics are returned as is.
+pub fn aggregate_metrics(
+    metrics: Vec<JsonPerfMetric>,
+    aggregate: Option<PerfAggregate>,
+    smoothing: Option<PerfSmoothing>,
+    smoothing_window: SampleSize,
+) -> Vec<JsonPerfMetric> {
+    let metrics = match aggregate {
+        Some(PerfAggregate::None) | None => metrics,
+        Some(aggregate) => bucket_metrics(metrics, aggregate),
+    };
+    match smoothing {
+        Some(PerfSmoothing::None) | None => metrics,
+        Some(smoothing) => smooth_metrics(&metrics, smoothing, smoothing_window),
+    }
+}
+
+fn bucket_key(aggregate: PerfAggregate, metric: &JsonPerfMetric) -> i64 {
+    let timestamp = metric.start_time.timestamp();
+    match aggregate {
+        PerfAggregate::None => 0,
+        PerfAggregate::Hour => timestamp.div_euclid(HOUR),
+        PerfAggregate::Day => timestamp.div_euclid(DAY),
+        PerfAggregate::Week => (timestamp + WEEK_OFFSET).div_euclid(WEEK),
+        PerfAggregate::Version => i64::from(metric.version.number.0),
+    }
+}
+
+fn bucket_metrics(metrics: Vec<JsonPerfMetric>, aggregate: PerfAggregate) -> Vec<JsonPerfMetric> {
+    let mut buckets = BTreeMap::<i64, Vec<JsonPerfMetric>>::new();
+    for metric in metrics {
+        buckets
+            .entry(bucket_key(aggregate, &metric))
+            .or_default()
+            .push(metric);
+    }
+    buckets.into_values().filter_map(aggregate_bucket).collect()
+}
+
+/// Merge a bucket of metrics into its mean.
+/// The report, version, threshold, and boundary are those of the last metric in the bucket,
+/// and the start time is that of the first metric in the bucket.
+/// If any of the metrics in the bucket has an alert, then it is kept.
+fn aggregate_bucket(bucket: Vec<JsonPerfMetric>) -> Option<JsonPerfMetric> {
+    let envelope = envelope(bucket.iter().map(|metric| metric.metric.value))?;
+    let start_time = bucket.first()?.start_time;
+    let alert = bucket.iter().rev().find_map(|metric| metric.alert.clone());
+    let metric = JsonMetric::mean(bucket.iter().map(|metric| metric.metric).collect())?;
+    let last = bucket.into_iter().last()?;
+    Some(JsonPerfMetric {
+        start_time,
+        metric,
+        alert,
+        envelope: Some(envelope),
+        ..last
+    })
+}
+
+/// Replace each metric with the mean or median of the trailing window of metrics ending with it.
+/// The envelope is widened to cover every metric in the window.
+fn smooth_metrics(
+    metrics: &[JsonPerfMetric],
+    smoothing: PerfSmoothing,
+    smoothing_window: SampleSize,
+) -> Vec<JsonPerfMetric> {
+    let smoothing_window = usize::from(smoothing_window);
+    metrics
+        .iter()
+        .enumerate()
+        .filter_map(|(index, metric)| {
+            let start = (index + 1).saturating_sub(smoothing_window);
+            let window = metrics.get(start..=index)?;
+            let values = window.iter().map(|metric| metric.metric).collect();
+            let smoothed = match smoothing {
+                PerfSmoothing::None => Some(metric.metric),
+                PerfSmoothing::Mean => JsonMetric::mean(values),
+                PerfSmoothing::Median => JsonMetric::median(values),
+            }?;
+            let envelope = window
+                .iter()
+                .map(|metric| {
+                    metric.envelope.unwrap_or(JsonPerfEnvelope {
+                        min: metric.metric.value,
+                        max: metric.metric.value,
+                        count: 1,
+                    })
+                })
+                .reduce(|acc, envelope| JsonPerfEnvelope {
+                    min: acc.min.min(envelope.min),
+                    max: acc.max.max(envelope.max),
+                    count: acc.count.saturating_add(envelope.count),
+                })?;
+            Some(JsonPerfMetric {
+                metric: smoothed,
+                envelope: Some(envelope),
+                ..metric.clone()
+            })
+        })
+        .collect()
+}
+
+fn envelope<I>(values: I) -> Option<JsonPerfEnvelope>
+where
+    I: Iterator<Item = OrderedFloat<f64>>,
+{
+    values.fold(None, |envelope, value| {
+        Some(match envelope {
+            Some(JsonPerfEnvelope { min, max, count }) => JsonPerfEnvelope {
+                min: min.min(value),
+                max: max.max(value),
+                count: count.saturating_add(1),
+            },
+            None => JsonPerfEnvelope {
+                min: value,
+                max: value,
+                count: 1,
+            },
+        })
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use bencher_json::{
+        project::{
+            branch::{JsonVersion, VersionNumber},
+            perf::{JsonPerfMetric, PerfAggregate, PerfSmoothing},
+            report::Iteration,
+        },
+        DateTime, JsonMetric, ReportUuid, SampleSize,
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::{aggregate_metrics, DAY, HOUR, WEEK};
+
+    // 1970-01-05 was the first Monday after the Unix epoch
+    const MONDAY: i64 = DAY * 4;
+
+    fn perf_metric(start_time: i64, version: u32, value: f64) -> JsonPerfMetric {
+        let start_time = DateTime::try_from(start_time).unwrap();
+        JsonPerfMetric {
+            report: ReportUuid::new(),
+            iteration: Iteration(0),
+            start_time,
+            end_time: start_time,
+            version: JsonVersion {
+                number: VersionNumber(version),
+                hash: None,
+            },
+            threshold: None,
+            metric: JsonMetric::new(value, None, None),
+            boundary: None,
+            alert: None,
+            envelope: None,
+        }
+    }
+
+    fn values(metrics: &[JsonPerfMetric]) -> Vec<JsonMetric> {
+        metrics.iter().map(|metric| metric.metric).collect()
+    }
+
+    fn metric_values(values: &[f64]) -> Vec<JsonMetric> {
+        values
+            .iter()
+            .map(|value| JsonMetric::new(*value, None, None))
+            .collect()
+    }
+
+    fn start_times(metrics: &[JsonPerfMetric]) -> Vec<i64> {
+        metrics
+            .iter()
+            .map(|metric| metric.start_time.timestamp())
+            .collect()
+    }
+
+    fn versions(metrics: &[JsonPerfMetric]) -> Vec<u32> {
+        metrics
+            .iter()
+            .map(|metric| metric.version.number.0)
+            .collect()
+    }
+
+    fn envelopes(metrics: &[JsonPerfMetric]) -> Vec<Option<(f64, f64, u32)>> {
+        metrics
+            .iter()
+            .map(|metric| {
+                metric.envelope.map(|envelope| {
+                    (
+                        envelope.min.into_inner(),
+                        envelope.max.into_inner(),
+                        envelope.count,
+                    )
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_aggregate_none() {
+        let metrics = vec![perf_metric(0, 0, 1.0), perf_metric(1, 1, 2.0)];
+        for aggregate in [None, Some(PerfAggregate::None)] {
+            for smoothing in [None, Some(PerfSmoothing::None)] {
+                let aggregated =
+                    aggregate_metrics(metrics.clone(), aggregate, smoothing, SampleSize::FIVE);
+                assert_eq!(values(&aggregated), metric_values(&[1.0, 2.0]));
+                assert_eq!(envelopes(&aggregated), vec![None, None]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_aggregate_hour() {
+        let metrics = vec![
+            perf_metric(0, 0, 1.0),
+            perf_metric(HOUR / 2, 1, 2.0),
+            perf_metric(HOUR - 1, 2, 6.0),
+            perf_metric(HOUR, 3, 4.0),
+            perf_metric(HOUR * 3, 4, 5.0),
+        ];
+        let aggregated =
+            aggregate_metrics(metrics, Some(PerfAggregate::Hour), None, SampleSize::FIVE);
+        assert_eq!(values(&aggregated), metric_values(&[3.0, 4.0, 5.0]));
+        // The start time is from the first metric and the version is from the last metric in each bucket
+        assert_eq!(start_times(&aggregated), vec![0, HOUR, HOUR * 3]);
+        assert_eq!(versions(&aggregated), vec![2, 3, 4]);
+        assert_eq!(
+            envelopes(&aggregated),
+            vec![
+                Some((1.0, 6.0, 3)),
+                Some((4.0, 4.0, 1)),
+                Some((5.0, 5.0, 1))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_aggregate_day() {
+        let metrics = vec![
+            perf_metric(HOUR, 0, 1.0),
+            perf_metric(DAY - 1, 1, 3.0),
+            perf_metric(DAY, 2, 10.0),
+            perf_metric(DAY * 3, 3, 20.0),
+            perf_metric(DAY * 3 + HOUR, 4, 30.0),
+        ];
+        let aggregated =
+            aggregate_metrics(metrics, Some(PerfAggregate::Day), None, SampleSize::FIVE);
+        assert_eq!(values(&aggregated), metric_values(&[2.0, 10.0, 25.0]));
+        assert_eq!(start_times(&aggregated), vec![HOUR, DAY, DAY * 3]);
+        assert_eq!(versions(&aggregated), vec![1, 2, 4]);
+    }
+
+    #[test]
+    fn test_aggregate_week() {
+        let metrics = vec![
+            // The Unix epoch was on a Thursday, so it is in the same week as the following Sunday
+            perf_metric(0, 0, 1.0),
+            perf_metric(MONDAY - 1, 1, 3.0),
+            // Weeks start on Monday
+            perf_metric(MONDAY, 2, 10.0),
+            perf_metric(MONDAY + WEEK - 1, 3, 20.0),
+            perf_metric(MONDAY + WEEK, 4, 100.0),
+        ];
+        let aggregated =
+            aggregate_metrics(metrics, Some(PerfAggregate::Week), None, SampleSize::FIVE);
+        assert_eq!(values(&aggregated), metric_values(&[2.0, 15.0, 100.0]));
+        assert_eq!(start_times(&aggregated), vec![0, MONDAY, MONDAY + WEEK]);
+        assert_eq!(versions(&aggregated), vec![1, 3, 4]);
+        assert_eq!(
+            envelopes(&aggregated),
+            vec![
+                Some((1.0, 3.0, 2)),
+                Some((10.0, 20.0, 2)),
+                Some((100.0, 100.0, 1))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_aggregate_version() {
+        // Version buckets ignore the start time
+        let metrics = vec![
+            perf_metric(0, 0, 1.0),
+            perf_metric(DAY * 30, 0, 3.0),
+            perf_metric(DAY * 31, 1, 5.0),
+            perf_metric(DAY * 32, 2, 7.0),
+            perf_metric(DAY * 33, 2, 8.0),
+            perf_metric(DAY * 34, 2, 9.0),
+        ];
+        let aggregated = aggregate_metrics(
+            metrics,
+            Some(PerfAggregate::Version),
+            None,
+            SampleSize::FIVE,
+        );
+        assert_eq!(values(&aggregated), metric_values(&[2.0, 5.0, 8.0]));
+        assert_eq!(start_times(&aggregated), vec![0, DAY * 31, DAY * 32]);
+        assert_eq!(versions(&aggregated), vec![0, 1, 2]);
+        assert_eq!(
+            envelopes(&aggregated),
+            vec![
+                Some((1.0, 3.0, 2)),
+                Some((5.0, 5.0, 1)),
+                Some((7.0, 9.0, 3))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_smoothing_mean() {
+        let metrics = [1.0, 2.0, 3.0, 4.0, 10.0]
+            .into_iter()
+            .zip(0..)
+            .map(|(value, version)| perf_metric(i64::from(version), version, value))
+            .collect();
+        let smoothed = aggregate_metrics(
+            metrics,
+            None,
+            Some(PerfSmoothing::Mean),
+            SampleSize::try_from(3).unwrap(),
+        );
+        // The window is shorter at the start of the series
+        assert_eq!(
+            values(&smoothed),
+            metric_values(&[1.0, 1.5, 2.0, 3.0, 17.0 / 3.0])
+        );
+        // Each smoothed metric keeps its own report details
+        assert_eq!(versions(&smoothed), vec![0, 1, 2, 3, 4]);
+        assert_eq!(
+            envelopes(&smoothed),
+            vec![
+                Some((1.0, 1.0, 1)),
+                Some((1.0, 2.0, 2)),
+                Some((1.0, 3.0, 3)),
+                Some((2.0, 4.0, 3)),
+                Some((3.0, 10.0, 3))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_smoothing_median() {
+        let metrics = [1.0, 10.0, 2.0, 3.0, 100.0]
+            .into_iter()
+            .zip(0..)
+            .map(|(value, version)| perf_metric(i64::from(version), version, value))
+            .collect();
+        let smoothed = aggregate_metrics(
+            metrics,
+            None,
+            Some(PerfSmoothing::Median),
+            SampleSize::try_from(3).unwrap(),
+        );
+        assert_eq!(values(&smoothed), metric_values(&[1.0, 5.5, 2.0, 3.0, 3.0]));
+        assert_eq!(
+            envelopes(&smoothed),
+            vec![
+                Some((1.0, 1.0, 1)),
+                Some((1.0, 10.0, 2)),
+                Some((1.0, 10.0, 3)),
+                Some((2.0, 10.0, 3)),
+                Some((2.0, 100.0, 3))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_aggregate_smoothing_envelope() {
+        let metrics = vec![
+            perf_metric(0, 0, 1.0),
+            perf_metric(1, 1, 3.0),
+            perf_metric(HOUR, 2, 5.0),
+            perf_metric(HOUR * 2, 3, 0.0),
+            perf_metric(HOUR * 2 + 1, 4, 20.0),
+        ];
+        let smoothed = aggregate_metrics(
+            metrics,
+            Some(PerfAggregate::Hour),
+            Some(PerfSmoothing::Mean),
+            SampleSize::MIN,
+        );
+        // The smoothing is over the bucket means
+        assert_eq!(values(&smoothed), metric_values(&[2.0, 3.5, 7.5]));
+        // The envelopes of the buckets in each window are merged,
+        // so they cover the full range of the underlying metrics
+        assert_eq!(
+            envelopes(&smoothed),
+            vec![
+                Some((1.0, 3.0, 2)),
+                Some((1.0, 5.0, 3)),
+                Some((0.0, 20.0, 3))
+            ]
+        );
+    }
+}
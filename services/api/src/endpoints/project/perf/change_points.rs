@@ -0,0 +1,135 @@
+use bencher_boundary::ChangePoints;
+use bencher_json::{
+    project::perf::{
+        JsonChangePoint, JsonPerfChangePointMetrics, JsonPerfMetrics, JsonPerfQueryParams,
+    },
+    JsonPerfChangePoints, JsonPerfQuery,
+};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext};
+
+use crate::{
+    context::ApiContext,
+    endpoints::{
+        endpoint::{CorsResponse, Get, ResponseOk},
+        Endpoint,
+    },
+    error::bad_request_error,
+    model::user::auth::{AuthUser, PubBearerToken},
+};
+
+use super::ProjPerfParams;
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/perf/change-points",
+    tags = ["projects", "perf"]
+}]
+pub async fn proj_perf_change_points_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjPerfParams>,
+    _query_params: Query<JsonPerfQueryParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// Detect change points in project performance metrics
+///
+/// Detect the change points in the performance metrics for a project.
+/// A change point is where there is a step change in the mean of the metrics over time.
+/// The query results are every permutation of each branch, testbed, benchmark, and measure.
+/// There is a limit of 256 permutations for a single request.
+/// Therefore, only the first 256 permutations are analyzed.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/perf/change-points",
+    tags = ["projects", "perf"]
+}]
+pub async fn proj_perf_change_points_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubBearerToken,
+    path_params: Path<ProjPerfParams>,
+    query_params: Query<JsonPerfQueryParams>,
+) -> Result<ResponseOk<JsonPerfChangePoints>, HttpError> {
+    // Second round of marshaling
+    let json_perf_query = query_params
+        .into_inner()
+        .try_into()
+        .map_err(bad_request_error)?;
+
+    let auth_user = AuthUser::from_pub_token(rqctx.context(), bearer_token).await?;
+    let json = get_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        json_perf_query,
+        auth_user.as_ref(),
+    )
+    .await?;
+    Ok(Get::response_ok(json, auth_user.is_some()))
+}
+
+async fn get_inner(
+    context: &ApiContext,
+    path_params: ProjPerfParams,
+    json_perf_query: JsonPerfQuery,
+    auth_user: Option<&AuthUser>,
+) -> Result<JsonPerfChangePoints, HttpError> {
+    let json_perf = super::get_inner(context, path_params, json_perf_query, auth_user).await?;
+
+    Ok(JsonPerfChangePoints {
+        project: json_perf.project,
+        start_time: json_perf.start_time,
+        end_time: json_perf.end_time,
+        results: json_perf
+            .results
+            .into_iter()
+            .map(change_point_metrics)
+            .collect(),
+    })
+}
+
+fn change_point_metrics(perf_metrics: JsonPerfMetrics) -> JsonPerfChangePointMetrics {
+    let JsonPerfMetrics {
+        branch,
+        testbed,
+        benchmark,
+        measure,
+        metrics,
+    } = perf_metrics;
+
+    let data = metrics
+        .iter()
+        .map(|metric| metric.metric.value.into())
+        .collect::<Vec<f64>>();
+    let change_points = ChangePoints::new(&data)
+        .map(|change_points| {
+            change_points
+                .segments
+                .windows(2)
+                .filter_map(|window| {
+                    let [before_segment, after_segment] = window else {
+                        return None;
+                    };
+                    let before = metrics.get(before_segment.end.checked_sub(1)?)?.clone();
+                    let after = metrics.get(after_segment.start)?.clone();
+                    Some(JsonChangePoint {
+                        before,
+                        after,
+                        before_mean: before_segment.mean.into(),
+                        after_mean: after_segment.mean.into(),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    JsonPerfChangePointMetrics {
+        branch,
+        testbed,
+        benchmark,
+        measure,
+        change_points,
+    }
+}
@@ -1,5 +1,5 @@
 use bencher_json::{
-    project::perf::{JsonPerfImgQueryParams, JsonPerfQueryParams},
+    project::perf::{JsonPerfImgQueryParams, JsonPerfQueryParams, PerfImgFormat},
     JsonPerfQuery,
 };
 use bencher_plot::LinePlot;
@@ -39,6 +39,10 @@ pub async fn proj_perf_img_options(
 /// The query results are every permutation of each branch, testbed, benchmark, and measure.
 /// There is a limit of 8 permutations for a single image.
 /// Therefore, only the first 8 permutations are plotted.
+/// The image can be generated as a JPEG, an SVG, or a self-contained HTML page
+/// with hover tooltips showing the version, report, and boundary for each metric.
+/// The boundary limits, alerts, and branch versions can optionally be drawn on the plot.
+/// The metrics can be aggregated and smoothed the same way as when querying project performance metrics.
 /// If the project is public, then the user does not need to be authenticated.
 /// If the project is private, then the user must be authenticated and have `view` permissions for the project.
 #[endpoint {
@@ -54,6 +58,11 @@ pub async fn proj_perf_img_get(
 ) -> Result<Response<Body>, HttpError> {
     let mut json_perf_img_query_params = query_params.into_inner();
     let title = json_perf_img_query_params.title.take();
+    let format = json_perf_img_query_params.format.take().unwrap_or_default();
+    let line_plot = LinePlot::new()
+        .with_boundaries(json_perf_img_query_params.boundaries.unwrap_or_default())
+        .with_alerts(json_perf_img_query_params.alerts.unwrap_or_default())
+        .with_versions(json_perf_img_query_params.versions.unwrap_or_default());
     let json_perf_query_params: JsonPerfQueryParams = json_perf_img_query_params.into();
     // Second round of marshaling
     let json_perf_query = json_perf_query_params
@@ -61,32 +70,50 @@ pub async fn proj_perf_img_get(
         .map_err(bad_request_error)?;
 
     let auth_user = AuthUser::from_pub_token(rqctx.context(), bearer_token).await?;
-    let jpeg = get_inner(
+    let img = get_inner(
         rqctx.context(),
         path_params.into_inner(),
+        &line_plot,
         title.as_deref(),
+        format,
         json_perf_query,
         auth_user.as_ref(),
     )
     .await?;
 
+    let content_type = match format {
+        PerfImgFormat::Jpeg => "image/jpeg",
+        PerfImgFormat::Svg => "image/svg+xml",
+        PerfImgFormat::Html => "text/html; charset=utf-8",
+    };
     Response::builder()
         .status(StatusCode::OK)
-        .header(http::header::CONTENT_TYPE, "image/jpeg")
+        .header(http::header::CONTENT_TYPE, content_type)
         .header(http::header::CACHE_CONTROL, "private, max-age=0, no-cache")
-        .body(jpeg.into())
+        .body(img.into())
         .map_err(Into::into)
 }
 
 async fn get_inner(
     context: &ApiContext,
     path_params: ProjPerfParams,
+    line_plot: &LinePlot,
     title: Option<&str>,
+    format: PerfImgFormat,
     json_perf_query: JsonPerfQuery,
     auth_user: Option<&AuthUser>,
 ) -> Result<Vec<u8>, HttpError> {
     let json_perf = super::get_inner(context, path_params, json_perf_query, auth_user).await?;
-    LinePlot::new().draw(title, &json_perf).map_err(|e| {
+    match format {
+        PerfImgFormat::Jpeg => line_plot.draw(title, &json_perf),
+        PerfImgFormat::Svg => line_plot
+            .draw_svg(title, &json_perf)
+            .map(String::into_bytes),
+        PerfImgFormat::Html => line_plot
+            .draw_html(title, &json_perf)
+            .map(String::into_bytes),
+    }
+    .map_err(|e| {
         issue_error(
             StatusCode::INTERNAL_SERVER_ERROR,
             "Failed to draw perf plot",
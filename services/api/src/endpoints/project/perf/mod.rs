@@ -8,8 +8,8 @@ use bencher_json::{
     ResourceId, TestbedUuid,
 };
 use diesel::{
-    ExpressionMethods, JoinOnDsl, NullableExpressionMethods, QueryDsl, RunQueryDsl,
-    SelectableHelper,
+    BelongingToDsl, ExpressionMethods, JoinOnDsl, NullableExpressionMethods, OptionalExtension,
+    QueryDsl, RunQueryDsl, SelectableHelper, TextExpressionMethods,
 };
 use dropshot::{endpoint, HttpError, Path, Query, RequestContext};
 use schemars::JsonSchema;
@@ -25,7 +25,7 @@ use crate::{
     error::{bad_request_error, resource_not_found_err},
     model::{
         project::{
-            benchmark::QueryBenchmark,
+            benchmark::{self, QueryBenchmark, TagSearch},
             branch::QueryBranch,
             measure::QueryMeasure,
             metric_boundary::QueryMetricBoundary,
@@ -40,6 +40,8 @@ use crate::{
     schema, view,
 };
 
+mod aggregate;
+pub mod change_points;
 pub mod img;
 
 const MAX_PERMUTATIONS: usize = 256;
@@ -70,6 +72,10 @@ pub async fn proj_perf_options(
 /// The query results are every permutation of each branch, testbed, benchmark, and measure.
 /// There is a limit of 256 permutations for a single request.
 /// Therefore, only the first 256 permutations are returned.
+/// The metrics for each permutation can optionally be aggregated per hour, day, week, or version
+/// and then smoothed with a rolling mean or median.
+/// Aggregated and smoothed metrics include the min/max envelope of the metrics they cover.
+/// The metrics can be limited to a range of commits using `git` hashes or branch version numbers.
 /// If the project is public, then the user does not need to be authenticated.
 /// If the project is private, then the user must be authenticated and have `view` permissions for the project.
 #[endpoint {
@@ -116,28 +122,91 @@ async fn get_inner(
     let JsonPerfQuery {
         branches,
         testbeds,
-        benchmarks,
+        mut benchmarks,
         measures,
         start_time,
         end_time,
+        start_hash,
+        end_hash,
+        start_version,
+        end_version,
+        archived,
+        aggregate,
+        smoothing,
+        smoothing_window,
+        group,
+        tag,
     } = json_perf_query;
 
-    let times = Times {
+    // Include all of the benchmarks in the group and/or with the tag
+    if group.is_some() || tag.is_some() {
+        let mut query = QueryBenchmark::belonging_to(&project).into_boxed();
+        if let Some(group) = group.as_ref() {
+            query = benchmark::filter_group(query, group);
+        }
+        if let Some(tag) = tag {
+            query = query.filter(schema::benchmark::tags.like(TagSearch::from(tag)));
+        }
+        let group_benchmarks = query
+            .order(schema::benchmark::name.asc())
+            .select(schema::benchmark::uuid)
+            .load::<BenchmarkUuid>(conn_lock!(context))
+            .map_err(resource_not_found_err!(Benchmark, &project))?;
+        for benchmark in group_benchmarks {
+            if !benchmarks.contains(&benchmark) {
+                benchmarks.push(benchmark);
+            }
+        }
+    }
+
+    // Every branch is named explicitly, so instead of silently leaving out an archived branch,
+    // it is an error to include one unless archived branches are allowed.
+    if !archived.unwrap_or_default() {
+        if let Some(archived_branch) = schema::branch::table
+            .filter(schema::branch::project_id.eq(project.id))
+            .filter(schema::branch::uuid.eq_any(&branches))
+            .filter(schema::branch::archived.is_not_null())
+            .select(schema::branch::uuid)
+            .first::<BranchUuid>(conn_lock!(context))
+            .optional()
+            .map_err(resource_not_found_err!(Branch, &project))?
+        {
+            return Err(bad_request_error(format!(
+                "The branch ({archived_branch}) is archived. Set `archived` to `true` to include it."
+            )));
+        }
+    }
+
+    let bounds = Bounds {
         start_time,
         end_time,
+        start_hash,
+        end_hash,
+        start_version,
+        end_version,
     };
 
-    let results = perf_results(
+    let mut results = perf_results(
         context,
         &project,
         &branches,
         &testbeds,
         &benchmarks,
         &measures,
-        times,
+        &bounds,
     )
     .await?;
 
+    // Aggregation and smoothing are computed server side on the raw metrics
+    if aggregate.is_some() || smoothing.is_some() {
+        let smoothing_window = smoothing_window.unwrap_or(aggregate::DEFAULT_SMOOTHING_WINDOW);
+        for perf_metrics in &mut results {
+            let metrics = std::mem::take(&mut perf_metrics.metrics);
+            perf_metrics.metrics =
+                aggregate::aggregate_metrics(metrics, aggregate, smoothing, smoothing_window);
+        }
+    }
+
     Ok(JsonPerf {
         project: project.into_json(conn_lock!(context))?,
         start_time,
@@ -146,10 +215,92 @@ async fn get_inner(
     })
 }
 
+struct Bounds {
+    start_time: Option<DateTime>,
+    end_time: Option<DateTime>,
+    start_hash: Option<GitHash>,
+    end_hash: Option<GitHash>,
+    start_version: Option<VersionNumber>,
+    end_version: Option<VersionNumber>,
+}
+
 #[derive(Clone, Copy)]
-struct Times {
+struct BranchBounds {
     start_time: Option<DateTime>,
     end_time: Option<DateTime>,
+    start_version: Option<VersionNumber>,
+    end_version: Option<VersionNumber>,
+}
+
+impl Bounds {
+    // Version numbers are specific to each branch,
+    // so the `git` hashes must be resolved against the versions for each branch.
+    // If both a hash and a version number are given, then the narrower bound is used.
+    fn for_branch(
+        &self,
+        conn: &mut DbConnection,
+        project: &QueryProject,
+        branch_uuid: BranchUuid,
+    ) -> Result<BranchBounds, HttpError> {
+        let start_version = if let Some(hash) = &self.start_hash {
+            let number = hash_version_numbers(conn, project, branch_uuid, hash)?
+                .into_iter()
+                .min_by_key(|number| number.0);
+            max_version(number, self.start_version)
+        } else {
+            self.start_version
+        };
+        let end_version = if let Some(hash) = &self.end_hash {
+            let number = hash_version_numbers(conn, project, branch_uuid, hash)?
+                .into_iter()
+                .max_by_key(|number| number.0);
+            min_version(number, self.end_version)
+        } else {
+            self.end_version
+        };
+        Ok(BranchBounds {
+            start_time: self.start_time,
+            end_time: self.end_time,
+            start_version,
+            end_version,
+        })
+    }
+}
+
+fn hash_version_numbers(
+    conn: &mut DbConnection,
+    project: &QueryProject,
+    branch_uuid: BranchUuid,
+    hash: &GitHash,
+) -> Result<Vec<VersionNumber>, HttpError> {
+    let numbers = schema::version::table
+        .inner_join(schema::branch_version::table.inner_join(
+            schema::branch::table.on(schema::branch_version::branch_id.eq(schema::branch::id)),
+        ))
+        .filter(schema::version::project_id.eq(project.id))
+        .filter(schema::branch::uuid.eq(branch_uuid))
+        .filter(schema::version::hash.eq(hash.as_ref()))
+        .select(schema::version::number)
+        .load::<VersionNumber>(conn)
+        .map_err(resource_not_found_err!(
+            Version,
+            (project, branch_uuid, hash)
+        ))?;
+    if numbers.is_empty() {
+        Err(bad_request_error(format!(
+            "No version with hash ({hash}) found for branch ({branch_uuid})"
+        )))
+    } else {
+        Ok(numbers)
+    }
+}
+
+fn max_version(left: Option<VersionNumber>, right: Option<VersionNumber>) -> Option<VersionNumber> {
+    left.into_iter().chain(right).max_by_key(|number| number.0)
+}
+
+fn min_version(left: Option<VersionNumber>, right: Option<VersionNumber>) -> Option<VersionNumber> {
+    left.into_iter().chain(right).min_by_key(|number| number.0)
 }
 
 async fn perf_results(
@@ -159,12 +310,13 @@ async fn perf_results(
     testbeds: &[TestbedUuid],
     benchmarks: &[BenchmarkUuid],
     measures: &[MeasureUuid],
-    times: Times,
+    bounds: &Bounds,
 ) -> Result<Vec<JsonPerfMetrics>, HttpError> {
     let permutations = branches.len() * testbeds.len() * benchmarks.len() * measures.len();
     let gt_max_permutations = permutations > MAX_PERMUTATIONS;
     let mut results = Vec::with_capacity(permutations.min(MAX_PERMUTATIONS));
     for (branch_index, branch_uuid) in branches.iter().enumerate() {
+        let branch_bounds = bounds.for_branch(conn_lock!(context), project, *branch_uuid)?;
         for (testbed_index, testbed_uuid) in testbeds.iter().enumerate() {
             for (benchmark_index, benchmark_uuid) in benchmarks.iter().enumerate() {
                 for (measure_index, measure_uuid) in measures.iter().enumerate() {
@@ -185,7 +337,7 @@ async fn perf_results(
                         *testbed_uuid,
                         *benchmark_uuid,
                         *measure_uuid,
-                        times,
+                        branch_bounds,
                     )
                     .await?;
 
@@ -223,7 +375,7 @@ async fn perf_query(
     testbed_uuid: TestbedUuid,
     benchmark_uuid: BenchmarkUuid,
     measure_uuid: MeasureUuid,
-    times: Times,
+    branch_bounds: BranchBounds,
 ) -> Result<Vec<PerfQuery>, HttpError> {
     let mut query = view::metric_boundary::table
         .inner_join(
@@ -255,16 +407,24 @@ async fn perf_query(
         .left_join(schema::alert::table.on(view::metric_boundary::boundary_id.eq(schema::alert::boundary_id.nullable())))
         .into_boxed();
 
-    let Times {
+    let BranchBounds {
         start_time,
         end_time,
-    } = times;
+        start_version,
+        end_version,
+    } = branch_bounds;
     if let Some(start_time) = start_time {
         query = query.filter(schema::report::start_time.ge(start_time));
     }
     if let Some(end_time) = end_time {
         query = query.filter(schema::report::end_time.le(end_time));
     }
+    if let Some(start_version) = start_version {
+        query = query.filter(schema::version::number.ge(start_version));
+    }
+    if let Some(end_version) = end_version {
+        query = query.filter(schema::version::number.le(end_version));
+    }
 
     query
         // Order by the version number so that the oldest version is first.
@@ -297,6 +457,7 @@ async fn perf_query(
                     schema::threshold::model_id,
                     schema::threshold::created,
                     schema::threshold::modified,
+                    schema::threshold::benchmark_group,
                 ),
                 (
                     schema::model::id,
@@ -318,6 +479,8 @@ async fn perf_query(
                     schema::alert::boundary_limit,
                     schema::alert::status,
                     schema::alert::modified,
+                    schema::alert::assignee_id,
+                    schema::alert::note,
                 ).nullable(),
             ).nullable(),
             QueryMetricBoundary::as_select(),
@@ -439,6 +602,7 @@ fn new_perf_metric(
         metric,
         boundary,
         alert,
+        envelope: None,
     }
 }
 
@@ -24,7 +24,7 @@ use crate::{
         user::auth::{AuthUser, BearerToken, PubBearerToken},
     },
     schema,
-    util::search::Search,
+    util::search::{lower, Search},
 };
 
 pub type ProjectsPagination = JsonPagination<ProjectsSort>;
@@ -116,10 +116,10 @@ async fn get_ls_inner(
     }
     if let Some(search) = query_params.search.as_ref() {
         query = query.filter(
-            schema::project::name
+            lower(schema::project::name)
                 .like(search)
-                .or(schema::project::slug.like(search))
-                .or(schema::project::uuid.like(search)),
+                .or(lower(schema::project::slug).like(search))
+                .or(lower(schema::project::uuid).like(search)),
         );
     }
 
@@ -20,7 +20,7 @@ use slog::Logger;
 
 use crate::{
     conn_lock,
-    context::ApiContext,
+    context::{ApiContext, DbConnection},
     endpoints::{
         endpoint::{CorsResponse, Delete, Get, Post, ResponseCreated, ResponseDeleted, ResponseOk},
         Endpoint,
@@ -30,10 +30,14 @@ use crate::{
     model::{
         project::{
             branch::{BranchId, QueryBranch},
-            report::{results::ReportResults, InsertReport, QueryReport, ReportId},
+            report::{
+                results::{detector::Detector, ReportResults},
+                InsertReport, QueryReport, ReportId,
+            },
             testbed::QueryTestbed,
             version::{QueryVersion, VersionId},
-            QueryProject,
+            webhook::QueryWebhook,
+            ProjectId, QueryProject,
         },
         user::auth::BearerToken,
     },
@@ -213,7 +217,7 @@ async fn post_inner(
     log: &Logger,
     context: &ApiContext,
     path_params: ProjReportsParams,
-    mut json_report: JsonNewReport,
+    json_report: JsonNewReport,
     auth_user: &AuthUser,
 ) -> Result<JsonReport, HttpError> {
     // Verify that the user is allowed
@@ -224,13 +228,6 @@ async fn post_inner(
         auth_user,
         Permission::Create,
     )?;
-    let project_id = project.id;
-
-    // Verify that the branch and testbed are part of the same project
-    let branch_id =
-        QueryBranch::from_name_id(conn_lock!(context), project_id, &json_report.branch)?.id;
-    let testbed_id =
-        QueryTestbed::from_name_id(conn_lock!(context), project_id, &json_report.testbed)?.id;
 
     // Check to see if the project is public or private
     // If private, then validate that there is an active subscription or license
@@ -243,15 +240,81 @@ async fn post_inner(
     )
     .await?;
 
+    #[cfg(feature = "plus")]
+    let mut usage = 0;
+
+    let (query_report, processed_report) = create_report(
+        log,
+        context,
+        auth_user,
+        project.id,
+        json_report,
+        false,
+        #[cfg(feature = "plus")]
+        &mut usage,
+    )
+    .await?;
+
+    #[cfg(feature = "plus")]
+    plan_kind
+        .check_usage(context.biller.as_ref(), &project, usage)
+        .await?;
+
+    // Don't return the error from processing the report until after the metrics usage has been checked
+    processed_report?;
+    // If the report was processed successfully, then return the report with the results
+    let json_report = query_report.into_json(log, conn_lock!(context))?;
+
+    // Notify any webhooks for the project about the new report and its alerts
+    QueryWebhook::send_report(log, context, &project, &json_report).await;
+
+    Ok(json_report)
+}
+
+// Create a new report and process its results.
+// Historical reports are placed in chronological order,
+// and their boundaries only use the metrics from before them without generating any alerts.
+// The error from processing the report results is returned separately,
+// so the metrics usage can always be checked first.
+async fn create_report(
+    log: &Logger,
+    context: &ApiContext,
+    auth_user: &AuthUser,
+    project_id: ProjectId,
+    mut json_report: JsonNewReport,
+    historical: bool,
+    #[cfg(feature = "plus")] usage: &mut u32,
+) -> Result<(QueryReport, Result<(), HttpError>), HttpError> {
+    // Verify that the branch and testbed are part of the same project
+    let branch_id =
+        QueryBranch::from_name_id(conn_lock!(context), project_id, &json_report.branch)?.id;
+    // A new report means that the branch is no longer stale.
+    // Historical reports are old, so they leave an archived branch archived.
+    if !historical {
+        QueryBranch::unarchive(conn_lock!(context), branch_id)?;
+    }
+    let testbed_id =
+        QueryTestbed::from_name_id(conn_lock!(context), project_id, &json_report.testbed)?.id;
+
     // If there is a hash then try to see if there is already a code version for
     // this branch with that particular hash.
     // Otherwise, create a new code version for this branch with/without the hash.
-    let version_id = QueryVersion::get_or_increment(
-        conn_lock!(context),
-        project_id,
-        branch_id,
-        json_report.hash.as_ref(),
-    )?;
+    let version_id = if historical {
+        QueryVersion::get_or_insert_historical(
+            conn_lock!(context),
+            project_id,
+            branch_id,
+            json_report.hash.as_ref(),
+            json_report.start_time,
+        )?
+    } else {
+        QueryVersion::get_or_increment(
+            conn_lock!(context),
+            project_id,
+            branch_id,
+            json_report.hash.as_ref(),
+        )?
+    };
 
     let json_settings = json_report.settings.take().unwrap_or_default();
     let adapter = json_settings.adapter.unwrap_or_default();
@@ -284,11 +347,14 @@ async fn post_inner(
             )
         })?;
 
-    #[cfg(feature = "plus")]
-    let mut usage = 0;
-
     // Process and record the report results
-    let mut report_results = ReportResults::new(project_id, branch_id, testbed_id, query_report.id);
+    let mut report_results = ReportResults::new(
+        project_id,
+        branch_id,
+        testbed_id,
+        query_report.id,
+        historical.then_some(json_report.start_time),
+    );
     let results_array = json_report
         .results
         .iter()
@@ -302,19 +368,171 @@ async fn post_inner(
             adapter,
             json_settings,
             #[cfg(feature = "plus")]
-            &mut usage,
+            usage,
         )
         .await;
 
+    Ok((query_report, processed_report))
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/import",
+    tags = ["projects", "reports"]
+}]
+pub async fn proj_import_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjReportsParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Post.into()]))
+}
+
+/// Import historical reports
+///
+/// Import a batch of historical reports for a project.
+/// The user must have `create` permissions for the project.
+/// The reports are created in chronological order by their start time,
+/// and their code versions are placed in chronological order for each branch.
+/// The boundaries for each report only use the metrics from before it, and no alerts are generated.
+/// The boundaries for any existing reports after the imported reports are then recomputed, again without generating any alerts.
+/// If a report fails to import, then all of the reports before it will have already been imported.
+/// If using the Bencher CLI, it is recommended to use the `bencher import` subcommand.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/import",
+    tags = ["projects", "reports"]
+}]
+pub async fn proj_import_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjReportsParams>,
+    body: TypedBody<Vec<JsonNewReport>>,
+) -> Result<ResponseCreated<JsonReports>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = import_inner(
+        &rqctx.log,
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Post::auth_response_created(json))
+}
+
+async fn import_inner(
+    log: &Logger,
+    context: &ApiContext,
+    path_params: ProjReportsParams,
+    mut json_reports: Vec<JsonNewReport>,
+    auth_user: &AuthUser,
+) -> Result<JsonReports, HttpError> {
+    // Verify that the user is allowed
+    let project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Create,
+    )?;
+
+    // Check to see if the project is public or private
+    // If private, then validate that there is an active subscription or license
+    #[cfg(feature = "plus")]
+    let plan_kind = crate::model::organization::plan::PlanKind::new_for_project(
+        conn_lock!(context),
+        context.biller.as_ref(),
+        &context.licensor,
+        &project,
+    )
+    .await?;
+
+    #[cfg(feature = "plus")]
+    let mut usage = 0;
+
+    // Import the reports in chronological order,
+    // so each report only has the reports from before it as its history.
+    json_reports.sort_by_key(|json_report| {
+        (
+            json_report.start_time.timestamp(),
+            json_report.end_time.timestamp(),
+        )
+    });
+    let mut query_reports = Vec::with_capacity(json_reports.len());
+    let mut imported_reports = Ok(());
+    for json_report in json_reports {
+        let processed_report = match create_report(
+            log,
+            context,
+            auth_user,
+            project.id,
+            json_report,
+            true,
+            #[cfg(feature = "plus")]
+            &mut usage,
+        )
+        .await
+        {
+            Ok((query_report, processed_report)) => {
+                query_reports.push(query_report);
+                processed_report
+            },
+            Err(e) => Err(e),
+        };
+        if processed_report.is_err() {
+            imported_reports = processed_report;
+            break;
+        }
+    }
+    let recomputed_boundaries = recompute_boundaries(log, conn_lock!(context), &query_reports);
+
     #[cfg(feature = "plus")]
     plan_kind
         .check_usage(context.biller.as_ref(), &project, usage)
         .await?;
 
-    // Don't return the error from processing the report until after the metrics usage has been checked
-    processed_report?;
-    // If the report was processed successfully, then return the report with the results
-    query_report.into_json(log, conn_lock!(context))
+    // Don't return the error from importing the reports until after the metrics usage has been checked
+    imported_reports?;
+    recomputed_boundaries?;
+
+    conn_lock!(context, |conn| query_reports
+        .into_iter()
+        .map(|query_report| query_report.into_json(log, conn))
+        .collect())
+}
+
+// The boundaries for any existing reports that started after the imported reports
+// were computed from a shorter history, without the imported reports.
+// So they are recomputed from the earliest imported report onward for each branch and testbed.
+fn recompute_boundaries(
+    log: &Logger,
+    conn: &mut DbConnection,
+    query_reports: &[QueryReport],
+) -> Result<(), HttpError> {
+    // The reports are imported in chronological order,
+    // so the first report for each branch and testbed is the earliest.
+    let mut since = HashMap::new();
+    for query_report in query_reports {
+        since
+            .entry((query_report.branch_id, query_report.testbed_id))
+            .or_insert(query_report.start_time);
+    }
+    let imported_reports = query_reports
+        .iter()
+        .map(|query_report| query_report.id)
+        .collect::<Vec<ReportId>>();
+    for ((branch_id, testbed_id), start_time) in since {
+        Detector::recompute(
+            log,
+            conn,
+            branch_id,
+            testbed_id,
+            start_time,
+            &imported_reports,
+        )?;
+    }
+    Ok(())
 }
 
 #[derive(Deserialize, JsonSchema)]
@@ -0,0 +1,272 @@
+use bencher_json::{JsonNewRetention, JsonRetention, JsonRetentionPrune, ResourceId};
+use bencher_rbac::project::Permission;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::{endpoint, HttpError, Path, RequestContext, TypedBody};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    endpoints::{
+        endpoint::{CorsResponse, Delete, Get, Post, Put, ResponseDeleted, ResponseOk},
+        Endpoint,
+    },
+    error::{resource_conflict_err, resource_not_found_err},
+    model::{
+        project::{
+            retention::{InsertRetention, QueryRetention, UpdateRetention},
+            QueryProject,
+        },
+        user::auth::{AuthUser, BearerToken},
+    },
+    schema,
+};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjRetentionParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/retention",
+    tags = ["projects", "retention"]
+}]
+pub async fn proj_retention_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjRetentionParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Put.into(), Delete.into()]))
+}
+
+/// View the retention policy for a project
+///
+/// View the retention policy for a project.
+/// The user must have `view` permissions for the project.
+/// If the project does not have a retention policy, then all of its data is kept forever.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/retention",
+    tags = ["projects", "retention"]
+}]
+pub async fn proj_retention_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjRetentionParams>,
+) -> Result<ResponseOk<JsonRetention>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_one_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: ProjRetentionParams,
+    auth_user: &AuthUser,
+) -> Result<JsonRetention, HttpError> {
+    conn_lock!(context, |conn| {
+        let query_project = QueryProject::is_allowed(
+            conn,
+            &context.rbac,
+            &path_params.project,
+            auth_user,
+            Permission::View,
+        )?;
+        QueryRetention::from_project(conn, query_project.id)?.into_json(conn, &query_project)
+    })
+}
+
+/// Set the retention policy for a project
+///
+/// Create or replace the retention policy for a project.
+/// The user must have `edit` permissions for the project.
+/// Metrics from reports older than the raw window are either downsampled
+/// to a single aggregated point per day per branch, testbed, benchmark, and measure or removed.
+/// The data for the tagged versions (versions with a git hash) of the protected branch is always kept.
+/// The retention policy is enforced hourly by the API server.
+#[endpoint {
+    method = PUT,
+    path =  "/v0/projects/{project}/retention",
+    tags = ["projects", "retention"]
+}]
+pub async fn proj_retention_put(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjRetentionParams>,
+    body: TypedBody<JsonNewRetention>,
+) -> Result<ResponseOk<JsonRetention>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = put_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Put::auth_response_ok(json))
+}
+
+async fn put_inner(
+    context: &ApiContext,
+    path_params: ProjRetentionParams,
+    json_retention: JsonNewRetention,
+    auth_user: &AuthUser,
+) -> Result<JsonRetention, HttpError> {
+    conn_lock!(context, |conn| {
+        let query_project = QueryProject::is_allowed(
+            conn,
+            &context.rbac,
+            &path_params.project,
+            auth_user,
+            Permission::Edit,
+        )?;
+
+        if let Ok(query_retention) = QueryRetention::from_project(conn, query_project.id) {
+            let update_retention =
+                UpdateRetention::from_json(conn, query_project.id, json_retention)?;
+            diesel::update(
+                schema::retention::table.filter(schema::retention::id.eq(query_retention.id)),
+            )
+            .set(&update_retention)
+            .execute(conn)
+            .map_err(resource_conflict_err!(Retention, update_retention))?;
+        } else {
+            let insert_retention =
+                InsertRetention::from_json(conn, query_project.id, json_retention)?;
+            diesel::insert_into(schema::retention::table)
+                .values(&insert_retention)
+                .execute(conn)
+                .map_err(resource_conflict_err!(Retention, insert_retention))?;
+        }
+
+        QueryRetention::from_project(conn, query_project.id)?.into_json(conn, &query_project)
+    })
+}
+
+/// Delete the retention policy for a project
+///
+/// Delete the retention policy for a project.
+/// The user must have `edit` permissions for the project.
+/// Any data that has already been removed is not restored,
+/// but all new data will be kept forever.
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/projects/{project}/retention",
+    tags = ["projects", "retention"]
+}]
+pub async fn proj_retention_delete(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjRetentionParams>,
+) -> Result<ResponseDeleted, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    delete_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Delete::auth_response_deleted())
+}
+
+async fn delete_inner(
+    context: &ApiContext,
+    path_params: ProjRetentionParams,
+    auth_user: &AuthUser,
+) -> Result<(), HttpError> {
+    conn_lock!(context, |conn| {
+        let query_project = QueryProject::is_allowed(
+            conn,
+            &context.rbac,
+            &path_params.project,
+            auth_user,
+            Permission::Edit,
+        )?;
+
+        let query_retention = QueryRetention::from_project(conn, query_project.id)?;
+        diesel::delete(
+            schema::retention::table.filter(schema::retention::id.eq(query_retention.id)),
+        )
+        .execute(conn)
+        .map_err(resource_not_found_err!(Retention, query_retention))?;
+
+        Ok(())
+    })
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/retention/prune",
+    tags = ["projects", "retention"]
+}]
+pub async fn proj_retention_prune_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjRetentionParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+}
+
+/// Dry run the retention policy for a project
+///
+/// Report what would be removed if the retention policy for a project was enforced now.
+/// The user must have `view` permissions for the project.
+/// Nothing is removed.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/retention/prune",
+    tags = ["projects", "retention"]
+}]
+pub async fn proj_retention_prune_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjRetentionParams>,
+) -> Result<ResponseOk<JsonRetentionPrune>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = prune_inner(rqctx.context(), path_params.into_inner(), &auth_user, true).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+/// Enforce the retention policy for a project
+///
+/// Enforce the retention policy for a project now,
+/// instead of waiting for the API server to enforce it.
+/// The user must have `delete` permissions for the project.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/retention/prune",
+    tags = ["projects", "retention"]
+}]
+pub async fn proj_retention_prune_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjRetentionParams>,
+) -> Result<ResponseOk<JsonRetentionPrune>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = prune_inner(rqctx.context(), path_params.into_inner(), &auth_user, false).await?;
+    Ok(Post::auth_response_ok(json))
+}
+
+async fn prune_inner(
+    context: &ApiContext,
+    path_params: ProjRetentionParams,
+    auth_user: &AuthUser,
+    dry_run: bool,
+) -> Result<JsonRetentionPrune, HttpError> {
+    let (query_project, query_retention) = conn_lock!(context, |conn| {
+        let query_project = QueryProject::is_allowed(
+            conn,
+            &context.rbac,
+            &path_params.project,
+            auth_user,
+            if dry_run {
+                Permission::View
+            } else {
+                Permission::Delete
+            },
+        )?;
+        let query_retention = QueryRetention::from_project(conn, query_project.id)?;
+        (query_project, query_retention)
+    });
+    query_retention
+        .prune(&context.database.pool, &query_project, dry_run)
+        .await
+}
@@ -30,7 +30,7 @@ use crate::{
         user::auth::BearerToken,
     },
     schema,
-    util::search::Search,
+    util::search::{lower, Search},
 };
 
 #[derive(Deserialize, JsonSchema)]
@@ -122,10 +122,10 @@ async fn get_ls_inner(
     }
     if let Some(search) = query_params.search.as_ref() {
         query = query.filter(
-            schema::testbed::name
+            lower(schema::testbed::name)
                 .like(search)
-                .or(schema::testbed::slug.like(search))
-                .or(schema::testbed::uuid.like(search)),
+                .or(lower(schema::testbed::slug).like(search))
+                .or(lower(schema::testbed::uuid).like(search)),
         );
     }
 
@@ -1,12 +1,20 @@
 use bencher_json::{
-    project::alert::{AlertStatus, JsonAlertStats, JsonUpdateAlert},
-    AlertUuid, JsonAlert, JsonAlerts, JsonDirection, JsonPagination, ResourceId,
+    project::{
+        alert::{AlertStatus, JsonAlertStats, JsonUpdateAlert},
+        webhook::WebhookEvent,
+    },
+    AlertUuid, JsonAlert, JsonAlertQuery, JsonAlertQueryParams, JsonAlerts, JsonDirection,
+    JsonPagination, ResourceId,
 };
 use bencher_rbac::project::Permission;
-use diesel::{dsl::count, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
+use diesel::{
+    BoolExpressionMethods, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper,
+    TextExpressionMethods,
+};
 use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
 use schemars::JsonSchema;
 use serde::Deserialize;
+use slog::Logger;
 
 use crate::{
     conn_lock,
@@ -15,16 +23,26 @@ use crate::{
         endpoint::{CorsResponse, Get, Patch, ResponseOk},
         Endpoint,
     },
-    error::{resource_conflict_err, resource_not_found_err},
+    error::{bad_request_error, resource_conflict_err, resource_not_found_err},
     model::user::auth::{AuthUser, PubBearerToken},
     model::{
         project::{
+            report::QueryReport,
             threshold::alert::{QueryAlert, UpdateAlert},
+            webhook::QueryWebhook,
             QueryProject,
         },
         user::auth::BearerToken,
+        user::QueryUser,
     },
     schema,
+    util::{
+        name_id::{
+            filter_benchmark_name_id, filter_branch_name_id, filter_measure_name_id,
+            filter_testbed_name_id,
+        },
+        search::{lower, Search},
+    },
 };
 
 #[derive(Deserialize, JsonSchema)]
@@ -55,6 +73,7 @@ pub async fn proj_alerts_options(
     _rqctx: RequestContext<ApiContext>,
     _path_params: Path<ProjAlertsParams>,
     _pagination_params: Query<ProjAlertsPagination>,
+    _query_params: Query<JsonAlertQueryParams>,
 ) -> Result<CorsResponse, HttpError> {
     Ok(Endpoint::cors(&[Get.into()]))
 }
@@ -64,7 +83,11 @@ pub async fn proj_alerts_options(
 /// List all alerts for a project.
 /// If the project is public, then the user does not need to be authenticated.
 /// If the project is private, then the user must be authenticated and have `view` permissions for the project.
-/// By default, the alerts are sorted by status (active then dismissed) and modification date time in reverse chronological order.
+/// The alerts can be filtered by status, assignee, branch, testbed, benchmark, measure,
+/// boundary limit, report, and report start time range.
+/// They can also be searched by benchmark name, slug, or UUID or by alert UUID.
+/// By default, the alerts are sorted by status (active, acknowledged, dismissed, silenced, then resolved)
+/// and modification date time in reverse chronological order.
 #[endpoint {
     method = GET,
     path =  "/v0/projects/{project}/alerts",
@@ -72,26 +95,35 @@ pub async fn proj_alerts_options(
 }]
 pub async fn proj_alerts_get(
     rqctx: RequestContext<ApiContext>,
-    bearer_token: PubBearerToken,
     path_params: Path<ProjAlertsParams>,
     pagination_params: Query<ProjAlertsPagination>,
+    query_params: Query<JsonAlertQueryParams>,
 ) -> Result<ResponseOk<JsonAlerts>, HttpError> {
-    let auth_user = AuthUser::from_pub_token(rqctx.context(), bearer_token).await?;
+    // Second round of marshaling
+    let json_alert_query = query_params
+        .into_inner()
+        .try_into()
+        .map_err(bad_request_error)?;
+
+    let auth_user = AuthUser::new_pub(&rqctx).await?;
     let json = get_ls_inner(
         rqctx.context(),
         auth_user.as_ref(),
         path_params.into_inner(),
         pagination_params.into_inner(),
+        json_alert_query,
     )
     .await?;
     Ok(Get::response_ok(json, auth_user.is_some()))
 }
 
+#[allow(clippy::too_many_lines)]
 async fn get_ls_inner(
     context: &ApiContext,
     auth_user: Option<&AuthUser>,
     path_params: ProjAlertsParams,
     pagination_params: ProjAlertsPagination,
+    json_alert_query: JsonAlertQuery,
 ) -> Result<JsonAlerts, HttpError> {
     let query_project = QueryProject::is_allowed_public(
         conn_lock!(context),
@@ -100,29 +132,84 @@ async fn get_ls_inner(
         auth_user,
     )?;
 
+    let search = json_alert_query.search.map(Search::from);
     let mut query = schema::alert::table
         .inner_join(
             schema::boundary::table.inner_join(
-                schema::metric::table.inner_join(
-                    schema::report_benchmark::table
-                        .inner_join(schema::report::table)
-                        .inner_join(schema::benchmark::table),
-                ),
+                schema::metric::table
+                    .inner_join(
+                        schema::report_benchmark::table
+                            .inner_join(
+                                schema::report::table
+                                    .inner_join(schema::branch::table)
+                                    .inner_join(schema::testbed::table),
+                            )
+                            .inner_join(schema::benchmark::table),
+                    )
+                    .inner_join(schema::measure::table),
             ),
         )
         .filter(schema::benchmark::project_id.eq(query_project.id))
         .select(QueryAlert::as_select())
         .into_boxed();
 
+    if let Some(status) = json_alert_query.status {
+        query = query.filter(schema::alert::status.eq(status));
+    }
+    if let Some(assignee) = json_alert_query.assignee.as_ref() {
+        let assignee_id = QueryUser::from_resource_id(conn_lock!(context), assignee)?.id;
+        query = query.filter(schema::alert::assignee_id.eq(assignee_id));
+    }
+    if let Some(branch) = json_alert_query.branch.as_ref() {
+        filter_branch_name_id!(query, branch);
+    }
+    if let Some(testbed) = json_alert_query.testbed.as_ref() {
+        filter_testbed_name_id!(query, testbed);
+    }
+    if let Some(benchmark) = json_alert_query.benchmark.as_ref() {
+        filter_benchmark_name_id!(query, benchmark);
+    }
+    if let Some(measure) = json_alert_query.measure.as_ref() {
+        filter_measure_name_id!(query, measure);
+    }
+    if let Some(limit) = json_alert_query.limit {
+        query = query.filter(schema::alert::boundary_limit.eq(limit));
+    }
+    if let Some(report) = json_alert_query.report {
+        query = query.filter(schema::report::uuid.eq(report));
+    }
+    if let Some(start_time) = json_alert_query.start_time {
+        query = query.filter(schema::report::start_time.ge(start_time));
+    }
+    if let Some(end_time) = json_alert_query.end_time {
+        query = query.filter(schema::report::start_time.le(end_time));
+    }
+    if let Some(search) = search.as_ref() {
+        query = query.filter(
+            lower(schema::benchmark::name)
+                .like(search)
+                .or(lower(schema::benchmark::slug).like(search))
+                .or(lower(schema::benchmark::uuid).like(search))
+                .or(lower(schema::alert::uuid).like(search)),
+        );
+    }
+
+    // Open alerts (active and acknowledged) are always listed first,
+    // followed by the closed alerts in order of their status.
+    let open_first = schema::alert::status
+        .eq_any([AlertStatus::Active, AlertStatus::Acknowledged])
+        .desc();
     query = match pagination_params.order() {
         ProjAlertsSort::Created => match pagination_params.direction {
             Some(JsonDirection::Asc) | None => query.order((
+                open_first,
                 schema::alert::status.asc(),
                 schema::report::start_time.asc(),
                 schema::benchmark::name.asc(),
                 schema::report_benchmark::iteration.asc(),
             )),
             Some(JsonDirection::Desc) => query.order((
+                open_first,
                 schema::alert::status.asc(),
                 schema::report::start_time.desc(),
                 schema::benchmark::name.asc(),
@@ -131,12 +218,14 @@ async fn get_ls_inner(
         },
         ProjAlertsSort::Modified => match pagination_params.direction {
             Some(JsonDirection::Asc) => query.order((
+                open_first,
                 schema::alert::status.asc(),
                 schema::alert::modified.asc(),
                 schema::benchmark::name.asc(),
                 schema::report_benchmark::iteration.asc(),
             )),
             Some(JsonDirection::Desc) | None => query.order((
+                open_first,
                 schema::alert::status.asc(),
                 schema::alert::modified.desc(),
                 schema::benchmark::name.asc(),
@@ -233,7 +322,8 @@ async fn get_one_inner(
 ///
 /// Update an alert for a project.
 /// The user must have `edit` permissions for the project.
-/// Use this endpoint to dismiss an alert.
+/// Use this endpoint to acknowledge, dismiss, or reactivate an alert,
+/// to assign it to a member of the project's organization, or to leave a note on it.
 #[endpoint {
     method = PATCH,
     path =  "/v0/projects/{project}/alerts/{alert}",
@@ -247,6 +337,7 @@ pub async fn proj_alert_patch(
 ) -> Result<ResponseOk<JsonAlert>, HttpError> {
     let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
     let json = patch_inner(
+        &rqctx.log,
         rqctx.context(),
         path_params.into_inner(),
         body.into_inner(),
@@ -257,6 +348,7 @@ pub async fn proj_alert_patch(
 }
 
 async fn patch_inner(
+    log: &Logger,
     context: &ApiContext,
     path_params: ProjAlertParams,
     json_alert: JsonUpdateAlert,
@@ -273,14 +365,39 @@ async fn patch_inner(
 
     let query_alert =
         QueryAlert::from_uuid(conn_lock!(context), query_project.id, path_params.alert)?;
-    let update_alert = UpdateAlert::from(json_alert.clone());
+    let update_alert =
+        UpdateAlert::from_json(conn_lock!(context), &query_project, json_alert.clone())?;
     diesel::update(schema::alert::table.filter(schema::alert::id.eq(query_alert.id)))
         .set(&update_alert)
         .execute(conn_lock!(context))
         .map_err(resource_conflict_err!(Alert, (&query_alert, &json_alert)))?;
 
-    conn_lock!(context, |conn| QueryAlert::get(conn, query_alert.id)?
-        .into_json(conn))
+    let json_alert = conn_lock!(context, |conn| QueryAlert::get(conn, query_alert.id)?
+        .into_json(conn))?;
+
+    // Notify any webhooks for the project if the alert was just dismissed
+    if matches!(
+        query_alert.status,
+        AlertStatus::Active | AlertStatus::Acknowledged
+    ) && matches!(json_alert.status, AlertStatus::Dismissed)
+    {
+        let json_report = schema::report::table
+            .filter(schema::report::uuid.eq(json_alert.report))
+            .first::<QueryReport>(conn_lock!(context))
+            .map_err(resource_not_found_err!(Report, json_alert.report))?
+            .into_json(log, conn_lock!(context))?;
+        QueryWebhook::send(
+            log,
+            context,
+            &query_project,
+            WebhookEvent::AlertDismissed,
+            &json_report,
+            Some(&json_alert),
+        )
+        .await;
+    }
+
+    Ok(json_alert)
 }
 
 #[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
@@ -335,20 +452,9 @@ async fn get_stats_inner(
         auth_user,
     )?;
 
-    let active =
-        schema::alert::table
-            .filter(schema::alert::status.eq(AlertStatus::Active))
-            .inner_join(schema::boundary::table.inner_join(
-                schema::metric::table.inner_join(
-                    schema::report_benchmark::table.inner_join(schema::benchmark::table),
-                ),
-            ))
-            .filter(schema::benchmark::project_id.eq(query_project.id))
-            .select(count(schema::alert::id))
-            .first::<i64>(conn_lock!(context))
-            .map_err(resource_not_found_err!(Alert, query_project))?;
+    let active = QueryAlert::active_count(conn_lock!(context), &query_project)?;
 
     Ok(JsonAlertStats {
-        active: u64::try_from(active).unwrap_or_default().into(),
+        active: active.into(),
     })
 }
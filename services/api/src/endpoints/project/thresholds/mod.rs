@@ -39,6 +39,7 @@ use crate::{
 };
 
 pub mod alerts;
+pub mod silences;
 
 #[derive(Deserialize, JsonSchema)]
 pub struct ProjThresholdsParams {
@@ -137,6 +138,9 @@ async fn get_ls_inner(
     if let Some(measure) = json_threshold_query.measure.as_ref() {
         filter_measure_name_id!(query, measure);
     }
+    if let Some(group) = json_threshold_query.group.as_ref() {
+        query = query.filter(schema::threshold::benchmark_group.eq(group));
+    }
 
     query = match pagination_params.order() {
         ProjThresholdsSort::Created => match pagination_params.direction {
@@ -172,7 +176,7 @@ async fn get_ls_inner(
 ///
 /// Create a threshold for a project.
 /// The user must have `create` permissions for the project.
-/// There can only be one threshold for any unique combination of: branch, testbed, and measure.
+/// There can only be one threshold for any unique combination of: branch, testbed, measure, and benchmark group.
 #[endpoint {
     method = POST,
     path =  "/v0/projects/{project}/thresholds",
@@ -229,6 +233,7 @@ async fn post_inner(
         branch_id,
         testbed_id,
         measure_id,
+        json_threshold.group.clone(),
         json_threshold.model,
     )?;
 
@@ -0,0 +1,312 @@
+use bencher_json::{
+    DateTime, JsonDirection, JsonNewSilence, JsonPagination, JsonSilence, JsonSilences, ResourceId,
+    SilenceUuid,
+};
+use bencher_rbac::project::Permission;
+use diesel::{BelongingToDsl, BoolExpressionMethods, ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    endpoints::{
+        endpoint::{CorsResponse, Delete, Get, Post, ResponseCreated, ResponseDeleted, ResponseOk},
+        Endpoint,
+    },
+    error::{resource_conflict_err, resource_not_found_err},
+    model::{
+        project::{
+            threshold::silence::{InsertSilence, QuerySilence},
+            QueryProject,
+        },
+        user::auth::{AuthUser, BearerToken, PubBearerToken},
+    },
+    schema,
+};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjSilencesParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+}
+
+pub type ProjSilencesPagination = JsonPagination<ProjSilencesSort>;
+
+#[derive(Clone, Copy, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjSilencesSort {
+    /// Sort by silence creation date time.
+    #[default]
+    Created,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjSilencesQuery {
+    /// If set to `true`, also return expired silences.
+    /// Otherwise, only unexpired silences are returned.
+    pub expired: Option<bool>,
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/silences",
+    tags = ["projects", "alerts"]
+}]
+pub async fn proj_silences_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjSilencesParams>,
+    _pagination_params: Query<ProjSilencesPagination>,
+    _query_params: Query<ProjSilencesQuery>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+}
+
+/// List alert silences for a project
+///
+/// List all alert silences for a project.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+/// By default, only unexpired silences are returned, sorted by their creation date time in reverse chronological order.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/silences",
+    tags = ["projects", "alerts"]
+}]
+pub async fn proj_silences_get(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<ProjSilencesParams>,
+    pagination_params: Query<ProjSilencesPagination>,
+    query_params: Query<ProjSilencesQuery>,
+) -> Result<ResponseOk<JsonSilences>, HttpError> {
+    let auth_user = AuthUser::new_pub(&rqctx).await?;
+    let json = get_ls_inner(
+        rqctx.context(),
+        auth_user.as_ref(),
+        path_params.into_inner(),
+        pagination_params.into_inner(),
+        query_params.into_inner(),
+    )
+    .await?;
+    Ok(Get::response_ok(json, auth_user.is_some()))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    auth_user: Option<&AuthUser>,
+    path_params: ProjSilencesParams,
+    pagination_params: ProjSilencesPagination,
+    query_params: ProjSilencesQuery,
+) -> Result<JsonSilences, HttpError> {
+    let query_project = QueryProject::is_allowed_public(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+    )?;
+
+    let mut query = QuerySilence::belonging_to(&query_project).into_boxed();
+
+    if query_params.expired != Some(true) {
+        query = query.filter(
+            schema::silence::expires
+                .is_null()
+                .or(schema::silence::expires.gt(DateTime::now())),
+        );
+    }
+
+    query = match pagination_params.order() {
+        ProjSilencesSort::Created => match pagination_params.direction {
+            Some(JsonDirection::Asc) => query.order(schema::silence::created.asc()),
+            Some(JsonDirection::Desc) | None => query.order(schema::silence::created.desc()),
+        },
+    };
+
+    conn_lock!(context, |conn| Ok(query
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<QuerySilence>(conn)
+        .map_err(resource_not_found_err!(Silence, &query_project))?
+        .into_iter()
+        .filter_map(
+            |silence| match silence.into_json_for_project(conn, &query_project) {
+                Ok(silence) => Some(silence),
+                Err(err) => {
+                    debug_assert!(false, "{err}");
+                    #[cfg(feature = "sentry")]
+                    sentry::capture_error(&err);
+                    None
+                },
+            }
+        )
+        .collect()))
+}
+
+/// Create an alert silence
+///
+/// Create an alert silence for a project.
+/// The user must have `create` permissions for the project.
+/// Until the silence expires, any new alert for a matching benchmark, measure, and testbed
+/// is created with a `silenced` status instead of being `active`.
+/// Silenced alerts are not included in reports and do not trigger webhooks.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/silences",
+    tags = ["projects", "alerts"]
+}]
+pub async fn proj_silence_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjSilencesParams>,
+    body: TypedBody<JsonNewSilence>,
+) -> Result<ResponseCreated<JsonSilence>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Post::auth_response_created(json))
+}
+
+async fn post_inner(
+    context: &ApiContext,
+    path_params: ProjSilencesParams,
+    json_silence: JsonNewSilence,
+    auth_user: &AuthUser,
+) -> Result<JsonSilence, HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Create,
+    )?;
+
+    let insert_silence =
+        InsertSilence::from_json(conn_lock!(context), query_project.id, json_silence)?;
+    diesel::insert_into(schema::silence::table)
+        .values(&insert_silence)
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(Silence, insert_silence))?;
+
+    conn_lock!(context, |conn| schema::silence::table
+        .filter(schema::silence::uuid.eq(&insert_silence.uuid))
+        .first::<QuerySilence>(conn)
+        .map_err(resource_not_found_err!(Silence, insert_silence))?
+        .into_json_for_project(conn, &query_project))
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjSilenceParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+    /// The UUID for an alert silence.
+    pub silence: SilenceUuid,
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/silences/{silence}",
+    tags = ["projects", "alerts"]
+}]
+pub async fn proj_silence_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjSilenceParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Delete.into()]))
+}
+
+/// View an alert silence
+///
+/// View an alert silence for a project.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/silences/{silence}",
+    tags = ["projects", "alerts"]
+}]
+pub async fn proj_silence_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubBearerToken,
+    path_params: Path<ProjSilenceParams>,
+) -> Result<ResponseOk<JsonSilence>, HttpError> {
+    let auth_user = AuthUser::from_pub_token(rqctx.context(), bearer_token).await?;
+    let json = get_one_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        auth_user.as_ref(),
+    )
+    .await?;
+    Ok(Get::response_ok(json, auth_user.is_some()))
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: ProjSilenceParams,
+    auth_user: Option<&AuthUser>,
+) -> Result<JsonSilence, HttpError> {
+    let query_project = QueryProject::is_allowed_public(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+    )?;
+
+    conn_lock!(context, |conn| QuerySilence::from_uuid(
+        conn,
+        query_project.id,
+        path_params.silence
+    )?
+    .into_json_for_project(conn, &query_project))
+}
+
+/// Delete an alert silence
+///
+/// Delete an alert silence for a project.
+/// The user must have `delete` permissions for the project.
+/// Alerts that were already silenced keep their `silenced` status.
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/projects/{project}/silences/{silence}",
+    tags = ["projects", "alerts"]
+}]
+pub async fn proj_silence_delete(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjSilenceParams>,
+) -> Result<ResponseDeleted, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    delete_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Delete::auth_response_deleted())
+}
+
+async fn delete_inner(
+    context: &ApiContext,
+    path_params: ProjSilenceParams,
+    auth_user: &AuthUser,
+) -> Result<(), HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Delete,
+    )?;
+
+    let query_silence =
+        QuerySilence::from_uuid(conn_lock!(context), query_project.id, path_params.silence)?;
+    diesel::delete(schema::silence::table.filter(schema::silence::id.eq(query_silence.id)))
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(Silence, query_silence))?;
+
+    Ok(())
+}
@@ -0,0 +1,433 @@
+use bencher_json::{
+    project::webhook::JsonUpdateWebhook, JsonDirection, JsonNewWebhook, JsonPagination,
+    JsonWebhook, JsonWebhookDeliveries, JsonWebhooks, ResourceId, WebhookUuid,
+};
+use bencher_rbac::project::Permission;
+use diesel::{BelongingToDsl, ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::{endpoint, HttpError, Path, Query, RequestContext, TypedBody};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    endpoints::{
+        endpoint::{
+            CorsResponse, Delete, Get, Patch, Post, ResponseCreated, ResponseDeleted, ResponseOk,
+        },
+        Endpoint,
+    },
+    error::{bad_request_error, resource_conflict_err, resource_not_found_err},
+    model::{
+        project::{
+            webhook::{
+                delivery::{check_url, QueryWebhookDelivery},
+                InsertWebhook, QueryWebhook, UpdateWebhook,
+            },
+            QueryProject,
+        },
+        user::auth::{AuthUser, BearerToken},
+    },
+    schema,
+};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjWebhooksParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+}
+
+pub type ProjWebhooksPagination = JsonPagination<ProjWebhooksSort>;
+
+#[derive(Clone, Copy, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjWebhooksSort {
+    /// Sort by webhook creation date time.
+    #[default]
+    Created,
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/webhooks",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhooks_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjWebhooksParams>,
+    _pagination_params: Query<ProjWebhooksPagination>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+}
+
+/// List webhooks for a project
+///
+/// List all webhooks for a project.
+/// The user must have `view` permissions for the project.
+/// Webhook secrets are never returned.
+/// By default, the webhooks are sorted by their creation date time in chronological order.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/webhooks",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhooks_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhooksParams>,
+    pagination_params: Query<ProjWebhooksPagination>,
+) -> Result<ResponseOk<JsonWebhooks>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_ls_inner(
+        rqctx.context(),
+        &auth_user,
+        path_params.into_inner(),
+        pagination_params.into_inner(),
+    )
+    .await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    auth_user: &AuthUser,
+    path_params: ProjWebhooksParams,
+    pagination_params: ProjWebhooksPagination,
+) -> Result<JsonWebhooks, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::View,
+    )?;
+
+    let mut query = QueryWebhook::belonging_to(&query_project).into_boxed();
+
+    query = match pagination_params.order() {
+        ProjWebhooksSort::Created => match pagination_params.direction {
+            Some(JsonDirection::Asc) | None => query.order(schema::webhook::created.asc()),
+            Some(JsonDirection::Desc) => query.order(schema::webhook::created.desc()),
+        },
+    };
+
+    Ok(query
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<QueryWebhook>(conn_lock!(context))
+        .map_err(resource_not_found_err!(Webhook, &query_project))?
+        .into_iter()
+        .map(|webhook| webhook.into_json_for_project(&query_project))
+        .collect())
+}
+
+/// Create a webhook
+///
+/// Create a webhook for a project.
+/// The user must have `create` permissions for the project.
+/// The webhook will be sent a signed JSON payload for each of its events.
+/// The webhook URL must use `http` or `https`, and it can't be a loopback, link-local, or private network address unless the server allows private webhooks.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/webhooks",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhooksParams>,
+    body: TypedBody<JsonNewWebhook>,
+) -> Result<ResponseCreated<JsonWebhook>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Post::auth_response_created(json))
+}
+
+async fn post_inner(
+    context: &ApiContext,
+    path_params: ProjWebhooksParams,
+    json_webhook: JsonNewWebhook,
+    auth_user: &AuthUser,
+) -> Result<JsonWebhook, HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Create,
+    )?;
+
+    check_url(&json_webhook.url, context.allow_private_webhooks).map_err(bad_request_error)?;
+    let insert_webhook = InsertWebhook::from_json(query_project.id, json_webhook);
+    diesel::insert_into(schema::webhook::table)
+        .values(&insert_webhook)
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(Webhook, insert_webhook))?;
+
+    schema::webhook::table
+        .filter(schema::webhook::uuid.eq(&insert_webhook.uuid))
+        .first::<QueryWebhook>(conn_lock!(context))
+        .map(|webhook| webhook.into_json_for_project(&query_project))
+        .map_err(resource_not_found_err!(Webhook, insert_webhook))
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjWebhookParams {
+    /// The slug or UUID for a project.
+    pub project: ResourceId,
+    /// The UUID for a webhook.
+    pub webhook: WebhookUuid,
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjWebhookParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Patch.into(), Delete.into()]))
+}
+
+/// View a webhook
+///
+/// View a webhook for a project.
+/// The user must have `view` permissions for the project.
+/// The webhook secret is never returned.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhookParams>,
+) -> Result<ResponseOk<JsonWebhook>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_one_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: ProjWebhookParams,
+    auth_user: &AuthUser,
+) -> Result<JsonWebhook, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::View,
+    )?;
+
+    QueryWebhook::from_uuid(conn_lock!(context), query_project.id, path_params.webhook)
+        .map(|webhook| webhook.into_json_for_project(&query_project))
+}
+
+/// Update a webhook
+///
+/// Update a webhook for a project.
+/// The user must have `edit` permissions for the project.
+/// The webhook URL must use `http` or `https`, and it can't be a loopback, link-local, or private network address unless the server allows private webhooks.
+#[endpoint {
+    method = PATCH,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_patch(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhookParams>,
+    body: TypedBody<JsonUpdateWebhook>,
+) -> Result<ResponseOk<JsonWebhook>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = patch_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Patch::auth_response_ok(json))
+}
+
+async fn patch_inner(
+    context: &ApiContext,
+    path_params: ProjWebhookParams,
+    json_webhook: JsonUpdateWebhook,
+    auth_user: &AuthUser,
+) -> Result<JsonWebhook, HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Edit,
+    )?;
+
+    let query_webhook =
+        QueryWebhook::from_uuid(conn_lock!(context), query_project.id, path_params.webhook)?;
+    if let Some(url) = json_webhook.url.as_ref() {
+        check_url(url, context.allow_private_webhooks).map_err(bad_request_error)?;
+    }
+    let update_webhook = UpdateWebhook::from(json_webhook.clone());
+    diesel::update(schema::webhook::table.filter(schema::webhook::id.eq(query_webhook.id)))
+        .set(&update_webhook)
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(
+            Webhook,
+            (&query_webhook, &json_webhook)
+        ))?;
+
+    QueryWebhook::get(conn_lock!(context), query_webhook.id)
+        .map(|webhook| webhook.into_json_for_project(&query_project))
+        .map_err(resource_not_found_err!(Webhook, query_webhook))
+}
+
+/// Delete a webhook
+///
+/// Delete a webhook for a project.
+/// The user must have `delete` permissions for the project.
+/// The delivery log for the webhook is also deleted.
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_delete(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhookParams>,
+) -> Result<ResponseDeleted, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    delete_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Delete::auth_response_deleted())
+}
+
+async fn delete_inner(
+    context: &ApiContext,
+    path_params: ProjWebhookParams,
+    auth_user: &AuthUser,
+) -> Result<(), HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Delete,
+    )?;
+
+    let query_webhook =
+        QueryWebhook::from_uuid(conn_lock!(context), query_project.id, path_params.webhook)?;
+    diesel::delete(schema::webhook::table.filter(schema::webhook::id.eq(query_webhook.id)))
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(Webhook, query_webhook))?;
+
+    Ok(())
+}
+
+pub type ProjWebhookDeliveriesPagination = JsonPagination<ProjWebhookDeliveriesSort>;
+
+#[derive(Clone, Copy, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjWebhookDeliveriesSort {
+    /// Sort by delivery creation date time.
+    #[default]
+    Created,
+}
+
+#[allow(clippy::no_effect_underscore_binding, clippy::unused_async)]
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/webhooks/{webhook}/deliveries",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_deliveries_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjWebhookParams>,
+    _pagination_params: Query<ProjWebhookDeliveriesPagination>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// List deliveries for a webhook
+///
+/// List the delivery log for a webhook.
+/// The user must have `view` permissions for the project.
+/// Each delivery records the event, the number of attempts, and the result of the most recent attempt.
+/// By default, the deliveries are sorted by their creation date time in reverse chronological order.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/webhooks/{webhook}/deliveries",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_deliveries_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhookParams>,
+    pagination_params: Query<ProjWebhookDeliveriesPagination>,
+) -> Result<ResponseOk<JsonWebhookDeliveries>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_deliveries_inner(
+        rqctx.context(),
+        &auth_user,
+        path_params.into_inner(),
+        pagination_params.into_inner(),
+    )
+    .await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_deliveries_inner(
+    context: &ApiContext,
+    auth_user: &AuthUser,
+    path_params: ProjWebhookParams,
+    pagination_params: ProjWebhookDeliveriesPagination,
+) -> Result<JsonWebhookDeliveries, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::View,
+    )?;
+    let query_webhook =
+        QueryWebhook::from_uuid(conn_lock!(context), query_project.id, path_params.webhook)?;
+
+    let mut query = schema::webhook_delivery::table
+        .filter(schema::webhook_delivery::webhook_id.eq(query_webhook.id))
+        .into_boxed();
+
+    query = match pagination_params.order() {
+        ProjWebhookDeliveriesSort::Created => match pagination_params.direction {
+            Some(JsonDirection::Asc) => query.order(schema::webhook_delivery::created.asc()),
+            Some(JsonDirection::Desc) | None => {
+                query.order(schema::webhook_delivery::created.desc())
+            },
+        },
+    };
+
+    Ok(query
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<QueryWebhookDelivery>(conn_lock!(context))
+        .map_err(resource_not_found_err!(WebhookDelivery, &query_webhook))?
+        .into_iter()
+        .map(|delivery| delivery.into_json_for_webhook(&query_webhook))
+        .collect())
+}
@@ -12,7 +12,7 @@ use tokio::io::{AsyncWriteExt, BufReader};
 
 use crate::{
     conn_lock,
-    context::ApiContext,
+    context::{ApiContext, DbConnection},
     endpoints::{
         endpoint::{CorsResponse, Post, ResponseCreated},
         Endpoint,
@@ -66,6 +66,8 @@ async fn post_inner(
 
 #[derive(Debug, thiserror::Error)]
 pub enum BackupError {
+    #[error("Server backups are only supported for SQLite. Use the backups for your PostgreSQL server instead.")]
+    Postgres,
     #[error("Failed to batch execute: {0}")]
     BatchExecute(diesel::result::Error),
     #[error("Failed to create backup file: {0}")]
@@ -154,8 +156,11 @@ async fn backup_database(context: &ApiContext) -> Result<Backup, BackupError> {
     let file_path_str = file_path.to_string_lossy();
     let query = format!("VACUUM INTO '{file_path_str}'");
 
-    conn_lock!(context)
-        .batch_execute(&query)
+    let conn = conn_lock!(context);
+    if matches!(conn, DbConnection::Postgres(_)) {
+        return Err(BackupError::Postgres);
+    }
+    conn.batch_execute(&query)
         .map_err(BackupError::BatchExecute)?;
 
     Ok(Backup {
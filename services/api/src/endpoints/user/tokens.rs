@@ -27,7 +27,7 @@ use crate::{
         },
     },
     schema,
-    util::search::Search,
+    util::search::{lower, Search},
 };
 
 #[derive(Deserialize, JsonSchema)]
@@ -116,9 +116,9 @@ async fn get_ls_inner(
     }
     if let Some(search) = query_params.search.as_ref() {
         query = query.filter(
-            schema::token::name
+            lower(schema::token::name)
                 .like(search)
-                .or(schema::token::uuid.like(search)),
+                .or(lower(schema::token::uuid).like(search)),
         );
     }
 
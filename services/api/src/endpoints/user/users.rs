@@ -22,7 +22,7 @@ use crate::{
         same_user, QueryUser, UpdateUser,
     },
     schema,
-    util::search::Search,
+    util::search::{lower, Search},
 };
 
 pub type UsersPagination = JsonPagination<UsersSort>;
@@ -94,10 +94,10 @@ async fn get_ls_inner(
     }
     if let Some(search) = query_params.search.as_ref() {
         query = query.filter(
-            schema::user::name
+            lower(schema::user::name)
                 .like(search)
-                .or(schema::user::slug.like(search))
-                .or(schema::user::uuid.like(search)),
+                .or(lower(schema::user::slug).like(search))
+                .or(lower(schema::user::uuid).like(search)),
         );
     }
 
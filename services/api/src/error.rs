@@ -24,6 +24,10 @@ pub enum BencherResource {
     Model,
     Boundary,
     Alert,
+    Silence,
+    Webhook,
+    WebhookDelivery,
+    Retention,
     User,
     Token,
     #[cfg(feature = "plus")]
@@ -55,6 +59,10 @@ impl fmt::Display for BencherResource {
                 Self::Model => "Model",
                 Self::Boundary => "Boundary",
                 Self::Alert => "Alert",
+                Self::Silence => "Silence",
+                Self::Webhook => "Webhook",
+                Self::WebhookDelivery => "Webhook Delivery",
+                Self::Retention => "Retention",
                 Self::User => "User",
                 Self::Token => "Token",
                 #[cfg(feature = "plus")]
@@ -237,3 +245,37 @@ pub fn assert_parentage<Id>(
         sentry::capture_error(&err);
     }
 }
+
+// An error from within a database transaction.
+// Diesel requires that the error for a transaction can be created from a Diesel error.
+#[derive(Debug)]
+pub enum TransactionError {
+    Http(HttpError),
+    Transaction(diesel::result::Error),
+}
+
+impl From<HttpError> for TransactionError {
+    fn from(error: HttpError) -> Self {
+        Self::Http(error)
+    }
+}
+
+impl From<diesel::result::Error> for TransactionError {
+    fn from(error: diesel::result::Error) -> Self {
+        Self::Transaction(error)
+    }
+}
+
+impl From<TransactionError> for HttpError {
+    fn from(error: TransactionError) -> Self {
+        match error {
+            TransactionError::Http(error) => error,
+            TransactionError::Transaction(error) => issue_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to complete database transaction",
+                "Failed to complete database transaction.",
+                error,
+            ),
+        }
+    }
+}
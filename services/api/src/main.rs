@@ -9,6 +9,8 @@ use bencher_api::{
 use bencher_json::system::config::JsonLitestream;
 #[cfg(feature = "sentry")]
 use sentry::ClientInitGuard;
+#[cfg(feature = "plus")]
+use slog::warn;
 use slog::{error, info, Logger};
 #[cfg(feature = "plus")]
 use tokio::process::Command;
@@ -67,11 +69,20 @@ async fn run(
 
         let (restart_tx, mut restart_rx) = tokio::sync::mpsc::channel(1);
         #[cfg(feature = "plus")]
-        if let Some(litestream) = config
+        let litestream = config
             .plus
             .as_ref()
-            .and_then(|plus| plus.litestream.clone())
-        {
+            .and_then(|plus| plus.litestream.clone());
+        // Litestream only replicates SQLite databases
+        #[cfg(feature = "plus")]
+        if litestream.is_some() && config.database.postgres.is_some() {
+            warn!(
+                log,
+                "Litestream is not supported with PostgreSQL. Skipping replication."
+            );
+        }
+        #[cfg(feature = "plus")]
+        if let Some(litestream) = litestream.filter(|_| config.database.postgres.is_none()) {
             let (replicate_tx, replicate_rx) = tokio::sync::oneshot::channel();
             let mut litestream_handle = run_litestream(log, &config, litestream, replicate_tx)?;
             // Wait for Litestream to start replicating
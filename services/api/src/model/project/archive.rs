@@ -0,0 +1,1006 @@
+use std::{collections::HashMap, fmt, hash::Hash};
+
+use bencher_json::{
+    project::{
+        archive::{
+            JsonArchiveAlert, JsonArchiveBenchmark, JsonArchiveBoundary, JsonArchiveBranch,
+            JsonArchiveMeasure, JsonArchiveMetric, JsonArchiveModel, JsonArchiveProject,
+            JsonArchiveReport, JsonArchiveReportBenchmark, JsonArchiveRetention,
+            JsonArchiveSilence, JsonArchiveStartPoint, JsonArchiveTestbed, JsonArchiveThreshold,
+            JsonArchiveVersion, PROJECT_ARCHIVE_VERSION,
+        },
+        Visibility,
+    },
+    DateTime, JsonProjectArchive, MeasureUuid, ModelUuid, ThresholdUuid,
+};
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl, SelectableHelper,
+};
+use dropshot::HttpError;
+use http::StatusCode;
+
+use crate::{
+    context::DbConnection,
+    error::{
+        bad_request_error, issue_error, resource_conflict_err, resource_not_found_err,
+        resource_not_found_error, BencherResource,
+    },
+    model::{organization::QueryOrganization, user::UserId},
+    schema,
+    util::slug::ok_slug,
+};
+
+use super::{
+    benchmark::{BenchmarkTags, InsertBenchmark, QueryBenchmark},
+    branch::{InsertBranch, QueryBranch},
+    branch_version::{BranchVersionId, InsertBranchVersion, QueryBranchVersion},
+    measure::{InsertMeasure, MeasureId, QueryMeasure},
+    metric::{InsertMetric, QueryMetric},
+    report::{
+        report_benchmark::{InsertReportBenchmark, QueryReportBenchmark, ReportBenchmarkId},
+        InsertReport, QueryReport,
+    },
+    retention::{InsertRetention, QueryRetention},
+    testbed::{InsertTestbed, QueryTestbed},
+    threshold::{
+        alert::{InsertAlert, QueryAlert},
+        boundary::{InsertBoundary, QueryBoundary},
+        model::{InsertModel, ModelId, QueryModel},
+        silence::{InsertSilence, QuerySilence},
+        InsertThreshold, QueryThreshold, ThresholdId,
+    },
+    version::{InsertVersion, QueryVersion},
+    InsertProject, ProjectId, QueryProject,
+};
+
+// Export all of the data for a project into a portable archive.
+// All of the references between items are converted from their database IDs to their UUIDs.
+#[allow(clippy::too_many_lines)]
+pub fn export(
+    conn: &mut DbConnection,
+    query_project: &QueryProject,
+) -> Result<JsonProjectArchive, HttpError> {
+    let project_id = query_project.id;
+
+    let branches = schema::branch::table
+        .filter(schema::branch::project_id.eq(project_id))
+        .order(schema::branch::created.asc())
+        .load::<QueryBranch>(conn)
+        .map_err(resource_not_found_err!(Branch, query_project))?;
+    let branch_uuids = branches
+        .iter()
+        .map(|branch| (branch.id, branch.uuid))
+        .collect::<HashMap<_, _>>();
+
+    let versions = schema::version::table
+        .filter(schema::version::project_id.eq(project_id))
+        .order(schema::version::number.asc())
+        .load::<QueryVersion>(conn)
+        .map_err(resource_not_found_err!(Version, query_project))?;
+    let version_uuids = versions
+        .iter()
+        .map(|version| (version.id, version.uuid))
+        .collect::<HashMap<_, _>>();
+
+    let branch_versions = schema::branch_version::table
+        .inner_join(schema::version::table)
+        .filter(schema::version::project_id.eq(project_id))
+        .select((
+            schema::branch_version::id,
+            schema::branch_version::branch_id,
+            schema::branch_version::version_id,
+        ))
+        .load::<QueryBranchVersion>(conn)
+        .map_err(resource_not_found_err!(BranchVersion, query_project))?;
+    let mut start_points = HashMap::with_capacity(branch_versions.len());
+    let mut version_branches = HashMap::<_, Vec<_>>::with_capacity(versions.len());
+    for branch_version in branch_versions {
+        let branch = export_uuid(
+            &branch_uuids,
+            BencherResource::Branch,
+            branch_version.branch_id,
+        )?;
+        let version = export_uuid(
+            &version_uuids,
+            BencherResource::Version,
+            branch_version.version_id,
+        )?;
+        start_points.insert(branch_version.id, JsonArchiveStartPoint { branch, version });
+        version_branches
+            .entry(branch_version.version_id)
+            .or_default()
+            .push(branch);
+    }
+
+    let testbeds = schema::testbed::table
+        .filter(schema::testbed::project_id.eq(project_id))
+        .order(schema::testbed::created.asc())
+        .load::<QueryTestbed>(conn)
+        .map_err(resource_not_found_err!(Testbed, query_project))?;
+    let testbed_uuids = testbeds
+        .iter()
+        .map(|testbed| (testbed.id, testbed.uuid))
+        .collect::<HashMap<_, _>>();
+
+    let benchmarks = schema::benchmark::table
+        .filter(schema::benchmark::project_id.eq(project_id))
+        .order(schema::benchmark::created.asc())
+        .load::<QueryBenchmark>(conn)
+        .map_err(resource_not_found_err!(Benchmark, query_project))?;
+    let benchmark_uuids = benchmarks
+        .iter()
+        .map(|benchmark| (benchmark.id, benchmark.uuid))
+        .collect::<HashMap<_, _>>();
+
+    let measures = schema::measure::table
+        .filter(schema::measure::project_id.eq(project_id))
+        .order(schema::measure::created.asc())
+        .load::<QueryMeasure>(conn)
+        .map_err(resource_not_found_err!(Measure, query_project))?;
+    let measure_uuids = measures
+        .iter()
+        .map(|measure| (measure.id, measure.uuid))
+        .collect::<HashMap<_, _>>();
+
+    let thresholds = schema::threshold::table
+        .filter(schema::threshold::project_id.eq(project_id))
+        .order(schema::threshold::created.asc())
+        .load::<QueryThreshold>(conn)
+        .map_err(resource_not_found_err!(Threshold, query_project))?;
+    let threshold_uuids = thresholds
+        .iter()
+        .map(|threshold| (threshold.id, threshold.uuid))
+        .collect::<HashMap<_, _>>();
+
+    let models = schema::model::table
+        .filter(
+            schema::model::threshold_id.eq_any(threshold_uuids.keys().copied().collect::<Vec<_>>()),
+        )
+        .order(schema::model::created.asc())
+        .select(QueryModel::as_select())
+        .load::<QueryModel>(conn)
+        .map_err(resource_not_found_err!(Model, query_project))?;
+    let model_uuids = models
+        .iter()
+        .map(|model| (model.id, model.uuid))
+        .collect::<HashMap<_, _>>();
+    let mut threshold_models = HashMap::<_, Vec<_>>::with_capacity(thresholds.len());
+    for model in models {
+        threshold_models
+            .entry(model.threshold_id)
+            .or_default()
+            .push(JsonArchiveModel {
+                uuid: model.uuid,
+                test: model.test,
+                min_sample_size: model.min_sample_size,
+                max_sample_size: model.max_sample_size,
+                window: model.window,
+                lower_boundary: model.lower_boundary,
+                upper_boundary: model.upper_boundary,
+                created: model.created,
+                replaced: model.replaced,
+            });
+    }
+
+    let reports = schema::report::table
+        .filter(schema::report::project_id.eq(project_id))
+        .order((
+            schema::report::start_time.asc(),
+            schema::report::created.asc(),
+        ))
+        .select(QueryReport::as_select())
+        .load::<QueryReport>(conn)
+        .map_err(resource_not_found_err!(Report, query_project))?;
+
+    let report_benchmarks = schema::report_benchmark::table
+        .inner_join(schema::report::table)
+        .filter(schema::report::project_id.eq(project_id))
+        .order(schema::report_benchmark::iteration.asc())
+        .select(QueryReportBenchmark::as_select())
+        .load::<QueryReportBenchmark>(conn)
+        .map_err(resource_not_found_err!(ReportBenchmark, query_project))?;
+
+    let alerts = schema::alert::table
+        .inner_join(schema::boundary::table.inner_join(schema::threshold::table))
+        .filter(schema::threshold::project_id.eq(project_id))
+        .select(QueryAlert::as_select())
+        .load::<QueryAlert>(conn)
+        .map_err(resource_not_found_err!(Alert, query_project))?
+        .into_iter()
+        .map(|alert| (alert.boundary_id, alert))
+        .collect::<HashMap<_, _>>();
+
+    let mut boundaries = HashMap::new();
+    for boundary in schema::boundary::table
+        .inner_join(schema::threshold::table)
+        .filter(schema::threshold::project_id.eq(project_id))
+        .select(QueryBoundary::as_select())
+        .load::<QueryBoundary>(conn)
+        .map_err(resource_not_found_err!(Boundary, query_project))?
+    {
+        let json_boundary = JsonArchiveBoundary {
+            uuid: boundary.uuid,
+            threshold: export_uuid(
+                &threshold_uuids,
+                BencherResource::Threshold,
+                boundary.threshold_id,
+            )?,
+            model: export_uuid(&model_uuids, BencherResource::Model, boundary.model_id)?,
+            baseline: boundary.baseline,
+            lower_limit: boundary.lower_limit,
+            upper_limit: boundary.upper_limit,
+            effect_size: boundary.effect_size,
+            alert: alerts.get(&boundary.id).map(|alert| JsonArchiveAlert {
+                uuid: alert.uuid,
+                limit: alert.boundary_limit,
+                status: alert.status,
+                modified: alert.modified,
+                note: alert.note.clone(),
+            }),
+        };
+        boundaries.insert(boundary.metric_id, json_boundary);
+    }
+
+    let mut benchmark_metrics = HashMap::<_, Vec<_>>::with_capacity(report_benchmarks.len());
+    for metric in schema::metric::table
+        .inner_join(schema::report_benchmark::table.inner_join(schema::report::table))
+        .filter(schema::report::project_id.eq(project_id))
+        .select(QueryMetric::as_select())
+        .load::<QueryMetric>(conn)
+        .map_err(resource_not_found_err!(Metric, query_project))?
+    {
+        let json_metric = JsonArchiveMetric {
+            uuid: metric.uuid,
+            measure: export_uuid(&measure_uuids, BencherResource::Measure, metric.measure_id)?,
+            value: metric.value,
+            lower_value: metric.lower_value,
+            upper_value: metric.upper_value,
+            boundary: boundaries.remove(&metric.id),
+        };
+        benchmark_metrics
+            .entry(metric.report_benchmark_id)
+            .or_default()
+            .push(json_metric);
+    }
+
+    let mut report_results = HashMap::<_, Vec<_>>::with_capacity(reports.len());
+    for report_benchmark in report_benchmarks {
+        let json_report_benchmark = JsonArchiveReportBenchmark {
+            uuid: report_benchmark.uuid,
+            iteration: report_benchmark.iteration,
+            benchmark: export_uuid(
+                &benchmark_uuids,
+                BencherResource::Benchmark,
+                report_benchmark.benchmark_id,
+            )?,
+            metrics: benchmark_metrics
+                .remove(&report_benchmark.id)
+                .unwrap_or_default(),
+        };
+        report_results
+            .entry(report_benchmark.report_id)
+            .or_default()
+            .push(json_report_benchmark);
+    }
+
+    let silences = schema::silence::table
+        .filter(schema::silence::project_id.eq(project_id))
+        .order(schema::silence::created.asc())
+        .select(QuerySilence::as_select())
+        .load::<QuerySilence>(conn)
+        .map_err(resource_not_found_err!(Silence, query_project))?;
+
+    let retention = if let Some(retention) = schema::retention::table
+        .filter(schema::retention::project_id.eq(project_id))
+        .select(QueryRetention::as_select())
+        .first::<QueryRetention>(conn)
+        .optional()
+        .map_err(resource_not_found_err!(Retention, query_project))?
+    {
+        Some(JsonArchiveRetention {
+            raw_window: retention.raw_window,
+            downsample: retention.downsample,
+            protected_branch: retention
+                .protected_branch_id
+                .map(|branch_id| export_uuid(&branch_uuids, BencherResource::Branch, branch_id))
+                .transpose()?,
+            created: retention.created,
+            modified: retention.modified,
+        })
+    } else {
+        None
+    };
+
+    Ok(JsonProjectArchive {
+        archive_version: PROJECT_ARCHIVE_VERSION,
+        exported: DateTime::now(),
+        project: JsonArchiveProject {
+            uuid: query_project.uuid,
+            name: query_project.name.clone(),
+            slug: query_project.slug.clone(),
+            url: query_project.url.clone(),
+            visibility: query_project.visibility,
+            created: query_project.created,
+            modified: query_project.modified,
+        },
+        branches: branches
+            .into_iter()
+            .map(|branch| JsonArchiveBranch {
+                uuid: branch.uuid,
+                name: branch.name,
+                slug: branch.slug,
+                start_point: branch
+                    .start_point_id
+                    .and_then(|start_point_id| start_points.get(&start_point_id).cloned()),
+                created: branch.created,
+                modified: branch.modified,
+                archived: branch.archived,
+            })
+            .collect(),
+        versions: versions
+            .into_iter()
+            .map(|version| JsonArchiveVersion {
+                uuid: version.uuid,
+                number: version.number,
+                hash: version.hash,
+                branches: version_branches.remove(&version.id).unwrap_or_default(),
+            })
+            .collect(),
+        testbeds: testbeds
+            .into_iter()
+            .map(|testbed| JsonArchiveTestbed {
+                uuid: testbed.uuid,
+                name: testbed.name,
+                slug: testbed.slug,
+                created: testbed.created,
+                modified: testbed.modified,
+            })
+            .collect(),
+        benchmarks: benchmarks
+            .into_iter()
+            .map(|benchmark| JsonArchiveBenchmark {
+                uuid: benchmark.uuid,
+                name: benchmark.name,
+                slug: benchmark.slug,
+                group: benchmark.benchmark_group,
+                tags: benchmark.tags.map(Into::into).unwrap_or_default(),
+                created: benchmark.created,
+                modified: benchmark.modified,
+            })
+            .collect(),
+        measures: measures
+            .into_iter()
+            .map(|measure| JsonArchiveMeasure {
+                uuid: measure.uuid,
+                name: measure.name,
+                slug: measure.slug,
+                units: measure.units,
+                unit: measure.unit,
+                expression: measure.expression,
+                created: measure.created,
+                modified: measure.modified,
+            })
+            .collect(),
+        thresholds: thresholds
+            .into_iter()
+            .map(|threshold| {
+                Ok(JsonArchiveThreshold {
+                    uuid: threshold.uuid,
+                    branch: export_uuid(
+                        &branch_uuids,
+                        BencherResource::Branch,
+                        threshold.branch_id,
+                    )?,
+                    testbed: export_uuid(
+                        &testbed_uuids,
+                        BencherResource::Testbed,
+                        threshold.testbed_id,
+                    )?,
+                    measure: export_uuid(
+                        &measure_uuids,
+                        BencherResource::Measure,
+                        threshold.measure_id,
+                    )?,
+                    group: threshold.benchmark_group,
+                    model: threshold
+                        .model_id
+                        .map(|model_id| export_uuid(&model_uuids, BencherResource::Model, model_id))
+                        .transpose()?,
+                    models: threshold_models.remove(&threshold.id).unwrap_or_default(),
+                    created: threshold.created,
+                    modified: threshold.modified,
+                })
+            })
+            .collect::<Result<_, HttpError>>()?,
+        reports: reports
+            .into_iter()
+            .map(|report| {
+                Ok(JsonArchiveReport {
+                    uuid: report.uuid,
+                    branch: export_uuid(&branch_uuids, BencherResource::Branch, report.branch_id)?,
+                    version: export_uuid(
+                        &version_uuids,
+                        BencherResource::Version,
+                        report.version_id,
+                    )?,
+                    testbed: export_uuid(
+                        &testbed_uuids,
+                        BencherResource::Testbed,
+                        report.testbed_id,
+                    )?,
+                    adapter: report.adapter,
+                    start_time: report.start_time,
+                    end_time: report.end_time,
+                    results: report_results.remove(&report.id).unwrap_or_default(),
+                    created: report.created,
+                })
+            })
+            .collect::<Result<_, HttpError>>()?,
+        silences: silences
+            .into_iter()
+            .map(|silence| {
+                Ok(JsonArchiveSilence {
+                    uuid: silence.uuid,
+                    benchmark: silence
+                        .benchmark_id
+                        .map(|benchmark_id| {
+                            export_uuid(&benchmark_uuids, BencherResource::Benchmark, benchmark_id)
+                        })
+                        .transpose()?,
+                    measure: silence
+                        .measure_id
+                        .map(|measure_id| {
+                            export_uuid(&measure_uuids, BencherResource::Measure, measure_id)
+                        })
+                        .transpose()?,
+                    testbed: silence
+                        .testbed_id
+                        .map(|testbed_id| {
+                            export_uuid(&testbed_uuids, BencherResource::Testbed, testbed_id)
+                        })
+                        .transpose()?,
+                    note: silence.note,
+                    expires: silence.expires,
+                    created: silence.created,
+                })
+            })
+            .collect::<Result<_, HttpError>>()?,
+        retention,
+    })
+}
+
+fn export_uuid<Id, Uuid>(
+    uuids: &HashMap<Id, Uuid>,
+    resource: BencherResource,
+    id: Id,
+) -> Result<Uuid, HttpError>
+where
+    Id: Copy + Eq + Hash + fmt::Debug,
+    Uuid: Copy,
+{
+    uuids.get(&id).copied().ok_or_else(|| {
+        resource_not_found_error(resource, id, "Not found in the project being exported")
+    })
+}
+
+// Restore a project from a portable archive.
+// If the project already exists on this server, then all of the UUIDs in the archive are replaced with new ones.
+// If the project slug is already in use, then a new slug is generated.
+pub struct ProjectRestore {
+    insert_project: InsertProject,
+    remap: bool,
+    archive: JsonProjectArchive,
+}
+
+impl ProjectRestore {
+    pub fn new(
+        conn: &mut DbConnection,
+        organization: &QueryOrganization,
+        archive: JsonProjectArchive,
+    ) -> Result<Self, HttpError> {
+        if archive.archive_version != PROJECT_ARCHIVE_VERSION {
+            return Err(bad_request_error(format!(
+                "Unsupported project archive version ({}). The only supported version is ({PROJECT_ARCHIVE_VERSION}).",
+                archive.archive_version
+            )));
+        }
+
+        let remap = schema::project::table
+            .filter(schema::project::uuid.eq(archive.project.uuid))
+            .select(schema::project::id)
+            .first::<ProjectId>(conn)
+            .is_ok();
+
+        let JsonArchiveProject {
+            uuid,
+            name,
+            slug,
+            url,
+            visibility,
+            created,
+            modified,
+        } = archive.project.clone();
+        let slug = ok_slug!(conn, &name, Some(slug), project, QueryProject)?;
+        let insert_project = InsertProject {
+            uuid: remap_uuid(remap, uuid),
+            organization_id: organization.id,
+            name,
+            slug,
+            url,
+            visibility,
+            created,
+            modified,
+        };
+
+        Ok(Self {
+            insert_project,
+            remap,
+            archive,
+        })
+    }
+
+    pub fn insert_project(&self) -> &InsertProject {
+        &self.insert_project
+    }
+
+    pub fn visibility(&self) -> Visibility {
+        self.insert_project.visibility
+    }
+
+    // Restoring a project is all or nothing, so it is done within a single transaction.
+    pub fn restore(
+        &self,
+        conn: &mut DbConnection,
+        user_id: UserId,
+    ) -> Result<QueryProject, HttpError> {
+        conn.transaction(|conn| self.restore_inner(conn, user_id))
+            .map_err(Into::into)
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn restore_inner(
+        &self,
+        conn: &mut DbConnection,
+        user_id: UserId,
+    ) -> Result<QueryProject, RestoreError> {
+        let archive = &self.archive;
+
+        diesel::insert_into(schema::project::table)
+            .values(&self.insert_project)
+            .execute(conn)
+            .map_err(resource_conflict_err!(Project, self.insert_project))?;
+        let query_project = schema::project::table
+            .filter(schema::project::uuid.eq(self.insert_project.uuid))
+            .first::<QueryProject>(conn)
+            .map_err(resource_not_found_err!(Project, self.insert_project))?;
+        let project_id = query_project.id;
+
+        // The start points for branches are set once all of the versions have been restored.
+        let mut branch_ids = HashMap::with_capacity(archive.branches.len());
+        for branch in &archive.branches {
+            let insert_branch = InsertBranch {
+                uuid: self.uuid(branch.uuid),
+                project_id,
+                name: branch.name.clone(),
+                slug: branch.slug.clone(),
+                start_point_id: None,
+                created: branch.created,
+                modified: branch.modified,
+                archived: branch.archived,
+            };
+            diesel::insert_into(schema::branch::table)
+                .values(&insert_branch)
+                .execute(conn)
+                .map_err(resource_conflict_err!(Branch, insert_branch))?;
+            branch_ids.insert(branch.uuid, QueryBranch::get_id(conn, insert_branch.uuid)?);
+        }
+
+        let mut version_ids = HashMap::with_capacity(archive.versions.len());
+        for version in &archive.versions {
+            let insert_version = InsertVersion {
+                uuid: self.uuid(version.uuid),
+                project_id,
+                number: version.number,
+                hash: version.hash.clone(),
+            };
+            diesel::insert_into(schema::version::table)
+                .values(&insert_version)
+                .execute(conn)
+                .map_err(resource_conflict_err!(Version, insert_version))?;
+            let version_id = QueryVersion::get_id(conn, insert_version.uuid)?;
+            version_ids.insert(version.uuid, version_id);
+
+            for branch in &version.branches {
+                let insert_branch_version = InsertBranchVersion {
+                    branch_id: restore_id(&branch_ids, BencherResource::Branch, *branch)?,
+                    version_id,
+                };
+                diesel::insert_into(schema::branch_version::table)
+                    .values(&insert_branch_version)
+                    .execute(conn)
+                    .map_err(resource_conflict_err!(BranchVersion, insert_branch_version))?;
+            }
+        }
+
+        for branch in &archive.branches {
+            let Some(start_point) = &branch.start_point else {
+                continue;
+            };
+            let start_point_id = schema::branch_version::table
+                .filter(schema::branch_version::branch_id.eq(restore_id(
+                    &branch_ids,
+                    BencherResource::Branch,
+                    start_point.branch,
+                )?))
+                .filter(schema::branch_version::version_id.eq(restore_id(
+                    &version_ids,
+                    BencherResource::Version,
+                    start_point.version,
+                )?))
+                .select(schema::branch_version::id)
+                .first::<BranchVersionId>(conn)
+                .map_err(resource_not_found_err!(BranchVersion, start_point))?;
+            diesel::update(
+                schema::branch::table.filter(schema::branch::id.eq(restore_id(
+                    &branch_ids,
+                    BencherResource::Branch,
+                    branch.uuid,
+                )?)),
+            )
+            .set(schema::branch::start_point_id.eq(start_point_id))
+            .execute(conn)
+            .map_err(resource_conflict_err!(Branch, branch))?;
+        }
+
+        let mut testbed_ids = HashMap::with_capacity(archive.testbeds.len());
+        for testbed in &archive.testbeds {
+            let insert_testbed = InsertTestbed {
+                uuid: self.uuid(testbed.uuid),
+                project_id,
+                name: testbed.name.clone(),
+                slug: testbed.slug.clone(),
+                created: testbed.created,
+                modified: testbed.modified,
+            };
+            diesel::insert_into(schema::testbed::table)
+                .values(&insert_testbed)
+                .execute(conn)
+                .map_err(resource_conflict_err!(Testbed, insert_testbed))?;
+            testbed_ids.insert(
+                testbed.uuid,
+                QueryTestbed::get_id(conn, insert_testbed.uuid)?,
+            );
+        }
+
+        let mut benchmark_ids = HashMap::with_capacity(archive.benchmarks.len());
+        for benchmark in &archive.benchmarks {
+            let insert_benchmark = InsertBenchmark {
+                uuid: self.uuid(benchmark.uuid),
+                project_id,
+                name: benchmark.name.clone(),
+                slug: benchmark.slug.clone(),
+                created: benchmark.created,
+                modified: benchmark.modified,
+                benchmark_group: benchmark.group.clone(),
+                tags: BenchmarkTags::new(benchmark.tags.clone()),
+            };
+            diesel::insert_into(schema::benchmark::table)
+                .values(&insert_benchmark)
+                .execute(conn)
+                .map_err(resource_conflict_err!(Benchmark, insert_benchmark))?;
+            benchmark_ids.insert(
+                benchmark.uuid,
+                QueryBenchmark::get_id(conn, insert_benchmark.uuid)?,
+            );
+        }
+
+        let mut measure_ids = HashMap::with_capacity(archive.measures.len());
+        for measure in &archive.measures {
+            let insert_measure = InsertMeasure {
+                uuid: self.uuid(measure.uuid),
+                project_id,
+                name: measure.name.clone(),
+                slug: measure.slug.clone(),
+                units: measure.units.clone(),
+                unit: measure.unit,
+                expression: measure.expression.clone(),
+                created: measure.created,
+                modified: measure.modified,
+            };
+            diesel::insert_into(schema::measure::table)
+                .values(&insert_measure)
+                .execute(conn)
+                .map_err(resource_conflict_err!(Measure, insert_measure))?;
+            measure_ids.insert(
+                measure.uuid,
+                QueryMeasure::get_id(conn, insert_measure.uuid)?,
+            );
+        }
+
+        // The current model for each threshold is set once all of its models have been restored.
+        let mut threshold_ids = HashMap::with_capacity(archive.thresholds.len());
+        let mut model_ids = HashMap::new();
+        for threshold in &archive.thresholds {
+            let insert_threshold = InsertThreshold {
+                uuid: self.uuid(threshold.uuid),
+                project_id,
+                branch_id: restore_id(&branch_ids, BencherResource::Branch, threshold.branch)?,
+                testbed_id: restore_id(&testbed_ids, BencherResource::Testbed, threshold.testbed)?,
+                measure_id: restore_id(&measure_ids, BencherResource::Measure, threshold.measure)?,
+                model_id: None,
+                created: threshold.created,
+                modified: threshold.modified,
+                benchmark_group: threshold.group.clone(),
+            };
+            diesel::insert_into(schema::threshold::table)
+                .values(&insert_threshold)
+                .execute(conn)
+                .map_err(resource_conflict_err!(Threshold, insert_threshold))?;
+            let threshold_id = QueryThreshold::get_id(conn, insert_threshold.uuid)?;
+            threshold_ids.insert(threshold.uuid, threshold_id);
+
+            for model in &threshold.models {
+                let insert_model = InsertModel {
+                    uuid: self.uuid(model.uuid),
+                    threshold_id,
+                    test: model.test,
+                    min_sample_size: model.min_sample_size,
+                    max_sample_size: model.max_sample_size,
+                    window: model.window,
+                    lower_boundary: model.lower_boundary,
+                    upper_boundary: model.upper_boundary,
+                    created: model.created,
+                    replaced: model.replaced,
+                };
+                diesel::insert_into(schema::model::table)
+                    .values(&insert_model)
+                    .execute(conn)
+                    .map_err(resource_conflict_err!(Model, insert_model))?;
+                model_ids.insert(model.uuid, QueryModel::get_id(conn, insert_model.uuid)?);
+            }
+
+            if let Some(model) = threshold.model {
+                diesel::update(
+                    schema::threshold::table.filter(schema::threshold::id.eq(threshold_id)),
+                )
+                .set(schema::threshold::model_id.eq(restore_id(
+                    &model_ids,
+                    BencherResource::Model,
+                    model,
+                )?))
+                .execute(conn)
+                .map_err(resource_conflict_err!(Threshold, threshold))?;
+            }
+        }
+
+        for silence in &archive.silences {
+            let insert_silence = InsertSilence {
+                uuid: self.uuid(silence.uuid),
+                project_id,
+                benchmark_id: silence
+                    .benchmark
+                    .map(|benchmark| {
+                        restore_id(&benchmark_ids, BencherResource::Benchmark, benchmark)
+                    })
+                    .transpose()?,
+                measure_id: silence
+                    .measure
+                    .map(|measure| restore_id(&measure_ids, BencherResource::Measure, measure))
+                    .transpose()?,
+                testbed_id: silence
+                    .testbed
+                    .map(|testbed| restore_id(&testbed_ids, BencherResource::Testbed, testbed))
+                    .transpose()?,
+                note: silence.note.clone(),
+                expires: silence.expires,
+                created: silence.created,
+            };
+            diesel::insert_into(schema::silence::table)
+                .values(&insert_silence)
+                .execute(conn)
+                .map_err(resource_conflict_err!(Silence, insert_silence))?;
+        }
+
+        if let Some(retention) = &archive.retention {
+            let insert_retention = InsertRetention {
+                project_id,
+                raw_window: retention.raw_window,
+                downsample: retention.downsample,
+                protected_branch_id: retention
+                    .protected_branch
+                    .map(|branch| restore_id(&branch_ids, BencherResource::Branch, branch))
+                    .transpose()?,
+                created: retention.created,
+                modified: retention.modified,
+            };
+            diesel::insert_into(schema::retention::table)
+                .values(&insert_retention)
+                .execute(conn)
+                .map_err(resource_conflict_err!(Retention, insert_retention))?;
+        }
+
+        // All of the restored reports are attributed to the user restoring the project.
+        for report in &archive.reports {
+            let insert_report = InsertReport {
+                uuid: self.uuid(report.uuid),
+                user_id,
+                project_id,
+                branch_id: restore_id(&branch_ids, BencherResource::Branch, report.branch)?,
+                version_id: restore_id(&version_ids, BencherResource::Version, report.version)?,
+                testbed_id: restore_id(&testbed_ids, BencherResource::Testbed, report.testbed)?,
+                adapter: report.adapter,
+                start_time: report.start_time,
+                end_time: report.end_time,
+                created: report.created,
+            };
+            diesel::insert_into(schema::report::table)
+                .values(&insert_report)
+                .execute(conn)
+                .map_err(resource_conflict_err!(Report, insert_report))?;
+            let report_id = QueryReport::get_id(conn, insert_report.uuid)?;
+
+            for report_benchmark in &report.results {
+                let insert_report_benchmark = InsertReportBenchmark {
+                    uuid: self.uuid(report_benchmark.uuid),
+                    report_id,
+                    iteration: report_benchmark.iteration,
+                    benchmark_id: restore_id(
+                        &benchmark_ids,
+                        BencherResource::Benchmark,
+                        report_benchmark.benchmark,
+                    )?,
+                };
+                diesel::insert_into(schema::report_benchmark::table)
+                    .values(&insert_report_benchmark)
+                    .execute(conn)
+                    .map_err(resource_conflict_err!(
+                        ReportBenchmark,
+                        insert_report_benchmark
+                    ))?;
+                let report_benchmark_id =
+                    QueryReportBenchmark::get_id(conn, insert_report_benchmark.uuid)?;
+
+                for metric in &report_benchmark.metrics {
+                    self.restore_metric(
+                        conn,
+                        report_benchmark_id,
+                        metric,
+                        &measure_ids,
+                        &threshold_ids,
+                        &model_ids,
+                    )?;
+                }
+            }
+        }
+
+        Ok(query_project)
+    }
+
+    fn restore_metric(
+        &self,
+        conn: &mut DbConnection,
+        report_benchmark_id: ReportBenchmarkId,
+        metric: &JsonArchiveMetric,
+        measure_ids: &HashMap<MeasureUuid, MeasureId>,
+        threshold_ids: &HashMap<ThresholdUuid, ThresholdId>,
+        model_ids: &HashMap<ModelUuid, ModelId>,
+    ) -> Result<(), HttpError> {
+        let insert_metric = InsertMetric {
+            uuid: self.uuid(metric.uuid),
+            report_benchmark_id,
+            measure_id: restore_id(measure_ids, BencherResource::Measure, metric.measure)?,
+            value: metric.value,
+            lower_value: metric.lower_value,
+            upper_value: metric.upper_value,
+        };
+        diesel::insert_into(schema::metric::table)
+            .values(&insert_metric)
+            .execute(conn)
+            .map_err(resource_conflict_err!(Metric, insert_metric))?;
+
+        let Some(boundary) = &metric.boundary else {
+            return Ok(());
+        };
+        let insert_boundary = InsertBoundary {
+            uuid: self.uuid(boundary.uuid),
+            threshold_id: restore_id(
+                threshold_ids,
+                BencherResource::Threshold,
+                boundary.threshold,
+            )?,
+            model_id: restore_id(model_ids, BencherResource::Model, boundary.model)?,
+            metric_id: QueryMetric::get_id(conn, insert_metric.uuid)?,
+            baseline: boundary.baseline,
+            lower_limit: boundary.lower_limit,
+            upper_limit: boundary.upper_limit,
+            effect_size: boundary.effect_size,
+        };
+        diesel::insert_into(schema::boundary::table)
+            .values(&insert_boundary)
+            .execute(conn)
+            .map_err(resource_conflict_err!(Boundary, insert_boundary))?;
+
+        let Some(alert) = &boundary.alert else {
+            return Ok(());
+        };
+        let insert_alert = InsertAlert {
+            uuid: self.uuid(alert.uuid),
+            boundary_id: QueryBoundary::get_id(conn, insert_boundary.uuid)?,
+            boundary_limit: alert.limit,
+            status: alert.status,
+            modified: alert.modified,
+            note: alert.note.clone(),
+        };
+        diesel::insert_into(schema::alert::table)
+            .values(&insert_alert)
+            .execute(conn)
+            .map_err(resource_conflict_err!(Alert, insert_alert))?;
+
+        Ok(())
+    }
+
+    fn uuid<U>(&self, uuid: U) -> U
+    where
+        U: From<uuid::Uuid>,
+    {
+        remap_uuid(self.remap, uuid)
+    }
+}
+
+fn remap_uuid<U>(remap: bool, uuid: U) -> U
+where
+    U: From<uuid::Uuid>,
+{
+    if remap {
+        uuid::Uuid::new_v4().into()
+    } else {
+        uuid
+    }
+}
+
+fn restore_id<Uuid, Id>(
+    ids: &HashMap<Uuid, Id>,
+    resource: BencherResource,
+    uuid: Uuid,
+) -> Result<Id, HttpError>
+where
+    Uuid: Copy + Eq + Hash + fmt::Display,
+    Id: Copy,
+{
+    ids.get(&uuid).copied().ok_or_else(|| {
+        bad_request_error(format!(
+            "{resource} ({uuid}) is referenced but not found in the project archive"
+        ))
+    })
+}
+
+enum RestoreError {
+    Http(HttpError),
+    Transaction(diesel::result::Error),
+}
+
+impl From<HttpError> for RestoreError {
+    fn from(error: HttpError) -> Self {
+        Self::Http(error)
+    }
+}
+
+impl From<diesel::result::Error> for RestoreError {
+    fn from(error: diesel::result::Error) -> Self {
+        Self::Transaction(error)
+    }
+}
+
+impl From<RestoreError> for HttpError {
+    fn from(error: RestoreError) -> Self {
+        match error {
+            RestoreError::Http(error) => error,
+            RestoreError::Transaction(error) => issue_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to restore project",
+                "Failed to restore project from archive.",
+                error,
+            ),
+        }
+    }
+}
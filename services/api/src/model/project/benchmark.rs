@@ -1,13 +1,19 @@
+use std::fmt;
+
 use bencher_json::{
     project::benchmark::{JsonBenchmarkMetric, JsonNewBenchmark, JsonUpdateBenchmark},
-    BenchmarkName, BenchmarkUuid, DateTime, JsonBenchmark, Slug,
+    BenchmarkGroup, BenchmarkName, BenchmarkUuid, DateTime, JsonBenchmark, Slug,
+    BENCHMARK_GROUP_SEPARATORS,
+};
+use diesel::{
+    BoolExpressionMethods, EscapeExpressionMethods, ExpressionMethods, QueryDsl, RunQueryDsl,
+    TextExpressionMethods,
 };
-use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
 use dropshot::HttpError;
 
 use super::{metric::QueryMetric, threshold::boundary::QueryBoundary, ProjectId, QueryProject};
 use crate::{
-    context::DbConnection,
+    context::{DbBackend, DbConnection},
     error::{assert_parentage, resource_conflict_err, resource_not_found_err, BencherResource},
     schema,
     schema::benchmark as benchmark_table,
@@ -33,6 +39,8 @@ pub struct QueryBenchmark {
     pub slug: Slug,
     pub created: DateTime,
     pub modified: DateTime,
+    pub benchmark_group: Option<BenchmarkGroup>,
+    pub tags: Option<BenchmarkTags>,
 }
 
 impl QueryBenchmark {
@@ -44,15 +52,14 @@ impl QueryBenchmark {
     fn_get_uuid!(benchmark, BenchmarkId, BenchmarkUuid);
     fn_from_uuid!(benchmark, BenchmarkUuid, Benchmark);
 
-    pub fn get_id_from_name(
+    pub fn get_from_name(
         conn: &mut DbConnection,
         project_id: ProjectId,
         name: &BenchmarkName,
-    ) -> Result<BenchmarkId, HttpError> {
+    ) -> Result<Self, HttpError> {
         schema::benchmark::table
             .filter(schema::benchmark::project_id.eq(project_id))
             .filter(schema::benchmark::name.eq(name))
-            .select(schema::benchmark::id)
             .first(conn)
             .map_err(resource_not_found_err!(Benchmark, (project_id, name)))
     }
@@ -61,12 +68,12 @@ impl QueryBenchmark {
         conn: &mut DbConnection,
         project_id: ProjectId,
         name: BenchmarkName,
-    ) -> Result<BenchmarkId, HttpError> {
+    ) -> Result<Self, HttpError> {
         // For historical reasons, we will only every be able to match on name and not name ID here.
         // The benchmark slugs were always created with a random suffix for a while.
         // Therefore, a name that happens to be a valid slug will fail to be found, when treated as a slug.
-        if let Ok(id) = Self::get_id_from_name(conn, project_id, &name) {
-            return Ok(id);
+        if let Ok(benchmark) = Self::get_from_name(conn, project_id, &name) {
+            return Ok(benchmark);
         }
 
         let insert_benchmark = InsertBenchmark::from_name(conn, project_id, name)?;
@@ -75,7 +82,15 @@ impl QueryBenchmark {
             .execute(conn)
             .map_err(resource_conflict_err!(Benchmark, insert_benchmark))?;
 
-        Self::get_id(conn, insert_benchmark.uuid)
+        Self::from_uuid(conn, project_id, insert_benchmark.uuid)
+    }
+
+    /// The group for the benchmark.
+    /// If a group has not been explicitly set, then it is parsed from the benchmark name.
+    pub fn group(&self) -> Option<BenchmarkGroup> {
+        self.benchmark_group
+            .clone()
+            .or_else(|| BenchmarkGroup::from_name(&self.name))
     }
 
     pub fn into_json(self, conn: &mut DbConnection) -> Result<JsonBenchmark, HttpError> {
@@ -84,11 +99,13 @@ impl QueryBenchmark {
     }
 
     pub fn into_json_for_project(self, project: &QueryProject) -> JsonBenchmark {
+        let group = self.group();
         let Self {
             uuid,
             project_id,
             name,
             slug,
+            tags,
             created,
             modified,
             ..
@@ -104,6 +121,8 @@ impl QueryBenchmark {
             project: project.uuid,
             name,
             slug,
+            group,
+            tags: tags.map(Into::into).unwrap_or_default(),
             created,
             modified,
         }
@@ -120,6 +139,8 @@ impl QueryBenchmark {
             project,
             name,
             slug,
+            group,
+            tags,
             created,
             modified,
         } = self.into_json_for_project(project);
@@ -130,6 +151,8 @@ impl QueryBenchmark {
             project,
             name,
             slug,
+            group,
+            tags,
             metric,
             boundary,
             created,
@@ -147,6 +170,8 @@ pub struct InsertBenchmark {
     pub slug: Slug,
     pub created: DateTime,
     pub modified: DateTime,
+    pub benchmark_group: Option<BenchmarkGroup>,
+    pub tags: Option<BenchmarkTags>,
 }
 
 impl InsertBenchmark {
@@ -155,9 +180,17 @@ impl InsertBenchmark {
         project_id: ProjectId,
         benchmark: JsonNewBenchmark,
     ) -> Result<Self, HttpError> {
-        let JsonNewBenchmark { name, slug } = benchmark;
+        let JsonNewBenchmark {
+            name,
+            slug,
+            group,
+            tags,
+        } = benchmark;
         let slug = ok_slug!(conn, project_id, &name, slug, benchmark, QueryBenchmark)?;
-        Ok(Self::new(project_id, name, slug))
+        let mut insert_benchmark = Self::new(project_id, name, slug);
+        insert_benchmark.benchmark_group = group;
+        insert_benchmark.tags = tags.and_then(BenchmarkTags::new);
+        Ok(insert_benchmark)
     }
 
     fn from_name(
@@ -178,6 +211,8 @@ impl InsertBenchmark {
             slug,
             created: timestamp,
             modified: timestamp,
+            benchmark_group: None,
+            tags: None,
         }
     }
 }
@@ -187,16 +222,122 @@ impl InsertBenchmark {
 pub struct UpdateBenchmark {
     pub name: Option<BenchmarkName>,
     pub slug: Option<Slug>,
+    pub benchmark_group: Option<BenchmarkGroup>,
+    pub tags: Option<Option<BenchmarkTags>>,
     pub modified: DateTime,
 }
 
 impl From<JsonUpdateBenchmark> for UpdateBenchmark {
     fn from(update: JsonUpdateBenchmark) -> Self {
-        let JsonUpdateBenchmark { name, slug } = update;
+        let JsonUpdateBenchmark {
+            name,
+            slug,
+            group,
+            tags,
+        } = update;
         Self {
             name,
             slug,
+            benchmark_group: group,
+            tags: tags.map(BenchmarkTags::new),
             modified: DateTime::now(),
         }
     }
 }
+
+/// Filter a benchmark query to the benchmarks in the given group or any of its subgroups.
+/// Benchmarks without an explicitly set group are matched by their name.
+pub fn filter_group<'a>(
+    query: schema::benchmark::BoxedQuery<'a, DbBackend>,
+    group: &BenchmarkGroup,
+) -> schema::benchmark::BoxedQuery<'a, DbBackend> {
+    let group = group.as_ref();
+    let escaped = group
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    let [subgroup, other_subgroup] =
+        BENCHMARK_GROUP_SEPARATORS.map(|separator| format!("{escaped}{separator}%"));
+    query.filter(
+        schema::benchmark::benchmark_group
+            .eq(group.to_owned())
+            .or(schema::benchmark::benchmark_group
+                .like(subgroup.clone())
+                .escape('\\'))
+            .or(schema::benchmark::benchmark_group
+                .like(other_subgroup.clone())
+                .escape('\\'))
+            .or(schema::benchmark::benchmark_group.is_null().and(
+                schema::benchmark::name
+                    .like(subgroup)
+                    .escape('\\')
+                    .or(schema::benchmark::name.like(other_subgroup).escape('\\')),
+            )),
+    )
+}
+
+/// The tags for a benchmark are stored as a comma delimited list with a leading and trailing comma,
+/// ie `,parser,slow,` so that a single tag can be matched with `LIKE '%,parser,%'`.
+#[derive(Debug, Clone, diesel::FromSqlRow, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Text)]
+pub struct BenchmarkTags(Vec<Slug>);
+
+impl BenchmarkTags {
+    /// Returns `None` if there are no tags, as no tags are stored as `NULL`.
+    pub fn new(mut tags: Vec<Slug>) -> Option<Self> {
+        tags.sort();
+        tags.dedup();
+        (!tags.is_empty()).then_some(Self(tags))
+    }
+}
+
+impl From<BenchmarkTags> for Vec<Slug> {
+    fn from(tags: BenchmarkTags) -> Self {
+        tags.0
+    }
+}
+
+impl fmt::Display for BenchmarkTags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ",")?;
+        for tag in &self.0 {
+            write!(f, "{tag},")?;
+        }
+        Ok(())
+    }
+}
+
+bencher_json::to_sql_converted!(diesel::sql_types::Text, BenchmarkTags, |tags| tags
+    .to_string());
+
+impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Text, DB> for BenchmarkTags
+where
+    DB: diesel::backend::Backend,
+    String: diesel::deserialize::FromSql<diesel::sql_types::Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+        Ok(Self(
+            String::from_sql(bytes)?
+                .split(',')
+                .filter(|tag| !tag.is_empty())
+                .map(str::parse)
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+/// Search for benchmarks with the given tag.
+#[derive(Debug, Clone, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Text)]
+pub struct TagSearch(Slug);
+
+bencher_json::to_sql_converted!(diesel::sql_types::Text, TagSearch, |tag| format!(
+    "%,{},%",
+    tag.0
+));
+
+impl From<Slug> for TagSearch {
+    fn from(tag: Slug) -> Self {
+        Self(tag)
+    }
+}
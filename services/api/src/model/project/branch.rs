@@ -1,5 +1,10 @@
+use std::collections::HashMap;
+
 use bencher_json::{
-    project::branch::{JsonBranchVersion, JsonNewStartPoint, JsonUpdateBranch, BRANCH_MAIN_STR},
+    project::branch::{
+        JsonArchiveBranches, JsonBranchVersion, JsonNewStartPoint, JsonUpdateBranch,
+        BRANCH_MAIN_STR,
+    },
     BranchName, BranchUuid, DateTime, JsonBranch, JsonNewBranch, Slug,
 };
 use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
@@ -8,6 +13,7 @@ use slog::Logger;
 
 use super::{
     branch_version::{BranchVersionId, InsertBranchVersion, QueryBranchVersion},
+    retention::QueryRetention,
     threshold::model::{InsertModel, QueryModel},
     version::{QueryVersion, VersionId},
     ProjectId, QueryProject,
@@ -42,6 +48,7 @@ pub struct QueryBranch {
     pub start_point_id: Option<BranchVersionId>,
     pub created: DateTime,
     pub modified: DateTime,
+    pub archived: Option<DateTime>,
 }
 
 impl QueryBranch {
@@ -69,6 +76,7 @@ impl QueryBranch {
             start_point,
             created,
             modified,
+            archived: _,
         } = Self::get(conn, branch_id)?.into_json(conn)?;
         Ok(JsonBranchVersion {
             uuid,
@@ -100,6 +108,7 @@ impl QueryBranch {
             start_point_id,
             created,
             modified,
+            archived,
             ..
         } = self;
         assert_parentage(
@@ -121,6 +130,7 @@ impl QueryBranch {
             start_point,
             created,
             modified,
+            archived,
         })
     }
 
@@ -128,6 +138,81 @@ impl QueryBranch {
         matches!(self.name.as_ref(), BRANCH_MAIN_STR)
             || matches!(self.slug.as_ref(), BRANCH_MAIN_STR)
     }
+
+    /// Unarchive a branch, if it is archived.
+    /// This is done whenever a new report is created for the branch.
+    pub fn unarchive(conn: &mut DbConnection, branch_id: BranchId) -> Result<(), HttpError> {
+        diesel::update(
+            schema::branch::table
+                .filter(schema::branch::id.eq(branch_id))
+                .filter(schema::branch::archived.is_not_null()),
+        )
+        .set((
+            schema::branch::archived.eq(None::<DateTime>),
+            schema::branch::modified.eq(DateTime::now()),
+        ))
+        .execute(conn)
+        .map_err(resource_conflict_err!(Branch, branch_id))?;
+        Ok(())
+    }
+
+    /// Get all of the unarchived branches for a project that should be archived.
+    /// The system branch and the retention policy protected branch are never archived.
+    pub fn archivable(
+        conn: &mut DbConnection,
+        project: &QueryProject,
+        json_archive: &JsonArchiveBranches,
+    ) -> Result<Vec<Self>, HttpError> {
+        let branches = schema::branch::table
+            .filter(schema::branch::project_id.eq(project.id))
+            .filter(schema::branch::archived.is_null())
+            .order(schema::branch::name.asc())
+            .load::<QueryBranch>(conn)
+            .map_err(resource_not_found_err!(Branch, project))?;
+        let protected_branch_id = QueryRetention::from_project(conn, project.id)
+            .ok()
+            .and_then(|retention| retention.protected_branch_id);
+
+        let stale_cutoff = json_archive
+            .stale
+            .map(|stale| DateTime::now().timestamp() - i64::from(stale));
+        let last_reports = if stale_cutoff.is_some() {
+            schema::report::table
+                .filter(schema::report::project_id.eq(project.id))
+                .group_by(schema::report::branch_id)
+                .select((
+                    schema::report::branch_id,
+                    diesel::dsl::max(schema::report::created),
+                ))
+                .load::<(BranchId, Option<DateTime>)>(conn)
+                .map_err(resource_not_found_err!(Report, project))?
+                .into_iter()
+                .collect::<HashMap<_, _>>()
+        } else {
+            HashMap::new()
+        };
+
+        Ok(branches
+            .into_iter()
+            .filter(|branch| !branch.is_system() && Some(branch.id) != protected_branch_id)
+            .filter(|branch| {
+                let is_named = json_archive
+                    .branches
+                    .as_ref()
+                    .is_some_and(|names| names.contains(&branch.name));
+                let is_stale = stale_cutoff.is_some_and(|cutoff| {
+                    // Branches without any reports are stale once they are older than the cutoff
+                    let last_activity = last_reports
+                        .get(&branch.id)
+                        .copied()
+                        .flatten()
+                        .unwrap_or(branch.created);
+                    last_activity.timestamp() < cutoff
+                });
+                is_named || is_stale
+            })
+            .collect())
+    }
 }
 
 #[derive(Debug, diesel::Insertable)]
@@ -140,6 +225,7 @@ pub struct InsertBranch {
     pub start_point_id: Option<BranchVersionId>,
     pub created: DateTime,
     pub modified: DateTime,
+    pub archived: Option<DateTime>,
 }
 
 impl InsertBranch {
@@ -196,6 +282,7 @@ impl InsertBranch {
             start_point_id,
             created: timestamp,
             modified: timestamp,
+            archived: None,
         })
     }
 
@@ -300,6 +387,7 @@ impl InsertBranch {
             new_branch_id,
             query_threshold.testbed_id,
             query_threshold.measure_id,
+            query_threshold.benchmark_group.clone(),
         );
 
         // Create the new threshold
@@ -350,15 +438,23 @@ pub struct UpdateBranch {
     pub name: Option<BranchName>,
     pub slug: Option<Slug>,
     pub modified: DateTime,
+    pub archived: Option<Option<DateTime>>,
 }
 
 impl From<JsonUpdateBranch> for UpdateBranch {
     fn from(update: JsonUpdateBranch) -> Self {
-        let JsonUpdateBranch { name, slug, .. } = update;
+        let JsonUpdateBranch {
+            name,
+            slug,
+            archived,
+            ..
+        } = update;
+        let modified = DateTime::now();
         Self {
             name,
             slug,
-            modified: DateTime::now(),
+            modified,
+            archived: archived.map(|archived| archived.then_some(modified)),
         }
     }
 }
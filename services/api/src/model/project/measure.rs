@@ -1,24 +1,30 @@
 use bencher_json::{
     project::measure::{
-        JsonUpdateMeasure, MeasureUuid, ESTIMATED_CYCLES_NAME_STR, ESTIMATED_CYCLES_SLUG_STR,
-        INSTRUCTIONS_NAME_STR, INSTRUCTIONS_SLUG_STR, L1_ACCESSES_NAME_STR, L1_ACCESSES_SLUG_STR,
-        L2_ACCESSES_NAME_STR, L2_ACCESSES_SLUG_STR, LATENCY_NAME_STR, LATENCY_SLUG_STR,
-        RAM_ACCESSES_NAME_STR, RAM_ACCESSES_SLUG_STR, THROUGHPUT_NAME_STR, THROUGHPUT_SLUG_STR,
-        TOTAL_ACCESSES_SLUG_STR,
+        expression::MeasureExpression, JsonMeasurePatch, JsonMeasurePatchNull, JsonUpdateMeasure,
+        MeasureUuid, ALLOCATIONS_NAME_STR, ALLOCATIONS_SLUG_STR, ALLOCATION_RATE_NAME_STR,
+        ALLOCATION_RATE_SLUG_STR, BYTES_ALLOCATED_NAME_STR, BYTES_ALLOCATED_SLUG_STR,
+        ESTIMATED_CYCLES_NAME_STR, ESTIMATED_CYCLES_SLUG_STR, INSTRUCTIONS_NAME_STR,
+        INSTRUCTIONS_SLUG_STR, L1_ACCESSES_NAME_STR, L1_ACCESSES_SLUG_STR, L2_ACCESSES_NAME_STR,
+        L2_ACCESSES_SLUG_STR, LATENCY_NAME_STR, LATENCY_SLUG_STR, RAM_ACCESSES_NAME_STR,
+        RAM_ACCESSES_SLUG_STR, ROUNDS_NAME_STR, ROUNDS_SLUG_STR, THROUGHPUT_NAME_STR,
+        THROUGHPUT_SLUG_STR, TOTAL_ACCESSES_SLUG_STR,
     },
-    DateTime, JsonMeasure, JsonNewMeasure, MeasureNameId, ResourceName, Slug,
+    DateTime, JsonMeasure, JsonNewMeasure, MeasureNameId, MeasureUnit, ResourceName, Slug,
 };
 use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
 use dropshot::HttpError;
 
 use crate::{
     context::DbConnection,
-    error::{assert_parentage, resource_conflict_err, BencherResource},
+    error::{
+        assert_parentage, bad_request_error, resource_conflict_err, resource_not_found_err,
+        BencherResource,
+    },
     model::project::QueryProject,
     schema,
     schema::measure as measure_table,
     util::{
-        fn_get::{fn_get, fn_get_id, fn_get_uuid},
+        fn_get::{fn_from_uuid, fn_get, fn_get_id, fn_get_uuid},
         name_id::{fn_eq_name_id, fn_from_name_id},
         resource_id::{fn_eq_resource_id, fn_from_resource_id},
         slug::ok_slug,
@@ -41,6 +47,8 @@ pub struct QueryMeasure {
     pub name: ResourceName,
     pub slug: Slug,
     pub units: ResourceName,
+    pub unit: Option<MeasureUnit>,
+    pub expression: Option<MeasureExpression>,
     pub created: DateTime,
     pub modified: DateTime,
 }
@@ -54,17 +62,18 @@ impl QueryMeasure {
 
     fn_get!(measure, MeasureId);
     fn_get_id!(measure, MeasureId, MeasureUuid);
+    fn_from_uuid!(measure, MeasureUuid, Measure);
     fn_get_uuid!(measure, MeasureId, MeasureUuid);
 
     pub fn get_or_create(
         conn: &mut DbConnection,
         project_id: ProjectId,
         measure: &MeasureNameId,
-    ) -> Result<MeasureId, HttpError> {
+    ) -> Result<Self, HttpError> {
         let query_measure = Self::from_name_id(conn, project_id, measure);
 
         let http_error = match query_measure {
-            Ok(measure) => return Ok(measure.id),
+            Ok(measure) => return Ok(measure),
             Err(e) => e,
         };
 
@@ -81,6 +90,10 @@ impl QueryMeasure {
             RAM_ACCESSES_SLUG_STR => InsertMeasure::ram_accesses(conn, project_id),
             TOTAL_ACCESSES_SLUG_STR => InsertMeasure::total_accesses(conn, project_id),
             ESTIMATED_CYCLES_SLUG_STR => InsertMeasure::estimated_cycles(conn, project_id),
+            ALLOCATIONS_SLUG_STR => InsertMeasure::allocations(conn, project_id),
+            BYTES_ALLOCATED_SLUG_STR => InsertMeasure::bytes_allocated(conn, project_id),
+            ALLOCATION_RATE_SLUG_STR => InsertMeasure::allocation_rate(conn, project_id),
+            ROUNDS_SLUG_STR => InsertMeasure::rounds(conn, project_id),
             _ => return Err(http_error),
         }?;
         diesel::insert_into(schema::measure::table)
@@ -88,7 +101,16 @@ impl QueryMeasure {
             .execute(conn)
             .map_err(resource_conflict_err!(Measure, insert_measure))?;
 
-        Self::get_id(conn, insert_measure.uuid)
+        Self::from_uuid(conn, project_id, insert_measure.uuid)
+    }
+
+    /// Get all of the derived measures for a project.
+    pub fn derived(conn: &mut DbConnection, project_id: ProjectId) -> Result<Vec<Self>, HttpError> {
+        schema::measure::table
+            .filter(schema::measure::project_id.eq(project_id))
+            .filter(schema::measure::expression.is_not_null())
+            .load::<Self>(conn)
+            .map_err(resource_not_found_err!(Measure, project_id))
     }
 
     pub fn is_system(&self) -> bool {
@@ -107,6 +129,8 @@ impl QueryMeasure {
             name,
             slug,
             units,
+            unit,
+            expression,
             created,
             modified,
             ..
@@ -123,6 +147,9 @@ impl QueryMeasure {
             name,
             slug,
             units,
+            dimension: unit.map(MeasureUnit::dimension),
+            unit,
+            expression,
             created,
             modified,
         }
@@ -137,6 +164,8 @@ pub struct InsertMeasure {
     pub name: ResourceName,
     pub slug: Slug,
     pub units: ResourceName,
+    pub unit: Option<MeasureUnit>,
+    pub expression: Option<MeasureExpression>,
     pub created: DateTime,
     pub modified: DateTime,
 }
@@ -147,8 +176,15 @@ impl InsertMeasure {
         project_id: ProjectId,
         measure: JsonNewMeasure,
     ) -> Result<Self, HttpError> {
-        let JsonNewMeasure { name, slug, units } = measure;
+        let JsonNewMeasure {
+            name,
+            slug,
+            units,
+            unit,
+            expression,
+        } = measure;
         let slug = ok_slug!(conn, project_id, &name, slug, measure, QueryMeasure)?;
+        validate_expression(conn, project_id, &name, &slug, expression.as_ref())?;
         let timestamp = DateTime::now();
         Ok(Self {
             uuid: MeasureUuid::new(),
@@ -156,6 +192,8 @@ impl InsertMeasure {
             name,
             slug,
             units,
+            unit,
+            expression,
             created: timestamp,
             modified: timestamp,
         })
@@ -199,11 +237,111 @@ impl InsertMeasure {
         Self::from_json(conn, project_id, JsonNewMeasure::estimated_cycles())
     }
 
+    pub fn allocations(conn: &mut DbConnection, project_id: ProjectId) -> Result<Self, HttpError> {
+        Self::from_json(conn, project_id, JsonNewMeasure::allocations())
+    }
+
+    pub fn bytes_allocated(
+        conn: &mut DbConnection,
+        project_id: ProjectId,
+    ) -> Result<Self, HttpError> {
+        Self::from_json(conn, project_id, JsonNewMeasure::bytes_allocated())
+    }
+
+    pub fn allocation_rate(
+        conn: &mut DbConnection,
+        project_id: ProjectId,
+    ) -> Result<Self, HttpError> {
+        Self::from_json(conn, project_id, JsonNewMeasure::allocation_rate())
+    }
+
+    pub fn rounds(conn: &mut DbConnection, project_id: ProjectId) -> Result<Self, HttpError> {
+        Self::from_json(conn, project_id, JsonNewMeasure::rounds())
+    }
+
     pub fn is_system(&self) -> bool {
         is_system(self.name.as_ref(), self.slug.as_ref())
     }
 }
 
+// A derived measure can not be a built-in measure, as those are reported directly by the adapters,
+// and its expression can not depend on the measure itself.
+// Derived measures are only computed from the measures in a report,
+// so every measure in the expression must already exist and can not be a derived measure itself.
+pub fn validate_expression(
+    conn: &mut DbConnection,
+    project_id: ProjectId,
+    name: &ResourceName,
+    slug: &Slug,
+    expression: Option<&MeasureExpression>,
+) -> Result<(), HttpError> {
+    let Some(expression) = expression else {
+        return Ok(());
+    };
+    if is_system(name.as_ref(), slug.as_ref()) {
+        return Err(bad_request_error(format!(
+            "The built-in measure ({slug}) can not be derived from other measures"
+        )));
+    }
+    if expression.measures().contains(slug) {
+        return Err(bad_request_error(format!(
+            "The derived measure ({slug}) expression ({expression}) can not depend on itself"
+        )));
+    }
+    for measure in expression.measures() {
+        let Ok(measure_expression) = schema::measure::table
+            .filter(schema::measure::project_id.eq(project_id))
+            .filter(schema::measure::slug.eq(&measure))
+            .select(schema::measure::expression)
+            .first::<Option<MeasureExpression>>(conn)
+        else {
+            return Err(bad_request_error(format!(
+                "The derived measure ({slug}) expression ({expression}) uses an unknown measure ({measure})"
+            )));
+        };
+        if measure_expression.is_some() {
+            return Err(bad_request_error(format!(
+                "The derived measure ({slug}) expression ({expression}) uses another derived measure ({measure})"
+            )));
+        }
+    }
+    Ok(())
+}
+
+// A measure that is used in the expression of a derived measure
+// can not change its slug or become a derived measure itself,
+// as that derived measure would no longer have a value.
+pub fn validate_dependents(
+    conn: &mut DbConnection,
+    query_measure: &QueryMeasure,
+    slug: &Slug,
+    expression: Option<&MeasureExpression>,
+) -> Result<(), HttpError> {
+    if *slug == query_measure.slug && expression.is_none() {
+        return Ok(());
+    }
+    let expressions = schema::measure::table
+        .filter(schema::measure::project_id.eq(query_measure.project_id))
+        .filter(schema::measure::id.ne(query_measure.id))
+        .filter(schema::measure::expression.is_not_null())
+        .select((schema::measure::slug, schema::measure::expression))
+        .load::<(Slug, Option<MeasureExpression>)>(conn)
+        .map_err(resource_not_found_err!(Measure, query_measure))?;
+    for (dependent, dependent_expression) in expressions {
+        if dependent_expression.is_some_and(|dependent_expression| {
+            dependent_expression
+                .measures()
+                .contains(&query_measure.slug)
+        }) {
+            return Err(bad_request_error(format!(
+                "The measure ({}) is used by the derived measure ({dependent})",
+                query_measure.slug
+            )));
+        }
+    }
+    Ok(())
+}
+
 fn is_system(name: &str, slug: &str) -> bool {
     matches!(
         name,
@@ -215,6 +353,10 @@ fn is_system(name: &str, slug: &str) -> bool {
             | RAM_ACCESSES_NAME_STR
             | TOTAL_ACCESSES_SLUG_STR
             | ESTIMATED_CYCLES_NAME_STR
+            | ALLOCATIONS_NAME_STR
+            | BYTES_ALLOCATED_NAME_STR
+            | ALLOCATION_RATE_NAME_STR
+            | ROUNDS_NAME_STR
     ) || matches!(
         slug,
         LATENCY_SLUG_STR
@@ -225,6 +367,10 @@ fn is_system(name: &str, slug: &str) -> bool {
             | RAM_ACCESSES_SLUG_STR
             | TOTAL_ACCESSES_SLUG_STR
             | ESTIMATED_CYCLES_SLUG_STR
+            | ALLOCATIONS_SLUG_STR
+            | BYTES_ALLOCATED_SLUG_STR
+            | ALLOCATION_RATE_SLUG_STR
+            | ROUNDS_SLUG_STR
     )
 }
 
@@ -234,17 +380,48 @@ pub struct UpdateMeasure {
     pub name: Option<ResourceName>,
     pub slug: Option<Slug>,
     pub units: Option<ResourceName>,
+    pub unit: Option<MeasureUnit>,
+    pub expression: Option<Option<MeasureExpression>>,
     pub modified: DateTime,
 }
 
 impl From<JsonUpdateMeasure> for UpdateMeasure {
     fn from(update: JsonUpdateMeasure) -> Self {
-        let JsonUpdateMeasure { name, slug, units } = update;
-        Self {
-            name,
-            slug,
-            units,
-            modified: DateTime::now(),
+        match update {
+            JsonUpdateMeasure::Patch(patch) => {
+                let JsonMeasurePatch {
+                    name,
+                    slug,
+                    units,
+                    unit,
+                    expression,
+                } = patch;
+                Self {
+                    name,
+                    slug,
+                    units,
+                    unit,
+                    expression: expression.map(Some),
+                    modified: DateTime::now(),
+                }
+            },
+            JsonUpdateMeasure::Null(patch_expression) => {
+                let JsonMeasurePatchNull {
+                    name,
+                    slug,
+                    units,
+                    unit,
+                    expression: (),
+                } = patch_expression;
+                Self {
+                    name,
+                    slug,
+                    units,
+                    unit,
+                    expression: Some(None),
+                    modified: DateTime::now(),
+                }
+            },
         }
     }
 }
@@ -6,6 +6,7 @@ use crate::{
     context::DbConnection,
     error::resource_not_found_err,
     schema::{self, metric as metric_table},
+    util::fn_get::fn_get_id,
 };
 
 use super::{
@@ -32,6 +33,8 @@ pub struct QueryMetric {
 }
 
 impl QueryMetric {
+    fn_get_id!(metric, MetricId, MetricUuid);
+
     pub fn from_uuid(conn: &mut DbConnection, uuid: MetricUuid) -> Result<Self, HttpError> {
         schema::metric::table
             .filter(schema::metric::uuid.eq(uuid))
@@ -22,6 +22,7 @@ use crate::{
 
 use super::{organization::OrganizationId, user::auth::BEARER_TOKEN_FORMAT};
 
+pub mod archive;
 pub mod benchmark;
 pub mod branch;
 pub mod branch_version;
@@ -30,9 +31,11 @@ pub mod metric;
 pub mod metric_boundary;
 pub mod project_role;
 pub mod report;
+pub mod retention;
 pub mod testbed;
 pub mod threshold;
 pub mod version;
+pub mod webhook;
 
 crate::util::typed_id::typed_id!(ProjectId);
 
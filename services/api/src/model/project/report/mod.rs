@@ -1,5 +1,8 @@
 use bencher_json::{
-    project::report::{Adapter, Iteration, JsonReportAlerts, JsonReportResult, JsonReportResults},
+    project::{
+        alert::AlertStatus,
+        report::{Adapter, Iteration, JsonReportAlerts, JsonReportResult, JsonReportResults},
+    },
     DateTime, JsonNewReport, JsonReport, ReportUuid,
 };
 use diesel::{
@@ -135,6 +138,7 @@ fn get_report_results(
                 schema::threshold::model_id,
                 schema::threshold::created,
                 schema::threshold::modified,
+                schema::threshold::benchmark_group,
             ),
             (
                 schema::model::id,
@@ -248,6 +252,8 @@ fn get_report_alerts(
             ),
         )
         .filter(schema::report::id.eq(report_id))
+        // Silenced alerts are not reported
+        .filter(schema::alert::status.ne(AlertStatus::Silenced))
         .order((schema::report_benchmark::iteration, schema::benchmark::name))
         .select((
             schema::report::uuid,
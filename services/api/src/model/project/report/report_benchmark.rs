@@ -10,7 +10,7 @@ use super::{QueryReport, ReportId};
 
 crate::util::typed_id::typed_id!(ReportBenchmarkId);
 
-#[derive(diesel::Queryable, diesel::Identifiable, diesel::Associations)]
+#[derive(diesel::Queryable, diesel::Identifiable, diesel::Associations, diesel::Selectable)]
 #[diesel(table_name = report_benchmark_table)]
 #[diesel(belongs_to(QueryReport, foreign_key = report_id))]
 pub struct QueryReportBenchmark {
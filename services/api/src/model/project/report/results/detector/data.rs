@@ -1,4 +1,5 @@
 use bencher_boundary::MetricsData;
+use bencher_json::DateTime;
 use chrono::offset::Utc;
 use diesel::{ExpressionMethods, JoinOnDsl, QueryDsl, RunQueryDsl};
 use dropshot::HttpError;
@@ -8,13 +9,15 @@ use crate::{
     context::DbConnection,
     error::not_found_error,
     model::project::{
-        benchmark::BenchmarkId, branch::BranchId, measure::MeasureId, testbed::TestbedId,
+        benchmark::BenchmarkId, branch::BranchId, measure::MeasureId, report::ReportId,
+        testbed::TestbedId,
     },
     schema,
 };
 
 use super::threshold::ThresholdModel;
 
+#[allow(clippy::too_many_arguments)]
 pub fn metrics_data(
     log: &Logger,
     conn: &mut DbConnection,
@@ -23,6 +26,8 @@ pub fn metrics_data(
     benchmark_id: BenchmarkId,
     measure_id: MeasureId,
     model: &ThresholdModel,
+    exclude_report_id: Option<ReportId>,
+    historical: Option<DateTime>,
 ) -> Result<MetricsData, HttpError> {
     let mut query =
         schema::metric::table
@@ -47,8 +52,20 @@ pub fn metrics_data(
             .filter(schema::metric::measure_id.eq(measure_id))
             .into_boxed();
 
+    if let Some(report_id) = exclude_report_id {
+        query = query.filter(schema::report::id.ne(report_id));
+    }
+
+    // Historical reports only use the metrics from reports that started before them
+    if let Some(start_time) = historical {
+        query = query.filter(schema::report::start_time.le(start_time));
+    }
+
     if let Some(window) = model.window {
-        let now = Utc::now().timestamp();
+        let now = historical.map_or_else(
+            || Utc::now().timestamp(),
+            |start_time| start_time.timestamp(),
+        );
         if let Some(start_time) = now.checked_sub(window.into()) {
             query = query.filter(schema::report::start_time.ge(start_time));
         } else {
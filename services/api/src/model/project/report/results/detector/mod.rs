@@ -1,20 +1,27 @@
 use bencher_boundary::MetricsBoundary;
-use bencher_json::BoundaryUuid;
-use diesel::RunQueryDsl;
+use bencher_json::{project::alert::AlertStatus, BenchmarkGroup, BoundaryUuid, DateTime};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
 use dropshot::HttpError;
 use slog::Logger;
 
 use crate::{
     conn_lock,
     context::{ApiContext, DbConnection},
-    error::{bad_request_error, resource_conflict_err},
+    error::{bad_request_error, resource_conflict_err, resource_not_found_err},
     model::project::{
         benchmark::BenchmarkId,
         branch::BranchId,
         measure::MeasureId,
         metric::QueryMetric,
+        report::ReportId,
         testbed::TestbedId,
-        threshold::{alert::InsertAlert, boundary::InsertBoundary},
+        threshold::{
+            alert::{InsertAlert, QueryAlert},
+            boundary::{BoundaryId, InsertBoundary},
+            model::QueryModel,
+            silence::QuerySilence,
+        },
+        ProjectId,
     },
     schema,
 };
@@ -23,30 +30,39 @@ pub mod data;
 pub mod threshold;
 
 use data::metrics_data;
-use threshold::Threshold;
+use threshold::{Threshold, ThresholdModel};
 
 #[derive(Debug, Clone)]
 pub struct Detector {
+    pub project_id: ProjectId,
     pub branch_id: BranchId,
     pub testbed_id: TestbedId,
     pub measure_id: MeasureId,
     pub threshold: Threshold,
+    pub historical: Option<DateTime>,
 }
 
 impl Detector {
     pub fn new(
         conn: &mut DbConnection,
+        project_id: ProjectId,
         branch_id: BranchId,
         testbed_id: TestbedId,
         measure_id: MeasureId,
+        benchmark_group: Option<&BenchmarkGroup>,
+        historical: Option<DateTime>,
     ) -> Option<Self> {
-        // Check to see if there is a threshold for the branch/testbed/measure grouping.
+        // Check to see if there is a threshold for the branch/testbed/measure/benchmark group grouping.
         // If not, then there will be nothing to detect.
-        Threshold::new(conn, branch_id, testbed_id, measure_id).map(|threshold| Self {
-            branch_id,
-            testbed_id,
-            measure_id,
-            threshold,
+        Threshold::new(conn, branch_id, testbed_id, measure_id, benchmark_group).map(|threshold| {
+            Self {
+                project_id,
+                branch_id,
+                testbed_id,
+                measure_id,
+                threshold,
+                historical,
+            }
         })
     }
 
@@ -66,6 +82,8 @@ impl Detector {
             benchmark_id,
             self.measure_id,
             &self.threshold.model,
+            None,
+            self.historical,
         )?;
 
         // Check to see if the metric has a boundary check for the given threshold model.
@@ -80,7 +98,66 @@ impl Detector {
         )
         .map_err(bad_request_error)?;
 
+        self.insert_boundary(context, benchmark_id, query_metric, boundary)
+            .await
+    }
+
+    // Check all of the metrics from every iteration of a report for a benchmark at once.
+    // The boundary for the entire sample is added to the metric from the last iteration.
+    pub async fn detect_sample(
+        &self,
+        log: &Logger,
+        context: &ApiContext,
+        report_id: ReportId,
+        benchmark_id: BenchmarkId,
+        query_metrics: &[QueryMetric],
+    ) -> Result<(), HttpError> {
+        let Some(last_metric) = query_metrics.last() else {
+            return Ok(());
+        };
+
+        // Query the historical population/sample data for the benchmark,
+        // excluding the new sample from the current report.
+        let metrics_data = metrics_data(
+            log,
+            conn_lock!(context),
+            self.branch_id,
+            self.testbed_id,
+            benchmark_id,
+            self.measure_id,
+            &self.threshold.model,
+            Some(report_id),
+            self.historical,
+        )?;
+
+        let sample = query_metrics
+            .iter()
+            .map(|query_metric| query_metric.value)
+            .collect::<Vec<f64>>();
+        let boundary = MetricsBoundary::new_sample(
+            log,
+            &sample,
+            &metrics_data,
+            self.threshold.model.test,
+            self.threshold.model.min_sample_size,
+            self.threshold.model.lower_boundary,
+            self.threshold.model.upper_boundary,
+        )
+        .map_err(bad_request_error)?;
+
+        self.insert_boundary(context, benchmark_id, last_metric, boundary)
+            .await
+    }
+
+    async fn insert_boundary(
+        &self,
+        context: &ApiContext,
+        benchmark_id: BenchmarkId,
+        query_metric: &QueryMetric,
+        boundary: MetricsBoundary,
+    ) -> Result<(), HttpError> {
         let boundary_uuid = BoundaryUuid::new();
+        let has_limits = boundary.limits.lower.is_some() || boundary.limits.upper.is_some();
         let insert_boundary = InsertBoundary {
             uuid: boundary_uuid,
             threshold_id: self.threshold.id,
@@ -89,6 +166,7 @@ impl Detector {
             baseline: boundary.limits.baseline,
             lower_limit: boundary.limits.lower.map(Into::into),
             upper_limit: boundary.limits.upper.map(Into::into),
+            effect_size: boundary.effect_size,
         };
 
         diesel::insert_into(schema::boundary::table)
@@ -96,11 +174,140 @@ impl Detector {
             .execute(conn_lock!(context))
             .map_err(resource_conflict_err!(Boundary, insert_boundary))?;
 
+        // Historical reports are imported without generating or resolving any alerts.
+        if self.historical.is_some() {
+            return Ok(());
+        }
+
         // If the boundary check detects an outlier then create an alert for it on the given side.
+        // Alerts that match an unexpired silence are created as already silenced.
+        // Otherwise, if the metric is back inside of the boundary limits,
+        // then resolve any outstanding alerts for the threshold and benchmark.
         if let Some(boundary_limit) = boundary.outlier {
-            InsertAlert::from_boundary(conn_lock!(context), boundary_uuid, boundary_limit)
+            let status = if QuerySilence::is_silenced(
+                conn_lock!(context),
+                self.project_id,
+                benchmark_id,
+                self.measure_id,
+                self.testbed_id,
+            )? {
+                AlertStatus::Silenced
+            } else {
+                AlertStatus::Active
+            };
+            InsertAlert::from_boundary(conn_lock!(context), boundary_uuid, boundary_limit, status)
+        } else if has_limits {
+            QueryAlert::resolve(conn_lock!(context), self.threshold.id, benchmark_id)
         } else {
             Ok(())
         }
     }
+
+    /// Recompute the boundaries for the reports on a branch and testbed that started at or after `since`,
+    /// except for the reports in `skip_reports`.
+    /// When historical reports are imported before existing reports,
+    /// the boundaries for those existing reports were computed from a shorter history.
+    /// The boundary limits are updated in place, so no alerts are created, resolved, or removed.
+    pub fn recompute(
+        log: &Logger,
+        conn: &mut DbConnection,
+        branch_id: BranchId,
+        testbed_id: TestbedId,
+        since: DateTime,
+        skip_reports: &[ReportId],
+    ) -> Result<usize, HttpError> {
+        let boundaries = schema::boundary::table
+            .inner_join(schema::model::table)
+            .inner_join(
+                schema::metric::table
+                    .inner_join(schema::report_benchmark::table.inner_join(schema::report::table)),
+            )
+            .filter(schema::report::branch_id.eq(branch_id))
+            .filter(schema::report::testbed_id.eq(testbed_id))
+            .filter(schema::report::start_time.ge(since))
+            .filter(schema::report::id.ne_all(skip_reports.to_vec()))
+            .order((
+                schema::report::start_time,
+                schema::report_benchmark::iteration,
+            ))
+            .select((
+                schema::boundary::id,
+                QueryModel::as_select(),
+                schema::report::id,
+                schema::report::start_time,
+                schema::report_benchmark::benchmark_id,
+                schema::metric::measure_id,
+                schema::metric::value,
+            ))
+            .load::<(
+                BoundaryId,
+                QueryModel,
+                ReportId,
+                DateTime,
+                BenchmarkId,
+                MeasureId,
+                f64,
+            )>(conn)
+            .map_err(resource_not_found_err!(
+                Boundary,
+                (branch_id, testbed_id, since)
+            ))?;
+
+        let count = boundaries.len();
+        for (boundary_id, query_model, report_id, start_time, benchmark_id, measure_id, value) in
+            boundaries
+        {
+            let model = ThresholdModel::from(query_model);
+            // Two sample boundaries are for all of the iterations of the benchmark in the report,
+            // and the report itself is not part of the historical data.
+            let (sample, exclude_report_id) = if model.test.is_two_sample() {
+                let sample = schema::metric::table
+                    .inner_join(schema::report_benchmark::table)
+                    .filter(schema::report_benchmark::report_id.eq(report_id))
+                    .filter(schema::report_benchmark::benchmark_id.eq(benchmark_id))
+                    .filter(schema::metric::measure_id.eq(measure_id))
+                    .order(schema::report_benchmark::iteration)
+                    .select(schema::metric::value)
+                    .load::<f64>(conn)
+                    .map_err(resource_not_found_err!(Metric, (report_id, benchmark_id)))?;
+                (sample, Some(report_id))
+            } else {
+                (vec![value], None)
+            };
+
+            let metrics_data = metrics_data(
+                log,
+                conn,
+                branch_id,
+                testbed_id,
+                benchmark_id,
+                measure_id,
+                &model,
+                exclude_report_id,
+                Some(start_time),
+            )?;
+            let boundary = MetricsBoundary::new_sample(
+                log,
+                &sample,
+                &metrics_data,
+                model.test,
+                model.min_sample_size,
+                model.lower_boundary,
+                model.upper_boundary,
+            )
+            .map_err(bad_request_error)?;
+
+            diesel::update(schema::boundary::table.filter(schema::boundary::id.eq(boundary_id)))
+                .set((
+                    schema::boundary::baseline.eq(boundary.limits.baseline),
+                    schema::boundary::lower_limit.eq(boundary.limits.lower.map(f64::from)),
+                    schema::boundary::upper_limit.eq(boundary.limits.upper.map(f64::from)),
+                    schema::boundary::effect_size.eq(boundary.effect_size),
+                ))
+                .execute(conn)
+                .map_err(resource_conflict_err!(Boundary, boundary_id))?;
+        }
+
+        Ok(count)
+    }
 }
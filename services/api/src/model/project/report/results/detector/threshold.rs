@@ -1,4 +1,4 @@
-use bencher_json::{Boundary, ModelTest, SampleSize, Window};
+use bencher_json::{BenchmarkGroup, Boundary, ModelTest, SampleSize, Window};
 use diesel::{
     ExpressionMethods, JoinOnDsl, NullableExpressionMethods, QueryDsl, RunQueryDsl,
     SelectableHelper,
@@ -36,11 +36,15 @@ pub struct ThresholdModel {
 }
 
 impl Threshold {
+    /// Find the threshold for the branch, testbed, and measure that applies to the benchmark group.
+    /// The threshold for the most specific group that contains the benchmark group is used.
+    /// Otherwise, the threshold without a group is used, if there is one.
     pub fn new(
         conn: &mut DbConnection,
         branch_id: BranchId,
         testbed_id: TestbedId,
         measure_id: MeasureId,
+        benchmark_group: Option<&BenchmarkGroup>,
     ) -> Option<Self> {
         schema::model::table
             .inner_join(
@@ -50,33 +54,51 @@ impl Threshold {
             .filter(schema::threshold::branch_id.eq(branch_id))
             .filter(schema::threshold::testbed_id.eq(testbed_id))
             .filter(schema::threshold::measure_id.eq(measure_id))
-            .select((schema::threshold::id, QueryModel::as_select()))
-            .first::<(ThresholdId, QueryModel)>(conn)
-            .map(|(threshold_id, query_model)| {
-                let QueryModel {
-                    id,
-                    test,
-                    min_sample_size,
-                    max_sample_size,
-                    window,
-                    lower_boundary,
-                    upper_boundary,
-                    ..
-                } = query_model;
-                let model = ThresholdModel {
-                    id,
-                    test,
-                    min_sample_size,
-                    max_sample_size,
-                    window,
-                    lower_boundary,
-                    upper_boundary,
-                };
-                Self {
-                    id: threshold_id,
-                    model,
-                }
+            .select((
+                schema::threshold::id,
+                schema::threshold::benchmark_group,
+                QueryModel::as_select(),
+            ))
+            .load::<(ThresholdId, Option<BenchmarkGroup>, QueryModel)>(conn)
+            .ok()?
+            .into_iter()
+            .filter_map(
+                |(threshold_id, group, query_model)| match (&group, benchmark_group) {
+                    (None, _) => Some((None, threshold_id, query_model)),
+                    (Some(group), Some(benchmark_group)) if group.contains(benchmark_group) => {
+                        Some((Some(group.depth()), threshold_id, query_model))
+                    },
+                    (Some(_), _) => None,
+                },
+            )
+            .max_by_key(|(depth, _, _)| *depth)
+            .map(|(_, threshold_id, query_model)| Self {
+                id: threshold_id,
+                model: query_model.into(),
             })
-            .ok()
+    }
+}
+
+impl From<QueryModel> for ThresholdModel {
+    fn from(query_model: QueryModel) -> Self {
+        let QueryModel {
+            id,
+            test,
+            min_sample_size,
+            max_sample_size,
+            window,
+            lower_boundary,
+            upper_boundary,
+            ..
+        } = query_model;
+        Self {
+            id,
+            test,
+            min_sample_size,
+            max_sample_size,
+            window,
+            lower_boundary,
+            upper_boundary,
+        }
     }
 }
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use bencher_adapter::{
     results::adapter_metrics::AdapterMetrics, AdapterResults, AdapterResultsArray,
@@ -6,7 +6,7 @@ use bencher_adapter::{
 };
 use bencher_json::{
     project::report::{Adapter, Iteration, JsonReportSettings},
-    BenchmarkName, MeasureNameId,
+    BenchmarkGroup, BenchmarkName, DateTime, JsonMetric, MeasureNameId, MeasureUnit,
 };
 use diesel::RunQueryDsl;
 use dropshot::HttpError;
@@ -41,9 +41,13 @@ pub struct ReportResults {
     pub branch_id: BranchId,
     pub testbed_id: TestbedId,
     pub report_id: ReportId,
-    pub benchmark_cache: HashMap<BenchmarkName, BenchmarkId>,
-    pub measure_cache: HashMap<MeasureNameId, MeasureId>,
-    pub detector_cache: HashMap<MeasureId, Option<Detector>>,
+    pub historical: Option<DateTime>,
+    pub benchmark_cache: HashMap<BenchmarkName, (BenchmarkId, Option<BenchmarkGroup>)>,
+    pub measure_cache: HashMap<MeasureNameId, QueryMeasure>,
+    pub derived_measures: Vec<QueryMeasure>,
+    pub detector_cache: HashMap<(MeasureId, Option<BenchmarkGroup>), Option<Detector>>,
+    // Ordered so that the boundaries and alerts from the two sample tests are always created in the same order
+    pub sample_cache: BTreeMap<(BenchmarkId, MeasureId), (Detector, Vec<QueryMetric>)>,
 }
 
 impl ReportResults {
@@ -52,15 +56,19 @@ impl ReportResults {
         branch_id: BranchId,
         testbed_id: TestbedId,
         report_id: ReportId,
+        historical: Option<DateTime>,
     ) -> Self {
         Self {
             project_id,
             branch_id,
             testbed_id,
             report_id,
+            historical,
             benchmark_cache: HashMap::new(),
             measure_cache: HashMap::new(),
+            derived_measures: Vec::new(),
             detector_cache: HashMap::new(),
+            sample_cache: BTreeMap::new(),
         }
     }
 
@@ -81,6 +89,9 @@ impl ReportResults {
                 ))
             })?;
 
+        // Derived measures are computed for each benchmark from the other reported measures
+        self.derived_measures = QueryMeasure::derived(conn_lock!(context), self.project_id)?;
+
         if let Some(fold) = settings.fold {
             let results = results_array.fold(fold);
             self.results(
@@ -106,7 +117,7 @@ impl ReportResults {
             }
         };
 
-        Ok(())
+        self.detect_samples(log, context).await
     }
 
     async fn results(
@@ -143,7 +154,7 @@ impl ReportResults {
     ) -> Result<(), HttpError> {
         // If benchmark name is ignored then strip the special suffix before querying
         let (benchmark_name, ignore_benchmark) = benchmark_name.to_strip_ignore();
-        let benchmark_id = self.benchmark_id(context, benchmark_name).await?;
+        let (benchmark_id, benchmark_group) = self.benchmark(context, benchmark_name).await?;
 
         let insert_report_benchmark =
             InsertReportBenchmark::from_json(self.report_id, iteration, benchmark_id);
@@ -157,9 +168,31 @@ impl ReportResults {
         let report_benchmark_id =
             QueryReportBenchmark::get_id(conn_lock!(context), insert_report_benchmark.uuid)?;
 
+        let mut measure_metrics = Vec::with_capacity(metrics.inner.len());
+        let mut values = HashMap::with_capacity(metrics.inner.len());
         for (measure_key, metric) in metrics.inner {
-            let measure_id = self.measure_id(context, measure_key).await?;
+            let query_measure = self.measure(context, measure_key.clone()).await?;
+            if query_measure.expression.is_some() {
+                return Err(bad_request_error(format!(
+                    "The measure ({measure_key}) is derived from other measures and can not be reported directly"
+                )));
+            }
+            let metric = convert_metric(&measure_key, query_measure.unit, metric)?;
+            values.insert(query_measure.slug, f64::from(metric.value));
+            measure_metrics.push((query_measure.id, metric));
+        }
+        for derived_measure in &self.derived_measures {
+            let Some(value) = derived_measure
+                .expression
+                .as_ref()
+                .and_then(|expression| expression.evaluate(|slug| values.get(slug).copied()))
+            else {
+                continue;
+            };
+            measure_metrics.push((derived_measure.id, JsonMetric::new(value, None, None)));
+        }
 
+        for (measure_id, metric) in measure_metrics {
             let insert_metric = InsertMetric::from_json(report_benchmark_id, measure_id, metric);
             diesel::insert_into(schema::metric::table)
                 .values(&insert_metric)
@@ -174,7 +207,10 @@ impl ReportResults {
 
             // Ignored benchmarks do not get checked against the threshold even if one exists
             if !ignore_benchmark {
-                let Some(detector) = self.detector(context, measure_id).await else {
+                let Some(detector) = self
+                    .detector(context, measure_id, benchmark_group.clone())
+                    .await
+                else {
                     continue;
                 };
                 let query_metric = QueryMetric::from_uuid(conn_lock!(context), insert_metric.uuid).map_err(|e| {
@@ -185,62 +221,131 @@ impl ReportResults {
                         e,
                     )
                 })?;
-                detector
-                    .detect(log, context, benchmark_id, &query_metric)
-                    .await?;
+                // Two sample tests are run once all of the iterations have been processed
+                if detector.threshold.model.test.is_two_sample() {
+                    self.sample_cache
+                        .entry((benchmark_id, measure_id))
+                        .or_insert_with(|| (detector, Vec::new()))
+                        .1
+                        .push(query_metric);
+                } else {
+                    detector
+                        .detect(log, context, benchmark_id, &query_metric)
+                        .await?;
+                }
             }
         }
 
         Ok(())
     }
 
-    async fn benchmark_id(
+    async fn detect_samples(
+        &mut self,
+        log: &Logger,
+        context: &ApiContext,
+    ) -> Result<(), HttpError> {
+        for ((benchmark_id, _measure_id), (detector, query_metrics)) in
+            std::mem::take(&mut self.sample_cache)
+        {
+            detector
+                .detect_sample(log, context, self.report_id, benchmark_id, &query_metrics)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn benchmark(
         &mut self,
         context: &ApiContext,
         benchmark_name: BenchmarkName,
-    ) -> Result<BenchmarkId, HttpError> {
+    ) -> Result<(BenchmarkId, Option<BenchmarkGroup>), HttpError> {
         Ok(
-            if let Some(id) = self.benchmark_cache.get(&benchmark_name) {
-                *id
+            if let Some(benchmark) = self.benchmark_cache.get(&benchmark_name) {
+                benchmark.clone()
             } else {
-                let benchmark_id = QueryBenchmark::get_or_create(
+                let query_benchmark = QueryBenchmark::get_or_create(
                     conn_lock!(context),
                     self.project_id,
                     benchmark_name.clone(),
                 )?;
-                self.benchmark_cache.insert(benchmark_name, benchmark_id);
-                benchmark_id
+                let benchmark = (query_benchmark.id, query_benchmark.group());
+                self.benchmark_cache
+                    .insert(benchmark_name, benchmark.clone());
+                benchmark
             },
         )
     }
 
-    async fn measure_id(
+    async fn measure(
         &mut self,
         context: &ApiContext,
         measure: MeasureNameId,
-    ) -> Result<MeasureId, HttpError> {
-        Ok(if let Some(id) = self.measure_cache.get(&measure) {
-            *id
-        } else {
-            let measure_id =
-                QueryMeasure::get_or_create(conn_lock!(context), self.project_id, &measure)?;
-            self.measure_cache.insert(measure, measure_id);
-            measure_id
-        })
+    ) -> Result<QueryMeasure, HttpError> {
+        Ok(
+            if let Some(query_measure) = self.measure_cache.get(&measure) {
+                query_measure.clone()
+            } else {
+                let query_measure =
+                    QueryMeasure::get_or_create(conn_lock!(context), self.project_id, &measure)?;
+                self.measure_cache.insert(measure, query_measure.clone());
+                query_measure
+            },
+        )
     }
 
-    async fn detector(&mut self, context: &ApiContext, measure_id: MeasureId) -> Option<Detector> {
-        if let Some(detector) = self.detector_cache.get(&measure_id) {
+    async fn detector(
+        &mut self,
+        context: &ApiContext,
+        measure_id: MeasureId,
+        benchmark_group: Option<BenchmarkGroup>,
+    ) -> Option<Detector> {
+        let key = (measure_id, benchmark_group);
+        if let Some(detector) = self.detector_cache.get(&key) {
             detector.clone()
         } else {
             let detector = Detector::new(
                 conn_lock!(context),
+                self.project_id,
                 self.branch_id,
                 self.testbed_id,
                 measure_id,
+                key.1.as_ref(),
+                self.historical,
             );
-            self.detector_cache.insert(measure_id, detector.clone());
+            self.detector_cache.insert(key, detector.clone());
             detector
         }
     }
 }
+
+// Convert a metric from the unit its adapter reports in to the unit of its measure.
+// Measures without a unit and measures without a known adapter unit are stored as-is.
+fn convert_metric(
+    measure: &MeasureNameId,
+    measure_unit: Option<MeasureUnit>,
+    metric: JsonMetric,
+) -> Result<JsonMetric, HttpError> {
+    let (Some(from), Some(to)) = (AdapterResults::measure_unit(measure), measure_unit) else {
+        return Ok(metric);
+    };
+    if from == to {
+        return Ok(metric);
+    }
+    let Some(factor) = from.convert(1.0, to) else {
+        return Err(bad_request_error(format!(
+            "The measure ({measure}) is in {to} ({}) but the results are in {from} ({})",
+            to.dimension(),
+            from.dimension()
+        )));
+    };
+    let JsonMetric {
+        value,
+        lower_value,
+        upper_value,
+    } = metric;
+    Ok(JsonMetric {
+        value: value * factor,
+        lower_value: lower_value.map(|v| v * factor),
+        upper_value: upper_value.map(|v| v * factor),
+    })
+}
@@ -0,0 +1,639 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use bencher_json::{DateTime, JsonNewRetention, JsonRetention, JsonRetentionPrune, Window};
+use diesel::{
+    BoolExpressionMethods, Connection, ExpressionMethods, NullableExpressionMethods, QueryDsl,
+    RunQueryDsl,
+};
+use dropshot::HttpError;
+use http::StatusCode;
+use slog::{error, info, Logger};
+
+use super::{
+    benchmark::BenchmarkId,
+    branch::{BranchId, QueryBranch},
+    measure::MeasureId,
+    metric::MetricId,
+    report::{report_benchmark::ReportBenchmarkId, ReportId},
+    testbed::TestbedId,
+    threshold::{alert::AlertId, boundary::BoundaryId},
+    ProjectId, QueryProject,
+};
+use crate::{
+    context::{DbConnection, DbPool},
+    error::{bad_request_error, issue_error, resource_not_found_err},
+    schema::retention as retention_table,
+    schema::{self},
+};
+
+// How often the retention policies for all projects are enforced
+const RETENTION_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+// Keep the number of bound parameters in a single statement well under the SQLite limit
+const PRUNE_CHUNK_SIZE: usize = 512;
+
+crate::util::typed_id::typed_id!(RetentionId);
+
+#[derive(
+    Debug, Clone, diesel::Queryable, diesel::Identifiable, diesel::Associations, diesel::Selectable,
+)]
+#[diesel(table_name = retention_table)]
+#[diesel(belongs_to(QueryProject, foreign_key = project_id))]
+pub struct QueryRetention {
+    pub id: RetentionId,
+    pub project_id: ProjectId,
+    pub raw_window: Window,
+    pub downsample: bool,
+    pub protected_branch_id: Option<BranchId>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl QueryRetention {
+    pub fn from_project(conn: &mut DbConnection, project_id: ProjectId) -> Result<Self, HttpError> {
+        schema::retention::table
+            .filter(schema::retention::project_id.eq(project_id))
+            .first::<Self>(conn)
+            .map_err(resource_not_found_err!(Retention, project_id))
+    }
+
+    pub fn into_json(
+        self,
+        conn: &mut DbConnection,
+        project: &QueryProject,
+    ) -> Result<JsonRetention, HttpError> {
+        let Self {
+            raw_window,
+            downsample,
+            protected_branch_id,
+            created,
+            modified,
+            ..
+        } = self;
+        let protected_branch = if let Some(branch_id) = protected_branch_id {
+            Some(QueryBranch::get_uuid(conn, branch_id)?)
+        } else {
+            None
+        };
+        Ok(JsonRetention {
+            project: project.uuid,
+            raw_window,
+            downsample,
+            protected_branch,
+            created,
+            modified,
+        })
+    }
+
+    /// Metrics from reports that started before the cutoff are subject to retention.
+    /// The cutoff is rounded down to the start of the day,
+    /// so that only whole days are ever downsampled.
+    fn cutoff(&self, now: DateTime) -> Result<DateTime, HttpError> {
+        let cutoff = now.timestamp() - i64::from(self.raw_window);
+        DateTime::try_from(cutoff - cutoff.rem_euclid(SECONDS_PER_DAY)).map_err(bad_request_error)
+    }
+
+    /// Enforce the retention policy for the project.
+    /// Old reports are pruned in batches, one day for each branch and testbed at a time,
+    /// and the connection is only held for a single batch.
+    /// All of the metrics for a report and for a downsampled day are always in the same batch.
+    /// If `dry_run` is `true`, then nothing is removed
+    /// and the summary is of what would have been removed.
+    pub async fn prune(
+        &self,
+        pool: &DbPool,
+        project: &QueryProject,
+        dry_run: bool,
+    ) -> Result<JsonRetentionPrune, HttpError> {
+        let cutoff = self.cutoff(DateTime::now())?;
+        let batches = self.old_reports(&mut *pool.get().await, cutoff)?;
+        let mut json = JsonRetentionPrune {
+            project: project.uuid,
+            dry_run,
+            cutoff,
+            metrics: 0,
+            downsampled: 0,
+            boundaries: 0,
+            alerts: 0,
+            reports: 0,
+        };
+        for reports in batches {
+            let conn = &mut *pool.get().await;
+            let plan = PrunePlan::new(&old_metrics(conn, &reports)?, self.downsample);
+            if !dry_run {
+                conn.transaction(|conn| plan.execute(conn)).map_err(|e| {
+                    issue_error(
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        "Failed to enforce retention policy",
+                        &format!("Failed to enforce retention policy for project ({project:?})."),
+                        e,
+                    )
+                })?;
+            }
+            plan.add_to(&mut json);
+        }
+        Ok(json)
+    }
+
+    /// The reports that started before the cutoff, batched by branch, testbed, and day.
+    /// Reports on the protected branch for a tagged version (ie a version with a git hash)
+    /// are always kept, so they are never subject to retention.
+    fn old_reports(
+        &self,
+        conn: &mut DbConnection,
+        cutoff: DateTime,
+    ) -> Result<Vec<Vec<ReportId>>, HttpError> {
+        let mut query = schema::report::table
+            .inner_join(schema::version::table)
+            .filter(schema::report::project_id.eq(self.project_id))
+            .filter(schema::report::start_time.lt(cutoff))
+            .into_boxed();
+        if let Some(branch_id) = self.protected_branch_id {
+            query = query.filter(
+                schema::report::branch_id
+                    .ne(branch_id)
+                    .or(schema::version::hash.is_null()),
+            );
+        }
+        let old_reports = query
+            .select((
+                schema::report::id,
+                schema::report::branch_id,
+                schema::report::testbed_id,
+                schema::report::start_time,
+            ))
+            .load::<(ReportId, BranchId, TestbedId, DateTime)>(conn)
+            .map_err(resource_not_found_err!(Report, (self.project_id, cutoff)))?;
+
+        let mut batches: HashMap<(BranchId, TestbedId, i64), Vec<ReportId>> = HashMap::new();
+        for (report_id, branch_id, testbed_id, start_time) in old_reports {
+            batches
+                .entry((branch_id, testbed_id, day(start_time)))
+                .or_default()
+                .push(report_id);
+        }
+        Ok(batches.into_values().collect())
+    }
+
+    /// Periodically enforce the retention policy for every project that has one.
+    pub fn spawn_prune(log: Logger, pool: Arc<DbPool>) {
+        tokio::spawn(async move {
+            #[allow(clippy::infinite_loop)]
+            loop {
+                tokio::time::sleep(RETENTION_INTERVAL).await;
+
+                let retentions = match schema::retention::table.load::<Self>(&mut *pool.get().await)
+                {
+                    Ok(retentions) => retentions,
+                    Err(e) => {
+                        error!(log, "Failed to get retention policies: {e}");
+                        continue;
+                    },
+                };
+                for retention in retentions {
+                    // Release the connection before pruning, which acquires it for each batch
+                    let project = QueryProject::get(&mut *pool.get().await, retention.project_id);
+                    let result = match project {
+                        Ok(project) => retention.prune(&pool, &project, false).await,
+                        Err(e) => Err(e),
+                    };
+                    match result {
+                        Ok(json) if json.metrics == 0 => {},
+                        Ok(json) => info!(
+                            log,
+                            "Enforced retention policy for project ({}): removed {} metric(s) before {}",
+                            json.project,
+                            json.metrics,
+                            json.cutoff
+                        ),
+                        Err(e) => error!(
+                            log,
+                            "Failed to enforce retention policy for project ({}): {e}",
+                            retention.project_id
+                        ),
+                    }
+                }
+            }
+        });
+    }
+}
+
+#[derive(Debug, diesel::Queryable)]
+struct OldMetric {
+    id: MetricId,
+    report_benchmark_id: ReportBenchmarkId,
+    report_id: ReportId,
+    branch_id: BranchId,
+    testbed_id: TestbedId,
+    benchmark_id: BenchmarkId,
+    measure_id: MeasureId,
+    start_time: DateTime,
+    value: f64,
+    lower_value: Option<f64>,
+    upper_value: Option<f64>,
+    boundary_id: Option<BoundaryId>,
+    alert_id: Option<AlertId>,
+}
+
+fn old_metrics(conn: &mut DbConnection, reports: &[ReportId]) -> Result<Vec<OldMetric>, HttpError> {
+    let mut old_metrics = Vec::new();
+    for reports in reports.chunks(PRUNE_CHUNK_SIZE) {
+        old_metrics.extend(
+            schema::metric::table
+                .inner_join(schema::report_benchmark::table.inner_join(schema::report::table))
+                .left_join(schema::boundary::table.left_join(schema::alert::table))
+                .filter(schema::report::id.eq_any(reports))
+                .select((
+                    schema::metric::id,
+                    schema::report_benchmark::id,
+                    schema::report::id,
+                    schema::report::branch_id,
+                    schema::report::testbed_id,
+                    schema::report_benchmark::benchmark_id,
+                    schema::metric::measure_id,
+                    schema::report::start_time,
+                    schema::metric::value,
+                    schema::metric::lower_value,
+                    schema::metric::upper_value,
+                    schema::boundary::id.nullable(),
+                    schema::alert::id.nullable(),
+                ))
+                .load::<OldMetric>(conn)
+                .map_err(resource_not_found_err!(Metric, reports))?,
+        );
+    }
+    Ok(old_metrics)
+}
+
+fn day(start_time: DateTime) -> i64 {
+    start_time.timestamp().div_euclid(SECONDS_PER_DAY)
+}
+
+type DownsampleKey = (BranchId, TestbedId, BenchmarkId, MeasureId, i64);
+
+#[derive(Debug, Default)]
+struct PrunePlan {
+    // Metrics that are removed without removing their report benchmark or report
+    metrics: Vec<MetricId>,
+    // Report benchmarks that are removed without removing their report
+    report_benchmarks: Vec<ReportBenchmarkId>,
+    reports: Vec<ReportId>,
+    aggregates: Vec<AggregateMetric>,
+    removed_metrics: u64,
+    removed_boundaries: u64,
+    removed_alerts: u64,
+}
+
+#[derive(Debug)]
+struct AggregateMetric {
+    id: MetricId,
+    value: f64,
+    lower_value: f64,
+    upper_value: f64,
+}
+
+impl PrunePlan {
+    fn new(old_metrics: &[OldMetric], downsample: bool) -> Self {
+        let mut plan = Self::default();
+
+        // Downsampling keeps the metric from the latest report of each day,
+        // and it becomes the aggregate of all of the metrics for that day.
+        // Otherwise, every old metric is removed.
+        let mut kept = HashSet::new();
+        if downsample {
+            let mut days: HashMap<DownsampleKey, Vec<&OldMetric>> = HashMap::new();
+            for old_metric in old_metrics {
+                let key = (
+                    old_metric.branch_id,
+                    old_metric.testbed_id,
+                    old_metric.benchmark_id,
+                    old_metric.measure_id,
+                    day(old_metric.start_time),
+                );
+                days.entry(key).or_default().push(old_metric);
+            }
+            for day in days.into_values() {
+                let Some(latest) = day.iter().max_by_key(|old_metric| {
+                    (old_metric.start_time.timestamp(), i32::from(old_metric.id))
+                }) else {
+                    continue;
+                };
+                kept.insert(latest.id);
+                if day.len() > 1 {
+                    plan.aggregates.push(AggregateMetric::new(latest.id, &day));
+                }
+            }
+        }
+
+        // Only the metrics that are not kept are removed.
+        // Report benchmarks and reports are removed once all of their metrics are removed.
+        let mut report_benchmarks: HashMap<ReportBenchmarkId, (ReportId, bool)> = HashMap::new();
+        let mut reports: HashMap<ReportId, bool> = HashMap::new();
+        for old_metric in old_metrics {
+            let is_kept = kept.contains(&old_metric.id);
+            let report_benchmark = report_benchmarks
+                .entry(old_metric.report_benchmark_id)
+                .or_insert((old_metric.report_id, true));
+            report_benchmark.1 &= !is_kept;
+            *reports.entry(old_metric.report_id).or_insert(true) &= !is_kept;
+            if !is_kept {
+                plan.removed_metrics += 1;
+                plan.removed_boundaries += u64::from(old_metric.boundary_id.is_some());
+                plan.removed_alerts += u64::from(old_metric.alert_id.is_some());
+            }
+        }
+        plan.reports = reports
+            .iter()
+            .filter_map(|(report_id, is_removed)| is_removed.then_some(*report_id))
+            .collect();
+        plan.report_benchmarks = report_benchmarks
+            .iter()
+            .filter_map(|(report_benchmark_id, (report_id, is_removed))| {
+                (*is_removed && !reports.get(report_id).copied().unwrap_or_default())
+                    .then_some(*report_benchmark_id)
+            })
+            .collect();
+        plan.metrics = old_metrics
+            .iter()
+            .filter(|old_metric| {
+                !kept.contains(&old_metric.id)
+                    && !reports
+                        .get(&old_metric.report_id)
+                        .copied()
+                        .unwrap_or_default()
+                    && !report_benchmarks
+                        .get(&old_metric.report_benchmark_id)
+                        .is_some_and(|(_, is_removed)| *is_removed)
+            })
+            .map(|old_metric| old_metric.id)
+            .collect();
+
+        plan
+    }
+
+    fn execute(&self, conn: &mut DbConnection) -> diesel::QueryResult<()> {
+        // Boundaries and alerts are removed along with their metrics,
+        // and metrics are removed along with their report benchmarks and reports.
+        for reports in self.reports.chunks(PRUNE_CHUNK_SIZE) {
+            diesel::delete(schema::report::table.filter(schema::report::id.eq_any(reports)))
+                .execute(conn)?;
+        }
+        for report_benchmarks in self.report_benchmarks.chunks(PRUNE_CHUNK_SIZE) {
+            diesel::delete(
+                schema::report_benchmark::table
+                    .filter(schema::report_benchmark::id.eq_any(report_benchmarks)),
+            )
+            .execute(conn)?;
+        }
+        for metrics in self.metrics.chunks(PRUNE_CHUNK_SIZE) {
+            diesel::delete(schema::metric::table.filter(schema::metric::id.eq_any(metrics)))
+                .execute(conn)?;
+        }
+        for aggregate in &self.aggregates {
+            diesel::update(schema::metric::table.filter(schema::metric::id.eq(aggregate.id)))
+                .set((
+                    schema::metric::value.eq(aggregate.value),
+                    schema::metric::lower_value.eq(aggregate.lower_value),
+                    schema::metric::upper_value.eq(aggregate.upper_value),
+                ))
+                .execute(conn)?;
+        }
+        Ok(())
+    }
+
+    fn add_to(&self, json: &mut JsonRetentionPrune) {
+        json.metrics += self.removed_metrics;
+        json.downsampled += self.aggregates.len() as u64;
+        json.boundaries += self.removed_boundaries;
+        json.alerts += self.removed_alerts;
+        json.reports += self.reports.len() as u64;
+    }
+}
+
+impl AggregateMetric {
+    // The aggregate value is the mean of the day,
+    // and the lower and upper values are the extremes of the day.
+    #[allow(clippy::cast_precision_loss)]
+    fn new(id: MetricId, day: &[&OldMetric]) -> Self {
+        let value = day.iter().map(|old_metric| old_metric.value).sum::<f64>() / day.len() as f64;
+        let lower_value = day
+            .iter()
+            .map(|old_metric| old_metric.lower_value.unwrap_or(old_metric.value))
+            .fold(f64::INFINITY, f64::min);
+        let upper_value = day
+            .iter()
+            .map(|old_metric| old_metric.upper_value.unwrap_or(old_metric.value))
+            .fold(f64::NEG_INFINITY, f64::max);
+        Self {
+            id,
+            value,
+            lower_value,
+            upper_value,
+        }
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = retention_table)]
+pub struct InsertRetention {
+    pub project_id: ProjectId,
+    pub raw_window: Window,
+    pub downsample: bool,
+    pub protected_branch_id: Option<BranchId>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl InsertRetention {
+    pub fn from_json(
+        conn: &mut DbConnection,
+        project_id: ProjectId,
+        retention: JsonNewRetention,
+    ) -> Result<Self, HttpError> {
+        let UpdateRetention {
+            raw_window,
+            downsample,
+            protected_branch_id,
+            modified,
+        } = UpdateRetention::from_json(conn, project_id, retention)?;
+        Ok(Self {
+            project_id,
+            raw_window,
+            downsample,
+            protected_branch_id,
+            created: modified,
+            modified,
+        })
+    }
+}
+
+#[derive(Debug, Clone, diesel::AsChangeset)]
+#[diesel(table_name = retention_table)]
+#[diesel(treat_none_as_null = true)]
+pub struct UpdateRetention {
+    pub raw_window: Window,
+    pub downsample: bool,
+    pub protected_branch_id: Option<BranchId>,
+    pub modified: DateTime,
+}
+
+impl UpdateRetention {
+    pub fn from_json(
+        conn: &mut DbConnection,
+        project_id: ProjectId,
+        retention: JsonNewRetention,
+    ) -> Result<Self, HttpError> {
+        let JsonNewRetention {
+            raw_window,
+            downsample,
+            protected_branch,
+        } = retention;
+        let protected_branch_id = if let Some(branch) = protected_branch.as_ref() {
+            Some(QueryBranch::from_name_id(conn, project_id, branch)?.id)
+        } else {
+            None
+        };
+        Ok(Self {
+            raw_window,
+            downsample: downsample.unwrap_or(true),
+            protected_branch_id,
+            modified: DateTime::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use bencher_json::DateTime;
+    use pretty_assertions::assert_eq;
+
+    use super::{OldMetric, PrunePlan, SECONDS_PER_DAY};
+
+    const HOUR: i64 = 60 * 60;
+
+    fn old_metric(
+        id: i32,
+        report_benchmark_id: i32,
+        report_id: i32,
+        benchmark_id: i32,
+        measure_id: i32,
+        start_time: i64,
+        value: f64,
+    ) -> OldMetric {
+        OldMetric {
+            id: id.into(),
+            report_benchmark_id: report_benchmark_id.into(),
+            report_id: report_id.into(),
+            branch_id: 1.into(),
+            testbed_id: 1.into(),
+            benchmark_id: benchmark_id.into(),
+            measure_id: measure_id.into(),
+            start_time: DateTime::try_from(start_time).unwrap(),
+            value,
+            lower_value: None,
+            upper_value: None,
+            boundary_id: None,
+            alert_id: None,
+        }
+    }
+
+    fn ids<T>(ids: &[T]) -> Vec<i32>
+    where
+        T: Copy + Into<i32>,
+    {
+        let mut ids = ids.iter().copied().map(Into::into).collect::<Vec<i32>>();
+        ids.sort_unstable();
+        ids
+    }
+
+    #[test]
+    fn test_prune_plan_downsample() {
+        let old_metrics = [
+            OldMetric {
+                boundary_id: Some(1.into()),
+                alert_id: Some(1.into()),
+                lower_value: Some(0.5),
+                ..old_metric(1, 1, 1, 1, 1, HOUR, 1.0)
+            },
+            OldMetric {
+                upper_value: Some(4.0),
+                ..old_metric(2, 2, 2, 1, 1, HOUR * 2, 3.0)
+            },
+            old_metric(3, 3, 3, 1, 1, SECONDS_PER_DAY + HOUR, 5.0),
+        ];
+        let plan = PrunePlan::new(&old_metrics, true);
+
+        // The latest metric of the first day is kept as the aggregate for the day
+        assert_eq!(plan.aggregates.len(), 1);
+        let aggregate = plan.aggregates.first().unwrap();
+        assert_eq!(i32::from(aggregate.id), 2);
+        assert_eq!(
+            (
+                aggregate.value,
+                aggregate.lower_value,
+                aggregate.upper_value
+            ),
+            (2.0, 0.5, 4.0)
+        );
+        // The only metric of the second day is kept as is
+        assert_eq!(ids(&plan.reports), vec![1]);
+        assert_eq!(ids(&plan.report_benchmarks), Vec::<i32>::new());
+        assert_eq!(ids(&plan.metrics), Vec::<i32>::new());
+        assert_eq!(plan.removed_metrics, 1);
+        assert_eq!(plan.removed_boundaries, 1);
+        assert_eq!(plan.removed_alerts, 1);
+    }
+
+    #[test]
+    fn test_prune_plan_remove_reports() {
+        let old_metrics = [
+            old_metric(1, 1, 1, 1, 1, HOUR, 1.0),
+            old_metric(2, 1, 1, 1, 2, HOUR, 2.0),
+            old_metric(3, 2, 1, 2, 1, HOUR, 3.0),
+            old_metric(4, 3, 2, 1, 1, SECONDS_PER_DAY, 4.0),
+        ];
+        let plan = PrunePlan::new(&old_metrics, false);
+
+        // Without downsampling, every report is removed along with all of its metrics
+        assert_eq!(ids(&plan.reports), vec![1, 2]);
+        assert_eq!(ids(&plan.report_benchmarks), Vec::<i32>::new());
+        assert_eq!(ids(&plan.metrics), Vec::<i32>::new());
+        assert!(plan.aggregates.is_empty());
+        assert_eq!(plan.removed_metrics, 4);
+    }
+
+    #[test]
+    fn test_prune_plan_partial_report() {
+        let old_metrics = [
+            // The first report has two benchmarks, and the first benchmark has two measures
+            old_metric(1, 1, 1, 1, 1, HOUR, 1.0),
+            old_metric(2, 1, 1, 1, 2, HOUR, 2.0),
+            old_metric(3, 2, 1, 2, 1, HOUR, 3.0),
+            // The second report only has the first measure for the first benchmark
+            old_metric(4, 3, 2, 1, 1, HOUR * 2, 4.0),
+            // The third report only has the second benchmark
+            old_metric(5, 4, 3, 2, 1, HOUR * 3, 5.0),
+        ];
+        let plan = PrunePlan::new(&old_metrics, true);
+
+        // The second measure for the first benchmark is still kept from the first report,
+        // so only the metric for the first measure is removed.
+        // The second benchmark is kept from the third report,
+        // so its report benchmark is removed from the first report.
+        assert_eq!(ids(&plan.reports), Vec::<i32>::new());
+        assert_eq!(ids(&plan.report_benchmarks), vec![2]);
+        assert_eq!(ids(&plan.metrics), vec![1]);
+        let mut aggregates = plan
+            .aggregates
+            .iter()
+            .map(|aggregate| (i32::from(aggregate.id), aggregate.value))
+            .collect::<Vec<_>>();
+        aggregates.sort_unstable_by_key(|(id, _)| *id);
+        assert_eq!(aggregates, vec![(4, 2.5), (5, 4.0)]);
+        assert_eq!(plan.removed_metrics, 2);
+    }
+}
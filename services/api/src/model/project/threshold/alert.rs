@@ -1,22 +1,31 @@
 use bencher_json::{
     project::{
-        alert::{AlertStatus, JsonAlert, JsonPerfAlert, JsonUpdateAlert},
+        alert::{AlertStatus, JsonAlert, JsonAlertAssignee, JsonPerfAlert, JsonUpdateAlert},
         boundary::BoundaryLimit,
         report::Iteration,
     },
     AlertUuid, BoundaryUuid, DateTime, ReportUuid,
 };
-use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
+use diesel::{
+    dsl::count, BoolExpressionMethods, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper,
+};
 use dropshot::HttpError;
 
 use super::{
     boundary::{BoundaryId, QueryBoundary},
-    QueryThreshold,
+    QueryThreshold, ThresholdId,
 };
 use crate::{
     context::DbConnection,
-    error::{resource_conflict_err, resource_not_found_err},
-    model::project::{benchmark::QueryBenchmark, metric::QueryMetric, ProjectId, QueryProject},
+    error::{bad_request_error, resource_conflict_err, resource_not_found_err},
+    model::{
+        project::{
+            benchmark::{BenchmarkId, QueryBenchmark},
+            metric::QueryMetric,
+            ProjectId, QueryProject,
+        },
+        user::{QueryUser, UserId},
+    },
     schema::alert as alert_table,
     schema::{self},
     util::fn_get::{fn_get, fn_get_id, fn_get_uuid},
@@ -33,6 +42,8 @@ pub struct QueryAlert {
     pub boundary_limit: BoundaryLimit,
     pub status: AlertStatus,
     pub modified: DateTime,
+    pub assignee_id: Option<UserId>,
+    pub note: Option<String>,
 }
 
 impl QueryAlert {
@@ -58,6 +69,50 @@ impl QueryAlert {
             .map_err(resource_not_found_err!(Alert, (project_id, uuid)))
     }
 
+    pub fn active_count(conn: &mut DbConnection, project: &QueryProject) -> Result<u64, HttpError> {
+        let active = schema::alert::table
+            .filter(schema::alert::status.eq(AlertStatus::Active))
+            .inner_join(schema::boundary::table.inner_join(
+                schema::metric::table.inner_join(
+                    schema::report_benchmark::table.inner_join(schema::benchmark::table),
+                ),
+            ))
+            .filter(schema::benchmark::project_id.eq(project.id))
+            .select(count(schema::alert::id))
+            .first::<i64>(conn)
+            .map_err(resource_not_found_err!(Alert, project))?;
+        Ok(u64::try_from(active).unwrap_or_default())
+    }
+
+    /// Resolve all of the active and acknowledged alerts for a threshold and benchmark.
+    pub fn resolve(
+        conn: &mut DbConnection,
+        threshold_id: ThresholdId,
+        benchmark_id: BenchmarkId,
+    ) -> Result<(), HttpError> {
+        let boundaries = schema::boundary::table
+            .inner_join(schema::metric::table.inner_join(schema::report_benchmark::table))
+            .filter(schema::boundary::threshold_id.eq(threshold_id))
+            .filter(schema::report_benchmark::benchmark_id.eq(benchmark_id))
+            .select(schema::boundary::id);
+        diesel::update(
+            schema::alert::table
+                .filter(
+                    schema::alert::status
+                        .eq(AlertStatus::Active)
+                        .or(schema::alert::status.eq(AlertStatus::Acknowledged)),
+                )
+                .filter(schema::alert::boundary_id.eq_any(boundaries)),
+        )
+        .set((
+            schema::alert::status.eq(AlertStatus::Resolved),
+            schema::alert::modified.eq(DateTime::now()),
+        ))
+        .execute(conn)
+        .map_err(resource_conflict_err!(Alert, (threshold_id, benchmark_id)))?;
+        Ok(())
+    }
+
     pub fn into_json(self, conn: &mut DbConnection) -> Result<JsonAlert, HttpError> {
         let (report_uuid, created, iteration, query_benchmark, query_metric, query_boundary) =
             schema::alert::table
@@ -118,8 +173,18 @@ impl QueryAlert {
             boundary_limit,
             status,
             modified,
+            assignee_id,
+            note,
             ..
         } = self;
+        let assignee = if let Some(assignee_id) = assignee_id {
+            let QueryUser {
+                uuid, name, slug, ..
+            } = QueryUser::get(conn, assignee_id)?;
+            Some(JsonAlertAssignee { uuid, name, slug })
+        } else {
+            None
+        };
         let threshold_id = query_boundary.threshold_id;
         let model_id = query_boundary.model_id;
         let benchmark =
@@ -132,6 +197,8 @@ impl QueryAlert {
             benchmark,
             limit: boundary_limit,
             status,
+            assignee,
+            note,
             created,
             modified,
         })
@@ -162,6 +229,7 @@ pub struct InsertAlert {
     pub boundary_limit: BoundaryLimit,
     pub status: AlertStatus,
     pub modified: DateTime,
+    pub note: Option<String>,
 }
 
 impl InsertAlert {
@@ -169,13 +237,15 @@ impl InsertAlert {
         conn: &mut DbConnection,
         boundary_uuid: BoundaryUuid,
         boundary_limit: BoundaryLimit,
+        status: AlertStatus,
     ) -> Result<(), HttpError> {
         let insert_alert = InsertAlert {
             uuid: AlertUuid::new(),
             boundary_id: QueryBoundary::get_id(conn, boundary_uuid)?,
             boundary_limit,
-            status: AlertStatus::default(),
+            status,
             modified: DateTime::now(),
+            note: None,
         };
 
         diesel::insert_into(schema::alert::table)
@@ -191,15 +261,57 @@ impl InsertAlert {
 #[diesel(table_name = alert_table)]
 pub struct UpdateAlert {
     pub status: Option<AlertStatus>,
+    pub assignee_id: Option<Option<UserId>>,
+    pub note: Option<Option<String>>,
     pub modified: DateTime,
 }
 
-impl From<JsonUpdateAlert> for UpdateAlert {
-    fn from(update: JsonUpdateAlert) -> Self {
-        let JsonUpdateAlert { status } = update;
-        Self {
+impl UpdateAlert {
+    pub fn from_json(
+        conn: &mut DbConnection,
+        project: &QueryProject,
+        update: JsonUpdateAlert,
+    ) -> Result<Self, HttpError> {
+        let JsonUpdateAlert {
             status,
+            assignee,
+            unassign,
+            note,
+        } = update;
+        let assignee_id = match (assignee, unassign) {
+            (Some(_), Some(true)) => {
+                return Err(bad_request_error(
+                    "An alert cannot be both assigned and unassigned at the same time.",
+                ))
+            },
+            (Some(assignee), _) => {
+                let query_user = QueryUser::from_resource_id(conn, &assignee)?;
+                // The assignee must be a member of the organization that owns the project
+                let is_member = diesel::select(diesel::dsl::exists(
+                    schema::organization_role::table
+                        .filter(schema::organization_role::user_id.eq(query_user.id))
+                        .filter(
+                            schema::organization_role::organization_id.eq(project.organization_id),
+                        ),
+                ))
+                .get_result::<bool>(conn)
+                .map_err(resource_not_found_err!(User, &assignee))?;
+                if !is_member {
+                    return Err(bad_request_error(format!(
+                        "User ({assignee}) is not a member of the organization for project ({})",
+                        project.slug
+                    )));
+                }
+                Some(Some(query_user.id))
+            },
+            (None, Some(true)) => Some(None),
+            (None, Some(false) | None) => None,
+        };
+        Ok(Self {
+            status,
+            assignee_id,
+            note: note.map(|note| (!note.is_empty()).then_some(note)),
             modified: DateTime::now(),
-        }
+        })
     }
 }
@@ -1,6 +1,6 @@
 use bencher_json::{
     project::threshold::{JsonThreshold, JsonThresholdModel},
-    DateTime, Model, ModelUuid, ThresholdUuid,
+    BenchmarkGroup, DateTime, Model, ModelUuid, ThresholdUuid,
 };
 use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
 use dropshot::HttpError;
@@ -24,6 +24,7 @@ use crate::{
 pub mod alert;
 pub mod boundary;
 pub mod model;
+pub mod silence;
 
 crate::util::typed_id::typed_id!(ThresholdId);
 
@@ -42,6 +43,7 @@ pub struct QueryThreshold {
     pub model_id: Option<ModelId>,
     pub created: DateTime,
     pub modified: DateTime,
+    pub benchmark_group: Option<BenchmarkGroup>,
 }
 
 impl QueryThreshold {
@@ -135,6 +137,7 @@ impl QueryThreshold {
             measure_id,
             created,
             modified,
+            benchmark_group,
             ..
         } = self;
         Ok(JsonThreshold {
@@ -143,6 +146,7 @@ impl QueryThreshold {
             branch: QueryBranch::get(conn, branch_id)?.into_json(conn)?,
             testbed: QueryTestbed::get(conn, testbed_id)?.into_json(conn)?,
             measure: QueryMeasure::get(conn, measure_id)?.into_json(conn)?,
+            group: benchmark_group,
             // TODO remove in due time
             statistic: Some(model),
             model,
@@ -201,6 +205,7 @@ pub struct InsertThreshold {
     pub model_id: Option<ModelId>,
     pub created: DateTime,
     pub modified: DateTime,
+    pub benchmark_group: Option<BenchmarkGroup>,
 }
 
 impl InsertThreshold {
@@ -209,6 +214,7 @@ impl InsertThreshold {
         branch_id: BranchId,
         testbed_id: TestbedId,
         measure_id: MeasureId,
+        benchmark_group: Option<BenchmarkGroup>,
     ) -> Self {
         let timestamp = DateTime::now();
         Self {
@@ -220,6 +226,7 @@ impl InsertThreshold {
             model_id: None,
             created: timestamp,
             modified: timestamp,
+            benchmark_group,
         }
     }
 
@@ -229,10 +236,17 @@ impl InsertThreshold {
         branch_id: BranchId,
         testbed_id: TestbedId,
         measure_id: MeasureId,
+        benchmark_group: Option<BenchmarkGroup>,
         model: Model,
     ) -> Result<ThresholdId, HttpError> {
         // Create the new threshold
-        let insert_threshold = InsertThreshold::new(project_id, branch_id, testbed_id, measure_id);
+        let insert_threshold = InsertThreshold::new(
+            project_id,
+            branch_id,
+            testbed_id,
+            measure_id,
+            benchmark_group,
+        );
         diesel::insert_into(schema::threshold::table)
             .values(&insert_threshold)
             .execute(conn)
@@ -276,6 +290,7 @@ impl InsertThreshold {
             branch_id,
             testbed_id,
             measure_id,
+            None,
             Model::lower_boundary(),
         )
     }
@@ -293,6 +308,7 @@ impl InsertThreshold {
             branch_id,
             testbed_id,
             measure_id,
+            None,
             Model::upper_boundary(),
         )
     }
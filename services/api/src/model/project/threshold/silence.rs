@@ -0,0 +1,193 @@
+use bencher_json::{DateTime, JsonNewSilence, JsonSilence, SilenceUuid};
+use diesel::{BoolExpressionMethods, ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::HttpError;
+
+use crate::{
+    context::DbConnection,
+    error::{assert_parentage, bad_request_error, resource_not_found_err, BencherResource},
+    model::project::{
+        benchmark::{BenchmarkId, QueryBenchmark},
+        measure::{MeasureId, QueryMeasure},
+        testbed::{QueryTestbed, TestbedId},
+        ProjectId, QueryProject,
+    },
+    schema::{self, silence as silence_table},
+    util::fn_get::{fn_from_uuid, fn_get},
+};
+
+crate::util::typed_id::typed_id!(SilenceId);
+
+#[derive(
+    Debug, Clone, diesel::Queryable, diesel::Identifiable, diesel::Associations, diesel::Selectable,
+)]
+#[diesel(table_name = silence_table)]
+#[diesel(belongs_to(QueryProject, foreign_key = project_id))]
+pub struct QuerySilence {
+    pub id: SilenceId,
+    pub uuid: SilenceUuid,
+    pub project_id: ProjectId,
+    pub benchmark_id: Option<BenchmarkId>,
+    pub measure_id: Option<MeasureId>,
+    pub testbed_id: Option<TestbedId>,
+    pub note: Option<String>,
+    pub expires: Option<DateTime>,
+    pub created: DateTime,
+}
+
+impl QuerySilence {
+    fn_get!(silence, SilenceId);
+    fn_from_uuid!(silence, SilenceUuid, Silence);
+
+    /// Check whether an unexpired silence for the project
+    /// matches the benchmark, measure, and testbed of an alert.
+    pub fn is_silenced(
+        conn: &mut DbConnection,
+        project_id: ProjectId,
+        benchmark_id: BenchmarkId,
+        measure_id: MeasureId,
+        testbed_id: TestbedId,
+    ) -> Result<bool, HttpError> {
+        let now = DateTime::now();
+        diesel::select(diesel::dsl::exists(
+            schema::silence::table
+                .filter(schema::silence::project_id.eq(project_id))
+                .filter(
+                    schema::silence::benchmark_id
+                        .is_null()
+                        .or(schema::silence::benchmark_id.eq(benchmark_id)),
+                )
+                .filter(
+                    schema::silence::measure_id
+                        .is_null()
+                        .or(schema::silence::measure_id.eq(measure_id)),
+                )
+                .filter(
+                    schema::silence::testbed_id
+                        .is_null()
+                        .or(schema::silence::testbed_id.eq(testbed_id)),
+                )
+                .filter(
+                    schema::silence::expires
+                        .is_null()
+                        .or(schema::silence::expires.gt(now)),
+                ),
+        ))
+        .get_result(conn)
+        .map_err(resource_not_found_err!(
+            Silence,
+            (project_id, benchmark_id, measure_id, testbed_id)
+        ))
+    }
+
+    pub fn into_json_for_project(
+        self,
+        conn: &mut DbConnection,
+        project: &QueryProject,
+    ) -> Result<JsonSilence, HttpError> {
+        let Self {
+            uuid,
+            project_id,
+            benchmark_id,
+            measure_id,
+            testbed_id,
+            note,
+            expires,
+            created,
+            ..
+        } = self;
+        assert_parentage(
+            BencherResource::Project,
+            project.id,
+            BencherResource::Silence,
+            project_id,
+        );
+        let benchmark = if let Some(benchmark_id) = benchmark_id {
+            Some(QueryBenchmark::get(conn, benchmark_id)?.into_json_for_project(project))
+        } else {
+            None
+        };
+        let measure = if let Some(measure_id) = measure_id {
+            Some(QueryMeasure::get(conn, measure_id)?.into_json_for_project(project))
+        } else {
+            None
+        };
+        let testbed = if let Some(testbed_id) = testbed_id {
+            Some(QueryTestbed::get(conn, testbed_id)?.into_json_for_project(project))
+        } else {
+            None
+        };
+        Ok(JsonSilence {
+            uuid,
+            project: project.uuid,
+            benchmark,
+            measure,
+            testbed,
+            note,
+            expires,
+            created,
+        })
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = silence_table)]
+pub struct InsertSilence {
+    pub uuid: SilenceUuid,
+    pub project_id: ProjectId,
+    pub benchmark_id: Option<BenchmarkId>,
+    pub measure_id: Option<MeasureId>,
+    pub testbed_id: Option<TestbedId>,
+    pub note: Option<String>,
+    pub expires: Option<DateTime>,
+    pub created: DateTime,
+}
+
+impl InsertSilence {
+    pub fn from_json(
+        conn: &mut DbConnection,
+        project_id: ProjectId,
+        silence: JsonNewSilence,
+    ) -> Result<Self, HttpError> {
+        let JsonNewSilence {
+            benchmark,
+            measure,
+            testbed,
+            note,
+            expires,
+        } = silence;
+        if benchmark.is_none() && measure.is_none() && testbed.is_none() {
+            return Err(bad_request_error(
+                "A silence requires at least one of a benchmark, measure, or testbed.",
+            ));
+        }
+        let created = DateTime::now();
+        if expires.is_some_and(|expires| expires.timestamp() <= created.timestamp()) {
+            return Err(bad_request_error("A silence must expire in the future."));
+        }
+        let benchmark_id = if let Some(benchmark) = benchmark.as_ref() {
+            Some(QueryBenchmark::from_resource_id(conn, project_id, benchmark)?.id)
+        } else {
+            None
+        };
+        let measure_id = if let Some(measure) = measure.as_ref() {
+            Some(QueryMeasure::from_resource_id(conn, project_id, measure)?.id)
+        } else {
+            None
+        };
+        let testbed_id = if let Some(testbed) = testbed.as_ref() {
+            Some(QueryTestbed::from_resource_id(conn, project_id, testbed)?.id)
+        } else {
+            None
+        };
+        Ok(Self {
+            uuid: SilenceUuid::new(),
+            project_id,
+            benchmark_id,
+            measure_id,
+            testbed_id,
+            note: note.filter(|note| !note.is_empty()),
+            expires,
+            created,
+        })
+    }
+}
@@ -1,13 +1,15 @@
+use std::collections::{HashMap, HashSet};
+
 use bencher_json::{
     project::branch::{JsonVersion, VersionNumber},
-    GitHash, VersionUuid,
+    DateTime, GitHash, VersionUuid,
 };
-use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use diesel::{Connection, ExpressionMethods, QueryDsl, RunQueryDsl};
 use dropshot::HttpError;
 
 use crate::{
     context::DbConnection,
-    error::resource_conflict_err,
+    error::{resource_conflict_err, resource_not_found_err, TransactionError},
     schema,
     schema::version as version_table,
     util::fn_get::{fn_get, fn_get_id, fn_get_uuid},
@@ -33,29 +35,59 @@ impl QueryVersion {
     fn_get_id!(version, VersionId, VersionUuid);
     fn_get_uuid!(version, VersionId, VersionUuid);
 
+    // The project is locked while looking up and adding the code version,
+    // so concurrent reports can't both add the same code version or version number.
     pub fn get_or_increment(
         conn: &mut DbConnection,
         project_id: ProjectId,
         branch_id: BranchId,
         hash: Option<&GitHash>,
     ) -> Result<VersionId, HttpError> {
-        if let Some(hash) = hash {
-            if let Ok(version_id) = schema::version::table
-                .inner_join(schema::branch_version::table)
-                .filter(schema::branch_version::branch_id.eq(branch_id))
-                .filter(schema::version::project_id.eq(project_id))
-                .filter(schema::version::hash.eq(hash.as_ref()))
-                .order(schema::version::number.desc())
-                .select(schema::version::id)
-                .first::<VersionId>(conn)
-            {
+        conn.transaction(|conn| {
+            conn.lock_project(project_id)?;
+            if let Some(version_id) = Self::get_hash(conn, project_id, branch_id, hash) {
                 Ok(version_id)
             } else {
-                InsertVersion::increment(conn, project_id, branch_id, Some(hash.clone()))
+                InsertVersion::increment(conn, project_id, branch_id, hash.cloned())
+                    .map_err(TransactionError::from)
             }
-        } else {
-            InsertVersion::increment(conn, project_id, branch_id, None)
-        }
+        })
+        .map_err(Into::into)
+    }
+
+    pub fn get_or_insert_historical(
+        conn: &mut DbConnection,
+        project_id: ProjectId,
+        branch_id: BranchId,
+        hash: Option<&GitHash>,
+        start_time: DateTime,
+    ) -> Result<VersionId, HttpError> {
+        conn.transaction(|conn| {
+            conn.lock_project(project_id)?;
+            if let Some(version_id) = Self::get_hash(conn, project_id, branch_id, hash) {
+                Ok(version_id)
+            } else {
+                InsertVersion::historical(conn, project_id, branch_id, hash.cloned(), start_time)
+            }
+        })
+        .map_err(Into::into)
+    }
+
+    fn get_hash(
+        conn: &mut DbConnection,
+        project_id: ProjectId,
+        branch_id: BranchId,
+        hash: Option<&GitHash>,
+    ) -> Option<VersionId> {
+        schema::version::table
+            .inner_join(schema::branch_version::table)
+            .filter(schema::branch_version::branch_id.eq(branch_id))
+            .filter(schema::version::project_id.eq(project_id))
+            .filter(schema::version::hash.eq(hash?.as_ref()))
+            .order(schema::version::number.desc())
+            .select(schema::version::id)
+            .first::<VersionId>(conn)
+            .ok()
     }
 
     pub fn into_json(self) -> JsonVersion {
@@ -74,7 +106,7 @@ pub struct InsertVersion {
 }
 
 impl InsertVersion {
-    pub fn increment(
+    fn increment(
         conn: &mut DbConnection,
         project_id: ProjectId,
         branch_id: BranchId,
@@ -94,6 +126,100 @@ impl InsertVersion {
             VersionNumber::default()
         };
 
+        Self::insert(conn, project_id, branch_id, number, hash)
+    }
+
+    // Historical reports may be older than the most recent code version for this branch.
+    // So the new code version is placed after the most recent code version with an earlier report,
+    // and all of the later code versions for this branch have their version numbers incremented.
+    // Code versions are shared between a branch and any branches created from it,
+    // so all of the later code versions on those other branches are incremented as well.
+    // That way every branch keeps its code versions in the same order.
+    // The renumbering and the new code version must all be done within a single transaction,
+    // with the project locked.
+    fn historical(
+        conn: &mut DbConnection,
+        project_id: ProjectId,
+        branch_id: BranchId,
+        hash: Option<GitHash>,
+        start_time: DateTime,
+    ) -> Result<VersionId, TransactionError> {
+        let number = if let Ok(number) = schema::version::table
+            .inner_join(schema::branch_version::table)
+            .inner_join(schema::report::table)
+            .filter(schema::branch_version::branch_id.eq(branch_id))
+            .filter(schema::report::start_time.le(start_time))
+            .select(schema::version::number)
+            .order(schema::version::number.desc())
+            .first::<VersionNumber>(conn)
+        {
+            number.increment()
+        } else {
+            VersionNumber::default()
+        };
+
+        for (version_id, version_number) in Self::later_versions(conn, branch_id, number)? {
+            diesel::update(schema::version::table.filter(schema::version::id.eq(version_id)))
+                .set(schema::version::number.eq(version_number.increment()))
+                .execute(conn)
+                .map_err(resource_conflict_err!(
+                    Version,
+                    (version_id, version_number)
+                ))?;
+        }
+
+        Self::insert(conn, project_id, branch_id, number, hash).map_err(Into::into)
+    }
+
+    // Find all of the code versions on or after the version number for the branch.
+    // If any of those code versions are shared with another branch,
+    // then all of the code versions on or after it for that other branch are included too.
+    fn later_versions(
+        conn: &mut DbConnection,
+        branch_id: BranchId,
+        number: VersionNumber,
+    ) -> Result<HashMap<VersionId, VersionNumber>, HttpError> {
+        let mut later_versions = HashMap::new();
+        let mut visited = HashSet::new();
+        let mut pending = vec![(branch_id, number)];
+        while let Some((branch_id, number)) = pending.pop() {
+            if !visited.insert((branch_id, number)) {
+                continue;
+            }
+            let versions = schema::version::table
+                .inner_join(schema::branch_version::table)
+                .filter(schema::branch_version::branch_id.eq(branch_id))
+                .filter(schema::version::number.ge(number))
+                .select((schema::version::id, schema::version::number))
+                .load::<(VersionId, VersionNumber)>(conn)
+                .map_err(resource_not_found_err!(Version, (branch_id, number)))?;
+            for (version_id, version_number) in versions {
+                if later_versions.insert(version_id, version_number).is_some() {
+                    continue;
+                }
+                let shared_branches = schema::branch_version::table
+                    .filter(schema::branch_version::version_id.eq(version_id))
+                    .filter(schema::branch_version::branch_id.ne(branch_id))
+                    .select(schema::branch_version::branch_id)
+                    .load::<BranchId>(conn)
+                    .map_err(resource_not_found_err!(BranchVersion, version_id))?;
+                pending.extend(
+                    shared_branches
+                        .into_iter()
+                        .map(|shared_branch_id| (shared_branch_id, version_number)),
+                );
+            }
+        }
+        Ok(later_versions)
+    }
+
+    fn insert(
+        conn: &mut DbConnection,
+        project_id: ProjectId,
+        branch_id: BranchId,
+        number: VersionNumber,
+        hash: Option<GitHash>,
+    ) -> Result<VersionId, HttpError> {
         let version_uuid = VersionUuid::new();
         let insert_version = InsertVersion {
             uuid: version_uuid,
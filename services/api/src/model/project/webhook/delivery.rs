@@ -0,0 +1,581 @@
+use std::{
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+    time::Duration,
+};
+
+use bencher_json::{
+    project::webhook::{JsonWebhookDelivery, JsonWebhookPayload, WebhookEvent},
+    DateTime, JsonAlert, JsonReport, Secret, Url, ValidError, WebhookDeliveryUuid,
+};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use dropshot::HttpError;
+use hmac::{Hmac, Mac};
+use http::StatusCode;
+use sha2::Sha256;
+use slog::{error, info, Logger};
+
+use super::{QueryWebhook, WebhookId};
+use crate::{
+    conn_lock,
+    context::{ApiContext, DbPool},
+    error::{issue_error, resource_conflict_err, resource_not_found_err},
+    schema::{self, webhook_delivery as webhook_delivery_table},
+};
+
+pub const SIGNATURE_HEADER: &str = "X-Bencher-Signature";
+pub const EVENT_HEADER: &str = "X-Bencher-Event";
+pub const DELIVERY_HEADER: &str = "X-Bencher-Delivery";
+
+// A delivery is attempted up to 5 times, waiting 1, 2, 4, and then 8 seconds between attempts.
+const MAX_ATTEMPTS: i32 = 5;
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+crate::util::typed_id::typed_id!(WebhookDeliveryId);
+
+#[derive(Debug, Clone, diesel::Queryable, diesel::Identifiable, diesel::Selectable)]
+#[diesel(table_name = webhook_delivery_table)]
+pub struct QueryWebhookDelivery {
+    pub id: WebhookDeliveryId,
+    pub uuid: WebhookDeliveryUuid,
+    pub webhook_id: WebhookId,
+    pub event: WebhookEvent,
+    pub attempts: i32,
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+    pub delivered: Option<DateTime>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl QueryWebhookDelivery {
+    pub fn into_json_for_webhook(self, webhook: &QueryWebhook) -> JsonWebhookDelivery {
+        let Self {
+            uuid,
+            event,
+            attempts,
+            status_code,
+            error,
+            delivered,
+            created,
+            modified,
+            ..
+        } = self;
+        JsonWebhookDelivery {
+            uuid,
+            webhook: webhook.uuid,
+            event,
+            attempts: u32::try_from(attempts).unwrap_or_default(),
+            status_code: status_code.and_then(|status_code| u16::try_from(status_code).ok()),
+            error,
+            delivered,
+            created,
+            modified,
+        }
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = webhook_delivery_table)]
+pub struct InsertWebhookDelivery {
+    pub uuid: WebhookDeliveryUuid,
+    pub webhook_id: WebhookId,
+    pub event: WebhookEvent,
+    pub attempts: i32,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl InsertWebhookDelivery {
+    /// Record a new delivery in the delivery log and then send it in the background.
+    pub async fn deliver(
+        log: &Logger,
+        context: &ApiContext,
+        webhook: &QueryWebhook,
+        event: WebhookEvent,
+        json_report: &JsonReport,
+        json_alert: Option<&JsonAlert>,
+    ) -> Result<(), HttpError> {
+        let timestamp = DateTime::now();
+        let insert_delivery = Self {
+            uuid: WebhookDeliveryUuid::new(),
+            webhook_id: webhook.id,
+            event,
+            attempts: 0,
+            created: timestamp,
+            modified: timestamp,
+        };
+
+        let payload = JsonWebhookPayload {
+            delivery: insert_delivery.uuid,
+            event,
+            alert: json_alert.cloned(),
+            report: json_report.clone(),
+            created: timestamp,
+        };
+        let body = serde_json::to_string(&payload).map_err(|e| {
+            issue_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to serialize webhook payload",
+                &format!("Failed to serialize webhook payload: {payload:?}"),
+                e,
+            )
+        })?;
+
+        diesel::insert_into(schema::webhook_delivery::table)
+            .values(&insert_delivery)
+            .execute(conn_lock!(context))
+            .map_err(resource_conflict_err!(WebhookDelivery, insert_delivery))?;
+        let delivery_id = schema::webhook_delivery::table
+            .filter(schema::webhook_delivery::uuid.eq(insert_delivery.uuid))
+            .select(schema::webhook_delivery::id)
+            .first::<WebhookDeliveryId>(conn_lock!(context))
+            .map_err(resource_not_found_err!(WebhookDelivery, insert_delivery))?;
+
+        let delivery = Delivery {
+            id: delivery_id,
+            uuid: insert_delivery.uuid,
+            event,
+            url: webhook.url.clone(),
+            allow_private: context.allow_private_webhooks,
+            signature: sign(&webhook.secret, &body),
+            body,
+        };
+        let send_log = log.clone();
+        let pool = context.database.pool.clone();
+        tokio::spawn(async move { delivery.send(&send_log, pool).await });
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, diesel::AsChangeset)]
+#[diesel(table_name = webhook_delivery_table)]
+#[diesel(treat_none_as_null = true)]
+pub struct UpdateWebhookDelivery {
+    pub attempts: i32,
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+    pub delivered: Option<DateTime>,
+    pub modified: DateTime,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookUrlError {
+    #[error("Invalid webhook URL: {0}")]
+    Url(ValidError),
+    #[error("Webhook URL ({0}) must use `http` or `https`")]
+    Scheme(url::Url),
+    #[error("Webhook URL ({0}) has no host")]
+    Host(url::Url),
+    #[error("Webhook URL ({0}) is a loopback, link-local, or private network address")]
+    Private(url::Url),
+    #[error("Failed to resolve webhook URL ({0}): {1}")]
+    Resolve(url::Url, std::io::Error),
+}
+
+/// Check that a webhook URL uses `http` or `https`.
+/// Unless the server allows private webhooks,
+/// the URL host must also not be a loopback, link-local, or private network address.
+pub fn check_url(url: &Url, allow_private: bool) -> Result<url::Url, WebhookUrlError> {
+    let url = url::Url::try_from(url.clone()).map_err(WebhookUrlError::Url)?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(WebhookUrlError::Scheme(url));
+    }
+    let is_private = match url.host() {
+        Some(url::Host::Domain(domain)) => {
+            let domain = domain.to_ascii_lowercase();
+            domain == "localhost" || domain.ends_with(".localhost")
+        },
+        Some(url::Host::Ipv4(ip)) => is_private_ip(IpAddr::V4(ip)),
+        Some(url::Host::Ipv6(ip)) => is_private_ip(IpAddr::V6(ip)),
+        None => return Err(WebhookUrlError::Host(url)),
+    };
+    if is_private && !allow_private {
+        return Err(WebhookUrlError::Private(url));
+    }
+    Ok(url)
+}
+
+fn is_private_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [first, second, ..] = ip.octets();
+            // The current network is `0.0.0.0/8` and the shared address space is `100.64.0.0/10`
+            ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_broadcast()
+                || first == 0
+                || (first == 100 && second & 0xc0 == 64)
+        },
+        IpAddr::V6(ip) => {
+            if let Some(ip) = ip.to_ipv4_mapped() {
+                return is_private_ip(IpAddr::V4(ip));
+            }
+            let [first, ..] = ip.segments();
+            // Unique local addresses are `fc00::/7` and link-local unicast addresses are `fe80::/10`
+            ip.is_loopback()
+                || ip.is_unspecified()
+                || first & 0xfe00 == 0xfc00
+                || first & 0xffc0 == 0xfe80
+        },
+    }
+}
+
+struct Delivery {
+    id: WebhookDeliveryId,
+    uuid: WebhookDeliveryUuid,
+    event: WebhookEvent,
+    url: Url,
+    allow_private: bool,
+    signature: String,
+    body: String,
+}
+
+impl Delivery {
+    async fn send(self, log: &Logger, pool: Arc<DbPool>) {
+        let Self { uuid, url, .. } = &self;
+        let (client, webhook_url) = match self.client().await {
+            Ok(client) => client,
+            Err(e) => {
+                error!(
+                    log,
+                    "Failed to create webhook ({uuid}) client for {url}: {e}"
+                );
+                let update_delivery = UpdateWebhookDelivery {
+                    attempts: 0,
+                    status_code: None,
+                    error: Some(e.to_string()),
+                    delivered: None,
+                    modified: DateTime::now(),
+                };
+                self.update(log, &pool, &update_delivery).await;
+                return;
+            },
+        };
+
+        let mut backoff = INITIAL_BACKOFF;
+        for attempts in 1..=MAX_ATTEMPTS {
+            let update_delivery = self.attempt(&client, &webhook_url, attempts).await;
+            let delivered = update_delivery.delivered.is_some();
+            self.update(log, &pool, &update_delivery).await;
+
+            if delivered {
+                info!(
+                    log,
+                    "Delivered webhook ({uuid}) to {url} after {attempts} attempt(s)"
+                );
+                return;
+            } else if attempts < MAX_ATTEMPTS {
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+            }
+        }
+        error!(
+            log,
+            "Failed to deliver webhook ({uuid}) to {url} after {MAX_ATTEMPTS} attempts"
+        );
+    }
+
+    // The webhook host is resolved and checked before any attempts are made.
+    // The client is then pinned to the checked address,
+    // so the host can't be changed to resolve to a private address in between.
+    // Redirects are not followed, as they could point to a private address.
+    async fn client(&self) -> Result<(reqwest::Client, url::Url), String> {
+        let url = check_url(&self.url, self.allow_private).map_err(|e| e.to_string())?;
+        let mut client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .redirect(reqwest::redirect::Policy::none());
+        if let (Some(url::Host::Domain(domain)), false) = (url.host(), self.allow_private) {
+            let port = url.port_or_known_default().unwrap_or_default();
+            let addrs = tokio::net::lookup_host((domain, port))
+                .await
+                .map_err(|e| WebhookUrlError::Resolve(url.clone(), e).to_string())?
+                .collect::<Vec<SocketAddr>>();
+            let Some(addr) = addrs.first() else {
+                return Err(WebhookUrlError::Host(url).to_string());
+            };
+            if addrs.iter().any(|addr| is_private_ip(addr.ip())) {
+                return Err(WebhookUrlError::Private(url).to_string());
+            }
+            client = client.resolve(domain, *addr);
+        }
+        client
+            .build()
+            .map(|client| (client, url))
+            .map_err(|e| e.to_string())
+    }
+
+    async fn attempt(
+        &self,
+        client: &reqwest::Client,
+        url: &url::Url,
+        attempts: i32,
+    ) -> UpdateWebhookDelivery {
+        let response = client
+            .post(url.clone())
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(SIGNATURE_HEADER, &self.signature)
+            .header(EVENT_HEADER, self.event.to_string())
+            .header(DELIVERY_HEADER, self.uuid.to_string())
+            .body(self.body.clone())
+            .send()
+            .await;
+        let (status_code, error, delivered) = match response {
+            Ok(response) => {
+                let status = response.status();
+                if status.is_success() {
+                    (Some(status.as_u16()), None, Some(DateTime::now()))
+                } else {
+                    (
+                        Some(status.as_u16()),
+                        Some(format!("Unsuccessful response status: {status}")),
+                        None,
+                    )
+                }
+            },
+            Err(e) => (
+                e.status().map(|status| status.as_u16()),
+                Some(e.to_string()),
+                None,
+            ),
+        };
+        UpdateWebhookDelivery {
+            attempts,
+            status_code: status_code.map(Into::into),
+            error,
+            delivered,
+            modified: DateTime::now(),
+        }
+    }
+
+    async fn update(&self, log: &Logger, pool: &DbPool, update_delivery: &UpdateWebhookDelivery) {
+        if let Err(e) = diesel::update(
+            schema::webhook_delivery::table.filter(schema::webhook_delivery::id.eq(self.id)),
+        )
+        .set(update_delivery)
+        .execute(&mut *pool.get().await)
+        {
+            error!(
+                log,
+                "Failed to update webhook delivery ({uuid}): {e}",
+                uuid = self.uuid
+            );
+        }
+    }
+}
+
+/// The hex encoded HMAC-SHA256 of the body, using the webhook secret as the key.
+fn sign(secret: &Secret, body: &str) -> String {
+    // HMAC can take a key of any size, so this should never fail.
+    #[allow(clippy::expect_used)]
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_ref().as_bytes())
+        .expect("HMAC can take a key of any size");
+    mac.update(body.as_bytes());
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use std::collections::HashMap;
+
+    use bencher_json::{project::webhook::WebhookEvent, Secret, Url, WebhookDeliveryUuid};
+    use pretty_assertions::assert_eq;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+        sync::mpsc,
+    };
+
+    use super::{check_url, sign, Delivery, EVENT_HEADER, SIGNATURE_HEADER};
+
+    struct Request {
+        headers: HashMap<String, String>,
+        body: String,
+    }
+
+    // A local stand-in for a webhook receiver that responds with each status code in order
+    async fn receiver(statuses: Vec<u16>) -> (Url, mpsc::UnboundedReceiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/webhook", listener.local_addr().unwrap())
+            .parse()
+            .unwrap();
+        let (tx, rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            for status in statuses {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let request = read_request(&mut stream).await;
+                tx.send(request).unwrap();
+                let response = format!(
+                    "HTTP/1.1 {status} Status\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+                stream.shutdown().await.unwrap();
+            }
+        });
+        (url, rx)
+    }
+
+    async fn read_request(stream: &mut TcpStream) -> Request {
+        let mut buffer = Vec::new();
+        let mut chunk = [0; 1024];
+        loop {
+            let n = stream.read(&mut chunk).await.unwrap();
+            assert_ne!(n, 0, "Connection closed before the request was complete");
+            buffer.extend_from_slice(chunk.get(..n).unwrap());
+            let text = String::from_utf8_lossy(&buffer);
+            let Some((head, body)) = text.split_once("\r\n\r\n") else {
+                continue;
+            };
+            let headers = head
+                .lines()
+                .skip(1)
+                .filter_map(|line| line.split_once(':'))
+                .map(|(key, value)| (key.trim().to_ascii_lowercase(), value.trim().to_owned()))
+                .collect::<HashMap<_, _>>();
+            let content_length = headers
+                .get("content-length")
+                .and_then(|length| length.parse::<usize>().ok())
+                .unwrap_or_default();
+            if body.len() >= content_length {
+                return Request {
+                    headers,
+                    body: body.to_owned(),
+                };
+            }
+        }
+    }
+
+    #[test]
+    fn test_sign() {
+        // RFC 4231 test case 2
+        let secret: Secret = "Jefe".parse().unwrap();
+        assert_eq!(
+            sign(&secret, "what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_check_url() {
+        for url in [
+            "https://example.com/webhook",
+            "http://example.com:8080",
+            "https://93.184.216.34",
+            "https://100.128.0.1",
+            "https://[2606:2800:220:1:248:1893:25c8:1946]",
+        ] {
+            assert!(check_url(&url.parse().unwrap(), false).is_ok(), "{url}");
+        }
+
+        for url in [
+            "http://localhost:3000",
+            "http://api.localhost",
+            "http://127.0.0.1",
+            "http://0.0.0.0",
+            "http://0.1.2.3",
+            "http://100.64.0.1",
+            "http://100.127.255.254",
+            "http://10.0.0.1",
+            "http://172.16.0.1",
+            "http://192.168.1.1",
+            "http://169.254.169.254/latest/meta-data",
+            "http://[::1]",
+            "http://[fe80::1]",
+            "http://[fc00::1]",
+            "http://[::ffff:127.0.0.1]",
+        ] {
+            assert!(check_url(&url.parse().unwrap(), false).is_err(), "{url}");
+            assert!(check_url(&url.parse().unwrap(), true).is_ok(), "{url}");
+        }
+
+        for url in ["ftp://example.com", "file:///etc/passwd"] {
+            assert!(check_url(&url.parse().unwrap(), false).is_err(), "{url}");
+            assert!(check_url(&url.parse().unwrap(), true).is_err(), "{url}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_client_redirect() {
+        let (target, mut requests) = receiver(vec![200]).await;
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url: Url = format!("http://{}/webhook", listener.local_addr().unwrap())
+            .parse()
+            .unwrap();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            read_request(&mut stream).await;
+            let response = format!(
+                "HTTP/1.1 302 Found\r\nLocation: {target}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+            stream.shutdown().await.unwrap();
+        });
+
+        let delivery = Delivery {
+            id: 1.into(),
+            uuid: WebhookDeliveryUuid::new(),
+            event: WebhookEvent::ReportCreated,
+            url,
+            allow_private: true,
+            signature: String::new(),
+            body: String::new(),
+        };
+        let (client, url) = delivery.client().await.unwrap();
+        let update_delivery = delivery.attempt(&client, &url, 1).await;
+
+        // The redirect is recorded as an unsuccessful response and is not followed
+        assert_eq!(update_delivery.status_code, Some(302));
+        assert!(update_delivery.delivered.is_none());
+        assert!(requests.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_attempt() {
+        let (url, mut requests) = receiver(vec![500, 200]).await;
+        let secret: Secret = "super secret".parse().unwrap();
+        let body = r#"{"event": "report_created"}"#.to_owned();
+        let delivery = Delivery {
+            id: 1.into(),
+            uuid: WebhookDeliveryUuid::new(),
+            event: WebhookEvent::ReportCreated,
+            url,
+            allow_private: true,
+            signature: sign(&secret, &body),
+            body,
+        };
+        let (client, url) = delivery.client().await.unwrap();
+
+        // A server error is recorded as an unsuccessful attempt
+        let update_delivery = delivery.attempt(&client, &url, 1).await;
+        assert_eq!(update_delivery.attempts, 1);
+        assert_eq!(update_delivery.status_code, Some(500));
+        assert!(update_delivery.error.is_some());
+        assert!(update_delivery.delivered.is_none());
+
+        let update_delivery = delivery.attempt(&client, &url, 2).await;
+        assert_eq!(update_delivery.attempts, 2);
+        assert_eq!(update_delivery.status_code, Some(200));
+        assert_eq!(update_delivery.error, None);
+        assert!(update_delivery.delivered.is_some());
+
+        for _ in 0..2 {
+            let request = requests.recv().await.unwrap();
+            assert_eq!(request.body, delivery.body);
+            assert_eq!(
+                request.headers.get(&SIGNATURE_HEADER.to_ascii_lowercase()),
+                Some(&sign(&secret, &request.body))
+            );
+            assert_eq!(
+                request
+                    .headers
+                    .get(&EVENT_HEADER.to_ascii_lowercase())
+                    .map(String::as_str),
+                Some("report_created")
+            );
+        }
+    }
+}
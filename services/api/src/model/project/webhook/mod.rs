@@ -0,0 +1,229 @@
+use bencher_json::{
+    project::webhook::{JsonUpdateWebhook, WebhookEvent},
+    DateTime, JsonAlert, JsonNewWebhook, JsonReport, JsonWebhook, Secret, Url, WebhookUuid,
+};
+use diesel::{BelongingToDsl, RunQueryDsl};
+use dropshot::HttpError;
+use slog::{error, Logger};
+
+use super::{ProjectId, QueryProject};
+use crate::{
+    conn_lock,
+    context::{ApiContext, DbConnection},
+    error::{assert_parentage, resource_not_found_err, BencherResource},
+    schema::webhook as webhook_table,
+    util::fn_get::{fn_from_uuid, fn_get},
+};
+
+use self::delivery::InsertWebhookDelivery;
+
+pub mod delivery;
+
+crate::util::typed_id::typed_id!(WebhookId);
+
+#[derive(
+    Debug, Clone, diesel::Queryable, diesel::Identifiable, diesel::Associations, diesel::Selectable,
+)]
+#[diesel(table_name = webhook_table)]
+#[diesel(belongs_to(QueryProject, foreign_key = project_id))]
+pub struct QueryWebhook {
+    pub id: WebhookId,
+    pub uuid: WebhookUuid,
+    pub project_id: ProjectId,
+    pub url: Url,
+    pub secret: Secret,
+    pub alert_created: bool,
+    pub alert_dismissed: bool,
+    pub report_created: bool,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl QueryWebhook {
+    fn_get!(webhook, WebhookId);
+    fn_from_uuid!(webhook, WebhookUuid, Webhook);
+
+    pub fn into_json_for_project(self, project: &QueryProject) -> JsonWebhook {
+        let events = self.events();
+        let Self {
+            uuid,
+            project_id,
+            url,
+            created,
+            modified,
+            ..
+        } = self;
+        assert_parentage(
+            BencherResource::Project,
+            project.id,
+            BencherResource::Webhook,
+            project_id,
+        );
+        JsonWebhook {
+            uuid,
+            project: project.uuid,
+            url,
+            events,
+            created,
+            modified,
+        }
+    }
+
+    fn events(&self) -> Vec<WebhookEvent> {
+        WebhookEvent::all()
+            .into_iter()
+            .filter(|event| self.is_subscribed(*event))
+            .collect()
+    }
+
+    fn is_subscribed(&self, event: WebhookEvent) -> bool {
+        match event {
+            WebhookEvent::AlertCreated => self.alert_created,
+            WebhookEvent::AlertDismissed => self.alert_dismissed,
+            WebhookEvent::ReportCreated => self.report_created,
+        }
+    }
+
+    /// Send the `report_created` event for a new report
+    /// and an `alert_created` event for each of its alerts.
+    pub async fn send_report(
+        log: &Logger,
+        context: &ApiContext,
+        project: &QueryProject,
+        json_report: &JsonReport,
+    ) {
+        Self::send(
+            log,
+            context,
+            project,
+            WebhookEvent::ReportCreated,
+            json_report,
+            None,
+        )
+        .await;
+        for json_alert in &json_report.alerts {
+            Self::send(
+                log,
+                context,
+                project,
+                WebhookEvent::AlertCreated,
+                json_report,
+                Some(json_alert),
+            )
+            .await;
+        }
+    }
+
+    /// Send an event to every webhook for the project that is subscribed to it.
+    /// Webhook failures are logged and recorded in the delivery log,
+    /// but they never cause the triggering request to fail.
+    pub async fn send(
+        log: &Logger,
+        context: &ApiContext,
+        project: &QueryProject,
+        event: WebhookEvent,
+        json_report: &JsonReport,
+        json_alert: Option<&JsonAlert>,
+    ) {
+        let webhooks = match Self::belonging_to(project)
+            .load::<Self>(conn_lock!(context))
+            .map_err(resource_not_found_err!(Webhook, project))
+        {
+            Ok(webhooks) => webhooks,
+            Err(e) => {
+                error!(log, "Failed to get webhooks for project ({project:?}): {e}");
+                return;
+            },
+        };
+
+        for webhook in webhooks
+            .into_iter()
+            .filter(|webhook| webhook.is_subscribed(event))
+        {
+            let uuid = webhook.uuid;
+            if let Err(e) = InsertWebhookDelivery::deliver(
+                log,
+                context,
+                &webhook,
+                event,
+                json_report,
+                json_alert,
+            )
+            .await
+            {
+                error!(
+                    log,
+                    "Failed to deliver {event} event to webhook ({uuid}): {e}"
+                );
+            }
+        }
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = webhook_table)]
+pub struct InsertWebhook {
+    pub uuid: WebhookUuid,
+    pub project_id: ProjectId,
+    pub url: Url,
+    pub secret: Secret,
+    pub alert_created: bool,
+    pub alert_dismissed: bool,
+    pub report_created: bool,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl InsertWebhook {
+    pub fn from_json(project_id: ProjectId, webhook: JsonNewWebhook) -> Self {
+        let JsonNewWebhook {
+            url,
+            secret,
+            events,
+        } = webhook;
+        let events = events.unwrap_or_else(WebhookEvent::all);
+        let timestamp = DateTime::now();
+        Self {
+            uuid: WebhookUuid::new(),
+            project_id,
+            url,
+            secret,
+            alert_created: events.contains(&WebhookEvent::AlertCreated),
+            alert_dismissed: events.contains(&WebhookEvent::AlertDismissed),
+            report_created: events.contains(&WebhookEvent::ReportCreated),
+            created: timestamp,
+            modified: timestamp,
+        }
+    }
+}
+
+#[derive(Debug, Clone, diesel::AsChangeset)]
+#[diesel(table_name = webhook_table)]
+pub struct UpdateWebhook {
+    pub url: Option<Url>,
+    pub secret: Option<Secret>,
+    pub alert_created: Option<bool>,
+    pub alert_dismissed: Option<bool>,
+    pub report_created: Option<bool>,
+    pub modified: DateTime,
+}
+
+impl From<JsonUpdateWebhook> for UpdateWebhook {
+    fn from(update: JsonUpdateWebhook) -> Self {
+        let JsonUpdateWebhook {
+            url,
+            secret,
+            events,
+        } = update;
+        let is_subscribed =
+            |event: WebhookEvent| events.as_ref().map(|events| events.contains(&event));
+        Self {
+            url,
+            secret,
+            alert_created: is_subscribed(WebhookEvent::AlertCreated),
+            alert_dismissed: is_subscribed(WebhookEvent::AlertDismissed),
+            report_created: is_subscribed(WebhookEvent::ReportCreated),
+            modified: DateTime::now(),
+        }
+    }
+}
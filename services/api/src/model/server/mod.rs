@@ -11,7 +11,7 @@ use slog::Logger;
 
 use crate::{
     config::plus::StatsSettings,
-    context::{Body, DbConnection, Message, Messenger, ServerStatsBody},
+    context::{Body, DbConnection, DbPool, Message, Messenger, ServerStatsBody},
     error::resource_conflict_err,
     model::{organization::plan::LicenseUsage, user::QueryUser},
     schema::{self, server as server_table},
@@ -54,7 +54,7 @@ impl QueryServer {
     pub fn spawn_stats(
         self,
         log: Logger,
-        conn: Arc<tokio::sync::Mutex<DbConnection>>,
+        pool: Arc<DbPool>,
         stats: StatsSettings,
         licensor: Option<Licensor>,
         messenger: Option<Messenger>,
@@ -74,7 +74,7 @@ impl QueryServer {
                 .unwrap_or(std::time::Duration::from_secs(24 * 60 * 60));
                 tokio::time::sleep(sleep_time).await;
 
-                let conn = &mut *conn.lock().await;
+                let conn = &mut *pool.get().await;
 
                 if enabled {
                     slog::info!(log, "Sending stats at {}", Utc::now());
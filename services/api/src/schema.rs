@@ -8,6 +8,8 @@ diesel::table! {
         boundary_limit -> Bool,
         status -> Integer,
         modified -> BigInt,
+        assignee_id -> Nullable<Integer>,
+        note -> Nullable<Text>,
     }
 }
 
@@ -20,6 +22,8 @@ diesel::table! {
         slug -> Text,
         created -> BigInt,
         modified -> BigInt,
+        benchmark_group -> Nullable<Text>,
+        tags -> Nullable<Text>,
     }
 }
 
@@ -33,6 +37,7 @@ diesel::table! {
         baseline -> Nullable<Double>,
         lower_limit -> Nullable<Double>,
         upper_limit -> Nullable<Double>,
+        effect_size -> Nullable<Double>,
     }
 }
 
@@ -46,6 +51,7 @@ diesel::table! {
         start_point_id -> Nullable<Integer>,
         created -> BigInt,
         modified -> BigInt,
+        archived -> Nullable<BigInt>,
     }
 }
 
@@ -65,6 +71,8 @@ diesel::table! {
         name -> Text,
         slug -> Text,
         units -> Text,
+        unit -> Nullable<Text>,
+        expression -> Nullable<Text>,
         created -> BigInt,
         modified -> BigInt,
     }
@@ -184,6 +192,18 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    retention (id) {
+        id -> Integer,
+        project_id -> Integer,
+        raw_window -> BigInt,
+        downsample -> Bool,
+        protected_branch_id -> Nullable<Integer>,
+        created -> BigInt,
+        modified -> BigInt,
+    }
+}
+
 diesel::table! {
     server (id) {
         id -> Integer,
@@ -192,6 +212,20 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    silence (id) {
+        id -> Integer,
+        uuid -> Text,
+        project_id -> Integer,
+        benchmark_id -> Nullable<Integer>,
+        measure_id -> Nullable<Integer>,
+        testbed_id -> Nullable<Integer>,
+        note -> Nullable<Text>,
+        expires -> Nullable<BigInt>,
+        created -> BigInt,
+    }
+}
+
 diesel::table! {
     testbed (id) {
         id -> Integer,
@@ -215,6 +249,7 @@ diesel::table! {
         model_id -> Nullable<Integer>,
         created -> BigInt,
         modified -> BigInt,
+        benchmark_group -> Nullable<Text>,
     }
 }
 
@@ -254,7 +289,38 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    webhook (id) {
+        id -> Integer,
+        uuid -> Text,
+        project_id -> Integer,
+        url -> Text,
+        secret -> Text,
+        alert_created -> Bool,
+        alert_dismissed -> Bool,
+        report_created -> Bool,
+        created -> BigInt,
+        modified -> BigInt,
+    }
+}
+
+diesel::table! {
+    webhook_delivery (id) {
+        id -> Integer,
+        uuid -> Text,
+        webhook_id -> Integer,
+        event -> Integer,
+        attempts -> Integer,
+        status_code -> Nullable<Integer>,
+        error -> Nullable<Text>,
+        delivered -> Nullable<BigInt>,
+        created -> BigInt,
+        modified -> BigInt,
+    }
+}
+
 diesel::joinable!(alert -> boundary (boundary_id));
+diesel::joinable!(alert -> user (assignee_id));
 diesel::joinable!(benchmark -> project (project_id));
 diesel::joinable!(boundary -> metric (metric_id));
 diesel::joinable!(boundary -> model (model_id));
@@ -276,6 +342,12 @@ diesel::joinable!(report -> user (user_id));
 diesel::joinable!(report -> version (version_id));
 diesel::joinable!(report_benchmark -> benchmark (benchmark_id));
 diesel::joinable!(report_benchmark -> report (report_id));
+diesel::joinable!(retention -> branch (protected_branch_id));
+diesel::joinable!(retention -> project (project_id));
+diesel::joinable!(silence -> benchmark (benchmark_id));
+diesel::joinable!(silence -> measure (measure_id));
+diesel::joinable!(silence -> project (project_id));
+diesel::joinable!(silence -> testbed (testbed_id));
 diesel::joinable!(testbed -> project (project_id));
 diesel::joinable!(threshold -> branch (branch_id));
 diesel::joinable!(threshold -> measure (measure_id));
@@ -283,6 +355,8 @@ diesel::joinable!(threshold -> project (project_id));
 diesel::joinable!(threshold -> testbed (testbed_id));
 diesel::joinable!(token -> user (user_id));
 diesel::joinable!(version -> project (project_id));
+diesel::joinable!(webhook -> project (project_id));
+diesel::joinable!(webhook_delivery -> webhook (webhook_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     alert,
@@ -300,10 +374,14 @@ diesel::allow_tables_to_appear_in_same_query!(
     project_role,
     report,
     report_benchmark,
+    retention,
     server,
+    silence,
     testbed,
     threshold,
     token,
     user,
     version,
+    webhook,
+    webhook_delivery,
 );
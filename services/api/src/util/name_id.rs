@@ -7,7 +7,7 @@ macro_rules! fn_eq_name_id {
             Box<
                 dyn diesel::BoxableExpression<
                     crate::schema::$table::table,
-                    diesel::sqlite::Sqlite,
+                    crate::context::DbBackend,
                     SqlType = diesel::sql_types::Bool,
                 >,
             >,
@@ -112,3 +112,11 @@ macro_rules! filter_measure_name_id {
 }
 
 pub(crate) use filter_measure_name_id;
+
+macro_rules! filter_benchmark_name_id {
+    ($query:ident, $name_id:ident) => {
+        crate::util::name_id::filter_name_id!(BenchmarkName, $query, benchmark, $name_id)
+    };
+}
+
+pub(crate) use filter_benchmark_name_id;
@@ -5,18 +5,18 @@ use serde::Deserialize;
 #[diesel(sql_type = diesel::sql_types::Text)]
 pub struct Search(String);
 
-impl<DB> diesel::serialize::ToSql<diesel::sql_types::Text, DB> for Search
-where
-    DB: diesel::backend::Backend,
-    for<'a> String: diesel::serialize::ToSql<diesel::sql_types::Text, DB>
-        + Into<<DB::BindCollector<'a> as diesel::query_builder::BindCollector<'a, DB>>::Buffer>,
-{
-    fn to_sql<'b>(
-        &'b self,
-        out: &mut diesel::serialize::Output<'b, '_, DB>,
-    ) -> diesel::serialize::Result {
-        // https://docs.rs/diesel/latest/diesel/serialize/trait.ToSql.html#examples
-        out.set_value(format!("%{}%", self.0));
-        Ok(diesel::serialize::IsNull::No)
+// `LIKE` is case insensitive with SQLite but case sensitive with PostgreSQL.
+// So searches compare the lowercase column to the lowercase search with both of them.
+// https://docs.rs/diesel/latest/diesel/serialize/trait.ToSql.html#examples
+bencher_json::to_sql_converted!(diesel::sql_types::Text, Search, |search| format!(
+    "%{}%",
+    search.0.to_lowercase()
+));
+
+diesel::sql_function!(fn lower(x: diesel::sql_types::Text) -> diesel::sql_types::Text);
+
+impl From<String> for Search {
+    fn from(search: String) -> Self {
+        Self(search)
     }
 }
@@ -5,6 +5,11 @@ use crate::{context::DbConnection, model::project::ProjectId};
 
 pub type SlugExistsFn = dyn FnOnce(&mut DbConnection, Option<ProjectId>, &str) -> bool;
 
+// The slug is only checked here, and it is inserted later.
+// With PostgreSQL, that may be on a different connection than a concurrent request for the same slug,
+// so both requests may pick the same slug.
+// The unique constraint on the slug rejects the second insert as a conflict,
+// which is the same as when the two requests are interleaved with SQLite.
 pub fn validate_slug<S>(
     conn: &mut DbConnection,
     project_id: Option<ProjectId>,
@@ -8,6 +8,8 @@ macro_rules! typed_id {
             Default,
             PartialEq,
             Eq,
+            PartialOrd,
+            Ord,
             Hash,
             derive_more::Display,
             diesel::FromSqlRow,
@@ -22,6 +24,13 @@ macro_rules! typed_id {
             }
         }
 
+        #[cfg(test)]
+        impl From<i32> for $name {
+            fn from(id: i32) -> Self {
+                Self(id)
+            }
+        }
+
         impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for $name
         where
             DB: diesel::backend::Backend,
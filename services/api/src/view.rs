@@ -19,6 +19,7 @@ diesel::table! {
         baseline -> Nullable<Double>,
         lower_limit -> Nullable<Double>,
         upper_limit -> Nullable<Double>,
+        effect_size -> Nullable<Double>,
     }
 }
 
@@ -14,10 +14,15 @@ use mock::Mock;
 pub use mock::MockError;
 use organization::{member::Member, organization::Organization};
 use project::{
-    alert::Alert, benchmark::Benchmark, branch::Branch, measure::Measure, perf::Perf,
-    project::Project, report::Report, run::Run, testbed::Testbed, threshold::Threshold,
+    alert::Alert, benchmark::Benchmark, branch::Branch, compare::Compare, import::Import,
+    measure::Measure, perf::Perf, project::Project, report::Report, retention::Retention, run::Run,
+    silence::Silence, testbed::Testbed, threshold::Threshold, webhook::Webhook,
 };
 pub use project::{
+    branch::BranchError,
+    import::ImportError,
+    perf::PerfError,
+    project::ArchiveError,
     run::{runner::output::Output, RunError},
     threshold::ThresholdError,
 };
@@ -35,13 +40,18 @@ pub enum Sub {
     Project(Project),
     Run(Box<Run>),
     Report(Report),
+    Import(Import),
     Perf(Perf),
+    Compare(Compare),
     Branch(Branch),
     Testbed(Testbed),
     Benchmark(Benchmark),
     Measure(Measure),
     Threshold(Threshold),
     Alert(Alert),
+    Silence(Silence),
+    Webhook(Webhook),
+    Retention(Retention),
     User(User),
     Token(Token),
     Server(Server),
@@ -64,13 +74,18 @@ impl TryFrom<CliSub> for Sub {
             CliSub::Project(project) => Self::Project(project.try_into()?),
             CliSub::Run(run) => Self::Run(Box::new((*run).try_into()?)),
             CliSub::Report(report) => Self::Report(report.try_into()?),
+            CliSub::Import(import) => Self::Import(import.try_into()?),
             CliSub::Perf(perf) => Self::Perf(perf.try_into()?),
+            CliSub::Compare(compare) => Self::Compare(compare.try_into()?),
             CliSub::Branch(branch) => Self::Branch(branch.try_into()?),
             CliSub::Testbed(testbed) => Self::Testbed(testbed.try_into()?),
             CliSub::Benchmark(benchmark) => Self::Benchmark(benchmark.try_into()?),
             CliSub::Measure(measure) => Self::Measure(measure.try_into()?),
             CliSub::Threshold(threshold) => Self::Threshold(threshold.try_into()?),
             CliSub::Alert(alert) => Self::Alert(alert.try_into()?),
+            CliSub::Silence(silence) => Self::Silence(silence.try_into()?),
+            CliSub::Webhook(webhook) => Self::Webhook(webhook.try_into()?),
+            CliSub::Retention(retention) => Self::Retention(retention.try_into()?),
             CliSub::User(user) => Self::User(user.try_into()?),
             CliSub::Token(token) => Self::Token(token.try_into()?),
             CliSub::Server(server) => Self::Server(server.try_into()?),
@@ -93,13 +108,18 @@ impl SubCmd for Sub {
             Self::Project(project) => project.exec().await,
             Self::Run(run) => run.exec().await,
             Self::Report(report) => report.exec().await,
+            Self::Import(import) => import.exec().await,
             Self::Perf(perf) => perf.exec().await,
+            Self::Compare(compare) => compare.exec().await,
             Self::Branch(branch) => branch.exec().await,
             Self::Testbed(testbed) => testbed.exec().await,
             Self::Benchmark(benchmark) => benchmark.exec().await,
             Self::Measure(measure) => measure.exec().await,
             Self::Threshold(threshold) => threshold.exec().await,
             Self::Alert(alert) => alert.exec().await,
+            Self::Silence(silence) => silence.exec().await,
+            Self::Webhook(webhook) => webhook.exec().await,
+            Self::Retention(retention) => retention.exec().await,
             Self::User(user) => user.exec().await,
             Self::Token(token) => token.exec().await,
             Self::Server(server) => server.exec().await,
@@ -1,10 +1,12 @@
-use bencher_client::types::{JsonDirection, ProjAlertsSort};
-use bencher_json::ResourceId;
+use bencher_client::types::{AlertStatus, BoundaryLimit, JsonDirection, ProjAlertsSort};
+use bencher_json::{
+    urlencoded::to_urlencoded, DateTime, DateTimeMillis, NameId, ReportUuid, ResourceId,
+};
 
 use crate::{
     bencher::{backend::PubBackend, sub::SubCmd},
     parser::{
-        project::alert::{CliAlertList, CliAlertsSort},
+        project::alert::{CliAlertLimit, CliAlertList, CliAlertsSort},
         CliPagination,
     },
     CliError,
@@ -13,6 +15,17 @@ use crate::{
 #[derive(Debug)]
 pub struct List {
     pub project: ResourceId,
+    pub status: Option<AlertStatus>,
+    pub assignee: Option<ResourceId>,
+    pub branch: Option<NameId>,
+    pub testbed: Option<NameId>,
+    pub benchmark: Option<NameId>,
+    pub measure: Option<NameId>,
+    pub limit: Option<BoundaryLimit>,
+    pub report: Option<ReportUuid>,
+    pub start_time: Option<DateTime>,
+    pub end_time: Option<DateTime>,
+    pub search: Option<String>,
     pub pagination: Pagination,
     pub backend: PubBackend,
 }
@@ -31,17 +44,48 @@ impl TryFrom<CliAlertList> for List {
     fn try_from(list: CliAlertList) -> Result<Self, Self::Error> {
         let CliAlertList {
             project,
+            status,
+            assignee,
+            branch,
+            testbed,
+            benchmark,
+            measure,
+            limit,
+            report,
+            start_time,
+            end_time,
+            search,
             pagination,
             backend,
         } = list;
         Ok(Self {
             project,
+            status: status.map(Into::into),
+            assignee,
+            branch,
+            testbed,
+            benchmark,
+            measure,
+            limit: limit.map(Into::into),
+            report,
+            start_time,
+            end_time,
+            search,
             pagination: pagination.into(),
             backend: backend.try_into()?,
         })
     }
 }
 
+impl From<CliAlertLimit> for BoundaryLimit {
+    fn from(limit: CliAlertLimit) -> Self {
+        match limit {
+            CliAlertLimit::Lower => Self::Lower,
+            CliAlertLimit::Upper => Self::Upper,
+        }
+    }
+}
+
 impl From<CliPagination<CliAlertsSort>> for Pagination {
     fn from(pagination: CliPagination<CliAlertsSort>) -> Self {
         let CliPagination {
@@ -68,6 +112,39 @@ impl SubCmd for List {
             .backend
             .send(|client| async move {
                 let mut client = client.proj_alerts_get().project(self.project.clone());
+                if let Some(status) = self.status {
+                    client = client.status(status);
+                }
+                if let Some(assignee) = self.assignee.clone() {
+                    client = client.assignee(assignee);
+                }
+                if let Some(branch) = self.branch.as_ref() {
+                    client = client.branch(to_urlencoded(branch));
+                }
+                if let Some(testbed) = self.testbed.as_ref() {
+                    client = client.testbed(to_urlencoded(testbed));
+                }
+                if let Some(benchmark) = self.benchmark.as_ref() {
+                    client = client.benchmark(to_urlencoded(benchmark));
+                }
+                if let Some(measure) = self.measure.as_ref() {
+                    client = client.measure(to_urlencoded(measure));
+                }
+                if let Some(limit) = self.limit {
+                    client = client.limit(limit);
+                }
+                if let Some(report) = self.report {
+                    client = client.report(report);
+                }
+                if let Some(start_time) = self.start_time {
+                    client = client.start_time(DateTimeMillis::from(start_time));
+                }
+                if let Some(end_time) = self.end_time {
+                    client = client.end_time(DateTimeMillis::from(end_time));
+                }
+                if let Some(search) = self.search.clone() {
+                    client = client.search(search);
+                }
                 if let Some(sort) = self.pagination.sort {
                     client = client.sort(sort);
                 }
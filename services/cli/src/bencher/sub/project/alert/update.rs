@@ -12,6 +12,9 @@ pub struct Update {
     pub project: ResourceId,
     pub alert: AlertUuid,
     pub status: Option<AlertStatus>,
+    pub assignee: Option<ResourceId>,
+    pub unassign: bool,
+    pub note: Option<String>,
     pub backend: AuthBackend,
 }
 
@@ -23,12 +26,18 @@ impl TryFrom<CliAlertUpdate> for Update {
             project,
             alert,
             status,
+            assignee,
+            unassign,
+            note,
             backend,
         } = create;
         Ok(Self {
             project,
             alert,
             status: status.map(Into::into),
+            assignee,
+            unassign,
+            note,
             backend: backend.try_into()?,
         })
     }
@@ -39,14 +48,28 @@ impl From<CliAlertStatus> for AlertStatus {
         match status {
             CliAlertStatus::Active => Self::Active,
             CliAlertStatus::Dismissed => Self::Dismissed,
+            CliAlertStatus::Acknowledged => Self::Acknowledged,
+            CliAlertStatus::Silenced => Self::Silenced,
+            CliAlertStatus::Resolved => Self::Resolved,
         }
     }
 }
 
 impl From<Update> for JsonUpdateAlert {
     fn from(update: Update) -> Self {
-        let Update { status, .. } = update;
-        Self { status }
+        let Update {
+            status,
+            assignee,
+            unassign,
+            note,
+            ..
+        } = update;
+        Self {
+            status,
+            assignee: assignee.map(Into::into),
+            unassign: unassign.then_some(true),
+            note,
+        }
     }
 }
 
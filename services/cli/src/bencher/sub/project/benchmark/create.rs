@@ -1,5 +1,5 @@
 use bencher_client::types::JsonNewBenchmark;
-use bencher_json::{BenchmarkName, ResourceId, Slug};
+use bencher_json::{BenchmarkGroup, BenchmarkName, ResourceId, Slug};
 
 use crate::{
     bencher::{backend::AuthBackend, sub::SubCmd},
@@ -12,6 +12,8 @@ pub struct Create {
     pub project: ResourceId,
     pub name: BenchmarkName,
     pub slug: Option<Slug>,
+    pub group: Option<BenchmarkGroup>,
+    pub tags: Vec<Slug>,
     pub backend: AuthBackend,
 }
 
@@ -23,12 +25,16 @@ impl TryFrom<CliBenchmarkCreate> for Create {
             project,
             name,
             slug,
+            group,
+            tags,
             backend,
         } = create;
         Ok(Self {
             project,
             name,
             slug,
+            group,
+            tags,
             backend: backend.try_into()?,
         })
     }
@@ -36,10 +42,18 @@ impl TryFrom<CliBenchmarkCreate> for Create {
 
 impl From<Create> for JsonNewBenchmark {
     fn from(create: Create) -> Self {
-        let Create { name, slug, .. } = create;
+        let Create {
+            name,
+            slug,
+            group,
+            tags,
+            ..
+        } = create;
         Self {
             name: name.into(),
             slug: slug.map(Into::into),
+            group: group.map(Into::into),
+            tags: (!tags.is_empty()).then(|| tags.into_iter().map(Into::into).collect()),
         }
     }
 }
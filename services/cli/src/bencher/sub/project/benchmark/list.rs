@@ -1,5 +1,5 @@
 use bencher_client::types::{JsonDirection, ProjBenchmarksSort};
-use bencher_json::{BenchmarkName, ResourceId};
+use bencher_json::{BenchmarkGroup, BenchmarkName, ResourceId, Slug};
 
 use crate::{
     bencher::{backend::PubBackend, sub::SubCmd},
@@ -15,6 +15,8 @@ pub struct List {
     pub project: ResourceId,
     pub name: Option<BenchmarkName>,
     pub search: Option<String>,
+    pub tag: Option<Slug>,
+    pub group: Option<BenchmarkGroup>,
     pub pagination: Pagination,
     pub backend: PubBackend,
 }
@@ -35,6 +37,8 @@ impl TryFrom<CliBenchmarkList> for List {
             project,
             name,
             search,
+            tag,
+            group,
             pagination,
             backend,
         } = list;
@@ -42,6 +46,8 @@ impl TryFrom<CliBenchmarkList> for List {
             project,
             name,
             search,
+            tag,
+            group,
             pagination: pagination.into(),
             backend: backend.try_into()?,
         })
@@ -79,6 +85,12 @@ impl SubCmd for List {
                 if let Some(search) = self.search.clone() {
                     client = client.search(search);
                 }
+                if let Some(tag) = self.tag.clone() {
+                    client = client.tag(tag);
+                }
+                if let Some(group) = self.group.clone() {
+                    client = client.group(group);
+                }
                 if let Some(sort) = self.pagination.sort {
                     client = client.sort(sort);
                 }
@@ -1,5 +1,5 @@
 use bencher_client::types::JsonUpdateBenchmark;
-use bencher_json::{BenchmarkName, ResourceId, Slug};
+use bencher_json::{BenchmarkGroup, BenchmarkName, ResourceId, Slug};
 
 use crate::{
     bencher::{backend::AuthBackend, sub::SubCmd},
@@ -13,6 +13,8 @@ pub struct Update {
     pub benchmark: ResourceId,
     pub name: Option<BenchmarkName>,
     pub slug: Option<Slug>,
+    pub group: Option<BenchmarkGroup>,
+    pub tags: Vec<Slug>,
     pub backend: AuthBackend,
 }
 
@@ -25,6 +27,8 @@ impl TryFrom<CliBenchmarkUpdate> for Update {
             benchmark,
             name,
             slug,
+            group,
+            tags,
             backend,
         } = create;
         Ok(Self {
@@ -32,6 +36,8 @@ impl TryFrom<CliBenchmarkUpdate> for Update {
             benchmark,
             name,
             slug,
+            group,
+            tags,
             backend: backend.try_into()?,
         })
     }
@@ -39,10 +45,18 @@ impl TryFrom<CliBenchmarkUpdate> for Update {
 
 impl From<Update> for JsonUpdateBenchmark {
     fn from(update: Update) -> Self {
-        let Update { name, slug, .. } = update;
+        let Update {
+            name,
+            slug,
+            group,
+            tags,
+            ..
+        } = update;
         Self {
             name: name.map(Into::into),
             slug: slug.map(Into::into),
+            group: group.map(Into::into),
+            tags: (!tags.is_empty()).then(|| tags.into_iter().map(Into::into).collect()),
         }
     }
 }
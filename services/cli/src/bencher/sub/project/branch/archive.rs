@@ -0,0 +1,157 @@
+use std::collections::HashSet;
+
+use bencher_client::types::JsonArchiveBranches;
+use bencher_json::{BranchName, ResourceId, Window};
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::branch::CliBranchArchive,
+    CliError,
+};
+
+use super::BranchError;
+
+#[derive(Debug, Clone)]
+pub struct Archive {
+    pub project: ResourceId,
+    pub stale: Option<Window>,
+    pub merged: Option<String>,
+    pub branches: Vec<BranchName>,
+    pub dry_run: bool,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliBranchArchive> for Archive {
+    type Error = CliError;
+
+    fn try_from(archive: CliBranchArchive) -> Result<Self, Self::Error> {
+        let CliBranchArchive {
+            project,
+            stale,
+            merged,
+            branch,
+            dry_run,
+            backend,
+        } = archive;
+        Ok(Self {
+            project,
+            stale,
+            merged,
+            branches: branch,
+            dry_run,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Archive> for JsonArchiveBranches {
+    fn from(archive: Archive) -> Self {
+        let Archive {
+            stale,
+            branches,
+            dry_run,
+            ..
+        } = archive;
+        Self {
+            stale: stale.map(Into::into),
+            branches: (!branches.is_empty())
+                .then(|| branches.into_iter().map(Into::into).collect()),
+            dry_run: Some(dry_run),
+        }
+    }
+}
+
+impl SubCmd for Archive {
+    async fn exec(&self) -> Result<(), CliError> {
+        let mut archive = self.clone();
+        if let Some(target) = &self.merged {
+            for branch in merged_branches(target)? {
+                if !archive.branches.contains(&branch) {
+                    archive.branches.push(branch);
+                }
+            }
+        }
+        let archive = &archive;
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_branches_archive_post()
+                    .project(self.project.clone())
+                    .body(archive.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
+
+/// Find all of the local and remote `git` branches that have been merged into the target.
+/// A branch is merged if its tip is an ancestor of the target.
+fn merged_branches(target: &str) -> Result<Vec<BranchName>, BranchError> {
+    let current_dir = std::env::current_dir().map_err(|_e| BranchError::NoRepository)?;
+    let repo = current_dir
+        .ancestors()
+        .find_map(|directory| gix::open(directory).ok())
+        .ok_or(BranchError::NoRepository)?;
+
+    let target_id = repo
+        .rev_parse_single(target)
+        .map_err(|err| BranchError::MergeTarget {
+            target: target.to_owned(),
+            err: Box::new(err),
+        })?;
+    let ancestors = target_id
+        .ancestors()
+        .all()
+        .map_err(|err| BranchError::RevWalk {
+            target: target.to_owned(),
+            err: Box::new(err),
+        })?
+        .filter_map(|info| info.ok().map(|info| info.id))
+        .collect::<HashSet<_>>();
+
+    let references = repo
+        .references()
+        .map_err(|e| BranchError::References(e.to_string()))?;
+    let local_branches = references
+        .local_branches()
+        .map_err(|e| BranchError::References(e.to_string()))?;
+    let remote_branches = references
+        .remote_branches()
+        .map_err(|e| BranchError::References(e.to_string()))?;
+
+    let target_name = target.split_once('/').map_or(target, |(_, name)| name);
+    let mut merged = Vec::new();
+    for reference in local_branches.chain(remote_branches) {
+        let Ok(mut reference) = reference else {
+            continue;
+        };
+        let Ok(id) = reference.peel_to_id_in_place() else {
+            continue;
+        };
+        if !ancestors.contains(&id.detach()) {
+            continue;
+        }
+        let name = reference.name().shorten().to_string();
+        // Remote branches are prefixed with the name of the remote
+        let name = if reference.name().category() == Some(gix::reference::Category::RemoteBranch) {
+            name.split_once('/')
+                .map_or(name.clone(), |(_, name)| name.to_owned())
+        } else {
+            name
+        };
+        if name == target || name == target_name || name == "HEAD" {
+            continue;
+        }
+        let Ok(branch_name) = name.parse::<BranchName>() else {
+            continue;
+        };
+        if !merged.contains(&branch_name) {
+            merged.push(branch_name);
+        }
+    }
+
+    Ok(merged)
+}
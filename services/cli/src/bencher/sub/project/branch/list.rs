@@ -15,6 +15,7 @@ pub struct List {
     pub project: ResourceId,
     pub name: Option<BranchName>,
     pub search: Option<String>,
+    pub archived: bool,
     pub pagination: Pagination,
     pub backend: PubBackend,
 }
@@ -35,6 +36,7 @@ impl TryFrom<CliBranchList> for List {
             project,
             name,
             search,
+            archived,
             pagination,
             backend,
         } = list;
@@ -42,6 +44,7 @@ impl TryFrom<CliBranchList> for List {
             project,
             name,
             search,
+            archived,
             pagination: pagination.into(),
             backend: backend.try_into()?,
         })
@@ -79,6 +82,9 @@ impl SubCmd for List {
                 if let Some(search) = self.search.clone() {
                     client = client.search(search);
                 }
+                if self.archived {
+                    client = client.archived(true);
+                }
                 if let Some(sort) = self.pagination.sort {
                     client = client.sort(sort);
                 }
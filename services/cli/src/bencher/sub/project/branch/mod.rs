@@ -1,5 +1,6 @@
 use crate::{bencher::sub::SubCmd, parser::project::branch::CliBranch, CliError};
 
+mod archive;
 mod create;
 mod delete;
 mod list;
@@ -13,6 +14,25 @@ pub enum Branch {
     View(view::View),
     Update(update::Update),
     Delete(delete::Delete),
+    Archive(archive::Archive),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum BranchError {
+    #[error("Failed to find a `git` repository in the current directory or any of its parents")]
+    NoRepository,
+    #[error("Failed to find merge target `git` ref ({target}): {err}")]
+    MergeTarget {
+        target: String,
+        err: Box<gix::revision::spec::parse::single::Error>,
+    },
+    #[error("Failed to walk the history of merge target `git` ref ({target}): {err}")]
+    RevWalk {
+        target: String,
+        err: Box<gix::revision::walk::Error>,
+    },
+    #[error("Failed to list `git` branches: {0}")]
+    References(String),
 }
 
 impl TryFrom<CliBranch> for Branch {
@@ -25,6 +45,7 @@ impl TryFrom<CliBranch> for Branch {
             CliBranch::View(view) => Self::View(view.try_into()?),
             CliBranch::Update(update) => Self::Update(update.try_into()?),
             CliBranch::Delete(delete) => Self::Delete(delete.try_into()?),
+            CliBranch::Archive(archive) => Self::Archive(archive.try_into()?),
         })
     }
 }
@@ -37,6 +58,7 @@ impl SubCmd for Branch {
             Self::View(view) => view.exec().await,
             Self::Update(update) => update.exec().await,
             Self::Delete(delete) => delete.exec().await,
+            Self::Archive(archive) => archive.exec().await,
         }
     }
 }
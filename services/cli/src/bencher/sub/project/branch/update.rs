@@ -14,6 +14,7 @@ pub struct Update {
     pub name: Option<BranchName>,
     pub slug: Option<Slug>,
     pub hash: Option<GitHash>,
+    pub archived: Option<bool>,
     pub backend: AuthBackend,
 }
 
@@ -27,14 +28,24 @@ impl TryFrom<CliBranchUpdate> for Update {
             name,
             slug,
             hash,
+            archive,
+            unarchive,
             backend,
         } = create;
+        let archived = if archive {
+            Some(true)
+        } else if unarchive {
+            Some(false)
+        } else {
+            None
+        };
         Ok(Self {
             project,
             branch,
             name,
             slug,
             hash,
+            archived,
             backend: backend.try_into()?,
         })
     }
@@ -43,12 +54,17 @@ impl TryFrom<CliBranchUpdate> for Update {
 impl From<Update> for JsonUpdateBranch {
     fn from(update: Update) -> Self {
         let Update {
-            name, slug, hash, ..
+            name,
+            slug,
+            hash,
+            archived,
+            ..
         } = update;
         Self {
             name: name.map(Into::into),
             slug: slug.map(Into::into),
             hash: hash.map(Into::into),
+            archived,
         }
     }
 }
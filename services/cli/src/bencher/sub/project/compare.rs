@@ -0,0 +1,142 @@
+use bencher_json::{
+    project::compare::JsonCompareQuery, JsonCompare, NameId, ReportUuid, ResourceId,
+};
+use tabled::{settings::Style, Table};
+
+use crate::{
+    bencher::{backend::PubBackend, sub::SubCmd},
+    cli_println,
+    parser::project::compare::{CliCompare, CliCompareFormat},
+    CliError,
+};
+
+#[derive(Debug, Clone)]
+pub struct Compare {
+    pub project: ResourceId,
+    pub base_report: Option<ReportUuid>,
+    pub head_report: Option<ReportUuid>,
+    pub base_branch: Option<NameId>,
+    pub head_branch: Option<NameId>,
+    pub testbed: Option<NameId>,
+    pub format: CompareFormat,
+    pub backend: PubBackend,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub enum CompareFormat {
+    #[default]
+    Json,
+    Text,
+    Markdown,
+}
+
+impl TryFrom<CliCompare> for Compare {
+    type Error = CliError;
+
+    fn try_from(compare: CliCompare) -> Result<Self, Self::Error> {
+        let CliCompare {
+            project,
+            base_report,
+            head_report,
+            base_branch,
+            head_branch,
+            testbed,
+            format,
+            backend,
+        } = compare;
+        let format = format.map(Into::into).unwrap_or_default();
+        let backend = PubBackend::try_from(backend)?.log(matches!(format, CompareFormat::Json));
+        Ok(Self {
+            project,
+            base_report,
+            head_report,
+            base_branch,
+            head_branch,
+            testbed,
+            format,
+            backend,
+        })
+    }
+}
+
+impl From<CliCompareFormat> for CompareFormat {
+    fn from(format: CliCompareFormat) -> Self {
+        match format {
+            CliCompareFormat::Json => Self::Json,
+            CliCompareFormat::Text => Self::Text,
+            CliCompareFormat::Markdown => Self::Markdown,
+        }
+    }
+}
+
+impl From<Compare> for JsonCompareQuery {
+    fn from(compare: Compare) -> Self {
+        let Compare {
+            base_report,
+            head_report,
+            base_branch,
+            head_branch,
+            testbed,
+            ..
+        } = compare;
+        Self {
+            base_report,
+            head_report,
+            base_branch,
+            head_branch,
+            testbed,
+        }
+    }
+}
+
+impl SubCmd for Compare {
+    async fn exec(&self) -> Result<(), CliError> {
+        let json_compare_query: &JsonCompareQuery = &self.clone().into();
+        let sender = |client: bencher_client::Client| async move {
+            let mut client = client.proj_compare_get().project(self.project.clone());
+
+            if let Some(base_report) = json_compare_query.base_report {
+                client = client.base_report(base_report);
+            }
+            if let Some(head_report) = json_compare_query.head_report {
+                client = client.head_report(head_report);
+            }
+            if let Some(base_branch) = json_compare_query.base_branch() {
+                client = client.base_branch(base_branch);
+            }
+            if let Some(head_branch) = json_compare_query.head_branch() {
+                client = client.head_branch(head_branch);
+            }
+            if let Some(testbed) = json_compare_query.testbed() {
+                client = client.testbed(testbed);
+            }
+
+            client.send().await
+        };
+
+        match self.format {
+            CompareFormat::Json => {
+                self.backend.send(sender).await?;
+            },
+            CompareFormat::Text => {
+                let json_compare: JsonCompare = self.backend.send_with(sender).await?;
+                cli_println!("Base: {}", json_compare.base);
+                cli_println!("Head: {}", json_compare.head);
+                let mut compare_table: Table = json_compare.into();
+                compare_table.with(Style::modern());
+                cli_println!("{compare_table}");
+            },
+            CompareFormat::Markdown => {
+                let json_compare: JsonCompare = self.backend.send_with(sender).await?;
+                cli_println!("**Base:** {}", json_compare.base);
+                cli_println!("");
+                cli_println!("**Head:** {}", json_compare.head);
+                cli_println!("");
+                let mut compare_table: Table = json_compare.into();
+                compare_table.with(Style::markdown());
+                cli_println!("{compare_table}");
+            },
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+use std::num::NonZeroUsize;
+
+use bencher_client::types::{JsonNewReport, JsonReportSettings};
+use bencher_json::ResourceId;
+use camino::{Utf8Path, Utf8PathBuf};
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    cli_println,
+    parser::project::import::CliImport,
+    CliError,
+};
+
+const DEFAULT_BATCH_SIZE: usize = 25;
+const JSON_EXTENSION: &str = "json";
+
+#[derive(Debug, Clone)]
+pub struct Import {
+    pub project: ResourceId,
+    pub path: Utf8PathBuf,
+    pub settings: Option<JsonReportSettings>,
+    pub batch_size: usize,
+    pub backend: AuthBackend,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ImportError {
+    #[error("Failed to read reports to import ({path}): {err}")]
+    Read {
+        path: Utf8PathBuf,
+        err: std::io::Error,
+    },
+    #[error("Failed to parse report to import ({location}): {err}")]
+    Parse {
+        location: String,
+        err: serde_json::Error,
+    },
+    #[error("No reports found to import: {0}")]
+    NoReports(Utf8PathBuf),
+}
+
+impl TryFrom<CliImport> for Import {
+    type Error = CliError;
+
+    fn try_from(import: CliImport) -> Result<Self, Self::Error> {
+        let CliImport {
+            project,
+            path,
+            adapter,
+            average,
+            fold,
+            batch_size,
+            backend,
+        } = import;
+        let settings = (adapter.is_some() || average.is_some() || fold.is_some()).then(|| {
+            JsonReportSettings {
+                adapter: adapter.map(Into::into),
+                average: average.map(Into::into),
+                fold: fold.map(Into::into),
+            }
+        });
+        Ok(Self {
+            project,
+            path,
+            settings,
+            batch_size: batch_size.map_or(DEFAULT_BATCH_SIZE, NonZeroUsize::get),
+            backend: AuthBackend::try_from(backend)?.log(false),
+        })
+    }
+}
+
+impl SubCmd for Import {
+    async fn exec(&self) -> Result<(), CliError> {
+        let mut reports = self.read()?;
+        if reports.is_empty() {
+            return Err(ImportError::NoReports(self.path.clone()).into());
+        }
+        // Send the reports in chronological order,
+        // so each batch only comes after the batches before it.
+        reports.sort_by_key(|report| (report.start_time.0, report.end_time.0));
+
+        let total = reports.len();
+        let mut imported = 0;
+        for batch in reports.chunks(self.batch_size) {
+            self.backend
+                .send(|client| async move {
+                    client
+                        .proj_import_post()
+                        .project(self.project.clone())
+                        .body(batch.to_vec())
+                        .send()
+                        .await
+                })
+                .await?;
+            imported += batch.len();
+            cli_println!("Imported {imported} of {total} reports");
+        }
+
+        Ok(())
+    }
+}
+
+impl Import {
+    // A directory is read as one report per JSON file.
+    // Otherwise, the file is read as one report per line.
+    fn read(&self) -> Result<Vec<JsonNewReport>, ImportError> {
+        let mut reports = if self.path.is_dir() {
+            let mut file_paths = Vec::new();
+            for entry in self.path.read_dir_utf8().map_err(|err| ImportError::Read {
+                path: self.path.clone(),
+                err,
+            })? {
+                let path = entry
+                    .map_err(|err| ImportError::Read {
+                        path: self.path.clone(),
+                        err,
+                    })?
+                    .into_path();
+                if path.is_file() && path.extension() == Some(JSON_EXTENSION) {
+                    file_paths.push(path);
+                }
+            }
+            file_paths.sort();
+            file_paths
+                .iter()
+                .map(|path| parse_report(&read_file(path)?, path.to_string()))
+                .collect::<Result<Vec<_>, _>>()?
+        } else {
+            read_file(&self.path)?
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| !line.trim().is_empty())
+                .map(|(index, line)| {
+                    parse_report(line, format!("{path}:{}", index + 1, path = self.path))
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        };
+
+        if let Some(settings) = &self.settings {
+            for report in &mut reports {
+                if report.settings.is_none() {
+                    report.settings = Some(settings.clone());
+                }
+            }
+        }
+
+        Ok(reports)
+    }
+}
+
+fn read_file(path: &Utf8Path) -> Result<String, ImportError> {
+    std::fs::read_to_string(path).map_err(|err| ImportError::Read {
+        path: path.to_owned(),
+        err,
+    })
+}
+
+fn parse_report(report: &str, location: String) -> Result<JsonNewReport, ImportError> {
+    serde_json::from_str(report).map_err(|err| ImportError::Parse { location, err })
+}
@@ -1,9 +1,9 @@
 use bencher_client::types::JsonNewMeasure;
-use bencher_json::{ResourceId, ResourceName, Slug};
+use bencher_json::{MeasureExpression, ResourceId, ResourceName, Slug};
 
 use crate::{
     bencher::{backend::AuthBackend, sub::SubCmd},
-    parser::project::measure::CliMeasureCreate,
+    parser::project::measure::{CliMeasureCreate, CliMeasureUnit},
     CliError,
 };
 
@@ -13,6 +13,8 @@ pub struct Create {
     pub name: ResourceName,
     pub slug: Option<Slug>,
     pub units: ResourceName,
+    pub unit: Option<CliMeasureUnit>,
+    pub expression: Option<MeasureExpression>,
     pub backend: AuthBackend,
 }
 
@@ -25,6 +27,8 @@ impl TryFrom<CliMeasureCreate> for Create {
             name,
             slug,
             units,
+            unit,
+            expression,
             backend,
         } = create;
         Ok(Self {
@@ -32,6 +36,8 @@ impl TryFrom<CliMeasureCreate> for Create {
             name,
             slug,
             units,
+            unit,
+            expression,
             backend: backend.try_into()?,
         })
     }
@@ -40,12 +46,19 @@ impl TryFrom<CliMeasureCreate> for Create {
 impl From<Create> for JsonNewMeasure {
     fn from(create: Create) -> Self {
         let Create {
-            name, slug, units, ..
+            name,
+            slug,
+            units,
+            unit,
+            expression,
+            ..
         } = create;
         Self {
             name: name.into(),
             slug: slug.map(Into::into),
             units: units.into(),
+            unit: unit.map(Into::into),
+            expression: expression.map(Into::into),
         }
     }
 }
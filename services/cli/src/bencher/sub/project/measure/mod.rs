@@ -1,4 +1,10 @@
-use crate::{bencher::sub::SubCmd, parser::project::measure::CliMeasure, CliError};
+use bencher_client::types::MeasureUnit;
+
+use crate::{
+    bencher::sub::SubCmd,
+    parser::project::measure::{CliMeasure, CliMeasureUnit},
+    CliError,
+};
 
 mod create;
 mod delete;
@@ -40,3 +46,24 @@ impl SubCmd for Measure {
         }
     }
 }
+
+impl From<CliMeasureUnit> for MeasureUnit {
+    fn from(unit: CliMeasureUnit) -> Self {
+        match unit {
+            CliMeasureUnit::Nanoseconds => Self::Nanoseconds,
+            CliMeasureUnit::Microseconds => Self::Microseconds,
+            CliMeasureUnit::Milliseconds => Self::Milliseconds,
+            CliMeasureUnit::Seconds => Self::Seconds,
+            CliMeasureUnit::Bytes => Self::Bytes,
+            CliMeasureUnit::Kibibytes => Self::Kibibytes,
+            CliMeasureUnit::Mebibytes => Self::Mebibytes,
+            CliMeasureUnit::Gibibytes => Self::Gibibytes,
+            CliMeasureUnit::BytesPerSecond => Self::BytesPerSecond,
+            CliMeasureUnit::KilobytesPerSecond => Self::KilobytesPerSecond,
+            CliMeasureUnit::MegabytesPerSecond => Self::MegabytesPerSecond,
+            CliMeasureUnit::GigabytesPerSecond => Self::GigabytesPerSecond,
+            CliMeasureUnit::OperationsPerSecond => Self::OperationsPerSecond,
+            CliMeasureUnit::Count => Self::Count,
+        }
+    }
+}
@@ -1,19 +1,22 @@
-use bencher_client::types::JsonUpdateMeasure;
-use bencher_json::{ResourceId, ResourceName, Slug};
+use bencher_client::types::{JsonMeasurePatch, JsonMeasurePatchNull, JsonUpdateMeasure};
+use bencher_json::{MeasureExpression, ResourceId, ResourceName, Slug};
 
 use crate::{
     bencher::{backend::AuthBackend, sub::SubCmd},
-    parser::project::measure::CliMeasureUpdate,
+    parser::project::measure::{CliMeasureUnit, CliMeasureUpdate},
     CliError,
 };
 
 #[derive(Debug, Clone)]
+#[allow(clippy::option_option)]
 pub struct Update {
     pub project: ResourceId,
     pub measure: ResourceId,
     pub name: Option<ResourceName>,
     pub slug: Option<Slug>,
     pub units: Option<ResourceName>,
+    pub unit: Option<CliMeasureUnit>,
+    pub expression: Option<Option<MeasureExpression>>,
     pub backend: AuthBackend,
 }
 
@@ -27,6 +30,8 @@ impl TryFrom<CliMeasureUpdate> for Update {
             name,
             slug,
             units,
+            unit,
+            expression,
             backend,
         } = create;
         Ok(Self {
@@ -35,6 +40,8 @@ impl TryFrom<CliMeasureUpdate> for Update {
             name,
             slug,
             units,
+            unit,
+            expression,
             backend: backend.try_into()?,
         })
     }
@@ -43,12 +50,44 @@ impl TryFrom<CliMeasureUpdate> for Update {
 impl From<Update> for JsonUpdateMeasure {
     fn from(update: Update) -> Self {
         let Update {
-            name, slug, units, ..
+            name,
+            slug,
+            units,
+            unit,
+            expression,
+            ..
         } = update;
-        Self {
-            name: name.map(Into::into),
-            slug: slug.map(Into::into),
-            units: units.map(Into::into),
+        match expression {
+            Some(Some(expression)) => Self {
+                subtype_0: Some(JsonMeasurePatch {
+                    name: name.map(Into::into),
+                    slug: slug.map(Into::into),
+                    units: units.map(Into::into),
+                    unit: unit.map(Into::into),
+                    expression: Some(expression.into()),
+                }),
+                subtype_1: None,
+            },
+            Some(None) => Self {
+                subtype_0: None,
+                subtype_1: Some(JsonMeasurePatchNull {
+                    name: name.map(Into::into),
+                    slug: slug.map(Into::into),
+                    units: units.map(Into::into),
+                    unit: unit.map(Into::into),
+                    expression: (),
+                }),
+            },
+            None => Self {
+                subtype_0: Some(JsonMeasurePatch {
+                    name: name.map(Into::into),
+                    slug: slug.map(Into::into),
+                    units: units.map(Into::into),
+                    unit: unit.map(Into::into),
+                    expression: None,
+                }),
+                subtype_1: None,
+            },
         }
     }
 }
@@ -1,11 +1,16 @@
 pub mod alert;
 pub mod benchmark;
 pub mod branch;
+pub mod compare;
+pub mod import;
 pub mod measure;
 pub mod perf;
 #[allow(clippy::module_inception)]
 pub mod project;
 pub mod report;
+pub mod retention;
 pub mod run;
+pub mod silence;
 pub mod testbed;
 pub mod threshold;
+pub mod webhook;
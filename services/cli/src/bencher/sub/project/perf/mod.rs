@@ -2,12 +2,22 @@ use std::future::Future;
 use std::pin::Pin;
 
 use bencher_json::{
-    BenchmarkUuid, BranchUuid, DateTime, JsonPerf, JsonPerfQuery, MeasureUuid, ResourceId,
-    TestbedUuid,
+    project::{
+        branch::VersionNumber,
+        perf::{PerfAggregate, PerfImgFormat, PerfSmoothing},
+    },
+    BenchmarkGroup, BenchmarkUuid, BranchUuid, DateTime, GitHash, JsonPerf, JsonPerfChangePoints,
+    JsonPerfQuery, MeasureUuid, ResourceId, SampleSize, Slug, TestbedUuid,
 };
+use camino::{Utf8Path, Utf8PathBuf};
 use tabled::Table;
 
-use crate::{bencher::backend::PubBackend, cli_println, parser::project::perf::CliPerf, CliError};
+use crate::{
+    bencher::backend::PubBackend,
+    cli_println,
+    parser::project::perf::{CliPerf, CliPerfAggregate, CliPerfSmoothing},
+    CliError,
+};
 
 use crate::bencher::SubCmd;
 
@@ -16,19 +26,53 @@ mod table_style;
 use table_style::TableStyle;
 
 #[derive(Debug, Clone)]
-#[allow(clippy::option_option)]
+#[allow(clippy::option_option, clippy::struct_excessive_bools)]
 pub struct Perf {
     project: ResourceId,
     branches: Vec<BranchUuid>,
     testbeds: Vec<TestbedUuid>,
     benchmarks: Vec<BenchmarkUuid>,
+    group: Option<BenchmarkGroup>,
+    tag: Option<Slug>,
     measures: Vec<MeasureUuid>,
     start_time: Option<DateTime>,
     end_time: Option<DateTime>,
+    start_hash: Option<GitHash>,
+    end_hash: Option<GitHash>,
+    start_version: Option<VersionNumber>,
+    end_version: Option<VersionNumber>,
+    archived: bool,
+    aggregate: Option<PerfAggregate>,
+    smoothing: Option<PerfSmoothing>,
+    smoothing_window: Option<SampleSize>,
+    change_points: bool,
     table: Option<Option<TableStyle>>,
+    img: Option<Utf8PathBuf>,
+    img_boundaries: bool,
+    img_alerts: bool,
+    img_versions: bool,
     backend: PubBackend,
 }
 
+#[derive(Debug, Clone, Copy)]
+struct ImgOptions {
+    format: PerfImgFormat,
+    boundaries: bool,
+    alerts: bool,
+    versions: bool,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum PerfError {
+    #[error("Unsupported perf image file extension ({0}). Use `jpeg`, `jpg`, `svg`, or `html`.")]
+    ImgExtension(Utf8PathBuf),
+    #[error("Failed to save perf image ({path}): {err}")]
+    SaveImg {
+        path: Utf8PathBuf,
+        err: std::io::Error,
+    },
+}
+
 impl TryFrom<CliPerf> for Perf {
     type Error = CliError;
 
@@ -38,22 +82,52 @@ impl TryFrom<CliPerf> for Perf {
             branches,
             testbeds,
             benchmarks,
+            group,
+            tag,
             measures,
             start_time,
             end_time,
+            start_hash,
+            end_hash,
+            start_version,
+            end_version,
+            archived,
+            aggregate,
+            smoothing,
+            smoothing_window,
+            change_points,
             table,
+            img,
+            img_boundaries,
+            img_alerts,
+            img_versions,
             backend,
         } = perf;
-        let backend = PubBackend::try_from(backend)?.log(table.is_none());
+        let backend = PubBackend::try_from(backend)?.log(table.is_none() && img.is_none());
         Ok(Self {
             project,
             branches,
             testbeds,
             benchmarks,
+            group,
+            tag,
             measures,
             start_time,
             end_time,
+            start_hash,
+            end_hash,
+            start_version: start_version.map(VersionNumber),
+            end_version: end_version.map(VersionNumber),
+            archived,
+            aggregate: aggregate.map(Into::into),
+            smoothing: smoothing.map(Into::into),
+            smoothing_window,
+            change_points,
             table: table.map(|t| t.map(Into::into)),
+            img,
+            img_boundaries,
+            img_alerts,
+            img_versions,
             backend,
         })
     }
@@ -68,6 +142,16 @@ impl From<Perf> for JsonPerfQuery {
             measures,
             start_time,
             end_time,
+            start_hash,
+            end_hash,
+            start_version,
+            end_version,
+            archived,
+            aggregate,
+            smoothing,
+            smoothing_window,
+            group,
+            tag,
             ..
         } = perf;
         Self {
@@ -77,12 +161,85 @@ impl From<Perf> for JsonPerfQuery {
             measures,
             start_time,
             end_time,
+            start_hash,
+            end_hash,
+            start_version,
+            end_version,
+            archived: archived.then_some(true),
+            aggregate,
+            smoothing,
+            smoothing_window,
+            group,
+            tag,
+        }
+    }
+}
+
+impl From<CliPerfAggregate> for PerfAggregate {
+    fn from(aggregate: CliPerfAggregate) -> Self {
+        match aggregate {
+            CliPerfAggregate::None => Self::None,
+            CliPerfAggregate::Hour => Self::Hour,
+            CliPerfAggregate::Day => Self::Day,
+            CliPerfAggregate::Week => Self::Week,
+            CliPerfAggregate::Version => Self::Version,
         }
     }
 }
 
+impl From<CliPerfSmoothing> for PerfSmoothing {
+    fn from(smoothing: CliPerfSmoothing) -> Self {
+        match smoothing {
+            CliPerfSmoothing::None => Self::None,
+            CliPerfSmoothing::Mean => Self::Mean,
+            CliPerfSmoothing::Median => Self::Median,
+        }
+    }
+}
+
+fn into_client_aggregate(aggregate: PerfAggregate) -> bencher_client::types::PerfAggregate {
+    match aggregate {
+        PerfAggregate::None => bencher_client::types::PerfAggregate::None,
+        PerfAggregate::Hour => bencher_client::types::PerfAggregate::Hour,
+        PerfAggregate::Day => bencher_client::types::PerfAggregate::Day,
+        PerfAggregate::Week => bencher_client::types::PerfAggregate::Week,
+        PerfAggregate::Version => bencher_client::types::PerfAggregate::Version,
+    }
+}
+
+fn img_format(path: &Utf8Path) -> Result<PerfImgFormat, PerfError> {
+    match path.extension().map(str::to_lowercase).as_deref() {
+        Some("jpeg" | "jpg") => Ok(PerfImgFormat::Jpeg),
+        Some("svg") => Ok(PerfImgFormat::Svg),
+        Some("html" | "htm") => Ok(PerfImgFormat::Html),
+        _ => Err(PerfError::ImgExtension(path.to_owned())),
+    }
+}
+
+fn into_client_img_format(format: PerfImgFormat) -> bencher_client::types::PerfImgFormat {
+    match format {
+        PerfImgFormat::Jpeg => bencher_client::types::PerfImgFormat::Jpeg,
+        PerfImgFormat::Svg => bencher_client::types::PerfImgFormat::Svg,
+        PerfImgFormat::Html => bencher_client::types::PerfImgFormat::Html,
+    }
+}
+
+fn into_client_smoothing(smoothing: PerfSmoothing) -> bencher_client::types::PerfSmoothing {
+    match smoothing {
+        PerfSmoothing::None => bencher_client::types::PerfSmoothing::None,
+        PerfSmoothing::Mean => bencher_client::types::PerfSmoothing::Mean,
+        PerfSmoothing::Median => bencher_client::types::PerfSmoothing::Median,
+    }
+}
+
 impl SubCmd for Perf {
     async fn exec(&self) -> Result<(), CliError> {
+        if self.change_points {
+            return self.exec_change_points().await;
+        }
+        if let Some(img) = &self.img {
+            return self.exec_img(img).await;
+        }
         let sender = perf_sender(self.project.clone(), self.clone());
         if let Some(table_style) = self.table {
             let json_perf: JsonPerf = self.backend.send_with(sender).await?;
@@ -98,6 +255,40 @@ impl SubCmd for Perf {
     }
 }
 
+impl Perf {
+    async fn exec_img(&self, path: &Utf8Path) -> Result<(), CliError> {
+        let options = ImgOptions {
+            format: img_format(path)?,
+            boundaries: self.img_boundaries,
+            alerts: self.img_alerts,
+            versions: self.img_versions,
+        };
+        let sender = img_sender(self.project.clone(), options, self.clone());
+        let img = self.backend.send_bytes(sender).await?;
+        std::fs::write(path, img).map_err(|err| PerfError::SaveImg {
+            path: path.to_owned(),
+            err,
+        })?;
+        cli_println!("Saved perf image to: {path}");
+        Ok(())
+    }
+
+    async fn exec_change_points(&self) -> Result<(), CliError> {
+        let sender = change_points_sender(self.project.clone(), self.clone());
+        if let Some(table_style) = self.table {
+            let json_change_points: JsonPerfChangePoints = self.backend.send_with(sender).await?;
+            let mut change_points_table: Table = json_change_points.into();
+            if let Some(table_style) = table_style {
+                table_style.stylize(&mut change_points_table);
+            }
+            cli_println!("{change_points_table}");
+        } else {
+            self.backend.send(sender).await?;
+        }
+        Ok(())
+    }
+}
+
 type PerfQueryResult = Pin<
     Box<
         dyn Future<
@@ -131,6 +322,186 @@ fn perf_sender(
             if let Some(end_time) = json_perf_query.end_time() {
                 client = client.end_time(end_time);
             }
+            if let Some(start_hash) = json_perf_query.start_hash.clone() {
+                client = client.start_hash(start_hash);
+            }
+            if let Some(end_hash) = json_perf_query.end_hash.clone() {
+                client = client.end_hash(end_hash);
+            }
+            if let Some(start_version) = json_perf_query.start_version {
+                client = client.start_version(start_version.0);
+            }
+            if let Some(end_version) = json_perf_query.end_version {
+                client = client.end_version(end_version.0);
+            }
+            if let Some(archived) = json_perf_query.archived {
+                client = client.archived(archived);
+            }
+            if let Some(aggregate) = json_perf_query.aggregate {
+                client = client.aggregate(into_client_aggregate(aggregate));
+            }
+            if let Some(smoothing) = json_perf_query.smoothing {
+                client = client.smoothing(into_client_smoothing(smoothing));
+            }
+            if let Some(smoothing_window) = json_perf_query.smoothing_window {
+                client = client.smoothing_window(smoothing_window);
+            }
+            if let Some(group) = json_perf_query.group.clone() {
+                client = client.group(group);
+            }
+            if let Some(tag) = json_perf_query.tag.clone() {
+                client = client.tag(tag);
+            }
+
+            client.send().await
+        })
+    })
+}
+
+type PerfChangePointsQueryResult = Pin<
+    Box<
+        dyn Future<
+                Output = Result<
+                    progenitor_client::ResponseValue<bencher_client::types::JsonPerfChangePoints>,
+                    bencher_client::Error<bencher_client::types::Error>,
+                >,
+            > + Send,
+    >,
+>;
+fn change_points_sender(
+    project: ResourceId,
+    json_perf_query: impl Into<JsonPerfQuery>,
+) -> Box<dyn Fn(bencher_client::Client) -> PerfChangePointsQueryResult + Send> {
+    let json_perf_query: JsonPerfQuery = json_perf_query.into();
+    Box::new(move |client: bencher_client::Client| {
+        let project = project.clone();
+        let json_perf_query = json_perf_query.clone();
+        Box::pin(async move {
+            let mut client = client
+                .proj_perf_change_points_get()
+                .project(project.clone())
+                .branches(json_perf_query.branches())
+                .testbeds(json_perf_query.testbeds())
+                .benchmarks(json_perf_query.benchmarks())
+                .measures(json_perf_query.measures());
+
+            if let Some(start_time) = json_perf_query.start_time() {
+                client = client.start_time(start_time);
+            }
+            if let Some(end_time) = json_perf_query.end_time() {
+                client = client.end_time(end_time);
+            }
+            if let Some(start_hash) = json_perf_query.start_hash.clone() {
+                client = client.start_hash(start_hash);
+            }
+            if let Some(end_hash) = json_perf_query.end_hash.clone() {
+                client = client.end_hash(end_hash);
+            }
+            if let Some(start_version) = json_perf_query.start_version {
+                client = client.start_version(start_version.0);
+            }
+            if let Some(end_version) = json_perf_query.end_version {
+                client = client.end_version(end_version.0);
+            }
+            if let Some(archived) = json_perf_query.archived {
+                client = client.archived(archived);
+            }
+            if let Some(aggregate) = json_perf_query.aggregate {
+                client = client.aggregate(into_client_aggregate(aggregate));
+            }
+            if let Some(smoothing) = json_perf_query.smoothing {
+                client = client.smoothing(into_client_smoothing(smoothing));
+            }
+            if let Some(smoothing_window) = json_perf_query.smoothing_window {
+                client = client.smoothing_window(smoothing_window);
+            }
+            if let Some(group) = json_perf_query.group.clone() {
+                client = client.group(group);
+            }
+            if let Some(tag) = json_perf_query.tag.clone() {
+                client = client.tag(tag);
+            }
+
+            client.send().await
+        })
+    })
+}
+
+type PerfImgResult = Pin<
+    Box<
+        dyn Future<
+                Output = Result<
+                    progenitor_client::ResponseValue<progenitor_client::ByteStream>,
+                    bencher_client::Error<progenitor_client::ByteStream>,
+                >,
+            > + Send,
+    >,
+>;
+fn img_sender(
+    project: ResourceId,
+    options: ImgOptions,
+    json_perf_query: impl Into<JsonPerfQuery>,
+) -> Box<dyn Fn(bencher_client::Client) -> PerfImgResult + Send> {
+    let json_perf_query: JsonPerfQuery = json_perf_query.into();
+    Box::new(move |client: bencher_client::Client| {
+        let project = project.clone();
+        let json_perf_query = json_perf_query.clone();
+        Box::pin(async move {
+            let mut client = client
+                .proj_perf_img_get()
+                .project(project.clone())
+                .format(into_client_img_format(options.format))
+                .branches(json_perf_query.branches())
+                .testbeds(json_perf_query.testbeds())
+                .benchmarks(json_perf_query.benchmarks())
+                .measures(json_perf_query.measures());
+
+            if options.boundaries {
+                client = client.boundaries(true);
+            }
+            if options.alerts {
+                client = client.alerts(true);
+            }
+            if options.versions {
+                client = client.versions(true);
+            }
+
+            if let Some(start_time) = json_perf_query.start_time() {
+                client = client.start_time(start_time);
+            }
+            if let Some(end_time) = json_perf_query.end_time() {
+                client = client.end_time(end_time);
+            }
+            if let Some(start_hash) = json_perf_query.start_hash.clone() {
+                client = client.start_hash(start_hash);
+            }
+            if let Some(end_hash) = json_perf_query.end_hash.clone() {
+                client = client.end_hash(end_hash);
+            }
+            if let Some(start_version) = json_perf_query.start_version {
+                client = client.start_version(start_version.0);
+            }
+            if let Some(end_version) = json_perf_query.end_version {
+                client = client.end_version(end_version.0);
+            }
+            if let Some(archived) = json_perf_query.archived {
+                client = client.archived(archived);
+            }
+            if let Some(aggregate) = json_perf_query.aggregate {
+                client = client.aggregate(into_client_aggregate(aggregate));
+            }
+            if let Some(smoothing) = json_perf_query.smoothing {
+                client = client.smoothing(into_client_smoothing(smoothing));
+            }
+            if let Some(smoothing_window) = json_perf_query.smoothing_window {
+                client = client.smoothing_window(smoothing_window);
+            }
+            if let Some(group) = json_perf_query.group.clone() {
+                client = client.group(group);
+            }
+            if let Some(tag) = json_perf_query.tag.clone() {
+                client = client.tag(tag);
+            }
 
             client.send().await
         })
@@ -0,0 +1,63 @@
+use bencher_json::ResourceId;
+use camino::Utf8PathBuf;
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    cli_println,
+    parser::project::CliProjectExport,
+    CliError,
+};
+
+use super::ArchiveError;
+
+#[derive(Debug)]
+pub struct Export {
+    pub project: ResourceId,
+    pub output: Option<Utf8PathBuf>,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliProjectExport> for Export {
+    type Error = CliError;
+
+    fn try_from(export: CliProjectExport) -> Result<Self, Self::Error> {
+        let CliProjectExport {
+            project,
+            output,
+            backend,
+        } = export;
+        // Only log the project archive to stdout if it is not being saved to a file
+        let log = output.is_none();
+        Ok(Self {
+            project,
+            output,
+            backend: AuthBackend::try_from(backend)?.log(log),
+        })
+    }
+}
+
+impl SubCmd for Export {
+    async fn exec(&self) -> Result<(), CliError> {
+        let json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_export_get()
+                    .project(self.project.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+
+        if let Some(path) = &self.output {
+            let archive = serde_json::to_string(&json).map_err(ArchiveError::Serialize)?;
+            std::fs::write(path, archive).map_err(|err| ArchiveError::Write {
+                path: path.clone(),
+                err,
+            })?;
+            cli_println!("Saved project archive to: {path}");
+        }
+
+        Ok(())
+    }
+}
@@ -1,9 +1,13 @@
+use camino::Utf8PathBuf;
+
 use crate::{bencher::sub::SubCmd, parser::project::CliProject, CliError};
 
 mod allowed;
 mod create;
 mod delete;
+mod export;
 mod list;
+mod restore;
 mod update;
 mod view;
 
@@ -15,6 +19,29 @@ pub enum Project {
     Update(update::Update),
     Delete(delete::Delete),
     Allowed(allowed::Allowed),
+    Export(export::Export),
+    Restore(restore::Restore),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ArchiveError {
+    #[error("Failed to serialize project archive: {0}")]
+    Serialize(serde_json::Error),
+    #[error("Failed to write project archive ({path}): {err}")]
+    Write {
+        path: Utf8PathBuf,
+        err: std::io::Error,
+    },
+    #[error("Failed to read project archive ({path}): {err}")]
+    Read {
+        path: Utf8PathBuf,
+        err: std::io::Error,
+    },
+    #[error("Failed to parse project archive ({path}): {err}")]
+    Parse {
+        path: Utf8PathBuf,
+        err: serde_json::Error,
+    },
 }
 
 impl TryFrom<CliProject> for Project {
@@ -28,6 +55,8 @@ impl TryFrom<CliProject> for Project {
             CliProject::Update(update) => Self::Update(update.try_into()?),
             CliProject::Delete(delete) => Self::Delete(delete.try_into()?),
             CliProject::Allowed(allowed) => Self::Allowed(allowed.try_into()?),
+            CliProject::Export(export) => Self::Export(export.try_into()?),
+            CliProject::Restore(restore) => Self::Restore(restore.try_into()?),
         })
     }
 }
@@ -41,6 +70,8 @@ impl SubCmd for Project {
             Self::Update(update) => update.exec().await,
             Self::Delete(delete) => delete.exec().await,
             Self::Allowed(allowed) => allowed.exec().await,
+            Self::Export(export) => export.exec().await,
+            Self::Restore(restore) => restore.exec().await,
         }
     }
 }
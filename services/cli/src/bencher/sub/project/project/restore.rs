@@ -0,0 +1,77 @@
+use bencher_client::types::JsonProjectArchive;
+use bencher_json::{ResourceId, ResourceName, Slug};
+use camino::Utf8PathBuf;
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::CliProjectRestore,
+    CliError,
+};
+
+use super::ArchiveError;
+
+#[derive(Debug)]
+pub struct Restore {
+    pub organization: ResourceId,
+    pub path: Utf8PathBuf,
+    pub name: Option<ResourceName>,
+    pub slug: Option<Slug>,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliProjectRestore> for Restore {
+    type Error = CliError;
+
+    fn try_from(restore: CliProjectRestore) -> Result<Self, Self::Error> {
+        let CliProjectRestore {
+            organization,
+            path,
+            name,
+            slug,
+            backend,
+        } = restore;
+        Ok(Self {
+            organization,
+            path,
+            name,
+            slug,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for Restore {
+    async fn exec(&self) -> Result<(), CliError> {
+        let archive = std::fs::read_to_string(&self.path).map_err(|err| ArchiveError::Read {
+            path: self.path.clone(),
+            err,
+        })?;
+        let mut archive: JsonProjectArchive =
+            serde_json::from_str(&archive).map_err(|err| ArchiveError::Parse {
+                path: self.path.clone(),
+                err,
+            })?;
+        if let Some(name) = &self.name {
+            archive.project.name = name.clone().into();
+        }
+        if let Some(slug) = &self.slug {
+            archive.project.slug = slug.clone().into();
+        }
+
+        let _json = self
+            .backend
+            .send(|client| {
+                let archive = archive.clone();
+                async move {
+                    client
+                        .org_project_restore_post()
+                        .organization(self.organization.clone())
+                        .body(archive)
+                        .send()
+                        .await
+                }
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use bencher_json::ResourceId;
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::retention::CliRetentionDelete,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct Delete {
+    pub project: ResourceId,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliRetentionDelete> for Delete {
+    type Error = CliError;
+
+    fn try_from(delete: CliRetentionDelete) -> Result<Self, Self::Error> {
+        let CliRetentionDelete { project, backend } = delete;
+        Ok(Self {
+            project,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for Delete {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_retention_delete()
+                    .project(self.project.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
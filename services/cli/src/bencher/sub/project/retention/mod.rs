@@ -0,0 +1,38 @@
+use crate::{bencher::sub::SubCmd, parser::project::retention::CliRetention, CliError};
+
+mod delete;
+mod prune;
+mod set;
+mod view;
+
+#[derive(Debug)]
+pub enum Retention {
+    View(view::View),
+    Set(set::Set),
+    Delete(delete::Delete),
+    Prune(prune::Prune),
+}
+
+impl TryFrom<CliRetention> for Retention {
+    type Error = CliError;
+
+    fn try_from(retention: CliRetention) -> Result<Self, Self::Error> {
+        Ok(match retention {
+            CliRetention::View(view) => Self::View(view.try_into()?),
+            CliRetention::Set(set) => Self::Set(set.try_into()?),
+            CliRetention::Delete(delete) => Self::Delete(delete.try_into()?),
+            CliRetention::Prune(prune) => Self::Prune(prune.try_into()?),
+        })
+    }
+}
+
+impl SubCmd for Retention {
+    async fn exec(&self) -> Result<(), CliError> {
+        match self {
+            Self::View(view) => view.exec().await,
+            Self::Set(set) => set.exec().await,
+            Self::Delete(delete) => delete.exec().await,
+            Self::Prune(prune) => prune.exec().await,
+        }
+    }
+}
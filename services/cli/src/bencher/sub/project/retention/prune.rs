@@ -0,0 +1,58 @@
+use bencher_json::ResourceId;
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::retention::CliRetentionPrune,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct Prune {
+    pub project: ResourceId,
+    pub dry_run: bool,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliRetentionPrune> for Prune {
+    type Error = CliError;
+
+    fn try_from(prune: CliRetentionPrune) -> Result<Self, Self::Error> {
+        let CliRetentionPrune {
+            project,
+            dry_run,
+            backend,
+        } = prune;
+        Ok(Self {
+            project,
+            dry_run,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for Prune {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = if self.dry_run {
+            self.backend
+                .send(|client| async move {
+                    client
+                        .proj_retention_prune_get()
+                        .project(self.project.clone())
+                        .send()
+                        .await
+                })
+                .await?
+        } else {
+            self.backend
+                .send(|client| async move {
+                    client
+                        .proj_retention_prune_post()
+                        .project(self.project.clone())
+                        .send()
+                        .await
+                })
+                .await?
+        };
+        Ok(())
+    }
+}
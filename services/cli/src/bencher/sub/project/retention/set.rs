@@ -0,0 +1,71 @@
+use bencher_client::types::JsonNewRetention;
+use bencher_json::{NameId, ResourceId, Window};
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::retention::CliRetentionSet,
+    CliError,
+};
+
+#[derive(Debug, Clone)]
+pub struct Set {
+    pub project: ResourceId,
+    pub raw_window: Window,
+    pub downsample: bool,
+    pub protected_branch: Option<NameId>,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliRetentionSet> for Set {
+    type Error = CliError;
+
+    fn try_from(set: CliRetentionSet) -> Result<Self, Self::Error> {
+        let CliRetentionSet {
+            project,
+            raw_window,
+            no_downsample,
+            protected_branch,
+            backend,
+        } = set;
+        Ok(Self {
+            project,
+            raw_window,
+            downsample: !no_downsample,
+            protected_branch,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Set> for JsonNewRetention {
+    fn from(set: Set) -> Self {
+        let Set {
+            raw_window,
+            downsample,
+            protected_branch,
+            ..
+        } = set;
+        Self {
+            raw_window: raw_window.into(),
+            downsample: Some(downsample),
+            protected_branch: protected_branch.map(Into::into),
+        }
+    }
+}
+
+impl SubCmd for Set {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_retention_put()
+                    .project(self.project.clone())
+                    .body(self.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
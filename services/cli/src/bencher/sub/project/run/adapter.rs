@@ -29,6 +29,8 @@ impl From<CliRunAdapter> for Adapter {
             CliRunAdapter::RustCriterion => Self::RustCriterion,
             CliRunAdapter::RustIai => Self::RustIai,
             CliRunAdapter::RustIaiCallgrind => Self::RustIaiCallgrind,
+            CliRunAdapter::RustDivan => Self::RustDivan,
+            CliRunAdapter::RustCriterionJson => Self::RustCriterionJson,
             CliRunAdapter::Shell => Self::Shell,
             CliRunAdapter::ShellHyperfine => Self::ShellHyperfine,
         }
@@ -537,6 +537,7 @@ async fn update_branch(
         name,
         slug,
         hash: None,
+        archived: None,
     };
     backend
         .send_with(|client| async move {
@@ -601,6 +602,7 @@ async fn reserve_hash(
         name: None,
         slug: None,
         hash: Some(hash.clone().into()),
+        archived: None,
     };
     backend
         .send(|client| async move {
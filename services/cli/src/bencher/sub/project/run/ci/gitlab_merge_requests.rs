@@ -0,0 +1,218 @@
+use bencher_comment::ReportComment;
+use serde::{Deserialize, Serialize};
+
+use crate::cli_println_quietable;
+
+const GITLAB_CI: &str = "GITLAB_CI";
+const CI_API_V4_URL: &str = "CI_API_V4_URL";
+const CI_PROJECT_ID: &str = "CI_PROJECT_ID";
+const CI_MERGE_REQUEST_IID: &str = "CI_MERGE_REQUEST_IID";
+
+const DEFAULT_API_V4_URL: &str = "https://gitlab.com/api/v4";
+// https://docs.gitlab.com/ee/api/rest/#personalprojectgroup-access-tokens
+const PRIVATE_TOKEN: &str = "PRIVATE-TOKEN";
+
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug)]
+pub struct GitLabMergeRequests {
+    pub ci_no_metrics: bool,
+    pub ci_only_thresholds: bool,
+    pub ci_only_on_alert: bool,
+    pub ci_public_links: bool,
+    pub ci_id: Option<String>,
+    pub ci_number: Option<u64>,
+    pub token: String,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum GitLabError {
+    #[error("Failed to get GitLab CI/CD project ID\n{}", docker_env(CI_PROJECT_ID))]
+    NoProjectId,
+    #[error("GitLab CI/CD merge request IID is invalid: {0}")]
+    BadMergeRequestIid(String),
+    #[error("Failed to create GitLab API client: {0}")]
+    Client(reqwest::Error),
+    #[error("Failed to list GitLab MR notes: {0}")]
+    Notes(reqwest::Error),
+    #[error("Failed to create GitLab MR note: {0}")]
+    CreateNote(reqwest::Error),
+    #[error("Failed to update GitLab MR note: {0}")]
+    UpdateNote(reqwest::Error),
+    #[error("GitLab API token does not have `api` scope with at least `Reporter` role for the project.\n{help}\nError: {0}", help = PERMISSIONS_HELP)]
+    BadPermissions(reqwest::Error),
+}
+
+// https://docs.gitlab.com/ee/user/project/settings/project_access_tokens.html
+const PERMISSIONS_HELP: &str = "To fix, create a project access token with the `api` scope and pass it in as a masked CI/CD variable: `--gitlab-merge-requests \"$BENCHER_GITLAB_TOKEN\"`\nSee: https://bencher.dev/docs/how-to/gitlab-ci-cd/#merge-requests";
+
+fn docker_env(env_var: &str) -> String {
+    format!(
+        "If you are running in a Docker container, then you need to pass in the `{env_var}` environment variable. See https://bencher.dev/docs/explanation/bencher-run/#--gitlab-merge-requests",
+    )
+}
+
+#[derive(Debug, Deserialize)]
+struct Note {
+    id: u64,
+    body: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct NoteBody {
+    body: String,
+}
+
+impl GitLabMergeRequests {
+    pub async fn run(&self, report_comment: &ReportComment, log: bool) -> Result<(), GitLabError> {
+        // Only post to CI if there are thresholds set
+        if self.ci_only_thresholds && !report_comment.has_threshold() {
+            cli_println_quietable!(log, "No thresholds set. Skipping CI integration.");
+            return Ok(());
+        }
+
+        if !is_gitlab_ci() {
+            cli_println_quietable!(
+                log,
+                "Not running in GitLab CI/CD. Skipping CI integration.\n{}",
+                docker_env(GITLAB_CI)
+            );
+            return Ok(());
+        }
+
+        // The merge request IID is only set for merge request pipelines.
+        // That is, pipelines with `$CI_PIPELINE_SOURCE == "merge_request_event"`.
+        let merge_request_iid = if let Some(merge_request_iid) = self.ci_number {
+            merge_request_iid
+        } else if let Ok(merge_request_iid) = std::env::var(CI_MERGE_REQUEST_IID) {
+            merge_request_iid
+                .parse()
+                .map_err(|_e| GitLabError::BadMergeRequestIid(merge_request_iid))?
+        } else {
+            cli_println_quietable!(
+                log,
+                "Not running in a GitLab CI/CD merge request pipeline. Skipping CI integration.\n{}",
+                docker_env(CI_MERGE_REQUEST_IID)
+            );
+            return Ok(());
+        };
+        let project_id = std::env::var(CI_PROJECT_ID).map_err(|_e| GitLabError::NoProjectId)?;
+        // Use the API URL for the GitLab instance running the pipeline, so self-hosted GitLab works as well
+        let api_url = std::env::var(CI_API_V4_URL).unwrap_or_else(|_e| DEFAULT_API_V4_URL.into());
+        let notes_url = format!(
+            "{api_url}/projects/{project_id}/merge_requests/{merge_request_iid}/notes",
+            api_url = api_url.trim_end_matches('/'),
+        );
+
+        let client = reqwest::Client::builder()
+            .build()
+            .map_err(GitLabError::Client)?;
+
+        // Get the note ID if it exists
+        let note_id = self
+            .get_note(
+                &client,
+                &notes_url,
+                &report_comment.bencher_tag(self.ci_id.as_deref()),
+            )
+            .await?;
+
+        // Update or create the note
+        let body = NoteBody {
+            body: report_comment.markdown(
+                !self.ci_no_metrics,
+                self.ci_only_thresholds,
+                self.ci_id.as_deref(),
+            ),
+        };
+        // Always update the note if it exists
+        let request = if let Some(note_id) = note_id {
+            client.put(format!("{notes_url}/{note_id}"))
+        } else {
+            if self.ci_only_on_alert && !report_comment.has_alert() {
+                cli_println_quietable!(log, "No alerts found. Skipping CI integration.");
+                return Ok(());
+            }
+            client.post(&notes_url)
+        };
+        let note = request
+            .header(PRIVATE_TOKEN, &self.token)
+            .json(&body)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status);
+        if let Err(e) = note {
+            return Err(
+                if matches!(
+                    e.status(),
+                    Some(reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN)
+                ) {
+                    GitLabError::BadPermissions(e)
+                } else if note_id.is_some() {
+                    GitLabError::UpdateNote(e)
+                } else {
+                    GitLabError::CreateNote(e)
+                },
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn get_note(
+        &self,
+        client: &reqwest::Client,
+        notes_url: &str,
+        bencher_tag: &str,
+    ) -> Result<Option<u64>, GitLabError> {
+        const PER_PAGE: u8 = 100;
+
+        let mut page: u32 = 1;
+        loop {
+            let notes = client
+                .get(notes_url)
+                .header(PRIVATE_TOKEN, &self.token)
+                .query(&[("per_page", u32::from(PER_PAGE)), ("page", page)])
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status)
+                .map_err(|e| {
+                    if matches!(
+                        e.status(),
+                        Some(reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN)
+                    ) {
+                        GitLabError::BadPermissions(e)
+                    } else {
+                        GitLabError::Notes(e)
+                    }
+                })?
+                .json::<Vec<Note>>()
+                .await
+                .map_err(GitLabError::Notes)?;
+
+            let notes_len = notes.len();
+            if notes_len == 0 {
+                return Ok(None);
+            }
+
+            for note in notes {
+                if let Some(body) = note.body {
+                    if body.ends_with(bencher_tag) {
+                        return Ok(Some(note.id));
+                    }
+                }
+            }
+
+            if notes_len < usize::from(PER_PAGE) {
+                return Ok(None);
+            }
+
+            page += 1;
+        }
+    }
+}
+
+// https://docs.gitlab.com/ee/ci/variables/predefined_variables.html
+// Available for all jobs executed in CI/CD. `true` when available.
+fn is_gitlab_ci() -> bool {
+    std::env::var(GITLAB_CI).ok().as_deref() == Some("true")
+}
@@ -3,18 +3,23 @@ use bencher_comment::ReportComment;
 use crate::parser::project::run::CliRunCi;
 
 mod github_actions;
+mod gitlab_merge_requests;
 
 use github_actions::{GitHubActions, GitHubError};
+use gitlab_merge_requests::{GitLabError, GitLabMergeRequests};
 
 #[derive(Debug)]
 pub enum Ci {
     GitHubActions(GitHubActions),
+    GitLabMergeRequests(GitLabMergeRequests),
 }
 
 #[derive(thiserror::Error, Debug)]
 pub enum CiError {
     #[error("{0}")]
     GitHub(#[from] GitHubError),
+    #[error("{0}")]
+    GitLab(#[from] GitLabError),
 }
 
 impl TryFrom<CliRunCi> for Option<Ci> {
@@ -30,9 +35,11 @@ impl TryFrom<CliRunCi> for Option<Ci> {
             ci_number,
             ci_i_am_vulnerable_to_pwn_requests,
             github_actions,
+            gitlab_merge_requests,
         } = ci;
-        Ok(github_actions.map(|token| {
-            Ci::GitHubActions(GitHubActions {
+        // The CI options are mutually exclusive, so at most one of these will be set
+        Ok(if let Some(token) = github_actions {
+            Some(Ci::GitHubActions(GitHubActions {
                 ci_no_metrics,
                 ci_only_thresholds,
                 ci_only_on_alert,
@@ -41,8 +48,20 @@ impl TryFrom<CliRunCi> for Option<Ci> {
                 ci_number,
                 ci_i_am_vulnerable_to_pwn_requests,
                 token,
+            }))
+        } else {
+            gitlab_merge_requests.map(|token| {
+                Ci::GitLabMergeRequests(GitLabMergeRequests {
+                    ci_no_metrics,
+                    ci_only_thresholds,
+                    ci_only_on_alert,
+                    ci_public_links,
+                    ci_id,
+                    ci_number,
+                    token,
+                })
             })
-        }))
+        })
     }
 }
 
@@ -52,6 +71,9 @@ impl Ci {
             Self::GitHubActions(github_actions) => {
                 github_actions.safety_check(log).map_err(Into::into)
             },
+            // GitLab merge request pipelines for forks run in the context of the fork by default,
+            // so there are no pwn requests to guard against.
+            Self::GitLabMergeRequests(_) => Ok(()),
         }
     }
 
@@ -61,6 +83,10 @@ impl Ci {
                 .run(report_comment, log)
                 .await
                 .map_err(Into::into),
+            Self::GitLabMergeRequests(gitlab_merge_requests) => gitlab_merge_requests
+                .run(report_comment, log)
+                .await
+                .map_err(Into::into),
         }
     }
 }
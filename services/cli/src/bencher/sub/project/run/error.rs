@@ -57,6 +57,37 @@ pub enum RunError {
     },
     #[error("Failed to read from output file: {0}")]
     OutputFileRead(std::io::Error),
+    #[error("Failed to parse JSON output file ({path}): {err}")]
+    ParseOutputFile {
+        path: camino::Utf8PathBuf,
+        err: serde_json::Error,
+    },
+    #[error("Invalid output file glob pattern ({pattern}): {err}")]
+    BadGlob {
+        pattern: String,
+        err: glob::PatternError,
+    },
+    #[error("Failed to read output file glob path: {0}")]
+    GlobPath(glob::GlobError),
+    #[error("Output file glob path is not valid UTF-8: {0}")]
+    GlobUtf8Path(camino::FromPathBufError),
+    #[error("No output files match glob pattern: {0}")]
+    NoGlobMatch(String),
+    #[error("Failed to convert report settings: {0}")]
+    ConvertSettings(serde_json::Error),
+    #[error("Failed to convert results from output file ({path}) with adapter ({adapter})")]
+    ConvertResultFile {
+        path: camino::Utf8PathBuf,
+        adapter: bencher_json::project::report::Adapter,
+    },
+    #[error("Duplicate results for benchmark ({benchmark_name}) and measure ({measure}) in output file ({path})")]
+    DuplicateResult {
+        path: camino::Utf8PathBuf,
+        benchmark_name: bencher_json::BenchmarkName,
+        measure: bencher_json::MeasureNameId,
+    },
+    #[error("Failed to serialize merged output file results: {0}")]
+    SerializeResults(serde_json::Error),
 
     #[error("Failed to serialize report JSON: {0}")]
     SerializeReport(serde_json::Error),
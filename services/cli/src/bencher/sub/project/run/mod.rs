@@ -25,7 +25,10 @@ mod testbed;
 use branch::Branch;
 use ci::Ci;
 pub use error::RunError;
-use runner::Runner;
+use runner::{
+    file::{merge_result_files, ResultFiles},
+    Runner,
+};
 use testbed::Testbed;
 
 use crate::bencher::SubCmd;
@@ -233,12 +236,22 @@ impl Run {
             .get(&self.project, self.dry_run, self.log, &self.backend)
             .await?;
 
+        // Results from multiple output files are merged into Bencher Metric Format (BMF) JSON
+        let merge_results = self
+            .runner
+            .result_files()
+            .is_some_and(ResultFiles::is_merged);
+
         let start_time = DateTime::now();
         let mut results = Vec::with_capacity(self.iter);
         for _ in 0..self.iter {
             let output = self.runner.run(self.log).await?;
             if output.is_success() {
-                results.push(output.result());
+                results.push(if merge_results {
+                    merge_result_files(output.result_files, self.adapter, self.average)?
+                } else {
+                    output.result()
+                });
             } else if self.allow_failure {
                 cli_eprintln_quietable!(self.log, "Skipping failure:\n{}", output);
             } else {
@@ -271,7 +284,11 @@ impl Run {
             end_time: end_time.into(),
             results,
             settings: Some(JsonReportSettings {
-                adapter: self.adapter,
+                adapter: if merge_results {
+                    Some(Adapter::Json)
+                } else {
+                    self.adapter
+                },
                 average: self.average,
                 fold: self.fold,
             }),
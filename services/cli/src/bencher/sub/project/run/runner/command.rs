@@ -133,6 +133,7 @@ impl Command {
             stdout,
             stderr,
             result: None,
+            result_files: Vec::new(),
         })
     }
 }
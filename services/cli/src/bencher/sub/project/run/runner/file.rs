@@ -0,0 +1,339 @@
+use std::{collections::HashSet, fmt};
+
+use bencher_adapter::{
+    results::adapter_results::{AdapterResults, ResultsMap},
+    Adaptable, Settings,
+};
+use bencher_client::types::{Adapter, JsonAverage};
+use camino::{Utf8Path, Utf8PathBuf};
+
+use super::RunError;
+
+const CRITERION_NEW_DIR: &str = "new";
+const CRITERION_BENCHMARK_JSON: &str = "benchmark.json";
+const CRITERION_ESTIMATES_JSON: &str = "estimates.json";
+
+#[derive(Debug, Clone)]
+pub struct ResultFiles {
+    paths: Vec<Utf8PathBuf>,
+    globs: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ResultFile {
+    pub path: Utf8PathBuf,
+    pub result: String,
+}
+
+impl ResultFiles {
+    pub fn new(paths: Vec<Utf8PathBuf>, globs: Vec<String>) -> Result<Option<Self>, RunError> {
+        for pattern in &globs {
+            glob::Pattern::new(pattern).map_err(|err| RunError::BadGlob {
+                pattern: pattern.clone(),
+                err,
+            })?;
+        }
+        Ok((!paths.is_empty() || !globs.is_empty()).then_some(Self { paths, globs }))
+    }
+
+    // A single file path is sent as is and parsed by the server.
+    // Otherwise, each file is parsed locally and the results are merged.
+    pub fn is_merged(&self) -> bool {
+        !(self.paths.len() == 1 && self.globs.is_empty())
+    }
+
+    pub fn read(&self) -> Result<Vec<ResultFile>, RunError> {
+        let mut file_paths = self.paths.clone();
+        for pattern in &self.globs {
+            let paths = glob::glob(pattern).map_err(|err| RunError::BadGlob {
+                pattern: pattern.clone(),
+                err,
+            })?;
+            // A glob may match a Criterion output directory as well as a file
+            let glob_paths = paths
+                .map(|path| {
+                    Utf8PathBuf::try_from(path.map_err(RunError::GlobPath)?)
+                        .map_err(RunError::GlobUtf8Path)
+                })
+                .collect::<Result<Vec<_>, RunError>>()?;
+            if glob_paths.is_empty() {
+                return Err(RunError::NoGlobMatch(pattern.clone()));
+            }
+            file_paths.extend(glob_paths);
+        }
+
+        // The same file may be matched more than once
+        let mut unique_paths = HashSet::new();
+        file_paths
+            .into_iter()
+            .filter(|path| unique_paths.insert(path.clone()))
+            .map(|path| {
+                let result = if path.is_dir() {
+                    read_criterion_dir(&path)?
+                } else {
+                    std::fs::read_to_string(&path).map_err(RunError::OutputFileRead)?
+                };
+                Ok(ResultFile { path, result })
+            })
+            .collect()
+    }
+}
+
+// A directory is read as a Criterion output directory (ie `target/criterion`).
+// The `benchmark.json` and `estimates.json` files from each `new` directory
+// are collected into a single JSON array for the `rust_criterion_json` adapter.
+fn read_criterion_dir(dir: &Utf8Path) -> Result<String, RunError> {
+    let mut new_dirs = Vec::new();
+    find_criterion_new_dirs(dir, &mut new_dirs)?;
+    new_dirs.sort();
+    let benchmarks = new_dirs
+        .iter()
+        .map(|new_dir| {
+            Ok(serde_json::json!({
+                "benchmark": read_json(&new_dir.join(CRITERION_BENCHMARK_JSON))?,
+                "estimates": read_json(&new_dir.join(CRITERION_ESTIMATES_JSON))?,
+            }))
+        })
+        .collect::<Result<Vec<_>, RunError>>()?;
+    serde_json::to_string(&benchmarks).map_err(RunError::SerializeResults)
+}
+
+fn find_criterion_new_dirs(
+    dir: &Utf8Path,
+    new_dirs: &mut Vec<Utf8PathBuf>,
+) -> Result<(), RunError> {
+    for entry in dir.read_dir_utf8().map_err(RunError::OutputFileRead)? {
+        let path = entry.map_err(RunError::OutputFileRead)?.into_path();
+        if !path.is_dir() {
+            continue;
+        }
+        if path.file_name() == Some(CRITERION_NEW_DIR)
+            && path.join(CRITERION_BENCHMARK_JSON).is_file()
+            && path.join(CRITERION_ESTIMATES_JSON).is_file()
+        {
+            new_dirs.push(path);
+        } else {
+            find_criterion_new_dirs(&path, new_dirs)?;
+        }
+    }
+    Ok(())
+}
+
+fn read_json(path: &Utf8Path) -> Result<serde_json::Value, RunError> {
+    let json = std::fs::read_to_string(path).map_err(RunError::OutputFileRead)?;
+    serde_json::from_str(&json).map_err(|err| RunError::ParseOutputFile {
+        path: path.to_owned(),
+        err,
+    })
+}
+
+impl fmt::Display for ResultFiles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let files = self
+            .paths
+            .iter()
+            .map(ToString::to_string)
+            .chain(self.globs.iter().cloned())
+            .collect::<Vec<_>>();
+        write!(f, "{}", files.join(" "))
+    }
+}
+
+impl fmt::Display for ResultFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:\n{}", self.path, self.result)
+    }
+}
+
+/// Merge the results from each file into a single Bencher Metric Format (BMF) JSON result.
+pub fn merge_result_files(
+    result_files: Vec<ResultFile>,
+    adapter: Option<Adapter>,
+    average: Option<JsonAverage>,
+) -> Result<String, RunError> {
+    // This is a bit of a kludge, but it should always work!
+    let adapter: bencher_json::project::report::Adapter = adapter
+        .map(|adapter| serde_json::from_value(serde_json::json!(adapter)))
+        .transpose()
+        .map_err(RunError::ConvertSettings)?
+        .unwrap_or_default();
+    let average = average
+        .map(|average| serde_json::from_value(serde_json::json!(average)))
+        .transpose()
+        .map_err(RunError::ConvertSettings)?;
+    let settings = Settings::new(average);
+
+    let mut results_map = ResultsMap::new();
+    for ResultFile { path, result } in result_files {
+        let Some(adapter_results) = adapter.convert(&result, settings) else {
+            return Err(RunError::ConvertResultFile { path, adapter });
+        };
+        for (benchmark_name, metrics) in adapter_results.inner {
+            let merged_metrics = results_map.entry(benchmark_name.clone()).or_default();
+            for (measure, metric) in metrics.inner {
+                if merged_metrics
+                    .inner
+                    .insert(measure.clone(), metric)
+                    .is_some()
+                {
+                    return Err(RunError::DuplicateResult {
+                        path,
+                        benchmark_name,
+                        measure,
+                    });
+                }
+            }
+        }
+    }
+    serde_json::to_string(&AdapterResults::from(results_map)).map_err(RunError::SerializeResults)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use bencher_client::types::Adapter;
+    use camino::Utf8PathBuf;
+
+    use super::{merge_result_files, ResultFile, ResultFiles, RunError};
+
+    const BMF_A: &str = r#"{
+        "bench_a": { "latency": { "value": 1.0 } },
+        "shared": { "latency": { "value": 2.0 } }
+    }"#;
+    const BMF_B: &str = r#"{
+        "shared": { "throughput": { "value": 3.0 } },
+        "bench_b": { "latency": { "value": 4.0 } }
+    }"#;
+    const BMF_DUPLICATE: &str = r#"{
+        "shared": { "latency": { "value": 5.0 } }
+    }"#;
+
+    fn result_file(name: &str, result: &str) -> ResultFile {
+        ResultFile {
+            path: Utf8PathBuf::from(name),
+            result: result.into(),
+        }
+    }
+
+    fn temp_dir(name: &str) -> Utf8PathBuf {
+        let dir = std::env::temp_dir().join(format!("bencher_cli_{name}_{}", std::process::id()));
+        drop(std::fs::remove_dir_all(&dir));
+        std::fs::create_dir_all(&dir).unwrap();
+        Utf8PathBuf::try_from(dir).unwrap()
+    }
+
+    #[test]
+    fn test_merge_result_files_overlapping() {
+        let merged = merge_result_files(
+            vec![result_file("a.json", BMF_A), result_file("b.json", BMF_B)],
+            Some(Adapter::Json),
+            None,
+        )
+        .unwrap();
+        let merged: serde_json::Value = serde_json::from_str(&merged).unwrap();
+
+        let benchmarks = merged.as_object().unwrap();
+        assert_eq!(benchmarks.len(), 3);
+        assert_eq!(merged["bench_a"]["latency"]["value"], 1.0);
+        assert_eq!(merged["bench_b"]["latency"]["value"], 4.0);
+        // The measures for the same benchmark name from both files are combined
+        assert_eq!(merged["shared"].as_object().unwrap().len(), 2);
+        assert_eq!(merged["shared"]["latency"]["value"], 2.0);
+        assert_eq!(merged["shared"]["throughput"]["value"], 3.0);
+    }
+
+    #[test]
+    fn test_merge_result_files_duplicate() {
+        let err = merge_result_files(
+            vec![
+                result_file("a.json", BMF_A),
+                result_file("duplicate.json", BMF_DUPLICATE),
+            ],
+            Some(Adapter::Json),
+            None,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                RunError::DuplicateResult {
+                    path,
+                    benchmark_name,
+                    measure,
+                } if path == "duplicate.json"
+                    && benchmark_name.as_ref() == "shared"
+                    && measure.as_ref() == "latency"
+            ),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_result_files_glob() {
+        let dir = temp_dir("glob");
+        std::fs::write(dir.join("a.json"), BMF_A).unwrap();
+        std::fs::write(dir.join("b.json"), BMF_B).unwrap();
+        std::fs::write(dir.join("c.txt"), "not a result").unwrap();
+
+        // The same file matched by both a path and a glob is only read once
+        let result_files =
+            ResultFiles::new(vec![dir.join("a.json")], vec![format!("{dir}/*.json")])
+                .unwrap()
+                .unwrap();
+        assert!(result_files.is_merged());
+        let mut paths = result_files
+            .read()
+            .unwrap()
+            .into_iter()
+            .map(|file| file.path)
+            .collect::<Vec<_>>();
+        paths.sort();
+        assert_eq!(paths, vec![dir.join("a.json"), dir.join("b.json")]);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_result_files_glob_dir() {
+        let dir = temp_dir("glob_dir");
+        let new_dir = dir.join("criterion").join("fib").join("new");
+        std::fs::create_dir_all(&new_dir).unwrap();
+        std::fs::write(new_dir.join("benchmark.json"), "{}").unwrap();
+        std::fs::write(new_dir.join("estimates.json"), "{}").unwrap();
+
+        let result_files = ResultFiles::new(Vec::new(), vec![format!("{dir}/crit*")])
+            .unwrap()
+            .unwrap();
+        let result_files = result_files.read().unwrap();
+        assert_eq!(result_files.len(), 1);
+        let result_file = result_files.first().unwrap();
+        assert_eq!(result_file.path, dir.join("criterion"));
+        let benchmarks: serde_json::Value = serde_json::from_str(&result_file.result).unwrap();
+        assert_eq!(benchmarks.as_array().unwrap().len(), 1);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_result_files_glob_empty() {
+        let dir = temp_dir("glob_empty");
+        let pattern = format!("{dir}/*.json");
+
+        let result_files = ResultFiles::new(Vec::new(), vec![pattern.clone()])
+            .unwrap()
+            .unwrap();
+        let err = result_files.read().unwrap_err();
+        assert!(
+            matches!(&err, RunError::NoGlobMatch(no_match) if *no_match == pattern),
+            "{err}"
+        );
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_result_files_none() {
+        assert!(ResultFiles::new(Vec::new(), Vec::new()).unwrap().is_none());
+        assert!(ResultFiles::new(Vec::new(), vec!["[".into()]).is_err());
+    }
+}
@@ -1,16 +1,16 @@
 use std::fmt;
 
-use camino::Utf8PathBuf;
-
 use crate::parser::project::run::CliRunCommand;
 
 pub mod command;
+pub mod file;
 mod flag;
 pub mod output;
 mod pipe;
 mod shell;
 
 use command::Command;
+use file::ResultFiles;
 use output::Output;
 use pipe::Pipe;
 
@@ -20,14 +20,15 @@ use super::{RunError, BENCHER_CMD};
 pub enum Runner {
     Pipe(Pipe),
     Command(Command),
-    CommandToFile(Command, Utf8PathBuf),
-    File(Utf8PathBuf),
+    CommandToFile(Command, ResultFiles),
+    File(ResultFiles),
 }
 
 impl TryFrom<CliRunCommand> for Runner {
     type Error = RunError;
 
     fn try_from(cmd: CliRunCommand) -> Result<Self, Self::Error> {
+        let result_files = ResultFiles::new(cmd.file, cmd.glob)?;
         let program_arguments = cmd.command.and_then(|c| {
             let mut c = c.into_iter();
             c.next().map(|program| (program, c.collect::<Vec<_>>()))
@@ -43,19 +44,19 @@ impl TryFrom<CliRunCommand> for Runner {
                 }
                 Command::new_exec(program, arguments)
             };
-            Ok(if let Some(file) = cmd.file {
+            Ok(if let Some(file) = result_files {
                 Self::CommandToFile(command, file)
             } else {
                 Self::Command(command)
             })
         } else if let Ok(command) = std::env::var(BENCHER_CMD) {
             let command = Command::new_shell(cmd.sh_c, command)?;
-            Ok(if let Some(file) = cmd.file {
+            Ok(if let Some(file) = result_files {
                 Self::CommandToFile(command, file)
             } else {
                 Self::Command(command)
             })
-        } else if let Some(file) = cmd.file {
+        } else if let Some(file) = result_files {
             Ok(Self::File(file))
         } else if let Some(pipe) = Pipe::new() {
             Ok(Self::Pipe(pipe))
@@ -70,10 +71,10 @@ impl fmt::Display for Runner {
         match self {
             Self::Pipe(pipe) => write!(f, "{pipe}"),
             Self::Command(command) => write!(f, "{command}"),
-            Self::CommandToFile(command, file_path) => {
-                write!(f, "{command} > {file_path}")
+            Self::CommandToFile(command, result_files) => {
+                write!(f, "{command} > {result_files}")
             },
-            Self::File(file_path) => write!(f, "{file_path}"),
+            Self::File(result_files) => write!(f, "{result_files}"),
         }
     }
 }
@@ -83,21 +84,23 @@ impl Runner {
         Ok(match self {
             Self::Pipe(pipe) => pipe.output(),
             Self::Command(command) => command.run(log).await?,
-            Self::CommandToFile(command, file_path) => {
+            Self::CommandToFile(command, result_files) => {
                 let mut output = command.run(log).await?;
-                let result =
-                    std::fs::read_to_string(file_path).map_err(RunError::OutputFileRead)?;
-                output.result = Some(result);
+                output.read_result_files(result_files)?;
                 output
             },
-            Self::File(file_path) => {
-                let result =
-                    std::fs::read_to_string(file_path).map_err(RunError::OutputFileRead)?;
-                Output {
-                    result: Some(result),
-                    ..Default::default()
-                }
+            Self::File(result_files) => {
+                let mut output = Output::default();
+                output.read_result_files(result_files)?;
+                output
             },
         })
     }
+
+    pub fn result_files(&self) -> Option<&ResultFiles> {
+        match self {
+            Self::Pipe(_) | Self::Command(_) => None,
+            Self::CommandToFile(_, result_files) | Self::File(result_files) => Some(result_files),
+        }
+    }
 }
@@ -1,11 +1,17 @@
 use std::fmt;
 
+use super::{
+    file::{ResultFile, ResultFiles},
+    RunError,
+};
+
 #[derive(Debug, Clone, Default)]
 pub struct Output {
     pub status: ExitStatus,
     pub stdout: String,
     pub stderr: String,
     pub result: Option<String>,
+    pub result_files: Vec<ResultFile>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -28,7 +34,11 @@ impl fmt::Display for Output {
             f,
             "{}\n{}\n{}{result}",
             self.status, self.stdout, self.stderr
-        )
+        )?;
+        for result_file in &self.result_files {
+            write!(f, "\n{result_file}")?;
+        }
+        Ok(())
     }
 }
 
@@ -43,6 +53,19 @@ impl Output {
         self.status.is_success()
     }
 
+    pub fn read_result_files(&mut self, result_files: &ResultFiles) -> Result<(), RunError> {
+        if result_files.is_merged() {
+            self.result_files = result_files.read()?;
+        } else {
+            self.result = result_files
+                .read()?
+                .into_iter()
+                .next()
+                .map(|result_file| result_file.result);
+        }
+        Ok(())
+    }
+
     pub fn result(self) -> String {
         self.result.unwrap_or(self.stdout)
     }
@@ -0,0 +1,81 @@
+use bencher_client::types::JsonNewSilence;
+use bencher_json::{DateTime, ResourceId};
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::silence::CliSilenceCreate,
+    CliError,
+};
+
+#[derive(Debug, Clone)]
+pub struct Create {
+    pub project: ResourceId,
+    pub benchmark: Option<ResourceId>,
+    pub measure: Option<ResourceId>,
+    pub testbed: Option<ResourceId>,
+    pub note: Option<String>,
+    pub expires: Option<DateTime>,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliSilenceCreate> for Create {
+    type Error = CliError;
+
+    fn try_from(create: CliSilenceCreate) -> Result<Self, Self::Error> {
+        let CliSilenceCreate {
+            project,
+            benchmark,
+            measure,
+            testbed,
+            note,
+            expires,
+            backend,
+        } = create;
+        Ok(Self {
+            project,
+            benchmark,
+            measure,
+            testbed,
+            note,
+            expires,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Create> for JsonNewSilence {
+    fn from(create: Create) -> Self {
+        let Create {
+            benchmark,
+            measure,
+            testbed,
+            note,
+            expires,
+            ..
+        } = create;
+        Self {
+            benchmark: benchmark.map(Into::into),
+            measure: measure.map(Into::into),
+            testbed: testbed.map(Into::into),
+            note,
+            expires: expires.map(Into::into),
+        }
+    }
+}
+
+impl SubCmd for Create {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_silence_post()
+                    .project(self.project.clone())
+                    .body(self.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
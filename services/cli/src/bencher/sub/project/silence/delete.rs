@@ -0,0 +1,48 @@
+use bencher_json::{ResourceId, SilenceUuid};
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::silence::CliSilenceDelete,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct Delete {
+    pub project: ResourceId,
+    pub silence: SilenceUuid,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliSilenceDelete> for Delete {
+    type Error = CliError;
+
+    fn try_from(delete: CliSilenceDelete) -> Result<Self, Self::Error> {
+        let CliSilenceDelete {
+            project,
+            silence,
+            backend,
+        } = delete;
+        Ok(Self {
+            project,
+            silence,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for Delete {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_silence_delete()
+                    .project(self.project.clone())
+                    .silence(self.silence)
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{bencher::sub::SubCmd, parser::project::silence::CliSilence, CliError};
+
+mod create;
+mod delete;
+mod list;
+mod view;
+
+#[derive(Debug)]
+pub enum Silence {
+    List(list::List),
+    Create(create::Create),
+    View(view::View),
+    Delete(delete::Delete),
+}
+
+impl TryFrom<CliSilence> for Silence {
+    type Error = CliError;
+
+    fn try_from(silence: CliSilence) -> Result<Self, Self::Error> {
+        Ok(match silence {
+            CliSilence::List(list) => Self::List(list.try_into()?),
+            CliSilence::Create(create) => Self::Create(create.try_into()?),
+            CliSilence::View(view) => Self::View(view.try_into()?),
+            CliSilence::Delete(delete) => Self::Delete(delete.try_into()?),
+        })
+    }
+}
+
+impl SubCmd for Silence {
+    async fn exec(&self) -> Result<(), CliError> {
+        match self {
+            Self::List(list) => list.exec().await,
+            Self::Create(create) => create.exec().await,
+            Self::View(view) => view.exec().await,
+            Self::Delete(delete) => delete.exec().await,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use bencher_json::{ResourceId, SilenceUuid};
+
+use crate::{
+    bencher::{backend::PubBackend, sub::SubCmd},
+    parser::project::silence::CliSilenceView,
+    CliError,
+};
+
+#[derive(Debug)]
+pub struct View {
+    pub project: ResourceId,
+    pub silence: SilenceUuid,
+    pub backend: PubBackend,
+}
+
+impl TryFrom<CliSilenceView> for View {
+    type Error = CliError;
+
+    fn try_from(view: CliSilenceView) -> Result<Self, Self::Error> {
+        let CliSilenceView {
+            project,
+            silence,
+            backend,
+        } = view;
+        Ok(Self {
+            project,
+            silence,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for View {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_silence_get()
+                    .project(self.project.clone())
+                    .silence(self.silence)
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use bencher_client::types::JsonNewThreshold;
-use bencher_json::{NameId, ResourceId};
+use bencher_json::{BenchmarkGroup, NameId, ResourceId};
 
 use super::model::Model;
 use crate::{
@@ -17,6 +17,7 @@ pub struct Create {
     pub branch: NameId,
     pub testbed: NameId,
     pub measure: NameId,
+    pub group: Option<BenchmarkGroup>,
     pub model: Model,
     pub backend: AuthBackend,
 }
@@ -38,6 +39,7 @@ impl TryFrom<CliThresholdCreate> for Create {
             branch,
             testbed,
             measure,
+            group,
             model,
             backend,
         } = create;
@@ -46,6 +48,7 @@ impl TryFrom<CliThresholdCreate> for Create {
             branch,
             testbed,
             measure,
+            group,
             model: model.try_into()?,
             backend: backend.try_into()?,
         })
@@ -71,6 +74,7 @@ impl From<Create> for JsonNewThreshold {
             testbed,
             model,
             measure,
+            group,
             ..
         } = create;
         let Model {
@@ -85,6 +89,7 @@ impl From<Create> for JsonNewThreshold {
             branch: branch.into(),
             testbed: testbed.into(),
             measure: measure.into(),
+            group: group.map(Into::into),
             test,
             min_sample_size,
             max_sample_size,
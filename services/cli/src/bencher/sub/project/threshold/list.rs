@@ -1,5 +1,5 @@
 use bencher_client::types::{JsonDirection, ProjThresholdsSort};
-use bencher_json::{project::threshold::JsonThresholdQuery, NameId, ResourceId};
+use bencher_json::{project::threshold::JsonThresholdQuery, BenchmarkGroup, NameId, ResourceId};
 
 use crate::{
     bencher::{backend::PubBackend, sub::SubCmd},
@@ -16,6 +16,7 @@ pub struct List {
     pub branch: Option<NameId>,
     pub testbed: Option<NameId>,
     pub measure: Option<NameId>,
+    pub group: Option<BenchmarkGroup>,
     pub pagination: Pagination,
     pub backend: PubBackend,
 }
@@ -37,6 +38,7 @@ impl TryFrom<CliThresholdList> for List {
             branch,
             testbed,
             measure,
+            group,
             pagination,
             backend,
         } = list;
@@ -45,6 +47,7 @@ impl TryFrom<CliThresholdList> for List {
             branch,
             testbed,
             measure,
+            group,
             pagination: pagination.into(),
             backend: backend.try_into()?,
         })
@@ -77,12 +80,14 @@ impl From<List> for JsonThresholdQuery {
             branch,
             testbed,
             measure,
+            group,
             ..
         } = list;
         Self {
             branch,
             testbed,
             measure,
+            group,
         }
     }
 }
@@ -104,6 +109,9 @@ impl SubCmd for List {
                 if let Some(measure) = json_threshold_query.measure() {
                     client = client.measure(measure);
                 }
+                if let Some(group) = json_threshold_query.group() {
+                    client = client.group(group);
+                }
 
                 if let Some(sort) = self.pagination.sort {
                     client = client.sort(sort);
@@ -58,6 +58,9 @@ impl From<CliModelTest> for bencher_json::ModelTest {
             CliModelTest::LogNormal => Self::LogNormal,
             CliModelTest::Iqr => Self::Iqr,
             CliModelTest::DeltaIqr => Self::DeltaIqr,
+            CliModelTest::MannWhitneyU => Self::MannWhitneyU,
+            CliModelTest::WelchT => Self::WelchT,
+            CliModelTest::KolmogorovSmirnov => Self::KolmogorovSmirnov,
         }
     }
 }
@@ -72,6 +75,9 @@ impl From<CliModelTest> for ModelTest {
             CliModelTest::LogNormal => Self::LogNormal,
             CliModelTest::Iqr => Self::Iqr,
             CliModelTest::DeltaIqr => Self::DeltaIqr,
+            CliModelTest::MannWhitneyU => Self::MannWhitneyU,
+            CliModelTest::WelchT => Self::WelchT,
+            CliModelTest::KolmogorovSmirnov => Self::KolmogorovSmirnov,
         }
     }
 }
@@ -0,0 +1,75 @@
+use bencher_client::types::{JsonUpdateWebhook, WebhookEvent};
+use bencher_json::{ResourceId, Secret, Url, WebhookUuid};
+
+use crate::{
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::webhook::CliWebhookUpdate,
+    CliError,
+};
+
+#[derive(Debug, Clone)]
+pub struct Update {
+    pub project: ResourceId,
+    pub webhook: WebhookUuid,
+    pub url: Option<Url>,
+    pub secret: Option<Secret>,
+    pub events: Option<Vec<WebhookEvent>>,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliWebhookUpdate> for Update {
+    type Error = CliError;
+
+    fn try_from(update: CliWebhookUpdate) -> Result<Self, Self::Error> {
+        let CliWebhookUpdate {
+            project,
+            webhook,
+            url,
+            secret,
+            event,
+            backend,
+        } = update;
+        Ok(Self {
+            project,
+            webhook,
+            url,
+            secret,
+            events: event.map(|events| events.into_iter().map(Into::into).collect()),
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Update> for JsonUpdateWebhook {
+    fn from(update: Update) -> Self {
+        let Update {
+            url,
+            secret,
+            events,
+            ..
+        } = update;
+        Self {
+            url: url.map(Into::into),
+            secret: secret.map(Into::into),
+            events,
+        }
+    }
+}
+
+impl SubCmd for Update {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_webhook_patch()
+                    .project(self.project.clone())
+                    .webhook(self.webhook)
+                    .body(self.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -5,6 +5,14 @@ pub enum CliError {
     #[error("{0}")]
     Run(#[from] crate::bencher::sub::RunError),
     #[error("{0}")]
+    Import(#[from] crate::bencher::sub::ImportError),
+    #[error("{0}")]
+    Archive(#[from] crate::bencher::sub::ArchiveError),
+    #[error("{0}")]
+    Branch(#[from] crate::bencher::sub::BranchError),
+    #[error("{0}")]
+    Perf(#[from] crate::bencher::sub::PerfError),
+    #[error("{0}")]
     Threshold(#[from] crate::bencher::sub::ThresholdError),
     #[error("{0}")]
     Mock(#[from] crate::bencher::sub::MockError),
@@ -12,9 +12,10 @@ use docker::{CliDown, CliLogs, CliUp};
 use mock::CliMock;
 use organization::{member::CliMember, CliOrganization};
 use project::{
-    alert::CliAlert, benchmark::CliBenchmark, branch::CliBranch, measure::CliMeasure,
-    perf::CliPerf, report::CliReport, run::CliRun, testbed::CliTestbed, threshold::CliThreshold,
-    CliProject,
+    alert::CliAlert, benchmark::CliBenchmark, branch::CliBranch, compare::CliCompare,
+    import::CliImport, measure::CliMeasure, perf::CliPerf, report::CliReport,
+    retention::CliRetention, run::CliRun, silence::CliSilence, testbed::CliTestbed,
+    threshold::CliThreshold, webhook::CliWebhook, CliProject,
 };
 use system::{auth::CliAuth, server::CliServer};
 use user::{token::CliToken, CliUser};
@@ -54,8 +55,12 @@ pub enum CliSub {
     /// Manage reports
     #[clap(subcommand)]
     Report(CliReport),
+    /// Import historical reports
+    Import(CliImport),
     /// Query benchmark data
     Perf(CliPerf),
+    /// Compare two reports
+    Compare(CliCompare),
 
     /// Manage branches
     #[clap(subcommand)]
@@ -76,6 +81,15 @@ pub enum CliSub {
     /// View alerts
     #[clap(subcommand)]
     Alert(CliAlert),
+    /// Manage alert silences
+    #[clap(subcommand)]
+    Silence(CliSilence),
+    /// Manage webhooks
+    #[clap(subcommand)]
+    Webhook(CliWebhook),
+    /// Manage retention policies
+    #[clap(subcommand)]
+    Retention(CliRetention),
 
     /// View user
     #[clap(subcommand)]
@@ -1,4 +1,4 @@
-use bencher_json::{AlertUuid, ResourceId};
+use bencher_json::{AlertUuid, DateTime, NameId, ReportUuid, ResourceId};
 use clap::{Parser, Subcommand, ValueEnum};
 
 use crate::parser::{CliBackend, CliPagination};
@@ -23,6 +23,50 @@ pub struct CliAlertList {
     /// Project slug or UUID
     pub project: ResourceId,
 
+    /// Alert status
+    #[clap(long)]
+    pub status: Option<CliAlertStatus>,
+
+    /// Assignee user slug or UUID
+    #[clap(long)]
+    pub assignee: Option<ResourceId>,
+
+    /// Branch UUID, slug, or name
+    #[clap(long)]
+    pub branch: Option<NameId>,
+
+    /// Testbed UUID, slug, or name
+    #[clap(long)]
+    pub testbed: Option<NameId>,
+
+    /// Benchmark UUID, slug, or name
+    #[clap(long)]
+    pub benchmark: Option<NameId>,
+
+    /// Measure UUID, slug, or name
+    #[clap(long)]
+    pub measure: Option<NameId>,
+
+    /// Boundary limit that was exceeded
+    #[clap(long)]
+    pub limit: Option<CliAlertLimit>,
+
+    /// Report UUID
+    #[clap(long)]
+    pub report: Option<ReportUuid>,
+
+    /// Report start time lower bound (seconds since epoch)
+    #[clap(long)]
+    pub start_time: Option<DateTime>,
+
+    /// Report start time upper bound (seconds since epoch)
+    #[clap(long)]
+    pub end_time: Option<DateTime>,
+
+    /// Alert search string
+    #[clap(long)]
+    pub search: Option<String>,
+
     #[clap(flatten)]
     pub pagination: CliPagination<CliAlertsSort>,
 
@@ -30,6 +74,15 @@ pub struct CliAlertList {
     pub backend: CliBackend,
 }
 
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliAlertLimit {
+    /// Lower boundary limit
+    Lower,
+    /// Upper boundary limit
+    Upper,
+}
+
 #[derive(ValueEnum, Debug, Clone)]
 #[clap(rename_all = "snake_case")]
 pub enum CliAlertsSort {
@@ -63,6 +116,18 @@ pub struct CliAlertUpdate {
     #[clap(long)]
     pub status: Option<CliAlertStatus>,
 
+    /// Assign the alert to a member of the project's organization (user slug or UUID)
+    #[clap(long)]
+    pub assignee: Option<ResourceId>,
+
+    /// Remove the current assignee from the alert
+    #[clap(long, conflicts_with = "assignee")]
+    pub unassign: bool,
+
+    /// Alert note (an empty note removes the current note)
+    #[clap(long)]
+    pub note: Option<String>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -75,6 +140,12 @@ pub enum CliAlertStatus {
     Active,
     /// Dismissed
     Dismissed,
+    /// Acknowledged
+    Acknowledged,
+    /// Silenced
+    Silenced,
+    /// Resolved
+    Resolved,
 }
 
 #[derive(Parser, Debug)]
@@ -1,4 +1,4 @@
-use bencher_json::{BenchmarkName, ResourceId, Slug};
+use bencher_json::{BenchmarkGroup, BenchmarkName, ResourceId, Slug};
 use clap::{Parser, Subcommand, ValueEnum};
 
 use crate::parser::{CliBackend, CliPagination};
@@ -35,6 +35,14 @@ pub struct CliBenchmarkList {
     #[clap(long)]
     pub search: Option<String>,
 
+    /// Benchmark tag
+    #[clap(long)]
+    pub tag: Option<Slug>,
+
+    /// Benchmark group, including all of its subgroups
+    #[clap(long)]
+    pub group: Option<BenchmarkGroup>,
+
     #[clap(flatten)]
     pub pagination: CliPagination<CliBenchmarksSort>,
 
@@ -62,6 +70,14 @@ pub struct CliBenchmarkCreate {
     #[clap(long)]
     pub slug: Option<Slug>,
 
+    /// Benchmark group (default: parsed from the name)
+    #[clap(long)]
+    pub group: Option<BenchmarkGroup>,
+
+    /// Benchmark tag (can be specified multiple times)
+    #[clap(long = "tag")]
+    pub tags: Vec<Slug>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -94,6 +110,14 @@ pub struct CliBenchmarkUpdate {
     #[clap(long)]
     pub slug: Option<Slug>,
 
+    /// Benchmark group
+    #[clap(long)]
+    pub group: Option<BenchmarkGroup>,
+
+    /// Benchmark tag, replacing all existing tags (can be specified multiple times)
+    #[clap(long = "tag")]
+    pub tags: Vec<Slug>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
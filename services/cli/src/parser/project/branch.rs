@@ -1,4 +1,4 @@
-use bencher_json::{BranchName, GitHash, NameId, ResourceId, Slug};
+use bencher_json::{BranchName, GitHash, NameId, ResourceId, Slug, Window};
 use clap::{Args, Parser, Subcommand, ValueEnum};
 
 use crate::parser::{CliBackend, CliPagination};
@@ -20,6 +20,8 @@ pub enum CliBranch {
     /// Delete a branch
     #[clap(alias = "rm")]
     Delete(CliBranchDelete),
+    /// Archive stale and merged branches
+    Archive(CliBranchArchive),
 }
 
 #[derive(Parser, Debug)]
@@ -35,6 +37,10 @@ pub struct CliBranchList {
     #[clap(long)]
     pub search: Option<String>,
 
+    /// Only list archived branches
+    #[clap(long)]
+    pub archived: bool,
+
     #[clap(flatten)]
     pub pagination: CliPagination<CliBranchesSort>,
 
@@ -123,6 +129,14 @@ pub struct CliBranchUpdate {
     #[clap(long)]
     pub hash: Option<GitHash>,
 
+    /// Archive the branch
+    #[clap(long, conflicts_with = "unarchive")]
+    pub archive: bool,
+
+    /// Unarchive the branch
+    #[clap(long)]
+    pub unarchive: bool,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -138,3 +152,29 @@ pub struct CliBranchDelete {
     #[clap(flatten)]
     pub backend: CliBackend,
 }
+
+#[derive(Parser, Debug)]
+pub struct CliBranchArchive {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Archive branches without a new report within this time window (seconds)
+    #[clap(long)]
+    pub stale: Option<Window>,
+
+    /// Archive branches whose `git` ref has been merged into this target branch
+    /// This requires running inside of a `git` repository
+    #[clap(long)]
+    pub merged: Option<String>,
+
+    /// Branch name to archive
+    #[clap(long)]
+    pub branch: Vec<BranchName>,
+
+    /// List the branches that would be archived without archiving them
+    #[clap(long)]
+    pub dry_run: bool,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
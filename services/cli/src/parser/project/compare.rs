@@ -0,0 +1,49 @@
+use bencher_json::{NameId, ReportUuid, ResourceId};
+use clap::{Parser, ValueEnum};
+
+use crate::parser::CliBackend;
+
+#[derive(Parser, Debug)]
+pub struct CliCompare {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Base report UUID
+    #[clap(long, required_unless_present = "base_branch")]
+    pub base_report: Option<ReportUuid>,
+
+    /// Head report UUID
+    #[clap(long, required_unless_present = "head_branch")]
+    pub head_report: Option<ReportUuid>,
+
+    /// Base branch name, slug, or UUID to use the latest report of on the testbed
+    #[clap(long, requires = "testbed", conflicts_with = "base_report")]
+    pub base_branch: Option<NameId>,
+
+    /// Head branch name, slug, or UUID to use the latest report of on the testbed
+    #[clap(long, requires = "testbed", conflicts_with = "head_report")]
+    pub head_branch: Option<NameId>,
+
+    /// Testbed name, slug, or UUID for the base and head branches
+    #[clap(long)]
+    pub testbed: Option<NameId>,
+
+    /// Output format (default json)
+    #[clap(value_enum, long)]
+    pub format: Option<CliCompareFormat>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+/// Supported Compare Formats
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliCompareFormat {
+    /// JSON
+    Json,
+    /// Plain text table
+    Text,
+    /// Markdown table
+    Markdown,
+}
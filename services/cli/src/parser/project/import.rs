@@ -0,0 +1,36 @@
+use std::num::NonZeroUsize;
+
+use bencher_json::ResourceId;
+use camino::Utf8PathBuf;
+use clap::Parser;
+
+use super::run::{CliRunAdapter, CliRunAverage, CliRunFold};
+use crate::parser::CliBackend;
+
+#[derive(Parser, Debug)]
+pub struct CliImport {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Path to a directory of JSON report files or a newline delimited JSON (NDJSON) file of reports
+    pub path: Utf8PathBuf,
+
+    /// Benchmark harness adapter for reports without settings (default is "magic")
+    #[clap(value_enum, long)]
+    pub adapter: Option<CliRunAdapter>,
+
+    /// Benchmark harness suggested central tendency (ie average) for reports without settings
+    #[clap(value_enum, long)]
+    pub average: Option<CliRunAverage>,
+
+    /// Fold multiple results into a single result for reports without settings
+    #[clap(value_enum, long)]
+    pub fold: Option<CliRunFold>,
+
+    /// Number of reports to import per request (default 25)
+    #[clap(long)]
+    pub batch_size: Option<NonZeroUsize>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
@@ -1,4 +1,4 @@
-use bencher_json::{ResourceId, ResourceName, Slug};
+use bencher_json::{MeasureExpression, ResourceId, ResourceName, Slug};
 use clap::{Parser, Subcommand, ValueEnum};
 
 use crate::parser::{CliBackend, CliPagination};
@@ -66,10 +66,51 @@ pub struct CliMeasureCreate {
     #[clap(long)]
     pub units: ResourceName,
 
+    /// Base unit the measure values are stored in
+    #[clap(value_enum, long)]
+    pub unit: Option<CliMeasureUnit>,
+
+    /// Expression over other measure slugs to derive the measure from (ie `instructions / estimated-cycles`)
+    #[clap(long)]
+    pub expression: Option<MeasureExpression>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
 
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliMeasureUnit {
+    /// Nanoseconds (ns)
+    Nanoseconds,
+    /// Microseconds (µs)
+    Microseconds,
+    /// Milliseconds (ms)
+    Milliseconds,
+    /// Seconds (s)
+    Seconds,
+    /// Bytes (B)
+    Bytes,
+    /// Kibibytes (KiB)
+    Kibibytes,
+    /// Mebibytes (MiB)
+    Mebibytes,
+    /// Gibibytes (GiB)
+    Gibibytes,
+    /// Bytes per second (B/s)
+    BytesPerSecond,
+    /// Kilobytes per second (KB/s)
+    KilobytesPerSecond,
+    /// Megabytes per second (MB/s)
+    MegabytesPerSecond,
+    /// Gigabytes per second (GB/s)
+    GigabytesPerSecond,
+    /// Operations per second (ops/s)
+    OperationsPerSecond,
+    /// Unitless count
+    Count,
+}
+
 #[derive(Parser, Debug)]
 pub struct CliMeasureView {
     /// Project slug or UUID
@@ -102,6 +143,17 @@ pub struct CliMeasureUpdate {
     #[clap(long)]
     pub units: Option<ResourceName>,
 
+    /// Base unit the measure values are stored in
+    /// Existing metrics are not converted
+    #[clap(value_enum, long)]
+    pub unit: Option<CliMeasureUnit>,
+
+    #[allow(clippy::option_option)]
+    /// Expression over other measure slugs to derive the measure from (null to remove)
+    /// Existing metrics are not recomputed
+    #[clap(long)]
+    pub expression: Option<Option<MeasureExpression>>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -1,4 +1,5 @@
 use bencher_json::{ResourceId, ResourceName, Slug, Url};
+use camino::Utf8PathBuf;
 use clap::{Parser, Subcommand, ValueEnum};
 
 use crate::parser::CliBackend;
@@ -8,12 +9,17 @@ use super::CliPagination;
 pub mod alert;
 pub mod benchmark;
 pub mod branch;
+pub mod compare;
+pub mod import;
 pub mod measure;
 pub mod perf;
 pub mod report;
+pub mod retention;
 pub mod run;
+pub mod silence;
 pub mod testbed;
 pub mod threshold;
+pub mod webhook;
 
 #[derive(Subcommand, Debug)]
 pub enum CliProject {
@@ -33,6 +39,10 @@ pub enum CliProject {
     Delete(CliProjectDelete),
     /// Check project permission
     Allowed(CliProjectAllowed),
+    /// Export a project archive
+    Export(CliProjectExport),
+    /// Restore a project from a project archive
+    Restore(CliProjectRestore),
 }
 
 #[derive(Parser, Debug)]
@@ -155,6 +165,39 @@ pub struct CliProjectAllowed {
     pub backend: CliBackend,
 }
 
+#[derive(Parser, Debug)]
+pub struct CliProjectExport {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// File path to save the project archive (default is stdout)
+    #[clap(long)]
+    pub output: Option<Utf8PathBuf>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliProjectRestore {
+    /// Organization slug or UUID
+    pub organization: ResourceId,
+
+    /// File path to the project archive
+    pub path: Utf8PathBuf,
+
+    /// Project name (default is the archived project name)
+    #[clap(long)]
+    pub name: Option<ResourceName>,
+
+    /// Project slug (default is the archived project slug)
+    #[clap(long)]
+    pub slug: Option<Slug>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
 /// Project permission
 #[derive(ValueEnum, Debug, Clone)]
 #[clap(rename_all = "snake_case")]
@@ -1,10 +1,14 @@
-use bencher_json::{BenchmarkUuid, BranchUuid, DateTime, MeasureUuid, ResourceId, TestbedUuid};
+use bencher_json::{
+    BenchmarkGroup, BenchmarkUuid, BranchUuid, DateTime, GitHash, MeasureUuid, ResourceId,
+    SampleSize, Slug, TestbedUuid,
+};
+use camino::Utf8PathBuf;
 use clap::{Parser, ValueEnum};
 
 use crate::parser::CliBackend;
 
 #[derive(Parser, Debug)]
-#[allow(clippy::option_option)]
+#[allow(clippy::option_option, clippy::struct_excessive_bools)]
 pub struct CliPerf {
     /// Project slug or UUID
     pub project: ResourceId,
@@ -18,9 +22,17 @@ pub struct CliPerf {
     pub testbeds: Vec<TestbedUuid>,
 
     /// Benchmark UUIDs
-    #[clap(long, required = true)]
+    #[clap(long, required_unless_present_any = ["group", "tag"])]
     pub benchmarks: Vec<BenchmarkUuid>,
 
+    /// Also include all benchmarks in this benchmark group and its subgroups
+    #[clap(long)]
+    pub group: Option<BenchmarkGroup>,
+
+    /// Also include all benchmarks with this tag
+    #[clap(long)]
+    pub tag: Option<Slug>,
+
     /// Measure UUIDs
     #[clap(long, required = true)]
     pub measures: Vec<MeasureUuid>,
@@ -33,14 +45,95 @@ pub struct CliPerf {
     #[clap(long)]
     pub end_time: Option<DateTime>,
 
+    /// Start branch version `git` hash
+    #[clap(long)]
+    pub start_hash: Option<GitHash>,
+
+    /// End branch version `git` hash
+    #[clap(long)]
+    pub end_hash: Option<GitHash>,
+
+    /// Start branch version number
+    #[clap(long)]
+    pub start_version: Option<u32>,
+
+    /// End branch version number
+    #[clap(long)]
+    pub end_version: Option<u32>,
+
+    /// Include archived branches
+    #[clap(long)]
+    pub archived: bool,
+
+    /// Aggregate the metrics into a single point per time bucket or version
+    #[clap(value_enum, long)]
+    pub aggregate: Option<CliPerfAggregate>,
+
+    /// Smooth the metrics using a rolling window
+    #[clap(value_enum, long)]
+    pub smoothing: Option<CliPerfSmoothing>,
+
+    /// Number of points in the rolling smoothing window (default: 5)
+    #[clap(long, requires = "smoothing")]
+    pub smoothing_window: Option<SampleSize>,
+
+    /// Detect change points in the metrics instead of listing every metric
+    #[clap(long)]
+    pub change_points: bool,
+
     /// Output results in a table
     #[clap(long)]
     pub table: Option<Option<CliPerfTableStyle>>,
 
+    /// Save a plot of the results to an image file
+    /// The file extension sets the image format: `jpeg`, `jpg`, `svg`, or `html`
+    #[clap(long, conflicts_with_all = ["change_points", "table"])]
+    pub img: Option<Utf8PathBuf>,
+
+    /// Shade the band between the lower and upper boundary limits in the image
+    #[clap(long, requires = "img")]
+    pub img_boundaries: bool,
+
+    /// Circle the metrics that generated an alert in the image
+    #[clap(long, requires = "img")]
+    pub img_alerts: bool,
+
+    /// Mark where each new branch version starts in the image
+    #[clap(long, requires = "img")]
+    pub img_versions: bool,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
 
+/// Supported Aggregations
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliPerfAggregate {
+    /// Do not aggregate
+    None,
+    /// Aggregate per hour
+    Hour,
+    /// Aggregate per day
+    Day,
+    /// Aggregate per week
+    Week,
+    /// Aggregate per branch version
+    Version,
+}
+
+/// Supported Smoothings
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliPerfSmoothing {
+    /// Do not smooth
+    None,
+    /// Rolling mean
+    Mean,
+    /// Rolling median
+    Median,
+}
+
 /// Supported Table Formats
 #[derive(ValueEnum, Debug, Clone)]
 #[clap(rename_all = "snake_case")]
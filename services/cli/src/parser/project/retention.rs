@@ -0,0 +1,70 @@
+use bencher_json::{NameId, ResourceId, Window};
+use clap::{Parser, Subcommand};
+
+use crate::parser::CliBackend;
+
+#[derive(Subcommand, Debug)]
+pub enum CliRetention {
+    /// View a project retention policy
+    #[clap(alias = "get")]
+    View(CliRetentionView),
+    /// Create or replace a project retention policy
+    Set(CliRetentionSet),
+    /// Delete a project retention policy
+    #[clap(alias = "rm")]
+    Delete(CliRetentionDelete),
+    /// Enforce a project retention policy now
+    Prune(CliRetentionPrune),
+}
+
+#[derive(Parser, Debug)]
+pub struct CliRetentionView {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliRetentionSet {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Time window to keep raw metrics for (seconds)
+    #[clap(long)]
+    pub raw_window: Window,
+
+    /// Remove old metrics instead of downsampling them to one aggregated point per day
+    #[clap(long)]
+    pub no_downsample: bool,
+
+    /// Branch UUID, slug, or name to always keep the data for its tagged versions (versions with a git hash)
+    #[clap(long)]
+    pub protected_branch: Option<NameId>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliRetentionDelete {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliRetentionPrune {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Report what would be removed without removing anything
+    #[clap(long)]
+    pub dry_run: bool,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
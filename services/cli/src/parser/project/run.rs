@@ -118,9 +118,18 @@ pub struct CliRunHash {
 
 #[derive(Args, Debug)]
 pub struct CliRunCommand {
-    /// Benchmark command output file path
+    /// Benchmark command output file path.
+    /// May be used multiple times, in which case the results from all files are merged.
+    /// If the path is a directory, then it is read as a Criterion output directory (ie `target/criterion`).
     #[clap(long)]
-    pub file: Option<Utf8PathBuf>,
+    pub file: Vec<Utf8PathBuf>,
+
+    /// Benchmark command output file glob pattern.
+    /// The pattern is matched after the benchmark command exits.
+    /// May be used multiple times, in which case the results from all files are merged.
+    /// Any matching directory is read as a Criterion output directory.
+    #[clap(long)]
+    pub glob: Vec<String>,
 
     #[clap(flatten)]
     pub sh_c: CliRunShell,
@@ -202,6 +211,10 @@ pub enum CliRunAdapter {
     RustIai,
     /// 🦀 Rust Iai-Callgrind
     RustIaiCallgrind,
+    /// 🦀 Rust Divan
+    RustDivan,
+    /// 🦀 Rust Criterion JSON
+    RustCriterionJson,
     /// ❯_ Shell
     Shell,
     /// ❯_ Shell Hyperfine
@@ -252,28 +265,31 @@ pub struct CliRunFmt {
 #[clap(group(
     ArgGroup::new("ci_cd")
         .multiple(false)
-        .args(&["github_actions"]),
+        .args(&["github_actions", "gitlab_merge_requests"]),
 ))]
 pub struct CliRunCi {
     /// GitHub API authentication token for GitHub Actions to comment on PRs (ie `--github-actions ${{ secrets.GITHUB_TOKEN }}`)
     #[clap(long)]
     pub github_actions: Option<String>,
-    /// Omit Benchmark Metrics and Boundary Limits (requires: `--github-actions`)
+    /// GitLab API authentication token for GitLab CI/CD to comment on MRs (ie `--gitlab-merge-requests "$BENCHER_GITLAB_TOKEN"`)
+    #[clap(long)]
+    pub gitlab_merge_requests: Option<String>,
+    /// Omit Benchmark Metrics and Boundary Limits (requires: `--github-actions` or `--gitlab-merge-requests`)
     #[clap(long, requires = "ci_cd")]
     pub ci_no_metrics: bool,
-    /// Only post results to CI if a Threshold exists for the Branch, Testbed, and Measure (requires: `--github-actions`)
+    /// Only post results to CI if a Threshold exists for the Branch, Testbed, and Measure (requires: `--github-actions` or `--gitlab-merge-requests`)
     #[clap(long, requires = "ci_cd")]
     pub ci_only_thresholds: bool,
-    /// Only start posting results to CI if an Alert is generated (requires: `--github-actions`)
+    /// Only start posting results to CI if an Alert is generated (requires: `--github-actions` or `--gitlab-merge-requests`)
     #[clap(long, requires = "ci_cd")]
     pub ci_only_on_alert: bool,
-    /// All links should be to public URLs that do not require a login (requires: `--github-actions`)
+    /// All links should be to public URLs that do not require a login (requires: `--github-actions` or `--gitlab-merge-requests`)
     #[clap(long, requires = "ci_cd")]
     pub ci_public_links: bool,
-    /// Custom ID for posting results to CI (requires: `--github-actions`)
+    /// Custom ID for posting results to CI (requires: `--github-actions` or `--gitlab-merge-requests`)
     #[clap(long, requires = "ci_cd")]
     pub ci_id: Option<String>,
-    /// Issue number for posting results to CI (requires: `--github-actions`)
+    /// Issue number for posting results to CI (requires: `--github-actions` or `--gitlab-merge-requests`)
     #[clap(long, requires = "ci_cd")]
     pub ci_number: Option<u64>,
     /// CAUTION: Override safety checks and accept that you are vulnerable to pwn requests (requires: `--github-actions`)
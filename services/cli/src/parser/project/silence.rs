@@ -0,0 +1,96 @@
+use bencher_json::{DateTime, ResourceId, SilenceUuid};
+use clap::{Parser, Subcommand, ValueEnum};
+
+use crate::parser::{CliBackend, CliPagination};
+
+#[derive(Subcommand, Debug)]
+pub enum CliSilence {
+    /// List alert silences
+    #[clap(alias = "ls")]
+    List(CliSilenceList),
+    /// Create an alert silence
+    #[clap(alias = "add")]
+    Create(CliSilenceCreate),
+    /// View an alert silence
+    #[clap(alias = "get")]
+    View(CliSilenceView),
+    /// Delete an alert silence
+    #[clap(alias = "rm")]
+    Delete(CliSilenceDelete),
+}
+
+#[derive(Parser, Debug)]
+pub struct CliSilenceList {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Also list expired silences
+    #[clap(long)]
+    pub expired: bool,
+
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliSilencesSort>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliSilencesSort {
+    /// Creation date time of the silence
+    Created,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliSilenceCreate {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Benchmark slug or UUID to silence (default all benchmarks)
+    #[clap(long)]
+    pub benchmark: Option<ResourceId>,
+
+    /// Measure slug or UUID to silence (default all measures)
+    #[clap(long)]
+    pub measure: Option<ResourceId>,
+
+    /// Testbed slug or UUID to silence (default all testbeds)
+    #[clap(long)]
+    pub testbed: Option<ResourceId>,
+
+    /// Note explaining why the alerts are silenced
+    #[clap(long)]
+    pub note: Option<String>,
+
+    /// Expiration time (seconds since epoch) (default never expires)
+    #[clap(long)]
+    pub expires: Option<DateTime>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliSilenceView {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Silence UUID
+    pub silence: SilenceUuid,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliSilenceDelete {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Silence UUID
+    pub silence: SilenceUuid,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
@@ -1,4 +1,6 @@
-use bencher_json::{Boundary, ModelUuid, NameId, ResourceId, SampleSize, ThresholdUuid, Window};
+use bencher_json::{
+    BenchmarkGroup, Boundary, ModelUuid, NameId, ResourceId, SampleSize, ThresholdUuid, Window,
+};
 use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
 
 use crate::parser::{CliBackend, CliPagination};
@@ -39,6 +41,10 @@ pub struct CliThresholdList {
     #[clap(long)]
     pub measure: Option<NameId>,
 
+    /// Benchmark group
+    #[clap(long)]
+    pub group: Option<BenchmarkGroup>,
+
     #[clap(flatten)]
     pub pagination: CliPagination<CliThresholdsSort>,
 
@@ -72,6 +78,10 @@ pub struct CliThresholdCreate {
     #[clap(long)]
     pub measure: NameId,
 
+    /// Benchmark group, to apply the threshold to all benchmarks in the group and its subgroups
+    #[clap(long)]
+    pub group: Option<BenchmarkGroup>,
+
     #[clap(flatten)]
     pub model: CliModel,
 
@@ -140,6 +150,13 @@ pub enum CliModelTest {
     Iqr,
     /// Delta interquartile range (ΔIQR)
     DeltaIqr,
+    /// Mann-Whitney U (rank-sum test)
+    MannWhitneyU,
+    /// Welch's t-test (two sample test)
+    WelchT,
+    /// Kolmogorov-Smirnov (two sample test)
+    #[clap(alias = "ks")]
+    KolmogorovSmirnov,
 }
 
 #[derive(Parser, Debug)]
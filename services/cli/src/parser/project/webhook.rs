@@ -0,0 +1,147 @@
+use bencher_json::{ResourceId, Secret, Url, WebhookUuid};
+use clap::{Parser, Subcommand, ValueEnum};
+
+use crate::parser::{CliBackend, CliPagination};
+
+#[derive(Subcommand, Debug)]
+pub enum CliWebhook {
+    /// List webhooks
+    #[clap(alias = "ls")]
+    List(CliWebhookList),
+    /// Create a webhook
+    #[clap(alias = "add")]
+    Create(CliWebhookCreate),
+    /// View a webhook
+    #[clap(alias = "get")]
+    View(CliWebhookView),
+    // Update a webhook
+    #[clap(alias = "edit")]
+    Update(CliWebhookUpdate),
+    /// Delete a webhook
+    #[clap(alias = "rm")]
+    Delete(CliWebhookDelete),
+    /// List webhook deliveries
+    Deliveries(CliWebhookDeliveries),
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookList {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliWebhooksSort>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliWebhooksSort {
+    /// Creation date time of the webhook
+    Created,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookCreate {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Webhook URL
+    #[clap(long)]
+    pub url: Url,
+
+    /// Webhook secret used to sign each payload
+    #[clap(long)]
+    pub secret: Secret,
+
+    /// Webhook event (default all events)
+    #[clap(long)]
+    pub event: Option<Vec<CliWebhookEvent>>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+/// Webhook events
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliWebhookEvent {
+    /// An alert was generated for a new report
+    AlertCreated,
+    /// An alert was dismissed
+    AlertDismissed,
+    /// A new report was created
+    ReportCreated,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookView {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Webhook UUID
+    pub webhook: WebhookUuid,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookUpdate {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Webhook UUID
+    pub webhook: WebhookUuid,
+
+    /// Webhook URL
+    #[clap(long)]
+    pub url: Option<Url>,
+
+    /// Webhook secret used to sign each payload
+    #[clap(long)]
+    pub secret: Option<Secret>,
+
+    /// Webhook event
+    #[clap(long)]
+    pub event: Option<Vec<CliWebhookEvent>>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookDelete {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Webhook UUID
+    pub webhook: WebhookUuid,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookDeliveries {
+    /// Project slug or UUID
+    pub project: ResourceId,
+
+    /// Webhook UUID
+    pub webhook: WebhookUuid,
+
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliWebhookDeliveriesSort>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliWebhookDeliveriesSort {
+    /// Creation date time of the delivery
+    Created,
+}
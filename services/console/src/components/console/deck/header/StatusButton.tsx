@@ -18,14 +18,20 @@ export interface Props {
 	handleRefresh: () => void;
 }
 
+const is_active = (status: undefined | AlertStatus) =>
+	status === AlertStatus.Active || status === AlertStatus.Acknowledged;
+
 const StatusButton = (props: Props) => {
 	const [submitting, setSubmitting] = createSignal(false);
 
 	const getStatus = () => {
 		switch (props.data()?.status) {
 			case AlertStatus.Active:
+			case AlertStatus.Acknowledged:
 				return { status: AlertStatus.Dismissed };
 			case AlertStatus.Dismissed:
+			case AlertStatus.Silenced:
+			case AlertStatus.Resolved:
 				return { status: AlertStatus.Active };
 			default:
 				console.error("Unknown status");
@@ -45,7 +51,7 @@ const StatusButton = (props: Props) => {
 		}
 
 		setSubmitting(true);
-		const isActive = props.data()?.status === AlertStatus.Active;
+		const isActive = is_active(props.data()?.status);
 		httpPatch(props.apiUrl, props.path(), token, data)
 			.then((_resp) => {
 				setSubmitting(false);
@@ -71,7 +77,7 @@ const StatusButton = (props: Props) => {
 
 	return (
 		<Switch>
-			<Match when={props.data()?.status === AlertStatus.Active}>
+			<Match when={is_active(props.data()?.status)}>
 				<button
 					class="button is-primary is-fullwidth"
 					type="button"
@@ -88,7 +94,7 @@ const StatusButton = (props: Props) => {
 					<span>Dismiss</span>
 				</button>
 			</Match>
-			<Match when={props.data()?.status === AlertStatus.Dismissed}>
+			<Match when={!is_active(props.data()?.status)}>
 				<button
 					class="button is-outlined is-fullwidth"
 					type="button"
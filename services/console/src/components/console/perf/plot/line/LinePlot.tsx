@@ -90,7 +90,9 @@ const get_x_type = (range: PerfRange) => {
 };
 
 const is_active = (alert: JsonPerfAlert) =>
-	alert?.status && alert.status == AlertStatus.Active;
+	alert?.status &&
+	(alert.status == AlertStatus.Active ||
+		alert.status == AlertStatus.Acknowledged);
 
 // A boundary is skipped if it is defined but its limit undefined
 // This indicates that the the boundary limit could not be calculated for the metric
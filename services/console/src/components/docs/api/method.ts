@@ -35,6 +35,7 @@ export enum HeadersKind {
 	Pub = "pub",
 	Auth = "auth",
 	Img = "img",
+	OpenMetrics = "open_metrics",
 }
 
 export const getSchema = (schema) => getRef(parseSchemaRef(schema));
@@ -147,6 +147,18 @@ const testValue = (selected: ModelTest) => {
 				value: ModelTest.DeltaIqr,
 				option: "Delta Interquartile Range (ΔIQR)",
 			},
+			{
+				value: ModelTest.MannWhitneyU,
+				option: "Mann-Whitney U",
+			},
+			{
+				value: ModelTest.WelchT,
+				option: "Welch's t-test",
+			},
+			{
+				value: ModelTest.KolmogorovSmirnov,
+				option: "Kolmogorov-Smirnov",
+			},
 		],
 	};
 };
@@ -197,6 +209,12 @@ const testFragment = (modelTest: ModelTest) => {
 			return "iqr-thresholds";
 		case ModelTest.DeltaIqr:
 			return "delta-iqr-thresholds";
+		case ModelTest.MannWhitneyU:
+			return "mann-whitney-u-thresholds";
+		case ModelTest.WelchT:
+			return "welch-t-test-thresholds";
+		case ModelTest.KolmogorovSmirnov:
+			return "kolmogorov-smirnov-thresholds";
 	}
 };
 
@@ -340,6 +358,9 @@ const FIELDS = {
 	[ModelTest.LogNormal]: cdfConfig(ModelTest.LogNormal),
 	[ModelTest.Iqr]: iqrConfig(ModelTest.Iqr),
 	[ModelTest.DeltaIqr]: iqrConfig(ModelTest.DeltaIqr),
+	[ModelTest.MannWhitneyU]: cdfConfig(ModelTest.MannWhitneyU),
+	[ModelTest.WelchT]: cdfConfig(ModelTest.WelchT),
+	[ModelTest.KolmogorovSmirnov]: cdfConfig(ModelTest.KolmogorovSmirnov),
 };
 
 const initForm = (fields) => {
@@ -6,6 +6,8 @@ export type VersionNumber = number;
 
 export type BenchmarkName = string;
 
+export type BenchmarkGroup = string;
+
 export type NameId = string;
 
 export type MeasureNameId = NameId;
@@ -34,10 +36,53 @@ export interface JsonMeasure {
 	name: ResourceName;
 	slug: Slug;
 	units: ResourceName;
+	dimension?: UnitDimension;
+	unit?: MeasureUnit;
+	expression?: MeasureExpression;
 	created: string;
 	modified: string;
 }
 
+/**
+ * An arithmetic expression over the slugs of other measures in the same project.
+ * It is evaluated for each benchmark in a report to compute a derived measure,
+ * ie `instructions / estimated-cycles`.
+ * Supported operators are `+`, `-`, `*`, `/`, and parentheses.
+ * A `-` between two slugs must be surrounded by whitespace,
+ * as it is otherwise part of the slug.
+ */
+export type MeasureExpression = string;
+
+/**
+ * The physical dimension of a measure.
+ * Values can only be converted between units of the same dimension.
+ */
+export enum UnitDimension {
+	Time = "time",
+	Data = "data",
+	DataRate = "data_rate",
+	Throughput = "throughput",
+	Count = "count",
+}
+
+/** The base unit that the values of a measure are stored in. */
+export enum MeasureUnit {
+	Nanoseconds = "nanoseconds",
+	Microseconds = "microseconds",
+	Milliseconds = "milliseconds",
+	Seconds = "seconds",
+	Bytes = "bytes",
+	Kibibytes = "kibibytes",
+	Mebibytes = "mebibytes",
+	Gibibytes = "gibibytes",
+	BytesPerSecond = "bytes_per_second",
+	KilobytesPerSecond = "kilobytes_per_second",
+	MegabytesPerSecond = "megabytes_per_second",
+	GigabytesPerSecond = "gigabytes_per_second",
+	OperationsPerSecond = "operations_per_second",
+	Count = "count",
+}
+
 export enum ModelTest {
 	Static = "static",
 	Percentage = "percentage",
@@ -46,6 +91,9 @@ export enum ModelTest {
 	LogNormal = "log_normal",
 	Iqr = "iqr",
 	DeltaIqr = "delta_iqr",
+	MannWhitneyU = "mann_whitney_u",
+	WelchT = "welch_t",
+	KolmogorovSmirnov = "kolmogorov_smirnov",
 }
 
 export type SampleSize = number;
@@ -79,6 +127,8 @@ export interface JsonBoundary {
 	baseline?: number;
 	lower_limit?: number;
 	upper_limit?: number;
+	/** The effect size of a two sample threshold model test. */
+	effect_size?: number;
 }
 
 export interface JsonBenchmarkMetric {
@@ -86,6 +136,8 @@ export interface JsonBenchmarkMetric {
 	project: Uuid;
 	name: BenchmarkName;
 	slug: Slug;
+	group?: BenchmarkGroup;
+	tags: Slug[];
 	metric: JsonMetric;
 	boundary?: JsonBoundary;
 	created: string;
@@ -113,6 +165,7 @@ export interface JsonBranch {
 	start_point?: JsonStartPoint;
 	created: string;
 	modified: string;
+	archived?: string;
 }
 
 export interface JsonTestbed {
@@ -130,6 +183,7 @@ export interface JsonThreshold {
 	branch: JsonBranch;
 	testbed: JsonTestbed;
 	measure: JsonMeasure;
+	group?: BenchmarkGroup;
 	statistic?: JsonModel;
 	model: JsonModel;
 	created: string;
@@ -142,8 +196,16 @@ export enum BoundaryLimit {
 }
 
 export enum AlertStatus {
+	/** The alert is active and needs attention. */
 	Active = "active",
+	/** The alert has been dismissed by a user. */
 	Dismissed = "dismissed",
+	/** The alert has been acknowledged by a user but is still being worked on. */
+	Acknowledged = "acknowledged",
+	/** The alert matched an alert silence when it was created. */
+	Silenced = "silenced",
+	/** A subsequent report for the same benchmark returned inside of the boundary limits. */
+	Resolved = "resolved",
 }
 
 export interface JsonAlert {
@@ -154,10 +216,18 @@ export interface JsonAlert {
 	benchmark: JsonBenchmarkMetric;
 	limit: BoundaryLimit;
 	status: AlertStatus;
+	assignee?: JsonAlertAssignee;
+	note?: string;
 	created: string;
 	modified: string;
 }
 
+export interface JsonAlertAssignee {
+	uuid: Uuid;
+	name: UserName;
+	slug: Slug;
+}
+
 export type JsonReportAlerts = JsonAlert[];
 
 export type Uuid = string;
@@ -315,6 +385,21 @@ export interface JsonAlertStats {
 export interface JsonUpdateAlert {
 	/** The new status of the alert. */
 	status?: AlertStatus;
+	/**
+	 * The UUID or slug of the user to assign the alert to.
+	 * The user must be a member of the project's organization.
+	 */
+	assignee?: ResourceId;
+	/**
+	 * Set to `true` to remove the current assignee from the alert.
+	 * This cannot be used along with `assignee`.
+	 */
+	unassign?: boolean;
+	/**
+	 * A free-text note for the alert.
+	 * Set to an empty string to remove the current note.
+	 */
+	note?: string;
 }
 
 export interface JsonPerfAlert {
@@ -329,6 +414,12 @@ export interface JsonBenchmark {
 	project: Uuid;
 	name: BenchmarkName;
 	slug: Slug;
+	/**
+	 * The group that the benchmark belongs to.
+	 * Unless explicitly set, this is parsed from the benchmark name.
+	 */
+	group?: BenchmarkGroup;
+	tags: Slug[];
 	created: string;
 	modified: string;
 }
@@ -376,6 +467,16 @@ export interface JsonPerfQuery {
 	measures: Uuid[];
 	start_time?: string;
 	end_time?: string;
+	start_hash?: GitHash;
+	end_hash?: GitHash;
+	start_version?: VersionNumber;
+	end_version?: VersionNumber;
+	archived?: boolean;
+	aggregate?: PerfAggregate;
+	smoothing?: PerfSmoothing;
+	smoothing_window?: SampleSize;
+	group?: BenchmarkGroup;
+	tag?: Slug;
 }
 
 export interface JsonPerfMetric {
@@ -388,6 +489,20 @@ export interface JsonPerfMetric {
 	metric: JsonMetric;
 	boundary?: JsonBoundary;
 	alert?: JsonPerfAlert;
+	/**
+	 * The range of the metrics that were aggregated or smoothed into this metric.
+	 * This is only present if the perf query used aggregation or smoothing.
+	 */
+	envelope?: JsonPerfEnvelope;
+}
+
+export interface JsonPerfEnvelope {
+	/** The minimum metric value. */
+	min: number;
+	/** The maximum metric value. */
+	max: number;
+	/** The number of metrics. */
+	count: number;
 }
 
 export interface JsonPerfMetrics {
@@ -405,6 +520,76 @@ export interface JsonPerf {
 	results: JsonPerfMetrics[];
 }
 
+export interface JsonChangePoint {
+	before: JsonPerfMetric;
+	after: JsonPerfMetric;
+	before_mean: number;
+	after_mean: number;
+}
+
+export interface JsonPerfChangePointMetrics {
+	branch: JsonBranch;
+	testbed: JsonTestbed;
+	benchmark: JsonBenchmark;
+	measure: JsonMeasure;
+	change_points: JsonChangePoint[];
+}
+
+export interface JsonPerfChangePoints {
+	project: JsonProject;
+	start_time?: string;
+	end_time?: string;
+	results: JsonPerfChangePointMetrics[];
+}
+
+export interface JsonCompareReport {
+	report: Uuid;
+	branch: JsonBranchVersion;
+	testbed: JsonTestbed;
+	start_time: string;
+	end_time: string;
+}
+
+export interface JsonCompareMetric {
+	/** The mean value across all of the iterations. */
+	value: number;
+	/** The number of iterations. */
+	iterations: number;
+}
+
+export interface JsonCompareResult {
+	benchmark: JsonBenchmark;
+	measure: JsonMeasure;
+	/** The base metric, if the benchmark and measure are in the base report. */
+	base?: JsonCompareMetric;
+	/** The head metric, if the benchmark and measure are in the head report. */
+	head?: JsonCompareMetric;
+	/** The difference between the head and base values. */
+	delta?: number;
+	/** The percent change from the base value to the head value. */
+	percent_change?: number;
+	/**
+	 * The two-tailed Welch's t-test p-value for the difference between the iteration samples.
+	 * This requires at least two iterations in both reports.
+	 */
+	p_value?: number;
+	/**
+	 * Whether the difference is statistically significant, using a significance level of 0.05.
+	 * This requires at least two iterations in both reports.
+	 */
+	significant?: boolean;
+}
+
+export interface JsonCompare {
+	project: Uuid;
+	/** The report being compared against. */
+	base: JsonCompareReport;
+	/** The report being compared. */
+	head: JsonCompareReport;
+	/** The comparison for each benchmark and measure in either report. */
+	results: JsonCompareResult[];
+}
+
 export interface JsonUser {
 	uuid: Uuid;
 	name: UserName;
@@ -422,6 +607,8 @@ export enum Adapter {
 	RustCriterion = "rust_criterion",
 	RustIai = "rust_iai",
 	RustIaiCallgrind = "rust_iai_callgrind",
+	RustDivan = "rust_divan",
+	RustCriterionJson = "rust_criterion_json",
 	Cpp = "cpp",
 	CppGoogle = "cpp_google",
 	CppCatch2 = "cpp_catch2",
@@ -457,6 +644,71 @@ export interface JsonReport {
 	created: string;
 }
 
+export interface JsonRetention {
+	project: Uuid;
+	raw_window: Window;
+	downsample: boolean;
+	protected_branch?: Uuid;
+	created: string;
+	modified: string;
+}
+
+export interface JsonSilence {
+	uuid: Uuid;
+	project: Uuid;
+	benchmark?: JsonBenchmark;
+	measure?: JsonMeasure;
+	testbed?: JsonTestbed;
+	note?: string;
+	expires?: string;
+	created: string;
+}
+
+export enum WebhookEvent {
+	/** An alert was generated for a new report. */
+	AlertCreated = "alert_created",
+	/** An alert was dismissed. */
+	AlertDismissed = "alert_dismissed",
+	/** A new report was created. */
+	ReportCreated = "report_created",
+}
+
+export interface JsonWebhook {
+	uuid: Uuid;
+	project: Uuid;
+	url: Url;
+	events: WebhookEvent[];
+	created: string;
+	modified: string;
+}
+
+/** The payload that is `POST`ed to a webhook URL. */
+export interface JsonWebhookPayload {
+	delivery: Uuid;
+	event: WebhookEvent;
+	/** The alert for `alert_created` and `alert_dismissed` events. */
+	alert?: JsonAlert;
+	/** The report, including its project, branch, and testbed. */
+	report: JsonReport;
+	created: string;
+}
+
+export interface JsonWebhookDelivery {
+	uuid: Uuid;
+	webhook: Uuid;
+	event: WebhookEvent;
+	/** The number of delivery attempts made so far. */
+	attempts: number;
+	/** The HTTP status code of the most recent delivery attempt, if a response was received. */
+	status_code?: number;
+	/** The error from the most recent delivery attempt, if it failed. */
+	error?: string;
+	/** The time of the successful delivery, if it has been delivered. */
+	delivered?: string;
+	created: string;
+	modified: string;
+}
+
 export interface JsonSignup {
 	name: UserName;
 	slug?: Slug;
@@ -584,6 +836,37 @@ export enum ProjectPermission {
 	DeleteRole = "delete_role",
 }
 
+export enum PerfAggregate {
+	/** Do not aggregate the metrics. */
+	None = "none",
+	/** Aggregate the metrics per hour. */
+	Hour = "hour",
+	/** Aggregate the metrics per day. */
+	Day = "day",
+	/** Aggregate the metrics per week, starting on Monday. */
+	Week = "week",
+	/** Aggregate the metrics per branch version. */
+	Version = "version",
+}
+
+export enum PerfSmoothing {
+	/** Do not smooth the metrics. */
+	None = "none",
+	/** Use the rolling mean of the smoothing window. */
+	Mean = "mean",
+	/** Use the rolling median of the smoothing window. */
+	Median = "median",
+}
+
+export enum PerfImgFormat {
+	/** A JPEG raster image. */
+	Jpeg = "jpeg",
+	/** A scalable vector graphic (SVG) image. */
+	Svg = "svg",
+	/** A self-contained HTML page with the SVG image and hover tooltips for each metric. */
+	Html = "html",
+}
+
 export enum PerfQueryKey {
 	Branches = "branches",
 	Testbeds = "testbeds",
@@ -591,6 +874,16 @@ export enum PerfQueryKey {
 	Measures = "measures",
 	StartTime = "start_time",
 	EndTime = "end_time",
+	StartHash = "start_hash",
+	EndHash = "end_hash",
+	StartVersion = "start_version",
+	EndVersion = "end_version",
+	Archived = "archived",
+	Aggregate = "aggregate",
+	Smoothing = "smoothing",
+	SmoothingWindow = "smoothing_window",
+	Group = "group",
+	Tag = "tag",
 	LowerBoundary = "lower_boundary",
 	UpperBoundary = "upper_boundary",
 }
@@ -53,6 +53,8 @@ pub enum TaskTestEnvironment {
     Localhost,
     /// Docker https://localhost:61016
     Docker,
+    /// PostgreSQL Docker https://localhost:61016
+    Postgres,
     /// https://bencher-api-dev.fly.dev
     Dev,
     /// https://bencher-api-test.fly.dev
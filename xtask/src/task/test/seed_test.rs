@@ -1,7 +1,9 @@
 use std::process::Command;
 
 use assert_cmd::{assert::OutputAssertExt, cargo::CommandCargoExt};
-use bencher_json::{Jwt, Url, LOCALHOST_BENCHER_API_URL};
+use bencher_json::{
+    project::webhook::WebhookEvent, JsonReport, Jwt, Url, LOCALHOST_BENCHER_API_URL,
+};
 use pretty_assertions::assert_eq;
 
 use crate::parser::TaskSeedTest;
@@ -9,6 +11,7 @@ use crate::parser::TaskSeedTest;
 const BENCHER_CMD: &str = "bencher";
 const HOST_ARG: &str = "--host";
 const TOKEN_ARG: &str = "--token";
+const USER_SLUG: &str = "muriel-bagge";
 const ORG_SLUG: &str = "muriel-bagge";
 const PROJECT_ARG: &str = "--project";
 const PROJECT_SLUG: &str = "the-computer";
@@ -18,6 +21,12 @@ const TESTBED_ARG: &str = "--testbed";
 const TESTBED_SLUG: &str = "base";
 const MEASURE_ARG: &str = "--measure";
 const MEASURE_SLUG: &str = "screams";
+const IMPORT_BRANCH_SLUG: &str = "import";
+const IMPORT_FEATURE_BRANCH_SLUG: &str = "import-feature";
+const RESTORED_PROJECT_SLUG: &str = "the-computer-restored";
+const RETENTION_PROJECT_SLUG: &str = "the-retention";
+const WEBHOOK_SECRET: &str = "super secret";
+const WEBHOOK_DELIVERY_POLLS: usize = 30;
 
 const CLI_DIR: &str = "./services/cli";
 
@@ -879,6 +888,787 @@ impl SeedTest {
         let _alert: bencher_json::JsonAlert =
             serde_json::from_slice(&assert.get_output().stdout).unwrap();
 
+        self.import()?;
+        self.metrics()?;
+        self.webhooks()?;
+        self.tokens_and_silences()?;
+        self.archive()?;
+        self.retention()?;
+
+        Ok(())
+    }
+
+    // Historical reports are imported before the existing reports on the branch,
+    // the code versions shared with another branch are renumbered on both branches,
+    // and the existing reports have their boundaries recomputed without generating any alerts.
+    #[allow(clippy::too_many_lines)]
+    fn import(&self) -> anyhow::Result<()> {
+        let host = self.url.as_ref();
+        let token = self.token.as_ref();
+
+        // cargo run -- branch create --host http://localhost:61016 --token $BENCHER_API_TOKEN --name import the-computer
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "branch",
+            "create",
+            HOST_ARG,
+            host,
+            TOKEN_ARG,
+            token,
+            "--name",
+            IMPORT_BRANCH_SLUG,
+            PROJECT_SLUG,
+        ])
+        .current_dir(CLI_DIR);
+        let assert = cmd.assert().success();
+        let _json: bencher_json::JsonBranch =
+            serde_json::from_slice(&assert.get_output().stdout).unwrap();
+
+        // The threshold alerts when a value is more than 10% over the mean
+        // cargo run -- threshold create --host http://localhost:61016 --token $BENCHER_API_TOKEN --branch import --testbed base --measure latency --test percentage --upper-boundary 0.1 the-computer
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "threshold",
+            "create",
+            HOST_ARG,
+            host,
+            TOKEN_ARG,
+            token,
+            BRANCH_ARG,
+            IMPORT_BRANCH_SLUG,
+            TESTBED_ARG,
+            TESTBED_SLUG,
+            MEASURE_ARG,
+            "latency",
+            "--test",
+            "percentage",
+            "--upper-boundary",
+            "0.1",
+            PROJECT_SLUG,
+        ])
+        .current_dir(CLI_DIR);
+        let assert = cmd.assert().success();
+        let _json: bencher_json::JsonThreshold =
+            serde_json::from_slice(&assert.get_output().stdout).unwrap();
+
+        let second = self.create_report(
+            IMPORT_BRANCH_SLUG,
+            "2000000000000000000000000000000000000000",
+            "1970-01-01T00:33:20Z",
+            10.0,
+        )?;
+        let third = self.create_report(
+            IMPORT_BRANCH_SLUG,
+            "3000000000000000000000000000000000000000",
+            "1970-01-01T00:50:00Z",
+            10.0,
+        )?;
+        assert_eq!(baseline(&third), Some(10.0));
+
+        // The feature branch shares the code version of the second report
+        // cargo run -- branch create --host http://localhost:61016 --token $BENCHER_API_TOKEN --name import-feature --start-point-branch import --start-point-hash 2000000000000000000000000000000000000000 the-computer
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "branch",
+            "create",
+            HOST_ARG,
+            host,
+            TOKEN_ARG,
+            token,
+            "--name",
+            IMPORT_FEATURE_BRANCH_SLUG,
+            "--start-point-branch",
+            IMPORT_BRANCH_SLUG,
+            "--start-point-hash",
+            &second.branch.version.hash.unwrap().to_string(),
+            PROJECT_SLUG,
+        ])
+        .current_dir(CLI_DIR);
+        let assert = cmd.assert().success();
+        let _json: bencher_json::JsonBranch =
+            serde_json::from_slice(&assert.get_output().stdout).unwrap();
+
+        let feature = self.create_report(
+            IMPORT_FEATURE_BRANCH_SLUG,
+            "2500000000000000000000000000000000000000",
+            "1970-01-01T00:41:40Z",
+            10.0,
+        )?;
+        assert_eq!(feature.branch.version.number.0, 1);
+
+        // The imported reports are out of order, and the later one is an outlier
+        let import_path = std::env::temp_dir().join("bencher_seed_test_import.ndjson");
+        let import_reports = [
+            ("1970-01-01T00:25:00Z", 100.0),
+            ("1970-01-01T00:16:40Z", 30.0),
+        ]
+        .into_iter()
+        .map(|(start_time, latency)| {
+            serde_json::json!({
+                "branch": IMPORT_BRANCH_SLUG,
+                "testbed": TESTBED_SLUG,
+                "start_time": start_time,
+                "end_time": start_time,
+                "results": [bmf_latency(latency)],
+                "settings": { "adapter": "json" },
+            })
+            .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+        std::fs::write(&import_path, import_reports)?;
+
+        // cargo run -- import --host http://localhost:61016 --token $BENCHER_API_TOKEN the-computer [import_path]
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "import",
+            HOST_ARG,
+            host,
+            TOKEN_ARG,
+            token,
+            PROJECT_SLUG,
+            import_path.to_string_lossy().as_ref(),
+        ])
+        .current_dir(CLI_DIR);
+        cmd.assert().success();
+        std::fs::remove_file(import_path)?;
+
+        // The imported code versions are placed before the existing ones.
+        // Each imported report only uses the reports that started before it,
+        // and the existing reports have their boundaries recomputed with the imported reports.
+        let reports = self
+            .list_reports(IMPORT_BRANCH_SLUG)?
+            .iter()
+            .map(|report| {
+                (
+                    report.start_time.timestamp(),
+                    report.branch.version.number.0,
+                    baseline(report),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            reports,
+            vec![
+                (1_000, 0, Some(30.0)),
+                (1_500, 1, Some(65.0)),
+                (2_000, 2, Some(140.0 / 3.0)),
+                (3_000, 3, Some(37.5)),
+            ]
+        );
+
+        // The shared code version moved, so the later code versions on the feature branch move with it
+        let reports = self.list_reports(IMPORT_FEATURE_BRANCH_SLUG)?;
+        assert_eq!(reports.len(), 1);
+        assert_eq!(
+            reports.first().map(|report| report.branch.version.number.0),
+            Some(3)
+        );
+
+        // No alerts are generated for the outlier
+        // cargo run -- alert ls --host http://localhost:61016 --token $BENCHER_API_TOKEN the-computer
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "alert",
+            "ls",
+            HOST_ARG,
+            host,
+            TOKEN_ARG,
+            token,
+            PROJECT_SLUG,
+        ])
+        .current_dir(CLI_DIR);
+        let assert = cmd.assert().success();
+        let alerts: bencher_json::JsonAlerts =
+            serde_json::from_slice(&assert.get_output().stdout).unwrap();
+        assert_eq!(alerts.0.len(), 5);
+
+        Ok(())
+    }
+
+    // The scrape only has the most recent metric for each branch, testbed, benchmark, and measure
+    fn metrics(&self) -> anyhow::Result<()> {
+        // curl http://localhost:61016/v0/projects/the-computer/metrics
+        let url = format!(
+            "{}/v0/projects/{PROJECT_SLUG}/metrics",
+            self.url.as_ref().trim_end_matches('/')
+        );
+        // The seed test runs inside of the `xtask` runtime, so the request gets its own
+        let open_metrics = std::thread::spawn(move || -> anyhow::Result<String> {
+            tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()?
+                .block_on(async { Ok(reqwest::get(url).await?.error_for_status()?.text().await?) })
+        })
+        .join()
+        .map_err(|_err| anyhow::anyhow!("Failed to scrape metrics"))??;
+
+        let labels = format!(
+            r#"branch="{IMPORT_BRANCH_SLUG}",testbed="{TESTBED_SLUG}",benchmark="bench",measure="latency",units="nanoseconds (ns)""#
+        );
+        let metrics = open_metrics
+            .lines()
+            .filter(|line| line.starts_with(&format!("bencher_metric{{{labels}}}")))
+            .collect::<Vec<_>>();
+        assert_eq!(metrics, vec![format!("bencher_metric{{{labels}}} 10")]);
+        assert!(open_metrics
+            .lines()
+            .any(|line| line == format!("bencher_metric_timestamp_seconds{{{labels}}} 3000")));
+        assert!(open_metrics
+            .lines()
+            .any(|line| line == "bencher_alerts_active 5"));
+
+        Ok(())
+    }
+
+    // A webhook to a private network address is rejected,
+    // and a delivery that fails before any attempts are made is still logged
+    #[allow(clippy::too_many_lines)]
+    fn webhooks(&self) -> anyhow::Result<()> {
+        let host = self.url.as_ref();
+        let token = self.token.as_ref();
+
+        // cargo run -- webhook create --host http://localhost:61016 --token $BENCHER_API_TOKEN --url http://127.0.0.1:8080/webhook --secret "super secret" the-computer
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "webhook",
+            "create",
+            HOST_ARG,
+            host,
+            TOKEN_ARG,
+            token,
+            "--url",
+            "http://127.0.0.1:8080/webhook",
+            "--secret",
+            WEBHOOK_SECRET,
+            PROJECT_SLUG,
+        ])
+        .current_dir(CLI_DIR);
+        cmd.assert().failure();
+
+        // The `.invalid` top-level domain never resolves
+        // cargo run -- webhook create --host http://localhost:61016 --token $BENCHER_API_TOKEN --url https://bencher.invalid/webhook --secret "super secret" --event report_created the-computer
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "webhook",
+            "create",
+            HOST_ARG,
+            host,
+            TOKEN_ARG,
+            token,
+            "--url",
+            "https://bencher.invalid/webhook",
+            "--secret",
+            WEBHOOK_SECRET,
+            "--event",
+            "report_created",
+            PROJECT_SLUG,
+        ])
+        .current_dir(CLI_DIR);
+        let assert = cmd.assert().success();
+        let webhook: bencher_json::JsonWebhook =
+            serde_json::from_slice(&assert.get_output().stdout).unwrap();
+        let webhook_uuid = webhook.uuid.to_string();
+
+        self.create_report(
+            IMPORT_BRANCH_SLUG,
+            "4000000000000000000000000000000000000000",
+            "1970-01-01T01:06:40Z",
+            10.0,
+        )?;
+
+        // Deliveries are sent in the background, so wait for the delivery log to be updated
+        let mut deliveries = Vec::new();
+        for _ in 0..WEBHOOK_DELIVERY_POLLS {
+            // cargo run -- webhook deliveries --host http://localhost:61016 --token $BENCHER_API_TOKEN the-computer [webhook.uuid]
+            let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+            cmd.args([
+                "webhook",
+                "deliveries",
+                HOST_ARG,
+                host,
+                TOKEN_ARG,
+                token,
+                PROJECT_SLUG,
+                &webhook_uuid,
+            ])
+            .current_dir(CLI_DIR);
+            let assert = cmd.assert().success();
+            let json: bencher_json::JsonWebhookDeliveries =
+                serde_json::from_slice(&assert.get_output().stdout).unwrap();
+            deliveries = json.0;
+            if deliveries.iter().all(|delivery| delivery.error.is_some()) && !deliveries.is_empty()
+            {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_secs(1));
+        }
+        assert_eq!(deliveries.len(), 1);
+        let delivery = deliveries.first().unwrap();
+        assert_eq!(delivery.event, WebhookEvent::ReportCreated);
+        assert_eq!(delivery.attempts, 0);
+        assert_eq!(delivery.status_code, None);
+        assert!(delivery.error.is_some());
+        assert!(delivery.delivered.is_none());
+
+        // cargo run -- webhook delete --host http://localhost:61016 --token $BENCHER_API_TOKEN the-computer [webhook.uuid]
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "webhook",
+            "delete",
+            HOST_ARG,
+            host,
+            TOKEN_ARG,
+            token,
+            PROJECT_SLUG,
+            &webhook_uuid,
+        ])
+        .current_dir(CLI_DIR);
+        cmd.assert().success();
+
+        Ok(())
+    }
+
+    // Round trip the tokens and silences through the database,
+    // so every table is written and read back when seeding a PostgreSQL backend
+    #[allow(clippy::too_many_lines)]
+    fn tokens_and_silences(&self) -> anyhow::Result<()> {
+        let host = self.url.as_ref();
+        let token = self.token.as_ref();
+
+        // cargo run -- token create --host http://localhost:61016 --token $BENCHER_API_TOKEN --name "Seed Token" muriel-bagge
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "token",
+            "create",
+            HOST_ARG,
+            host,
+            TOKEN_ARG,
+            token,
+            "--name",
+            "Seed Token",
+            USER_SLUG,
+        ])
+        .current_dir(CLI_DIR);
+        let assert = cmd.assert().success();
+        let new_token: bencher_json::JsonToken =
+            serde_json::from_slice(&assert.get_output().stdout).unwrap();
+
+        // cargo run -- token view --host http://localhost:61016 --token $BENCHER_API_TOKEN muriel-bagge [token.uuid]
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "token",
+            "view",
+            HOST_ARG,
+            host,
+            TOKEN_ARG,
+            token,
+            USER_SLUG,
+            new_token.uuid.to_string().as_str(),
+        ])
+        .current_dir(CLI_DIR);
+        let assert = cmd.assert().success();
+        let json: bencher_json::JsonToken =
+            serde_json::from_slice(&assert.get_output().stdout).unwrap();
+        assert_eq!(json.name, new_token.name);
+        assert_eq!(json.expiration, new_token.expiration);
+
+        // cargo run -- silence create --host http://localhost:61016 --token $BENCHER_API_TOKEN --testbed base --measure latency --note "Seed silence" --expires 4102444800 the-computer
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "silence",
+            "create",
+            HOST_ARG,
+            host,
+            TOKEN_ARG,
+            token,
+            TESTBED_ARG,
+            TESTBED_SLUG,
+            MEASURE_ARG,
+            "latency",
+            "--note",
+            "Seed silence",
+            "--expires",
+            "4102444800",
+            PROJECT_SLUG,
+        ])
+        .current_dir(CLI_DIR);
+        let assert = cmd.assert().success();
+        let silence: bencher_json::JsonSilence =
+            serde_json::from_slice(&assert.get_output().stdout).unwrap();
+        let silence_uuid = silence.uuid.to_string();
+
+        // cargo run -- silence view --host http://localhost:61016 --token $BENCHER_API_TOKEN the-computer [silence.uuid]
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "silence",
+            "view",
+            HOST_ARG,
+            host,
+            TOKEN_ARG,
+            token,
+            PROJECT_SLUG,
+            &silence_uuid,
+        ])
+        .current_dir(CLI_DIR);
+        let assert = cmd.assert().success();
+        let json: bencher_json::JsonSilence =
+            serde_json::from_slice(&assert.get_output().stdout).unwrap();
+        assert_eq!(json.note, silence.note);
+        assert_eq!(json.expires, silence.expires);
+        assert_eq!(
+            json.testbed.map(|testbed| testbed.uuid),
+            silence.testbed.map(|testbed| testbed.uuid)
+        );
+
+        // cargo run -- silence delete --host http://localhost:61016 --token $BENCHER_API_TOKEN the-computer [silence.uuid]
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "silence",
+            "delete",
+            HOST_ARG,
+            host,
+            TOKEN_ARG,
+            token,
+            PROJECT_SLUG,
+            &silence_uuid,
+        ])
+        .current_dir(CLI_DIR);
+        cmd.assert().success();
+
+        Ok(())
+    }
+
+    // A project restored from an archive on the same server gets new UUIDs for everything,
+    // but it is otherwise the same project
+    #[allow(clippy::too_many_lines)]
+    fn archive(&self) -> anyhow::Result<()> {
+        let host = self.url.as_ref();
+        let token = self.token.as_ref();
+
+        let archive_path = std::env::temp_dir().join("bencher_seed_test_archive.json");
+        let archive_path_str = archive_path.to_string_lossy().to_string();
+        let archive = self.export(PROJECT_SLUG, &archive_path_str)?;
+
+        // cargo run -- project restore --host http://localhost:61016 --token $BENCHER_API_TOKEN --slug the-computer-restored muriel-bagge [archive_path]
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "project",
+            "restore",
+            HOST_ARG,
+            host,
+            TOKEN_ARG,
+            token,
+            "--slug",
+            RESTORED_PROJECT_SLUG,
+            ORG_SLUG,
+            &archive_path_str,
+        ])
+        .current_dir(CLI_DIR);
+        let assert = cmd.assert().success();
+        let project: bencher_json::JsonProject =
+            serde_json::from_slice(&assert.get_output().stdout).unwrap();
+        assert_eq!(project.slug.as_ref(), RESTORED_PROJECT_SLUG);
+        assert_ne!(project.uuid, archive.project.uuid);
+
+        let restored = self.export(RESTORED_PROJECT_SLUG, &archive_path_str)?;
+        std::fs::remove_file(archive_path)?;
+
+        assert_eq!(
+            restored
+                .branches
+                .iter()
+                .map(|branch| branch.slug.as_ref())
+                .collect::<Vec<_>>(),
+            archive
+                .branches
+                .iter()
+                .map(|branch| branch.slug.as_ref())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            restored
+                .versions
+                .iter()
+                .map(|version| version.number)
+                .collect::<Vec<_>>(),
+            archive
+                .versions
+                .iter()
+                .map(|version| version.number)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(restored.testbeds.len(), archive.testbeds.len());
+        assert_eq!(restored.benchmarks.len(), archive.benchmarks.len());
+        assert_eq!(restored.measures.len(), archive.measures.len());
+        assert_eq!(restored.thresholds.len(), archive.thresholds.len());
+        assert_eq!(restored.reports.len(), archive.reports.len());
+        for (restored_report, report) in restored.reports.iter().zip(&archive.reports) {
+            assert_ne!(restored_report.uuid, report.uuid);
+        }
+
+        // cargo run -- project delete --host http://localhost:61016 --token $BENCHER_API_TOKEN the-computer-restored
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "project",
+            "delete",
+            HOST_ARG,
+            host,
+            TOKEN_ARG,
+            token,
+            RESTORED_PROJECT_SLUG,
+        ])
+        .current_dir(CLI_DIR);
+        cmd.assert().success();
+
         Ok(())
     }
+
+    // cargo run -- project export --host http://localhost:61016 --token $BENCHER_API_TOKEN --output [path] [project]
+    fn export(
+        &self,
+        project: &str,
+        path: &str,
+    ) -> anyhow::Result<bencher_json::JsonProjectArchive> {
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "project",
+            "export",
+            HOST_ARG,
+            self.url.as_ref(),
+            TOKEN_ARG,
+            self.token.as_ref(),
+            "--output",
+            path,
+            project,
+        ])
+        .current_dir(CLI_DIR);
+        cmd.assert().success();
+        Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    // Retention removes the old untagged versions on the protected branch,
+    // but it always keeps the tagged versions
+    #[allow(clippy::too_many_lines)]
+    fn retention(&self) -> anyhow::Result<()> {
+        let host = self.url.as_ref();
+        let token = self.token.as_ref();
+
+        // cargo run -- project create --host http://localhost:61016 --token $BENCHER_API_TOKEN --name "The Retention" --slug the-retention muriel-bagge
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "project",
+            "create",
+            HOST_ARG,
+            host,
+            TOKEN_ARG,
+            token,
+            "--name",
+            "The Retention",
+            "--slug",
+            RETENTION_PROJECT_SLUG,
+            ORG_SLUG,
+        ])
+        .current_dir(CLI_DIR);
+        let assert = cmd.assert().success();
+        let _json: bencher_json::JsonProject =
+            serde_json::from_slice(&assert.get_output().stdout).unwrap();
+
+        // The first report is for a tagged version, and the second one is not
+        let mut reports = Vec::new();
+        for (hash, start_time) in [
+            (
+                Some("5000000000000000000000000000000000000000"),
+                "1970-01-02T00:00:00Z",
+            ),
+            (None, "1970-01-03T00:00:00Z"),
+        ] {
+            // cargo run -- report create --host http://localhost:61016 --token $BENCHER_API_TOKEN --branch main [--hash hash] --testbed localhost --start-time [start_time] --end-time [start_time] --results [results] --adapter json the-retention
+            let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+            cmd.args([
+                "report",
+                "create",
+                HOST_ARG,
+                host,
+                TOKEN_ARG,
+                token,
+                BRANCH_ARG,
+                "main",
+                TESTBED_ARG,
+                "localhost",
+                "--start-time",
+                start_time,
+                "--end-time",
+                start_time,
+                "--results",
+                &bmf_latency(1.0),
+                "--adapter",
+                "json",
+            ]);
+            if let Some(hash) = hash {
+                cmd.args(["--hash", hash]);
+            }
+            cmd.arg(RETENTION_PROJECT_SLUG).current_dir(CLI_DIR);
+            let assert = cmd.assert().success();
+            let report: JsonReport = serde_json::from_slice(&assert.get_output().stdout).unwrap();
+            reports.push(report);
+        }
+
+        // cargo run -- retention set --host http://localhost:61016 --token $BENCHER_API_TOKEN --raw-window 86400 --no-downsample --protected-branch main the-retention
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "retention",
+            "set",
+            HOST_ARG,
+            host,
+            TOKEN_ARG,
+            token,
+            "--raw-window",
+            "86400",
+            "--no-downsample",
+            "--protected-branch",
+            "main",
+            RETENTION_PROJECT_SLUG,
+        ])
+        .current_dir(CLI_DIR);
+        let assert = cmd.assert().success();
+        let _json: bencher_json::JsonRetention =
+            serde_json::from_slice(&assert.get_output().stdout).unwrap();
+
+        // Only the report for the untagged version is subject to retention
+        for (dry_run, metrics) in [(true, 1), (false, 1), (false, 0)] {
+            // cargo run -- retention prune --host http://localhost:61016 --token $BENCHER_API_TOKEN [--dry-run] the-retention
+            let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+            cmd.args(["retention", "prune", HOST_ARG, host, TOKEN_ARG, token]);
+            if dry_run {
+                cmd.arg("--dry-run");
+            }
+            cmd.arg(RETENTION_PROJECT_SLUG).current_dir(CLI_DIR);
+            let assert = cmd.assert().success();
+            let json: bencher_json::JsonRetentionPrune =
+                serde_json::from_slice(&assert.get_output().stdout).unwrap();
+            assert_eq!(json.dry_run, dry_run);
+            assert_eq!(json.metrics, metrics);
+            assert_eq!(json.reports, metrics);
+        }
+
+        // cargo run -- report ls --host http://localhost:61016 --token $BENCHER_API_TOKEN the-retention
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "report",
+            "ls",
+            HOST_ARG,
+            host,
+            TOKEN_ARG,
+            token,
+            RETENTION_PROJECT_SLUG,
+        ])
+        .current_dir(CLI_DIR);
+        let assert = cmd.assert().success();
+        let json: bencher_json::JsonReports =
+            serde_json::from_slice(&assert.get_output().stdout).unwrap();
+        let tagged_report = reports.first().unwrap();
+        assert_eq!(
+            json.0.iter().map(|report| report.uuid).collect::<Vec<_>>(),
+            vec![tagged_report.uuid]
+        );
+
+        // cargo run -- project delete --host http://localhost:61016 --token $BENCHER_API_TOKEN the-retention
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "project",
+            "delete",
+            HOST_ARG,
+            host,
+            TOKEN_ARG,
+            token,
+            RETENTION_PROJECT_SLUG,
+        ])
+        .current_dir(CLI_DIR);
+        cmd.assert().success();
+
+        Ok(())
+    }
+
+    // cargo run -- report create --host http://localhost:61016 --token $BENCHER_API_TOKEN --branch [branch] --hash [hash] --testbed base --start-time [start_time] --end-time [start_time] --results [results] --adapter json the-computer
+    fn create_report(
+        &self,
+        branch: &str,
+        hash: &str,
+        start_time: &str,
+        latency: f64,
+    ) -> anyhow::Result<JsonReport> {
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "report",
+            "create",
+            HOST_ARG,
+            self.url.as_ref(),
+            TOKEN_ARG,
+            self.token.as_ref(),
+            BRANCH_ARG,
+            branch,
+            "--hash",
+            hash,
+            TESTBED_ARG,
+            TESTBED_SLUG,
+            "--start-time",
+            start_time,
+            "--end-time",
+            start_time,
+            "--results",
+            &bmf_latency(latency),
+            "--adapter",
+            "json",
+            PROJECT_SLUG,
+        ])
+        .current_dir(CLI_DIR);
+        let assert = cmd.assert().success();
+        Ok(serde_json::from_slice(&assert.get_output().stdout)?)
+    }
+
+    // The reports for the branch in chronological order
+    // cargo run -- report ls --host http://localhost:61016 --token $BENCHER_API_TOKEN --branch [branch] --direction asc --per-page 255 the-computer
+    fn list_reports(&self, branch: &str) -> anyhow::Result<Vec<JsonReport>> {
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "report",
+            "ls",
+            HOST_ARG,
+            self.url.as_ref(),
+            TOKEN_ARG,
+            self.token.as_ref(),
+            BRANCH_ARG,
+            branch,
+            "--direction",
+            "asc",
+            "--per-page",
+            "255",
+            PROJECT_SLUG,
+        ])
+        .current_dir(CLI_DIR);
+        let assert = cmd.assert().success();
+        let reports: bencher_json::JsonReports =
+            serde_json::from_slice(&assert.get_output().stdout)?;
+        Ok(reports.0)
+    }
+}
+
+// A Bencher Metric Format (BMF) result with a single `latency` value for the `bench` benchmark
+fn bmf_latency(latency: f64) -> String {
+    format!(r#"{{"bench": {{"latency": {{"value": {latency:.1}}}}}}}"#)
+}
+
+// The boundary baseline for the first benchmark in the report
+fn baseline(report: &JsonReport) -> Option<f64> {
+    report
+        .results
+        .first()?
+        .first()?
+        .benchmarks
+        .first()?
+        .boundary
+        .as_ref()?
+        .baseline
+        .map(|baseline| baseline.0)
 }